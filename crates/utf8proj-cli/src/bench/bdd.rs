@@ -207,7 +207,7 @@ pub fn run_bdd_benchmark(
     let bdd_analysis_time = bdd_start.elapsed();
 
     // Heuristic leveling
-    let calendar = project.calendars.first().cloned().unwrap_or_default();
+    let calendar = project.default_calendar();
     let heuristic_start = Instant::now();
     let leveling_result = level_resources(&project, &schedule, &calendar);
     let heuristic_level_time = heuristic_start.elapsed();
@@ -57,7 +57,7 @@ enum Commands {
         #[arg(short, long)]
         quiet: bool,
 
-        /// Show only calendar diagnostics (C001-C023)
+        /// Show only calendar diagnostics (C001-C024)
         #[arg(long)]
         calendars: bool,
 
@@ -125,6 +125,7 @@ enum Commands {
         task_ids: bool,
 
         /// Verbose output: show both task ID and display name
+        /// (JSON: add derived per-task fields)
         #[arg(short = 'V', long)]
         verbose: bool,
 
@@ -132,7 +133,7 @@ enum Commands {
         #[arg(short = 'w', long, default_value = "40")]
         width: usize,
 
-        /// Show only calendar diagnostics (C001-C023)
+        /// Show only calendar diagnostics (C001-C024)
         #[arg(long)]
         calendars: bool,
 
@@ -863,7 +864,7 @@ fn cmd_schedule(
 
     // Apply resource leveling if enabled (RFC-0003: explicit opt-in)
     let (schedule, leveling_diagnostics) = if leveling {
-        let calendar = project.calendars.first().cloned().unwrap_or_default();
+        let calendar = project.default_calendar();
 
         // Parse leveling strategy (RFC-0014)
         let strategy = match leveling_strategy {
@@ -908,7 +909,7 @@ fn cmd_schedule(
 
     // Calculate and emit resource utilization (I003)
     if !project.resources.is_empty() {
-        let calendar = project.calendars.first().cloned().unwrap_or_default();
+        let calendar = project.default_calendar();
         let utilization = calculate_utilization(&project, &schedule, &calendar);
 
         // Build utilization message
@@ -988,6 +989,7 @@ fn cmd_schedule(
                 &schedule,
                 show_progress,
                 task_ids,
+                verbose,
                 &json_emitter,
             )?;
 
@@ -1546,11 +1548,18 @@ fn format_text(
 }
 
 /// Format schedule as JSON with diagnostics included
+///
+/// With `verbose`, each task gains a read-only `derived` object:
+/// - `cost_spread_percent`: ± spread of the task cost range (null without costs)
+/// - `is_behind`: forecast finish is later than baseline finish
+/// - `total_slack_days`: working days the task can slip without delaying the project
+/// - `free_slack_days`: working days the task can slip without delaying a successor
 fn format_json_with_diagnostics(
     project: &utf8proj_core::Project,
     schedule: &utf8proj_core::Schedule,
     show_progress: bool,
     _task_ids: bool, // JSON always includes both id and name
+    verbose: bool,
     json_emitter: &JsonEmitter,
 ) -> Result<String> {
     // Create a summary structure for JSON output
@@ -1602,6 +1611,16 @@ fn format_json_with_diagnostics(
                         "finish_variance_days": t.finish_variance_days,
                     });
                 }
+
+                // Add derived convenience fields if requested
+                if verbose {
+                    task_json["derived"] = serde_json::json!({
+                        "cost_spread_percent": t.cost_range.as_ref().map(|c| c.spread_percent()),
+                        "is_behind": t.finish_variance_days > 0,
+                        "total_slack_days": t.slack.as_days(),
+                        "free_slack_days": t.free_slack.as_days(),
+                    });
+                }
                 task_json
             }).collect::<Vec<_>>(),
         },
@@ -2127,6 +2146,20 @@ fn serialize_task(output: &mut String, task: &utf8proj_core::Task, indent: usize
             utf8proj_core::TaskConstraint::FinishNoLaterThan(date) => {
                 output.push_str(&format!("{}finish_no_later_than: {}\n", inner_indent, date));
             }
+            // No .proj syntax for relative anchors yet; keep them visible
+            utf8proj_core::TaskConstraint::RelativeTo {
+                anchor,
+                offset,
+                from,
+            } => {
+                output.push_str(&format!(
+                    "{}# relative_to: {} {} {:+}d\n",
+                    inner_indent,
+                    anchor,
+                    from,
+                    offset.round_days()
+                ));
+            }
         }
     }
 
@@ -2139,6 +2172,12 @@ fn serialize_task(output: &mut String, task: &utf8proj_core::Task, indent: usize
     if let Some(complete) = task.complete {
         output.push_str(&format!("{}complete: {}%\n", inner_indent, complete as i32));
     }
+    if let Some(physical) = task.physical_complete {
+        output.push_str(&format!(
+            "{}physical_complete: {}%\n",
+            inner_indent, physical as i32
+        ));
+    }
 
     // Remaining duration (explicit override)
     if let Some(ref remaining) = task.explicit_remaining {
@@ -53,11 +53,17 @@ fn run_schedule(fixture: &str, strict: bool) -> String {
 
 /// Run utf8proj schedule with JSON output
 fn run_schedule_json(fixture: &str) -> String {
+    run_schedule_json_with(fixture, &[])
+}
+
+/// Run utf8proj schedule with JSON output and extra arguments
+fn run_schedule_json_with(fixture: &str, args: &[&str]) -> String {
     let input_path = fixtures_dir().join(fixture);
 
     let output = Command::new(utf8proj_binary())
         .arg("schedule")
         .arg("--format=json")
+        .args(args)
         .arg(&input_path)
         .output()
         .expect("failed to execute utf8proj");
@@ -120,6 +126,11 @@ fn w004_approximate_leveling() {
     assert_stderr_matches("w004_approximate_leveling", false);
 }
 
+#[test]
+fn w008_capacity_shortfall() {
+    assert_stderr_matches("w008_capacity_shortfall", false);
+}
+
 // =============================================================================
 // Hint Tests
 // =============================================================================
@@ -144,6 +155,11 @@ fn h004_unconstrained_task() {
     assert_stderr_matches("h004_unconstrained_task", false);
 }
 
+#[test]
+fn h005_disconnected_task() {
+    assert_stderr_matches("h005_disconnected_task", false);
+}
+
 // =============================================================================
 // Error Tests
 // =============================================================================
@@ -198,6 +214,27 @@ fn w001_json_output() {
     );
 }
 
+#[test]
+fn json_verbose_adds_derived_task_fields() {
+    let lean: serde_json::Value =
+        serde_json::from_str(&run_schedule_json("w001_abstract_assignment.proj")).unwrap();
+    assert!(lean["schedule"]["tasks"][0].get("derived").is_none());
+
+    let verbose: serde_json::Value = serde_json::from_str(&run_schedule_json_with(
+        "w001_abstract_assignment.proj",
+        &["--verbose"],
+    ))
+    .unwrap();
+    let derived = &verbose["schedule"]["tasks"][0]["derived"];
+
+    // 100-200/day profile rate: expected 150, ±50 is a 33.3% spread
+    let spread = derived["cost_spread_percent"].as_f64().unwrap();
+    assert!((spread - 100.0 / 3.0).abs() < 0.01);
+    assert_eq!(derived["is_behind"], false);
+    assert_eq!(derived["total_slack_days"], 0.0);
+    assert_eq!(derived["free_slack_days"], 0.0);
+}
+
 // =============================================================================
 // Regression Tests
 // =============================================================================
@@ -30,6 +30,7 @@
 //!     task_id: "design".to_string(),
 //!     start: NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
 //!     finish: NaiveDate::from_ymd_opt(2026, 1, 10).unwrap(),
+//!     cost: None,
 //! });
 //!
 //! let baseline = Baseline {
@@ -121,6 +122,20 @@ impl Baseline {
     pub fn task_count(&self) -> usize {
         self.tasks.len()
     }
+
+    /// Snapshot a schedule's leaf tasks under the given name
+    ///
+    /// Records each leaf's early start/finish and expected cost (see
+    /// [`extract_leaf_tasks`]). The result serializes on its own, so an
+    /// approved plan can be stored and later attached to
+    /// [`Project::baseline`] to measure variance against it.
+    pub fn capture(name: impl Into<String>, schedule: &Schedule, project: &Project) -> Self {
+        let mut baseline = Self::new(name);
+        for (_, snapshot) in extract_leaf_tasks(schedule, project) {
+            baseline.add_task(snapshot);
+        }
+        baseline
+    }
 }
 
 /// Snapshot of a leaf task's scheduled dates (RFC-0013)
@@ -137,6 +152,10 @@ pub struct TaskSnapshot {
 
     /// Scheduled early finish date
     pub finish: NaiveDate,
+
+    /// Expected cost at capture time, when the task was costed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cost: Option<Money>,
 }
 
 impl TaskSnapshot {
@@ -146,8 +165,15 @@ impl TaskSnapshot {
             task_id: task_id.into(),
             start,
             finish,
+            cost: None,
         }
     }
+
+    /// Set the baselined cost
+    pub fn cost(mut self, cost: Money) -> Self {
+        self.cost = Some(cost);
+        self
+    }
 }
 
 /// Collection of baselines for a project (RFC-0013)
@@ -332,6 +358,41 @@ impl TaskVariance {
     }
 }
 
+/// Forecast movement of a milestone against a baseline (RFC-0013)
+///
+/// Produced by `Schedule::milestone_slips` as an executive summary of key
+/// dates, separate from the full task-level comparison.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MilestoneSlip {
+    /// Milestone task identifier
+    pub task_id: String,
+
+    /// Baselined milestone date
+    pub baseline_date: NaiveDate,
+
+    /// Current forecast milestone date
+    pub forecast_date: NaiveDate,
+
+    /// Slip in calendar days (forecast - baseline, positive = later)
+    pub slip_days: i32,
+}
+
+impl MilestoneSlip {
+    /// Create a slip entry, computing the slip from the two dates
+    pub fn new(
+        task_id: impl Into<String>,
+        baseline_date: NaiveDate,
+        forecast_date: NaiveDate,
+    ) -> Self {
+        Self {
+            task_id: task_id.into(),
+            baseline_date,
+            forecast_date,
+            slip_days: (forecast_date - baseline_date).num_days() as i32,
+        }
+    }
+}
+
 /// Classification of task variance status (RFC-0013)
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum VarianceStatus {
@@ -456,7 +517,7 @@ impl std::error::Error for BaselineError {}
 // Comparison Functions
 // ============================================================================
 
-use crate::{Project, Schedule, Task};
+use crate::{Money, Project, Schedule, Task};
 
 /// Compare a current schedule against a baseline (RFC-0013)
 ///
@@ -573,8 +634,8 @@ fn collect_containers_recursive(
 
 /// Extract leaf tasks from a schedule for baseline creation
 ///
-/// Returns a mapping of fully-qualified task IDs to `TaskSnapshot`s.
-/// Container tasks are excluded.
+/// Returns a mapping of fully-qualified task IDs to `TaskSnapshot`s, with
+/// each task's expected cost when it has one. Container tasks are excluded.
 pub fn extract_leaf_tasks(
     schedule: &Schedule,
     project: &Project,
@@ -586,11 +647,17 @@ pub fn extract_leaf_tasks(
         if !container_ids.contains(task_id) {
             tasks.insert(
                 task_id.clone(),
-                TaskSnapshot::new(
-                    task_id,
-                    scheduled_task.early_start,
-                    scheduled_task.early_finish,
-                ),
+                TaskSnapshot {
+                    cost: scheduled_task
+                        .cost_range
+                        .as_ref()
+                        .map(|range| Money::new(range.expected, range.currency.clone())),
+                    ..TaskSnapshot::new(
+                        task_id,
+                        scheduled_task.early_start,
+                        scheduled_task.early_finish,
+                    )
+                },
             );
         }
     }
@@ -0,0 +1,200 @@
+//! Renderer-independent Gantt chart model
+//!
+//! [`Schedule::bars`] turns a schedule into one [`GanttBar`] per task with
+//! its row, nesting depth and kind already worked out, so chart renderers
+//! and third-party UIs draw from the same data instead of re-deriving it.
+//! [`ResourceConflictPeriod`] describes resource clashes to overlay on them,
+//! and [`Schedule::dependency_lines`] the arrows to draw between them.
+
+use crate::graph::{flatten_tasks_with_context, resolve_dependency_path};
+use crate::{DependencyType, Project, ResourceId, Schedule, TaskId};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// How a bar is drawn
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GanttBarKind {
+    /// Leaf task with work
+    Task,
+    /// Container spanning its children
+    Summary,
+    /// Milestone, drawn as a point in time
+    Milestone,
+}
+
+/// Everything a chart needs to draw one task
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GanttBar {
+    /// Qualified task ID ("phase.task")
+    pub task_id: TaskId,
+    /// Row index from the top of the chart (0-based)
+    pub row: usize,
+    /// Nesting depth from the qualified ID (0 = top level)
+    pub depth: usize,
+    /// First day of the bar
+    pub start: NaiveDate,
+    /// Last day of the bar (inclusive)
+    pub finish: NaiveDate,
+    /// Progress fill (0-100)
+    pub progress: u8,
+    /// Whether the task is on the critical path
+    pub is_critical: bool,
+    /// Whether the task is a milestone
+    pub is_milestone: bool,
+    /// How the bar is drawn
+    pub kind: GanttBarKind,
+}
+
+/// A window in which a resource is asked for more than it can give
+///
+/// Overlay geometry for shading conflicts on the chart; `task_rows` are
+/// the rows of the contending tasks in [`Schedule::bars`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ResourceConflictPeriod {
+    /// Over-allocated resource
+    pub resource_id: ResourceId,
+    /// First over-allocated day
+    pub start: NaiveDate,
+    /// Last over-allocated day (inclusive)
+    pub finish: NaiveDate,
+    /// Highest daily demand in the window (1.0 = one full-time unit)
+    pub peak_units: f32,
+    /// Highest daily demand above that day's capacity
+    pub excess_units: f32,
+    /// Tasks drawing on the resource in the window, sorted
+    pub task_ids: Vec<TaskId>,
+    /// Bar rows of `task_ids`, in the same order
+    pub task_rows: Vec<usize>,
+}
+
+/// Which end of a bar a dependency line attaches to
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BarEnd {
+    Start,
+    Finish,
+}
+
+impl BarEnd {
+    /// Endpoints joined by a dependency of `dep_type`, predecessor first
+    ///
+    /// FS runs from the predecessor's finish to the successor's start, SS
+    /// joins the starts, FF the finishes and SF runs start to finish.
+    pub fn for_dependency(dep_type: DependencyType) -> (BarEnd, BarEnd) {
+        match dep_type {
+            DependencyType::FinishToStart => (BarEnd::Finish, BarEnd::Start),
+            DependencyType::StartToStart => (BarEnd::Start, BarEnd::Start),
+            DependencyType::FinishToFinish => (BarEnd::Finish, BarEnd::Finish),
+            DependencyType::StartToFinish => (BarEnd::Start, BarEnd::Finish),
+        }
+    }
+}
+
+/// An arrow from a predecessor's bar to its successor's
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DependencyLine {
+    /// Predecessor task ID
+    pub from_task: TaskId,
+    /// Successor task ID
+    pub to_task: TaskId,
+    /// Predecessor's row in [`Schedule::bars`]
+    pub from_row: usize,
+    /// Successor's row in [`Schedule::bars`]
+    pub to_row: usize,
+    /// End of the predecessor's bar the line leaves from
+    pub from_end: BarEnd,
+    /// End of the successor's bar the line arrives at
+    pub to_end: BarEnd,
+    /// Dependency the line draws
+    pub dep_type: DependencyType,
+    /// Whether both tasks are on the critical path
+    pub is_critical: bool,
+}
+
+impl Schedule {
+    /// One bar per scheduled task, in row order
+    ///
+    /// Rows run by start date with the task ID breaking ties, so a container
+    /// sits directly above children that start with it. Dependencies are not
+    /// consulted: a predecessor that starts after its successor (say across a
+    /// finish-to-finish link) gets the lower row.
+    pub fn bars(&self) -> Vec<GanttBar> {
+        let mut tasks: Vec<_> = self.tasks.values().collect();
+        tasks.sort_by(|a, b| {
+            a.start
+                .cmp(&b.start)
+                .then_with(|| a.task_id.cmp(&b.task_id))
+        });
+
+        tasks
+            .into_iter()
+            .enumerate()
+            .map(|(row, task)| {
+                let kind = if self.has_children(&task.task_id) {
+                    GanttBarKind::Summary
+                } else if task.is_milestone {
+                    GanttBarKind::Milestone
+                } else {
+                    GanttBarKind::Task
+                };
+                GanttBar {
+                    task_id: task.task_id.clone(),
+                    row,
+                    depth: task.task_id.matches('.').count(),
+                    start: task.start,
+                    finish: task.finish,
+                    progress: task.percent_complete,
+                    is_critical: task.is_critical,
+                    is_milestone: task.is_milestone,
+                    kind,
+                }
+            })
+            .collect()
+    }
+
+    /// One line per dependency between scheduled tasks, for drawing arrows
+    ///
+    /// Dependencies are resolved against `project` the way the solver
+    /// resolves them; ones that do not resolve to a scheduled task are
+    /// left out. Lines are sorted by successor row, then predecessor row.
+    pub fn dependency_lines(&self, project: &Project) -> Vec<DependencyLine> {
+        let rows: HashMap<TaskId, usize> = self
+            .bars()
+            .into_iter()
+            .map(|bar| (bar.task_id, bar.row))
+            .collect();
+        let (task_map, context_map) = flatten_tasks_with_context(&project.tasks);
+
+        let mut lines = Vec::new();
+        for (id, task) in &task_map {
+            let Some(&to_row) = rows.get(id) else {
+                continue;
+            };
+            for dep in &task.depends {
+                let Some(pred_id) =
+                    resolve_dependency_path(&dep.predecessor, id, &context_map, &task_map)
+                else {
+                    continue;
+                };
+                let Some(&from_row) = rows.get(&pred_id) else {
+                    continue;
+                };
+                let (from_end, to_end) = BarEnd::for_dependency(dep.dep_type);
+                lines.push(DependencyLine {
+                    is_critical: self.tasks[&pred_id].is_critical && self.tasks[id].is_critical,
+                    from_task: pred_id,
+                    to_task: id.clone(),
+                    from_row,
+                    to_row,
+                    from_end,
+                    to_end,
+                    dep_type: dep.dep_type,
+                });
+            }
+        }
+        lines.sort_by_key(|line| (line.to_row, line.from_row));
+        lines
+    }
+}
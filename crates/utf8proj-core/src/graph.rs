@@ -0,0 +1,299 @@
+//! Task dependency graph
+//!
+//! Flattening, dependency path resolution and topological ordering over a
+//! project's task tree. The solver schedules tasks in the order produced
+//! here, so [`Project::topological_order`] reports exactly the sequence the
+//! forward pass will visit.
+//!
+//! Nested tasks are addressed by qualified ids (`"phase1.act1"`). A
+//! dependency written without a dot is first tried as an absolute id and
+//! then resolved against the depending task's container, so `act1` inside
+//! `phase1` refers to `phase1.act1`.
+
+use crate::{Project, ScheduleError, Task, TaskId};
+use std::collections::{HashMap, VecDeque};
+
+/// Result of a topological sort, including the precomputed successor map
+#[derive(Clone, Debug, Default)]
+pub struct TopoSortResult {
+    /// Qualified task ids in topological order
+    pub sorted_ids: Vec<TaskId>,
+    /// Map from task id to the tasks that depend on it (explicit edges only)
+    pub successors: HashMap<TaskId, Vec<TaskId>>,
+}
+
+impl Project {
+    /// Qualified task ids in the order the solver schedules them
+    ///
+    /// Every task comes after its resolved predecessors, and every container
+    /// comes after its children. Dependencies that do not resolve to a task
+    /// are ignored, as they are when scheduling.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ScheduleError::CircularDependency`] if the dependencies
+    /// form a cycle.
+    pub fn topological_order(&self) -> Result<Vec<TaskId>, ScheduleError> {
+        let (task_map, context_map) = flatten_tasks_with_context(&self.tasks);
+        topological_sort(&task_map, &context_map).map(|result| result.sorted_ids)
+    }
+}
+
+/// Flatten the hierarchical task tree into a HashMap with qualified IDs
+///
+/// For nested tasks like:
+///   phase1 { act1 { sub1 } }
+///
+/// This produces:
+///   "phase1" -> phase1
+///   "phase1.act1" -> act1
+///   "phase1.act1.sub1" -> sub1
+///
+/// Also builds a context map for resolving relative dependencies:
+///   "phase1.act1" -> "phase1" (parent context for resolving siblings)
+fn flatten_tasks_with_prefix<'a>(
+    tasks: &'a [Task],
+    prefix: &str,
+    map: &mut HashMap<String, &'a Task>,
+    context_map: &mut HashMap<String, String>,
+) {
+    for task in tasks {
+        let qualified_id = if prefix.is_empty() {
+            task.id.clone()
+        } else {
+            format!("{}.{}", prefix, task.id)
+        };
+
+        map.insert(qualified_id.clone(), task);
+        context_map.insert(qualified_id.clone(), prefix.to_string());
+
+        if !task.children.is_empty() {
+            flatten_tasks_with_prefix(&task.children, &qualified_id, map, context_map);
+        }
+    }
+}
+
+/// Flatten tasks and return both the task map and context map for dependency resolution
+pub fn flatten_tasks_with_context(
+    tasks: &[Task],
+) -> (HashMap<String, &Task>, HashMap<String, String>) {
+    let mut task_map = HashMap::new();
+    let mut context_map = HashMap::new();
+    flatten_tasks_with_prefix(tasks, "", &mut task_map, &mut context_map);
+    (task_map, context_map)
+}
+
+/// Build a map from parent qualified ID to list of direct children qualified IDs
+#[allow(clippy::implicit_hasher)]
+pub fn build_children_map(task_map: &HashMap<String, &Task>) -> HashMap<String, Vec<String>> {
+    let mut children_map: HashMap<String, Vec<String>> = HashMap::new();
+
+    for qualified_id in task_map.keys() {
+        // Find the parent by removing the last component
+        if let Some(dot_pos) = qualified_id.rfind('.') {
+            let parent_id = &qualified_id[..dot_pos];
+            children_map
+                .entry(parent_id.to_string())
+                .or_default()
+                .push(qualified_id.clone());
+        }
+    }
+
+    children_map
+}
+
+/// Resolve a dependency path to a qualified task ID
+///
+/// Handles:
+/// - Absolute paths: "phase1.act1" -> "phase1.act1"
+/// - Relative paths: "act1" (from phase1.act2) -> "phase1.act1"
+#[allow(clippy::implicit_hasher)]
+pub fn resolve_dependency_path(
+    dep_path: &str,
+    from_qualified_id: &str,
+    context_map: &HashMap<String, String>,
+    task_map: &HashMap<String, &Task>,
+) -> Option<String> {
+    // First, try as absolute path
+    if task_map.contains_key(dep_path) {
+        return Some(dep_path.to_string());
+    }
+
+    // If path contains a dot, it's meant to be absolute - don't try relative resolution
+    if dep_path.contains('.') {
+        return None;
+    }
+
+    // Try relative resolution: look in the same container
+    if let Some(container) = context_map.get(from_qualified_id) {
+        let qualified = if container.is_empty() {
+            dep_path.to_string()
+        } else {
+            format!("{}.{}", container, dep_path)
+        };
+
+        if task_map.contains_key(&qualified) {
+            return Some(qualified);
+        }
+    }
+
+    None
+}
+
+/// Perform topological sort using Kahn's algorithm
+/// Returns sorted task IDs and a precomputed successors map
+///
+/// This ensures:
+/// 1. Tasks come after their dependencies (explicit edges)
+/// 2. Container tasks come after their children (implicit edges)
+#[allow(clippy::implicit_hasher)]
+pub fn topological_sort(
+    tasks: &HashMap<String, &Task>,
+    context_map: &HashMap<String, String>,
+) -> Result<TopoSortResult, ScheduleError> {
+    // Build children map for container handling
+    let children_map = build_children_map(tasks);
+
+    // Build adjacency list, in-degree count, and successors map
+    let mut in_degree: HashMap<String, usize> = HashMap::new();
+    let mut adjacency: HashMap<String, Vec<String>> = HashMap::new();
+    let mut successors: HashMap<String, Vec<String>> = HashMap::new();
+
+    // Initialize all tasks with 0 in-degree and empty successors
+    for id in tasks.keys() {
+        in_degree.insert(id.clone(), 0);
+        adjacency.insert(id.clone(), Vec::new());
+        successors.insert(id.clone(), Vec::new());
+    }
+
+    // Add implicit edges: children -> container (container comes after children)
+    for (container_id, children) in &children_map {
+        for child_id in children {
+            // child -> container edge
+            if let Some(adj) = adjacency.get_mut(child_id) {
+                adj.push(container_id.clone());
+            }
+            if let Some(deg) = in_degree.get_mut(container_id) {
+                *deg += 1;
+            }
+            // Note: container is not a "real" successor for backward pass
+        }
+    }
+
+    // Build the graph with resolved dependency paths
+    for (qualified_id, task) in tasks {
+        for dep in &task.depends {
+            // Resolve the dependency path (handles both absolute and relative)
+            let resolved =
+                resolve_dependency_path(&dep.predecessor, qualified_id, context_map, tasks);
+
+            if let Some(pred_id) = resolved {
+                // pred_id -> qualified_id (predecessor must come before this task)
+                if let Some(adj) = adjacency.get_mut(&pred_id) {
+                    adj.push(qualified_id.clone());
+                }
+                if let Some(deg) = in_degree.get_mut(qualified_id) {
+                    *deg += 1;
+                }
+                // Build successors map: pred_id has qualified_id as successor
+                if let Some(succ) = successors.get_mut(&pred_id) {
+                    succ.push(qualified_id.clone());
+                }
+            }
+            // If dependency can't be resolved, we skip it (might be external or error)
+        }
+    }
+
+    // Kahn's algorithm
+    let mut queue: VecDeque<String> = VecDeque::new();
+    let mut result: Vec<String> = Vec::new();
+
+    // Start with tasks that have no dependencies
+    for (id, &degree) in &in_degree {
+        if degree == 0 {
+            queue.push_back(id.clone());
+        }
+    }
+
+    while let Some(id) = queue.pop_front() {
+        result.push(id.clone());
+
+        if let Some(successors) = adjacency.get(&id) {
+            for successor in successors {
+                if let Some(deg) = in_degree.get_mut(successor) {
+                    *deg -= 1;
+                    if *deg == 0 {
+                        queue.push_back(successor.clone());
+                    }
+                }
+            }
+        }
+    }
+
+    // Check for cycles
+    if result.len() != tasks.len() {
+        let remaining: Vec<_> = tasks
+            .keys()
+            .filter(|id| !result.contains(id))
+            .cloned()
+            .collect();
+        return Err(ScheduleError::CircularDependency(format!(
+            "Cycle detected involving tasks: {:?}",
+            remaining
+        )));
+    }
+
+    Ok(TopoSortResult {
+        sorted_ids: result,
+        successors,
+    })
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// design -> phase1 { build -> test } -> release, with a relative dependency
+    fn chain_project() -> Project {
+        let mut project = Project::new("Chain");
+        project.tasks = vec![
+            Task::new("release").depends_on("phase1"),
+            Task::new("phase1")
+                .child(Task::new("test").depends_on("build"))
+                .child(Task::new("build").depends_on("design")),
+            Task::new("design"),
+        ];
+        project
+    }
+
+    #[test]
+    fn topological_order_follows_a_known_chain() {
+        let order = chain_project().topological_order().unwrap();
+
+        assert_eq!(
+            order,
+            vec!["design", "phase1.build", "phase1.test", "phase1", "release"]
+        );
+    }
+
+    #[test]
+    fn topological_order_ignores_unresolved_dependencies() {
+        let mut project = Project::new("External");
+        project.tasks = vec![Task::new("a").depends_on("other.project.task")];
+
+        assert_eq!(project.topological_order().unwrap(), vec!["a"]);
+    }
+
+    #[test]
+    fn topological_order_reports_cycles() {
+        let mut project = chain_project();
+        project.tasks[2] = Task::new("design").depends_on("release");
+
+        let err = project.topological_order().unwrap_err();
+        assert!(matches!(err, ScheduleError::CircularDependency(_)));
+    }
+}
@@ -27,7 +27,7 @@
 //! project.resources.push(Resource::new("dev").capacity(1.0));
 //! ```
 
-use chrono::{Datelike, NaiveDate};
+use chrono::{Datelike, NaiveDate, NaiveTime, Weekday};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -38,7 +38,12 @@ use thiserror::Error;
 // ============================================================================
 
 pub mod baseline;
+pub mod gantt;
+pub mod graph;
+pub mod query;
+pub mod refinement;
 pub mod status;
+pub mod verify;
 
 // ============================================================================
 // Type Aliases
@@ -59,8 +64,46 @@ pub type ProfileId = String;
 /// Unique identifier for a trait
 pub type TraitId = String;
 
+// ============================================================================
+// Serde Defaults
+// ============================================================================
+//
+// Model fields at their default value are left out when serializing and
+// filled back in when reading, so saved projects only carry what was set.
+
+fn is_default<T: Default + PartialEq>(value: &T) -> bool {
+    *value == T::default()
+}
+
+fn default_one() -> f32 {
+    1.0
+}
+
+fn is_one(value: &f32) -> bool {
+    *value == 1.0
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn is_true(value: &bool) -> bool {
+    *value
+}
+
+fn default_priority() -> u32 {
+    500
+}
+
+fn is_default_priority(value: &u32) -> bool {
+    *value == default_priority()
+}
+
 /// Duration in working time
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// Ordered by length, so durations sort directly and compare with `<`;
+/// `Ord` also provides `max`, `min` and `clamp`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct Duration {
     /// Number of minutes
     pub minutes: i64,
@@ -95,9 +138,59 @@ impl Duration {
         self.minutes as f64 / (8.0 * 60.0)
     }
 
+    /// Whole working days, rounded to the nearest day (halves away from zero)
+    ///
+    /// Scheduling is day-granular, so sub-day amounts such as a 4-hour lag
+    /// are rounded rather than truncated: `+4h` becomes one day and `-4h`
+    /// minus one day, instead of both vanishing.
+    pub fn round_days(&self) -> i64 {
+        self.as_days().round() as i64
+    }
+
     pub fn as_hours(&self) -> f64 {
         self.minutes as f64 / 60.0
     }
+
+    /// Add, clamping at the `i64` minute bounds instead of overflowing
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        Self {
+            minutes: self.minutes.saturating_add(rhs.minutes),
+        }
+    }
+
+    /// Total of many durations, saturating instead of overflowing
+    ///
+    /// The `+` operator panics on overflow in debug builds and wraps in
+    /// release; use this for large aggregations such as project effort.
+    pub fn sum(iter: impl IntoIterator<Item = Duration>) -> Self {
+        Self::sum_with_overflow(iter).0
+    }
+
+    /// Saturating total, plus whether any step overflowed
+    ///
+    /// When the flag is set the total is clamped at `i64::MAX` or
+    /// `i64::MIN` minutes and no longer the true sum.
+    pub fn sum_with_overflow(iter: impl IntoIterator<Item = Duration>) -> (Self, bool) {
+        let mut overflowed = false;
+        let mut total = Self::zero();
+        for duration in iter {
+            overflowed |= total.minutes.checked_add(duration.minutes).is_none();
+            total = total.saturating_add(duration);
+        }
+        (total, overflowed)
+    }
+}
+
+impl std::iter::Sum for Duration {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Duration::sum(iter)
+    }
+}
+
+impl<'a> std::iter::Sum<&'a Duration> for Duration {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        Duration::sum(iter.copied())
+    }
 }
 
 impl std::ops::Add for Duration {
@@ -132,6 +225,104 @@ impl Money {
             currency: currency.into(),
         }
     }
+
+    /// Render the amount with its currency and thousands separators
+    ///
+    /// ```
+    /// use utf8proj_core::{CurrencyFormat, Money};
+    ///
+    /// let fee = Money::new(rust_decimal::Decimal::new(123456, 2), "EUR");
+    /// assert_eq!(fee.format(&CurrencyFormat::default()), "€1,234.56");
+    /// assert_eq!(fee.format(&CurrencyFormat::code()), "EUR 1,234.56");
+    /// ```
+    pub fn format(&self, format: &CurrencyFormat) -> String {
+        format.render(self.amount, &self.currency)
+    }
+}
+
+/// How monetary amounts are rendered for people
+///
+/// Known currencies use their symbol by default (`$1,234.56`); unknown
+/// ones, or every currency when symbols are off, are prefixed with their
+/// ISO code (`CHF 1,234.56`). A minus sign leads (`-$999.50`).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CurrencyFormat {
+    /// Prefer the currency symbol over its ISO code
+    pub use_symbol: bool,
+    /// Digits after the decimal point
+    pub decimal_places: u32,
+}
+
+impl Default for CurrencyFormat {
+    fn default() -> Self {
+        Self {
+            use_symbol: true,
+            decimal_places: 2,
+        }
+    }
+}
+
+impl CurrencyFormat {
+    /// Always prefix amounts with the ISO code
+    pub fn code() -> Self {
+        Self {
+            use_symbol: false,
+            ..Self::default()
+        }
+    }
+
+    /// Set the digits after the decimal point (builder pattern)
+    pub fn decimal_places(mut self, places: u32) -> Self {
+        self.decimal_places = places;
+        self
+    }
+
+    /// Render `amount` in `currency`
+    pub fn render(&self, amount: Decimal, currency: &str) -> String {
+        let rounded = amount.round_dp_with_strategy(
+            self.decimal_places,
+            rust_decimal::RoundingStrategy::MidpointAwayFromZero,
+        );
+        let digits = format!("{:.*}", self.decimal_places as usize, rounded.abs());
+        let (whole, fraction) = match digits.split_once('.') {
+            Some((whole, fraction)) => (whole, Some(fraction)),
+            None => (digits.as_str(), None),
+        };
+
+        let sign = if rounded.is_sign_negative() && !rounded.is_zero() {
+            "-"
+        } else {
+            ""
+        };
+        let mut number = String::new();
+        for (i, digit) in whole.chars().enumerate() {
+            if i > 0 && (whole.len() - i) % 3 == 0 {
+                number.push(',');
+            }
+            number.push(digit);
+        }
+        if let Some(fraction) = fraction {
+            number.push('.');
+            number.push_str(fraction);
+        }
+
+        match currency_symbol(currency).filter(|_| self.use_symbol) {
+            Some(symbol) => format!("{}{}{}", sign, symbol, number),
+            None => format!("{}{} {}", sign, currency, number),
+        }
+    }
+}
+
+/// Symbol for the ISO currency codes that have an unambiguous one
+fn currency_symbol(code: &str) -> Option<&'static str> {
+    match code {
+        "USD" => Some("$"),
+        "EUR" => Some("€"),
+        "GBP" => Some("£"),
+        "JPY" => Some("¥"),
+        "INR" => Some("₹"),
+        _ => None,
+    }
 }
 
 // ============================================================================
@@ -315,18 +506,25 @@ pub struct ResourceProfile {
     /// Human-readable name
     pub name: String,
     /// Optional description
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     /// Parent profile (constraint refinement, not OO inheritance)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub specializes: Option<ProfileId>,
     /// Required skills
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub skills: Vec<String>,
     /// Applied traits (rate modifiers)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub traits: Vec<TraitId>,
     /// Rate (can be range or fixed)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub rate: Option<ResourceRate>,
     /// Custom calendar
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub calendar: Option<CalendarId>,
     /// Efficiency factor
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub efficiency: Option<f32>,
 }
 
@@ -437,6 +635,9 @@ pub struct CostRange {
     pub max: Decimal,
     /// Currency
     pub currency: String,
+    /// Policy that produced `expected`
+    #[serde(default)]
+    pub policy: CostPolicy,
 }
 
 impl CostRange {
@@ -447,9 +648,16 @@ impl CostRange {
             expected,
             max,
             currency: currency.into(),
+            policy: CostPolicy::default(),
         }
     }
 
+    /// Record the policy that produced `expected`
+    pub fn with_policy(mut self, policy: CostPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
     /// Create a fixed (zero-spread) cost range
     pub fn fixed(amount: Decimal, currency: impl Into<String>) -> Self {
         Self {
@@ -457,6 +665,7 @@ impl CostRange {
             expected: amount,
             max: amount,
             currency: currency.into(),
+            policy: CostPolicy::default(),
         }
     }
 
@@ -475,13 +684,45 @@ impl CostRange {
         self.min == self.max
     }
 
-    /// Add two cost ranges
+    /// Render as `min - max (expected: x)`, or the single amount when fixed
+    pub fn format(&self, format: &CurrencyFormat) -> String {
+        let amount = |value| format.render(value, &self.currency);
+        if self.is_fixed() {
+            amount(self.expected)
+        } else {
+            format!(
+                "{} - {} (expected: {})",
+                amount(self.min),
+                amount(self.max),
+                amount(self.expected)
+            )
+        }
+    }
+
+    /// Expected cost as `policy` would have computed it
+    ///
+    /// Returns `expected` for the policy that produced it. Otherwise reads
+    /// the range: the midpoint, `min` or `max`. A `Triangular` policy that
+    /// did not produce the range draws once over the whole range, which is
+    /// not the sum of per-assignment draws a schedule would give.
+    pub fn expected_under(&self, policy: CostPolicy) -> Decimal {
+        if policy == self.policy {
+            return self.expected;
+        }
+        policy.expected(self.min, self.max)
+    }
+
+    /// Add two cost ranges (the result keeps this range's policy)
+    ///
+    /// Amounts are added as they are and the result keeps this range's
+    /// currency; check that the currencies match before adding.
     pub fn add(&self, other: &CostRange) -> Self {
         Self {
             min: self.min + other.min,
             expected: self.expected + other.expected,
             max: self.max + other.max,
             currency: self.currency.clone(),
+            policy: self.policy,
         }
     }
 }
@@ -498,6 +739,18 @@ pub enum LevelingMode {
     Optimal,
 }
 
+/// How leaf tasks are weighted when rolling progress up to the project
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ProgressWeighting {
+    /// Weight by scheduled duration in days
+    #[default]
+    ByDuration,
+    /// Weight by effort (tasks without effort fall back to their duration)
+    ByEffort,
+    /// Weight by expected cost (tasks without a cost carry no weight)
+    ByCost,
+}
+
 /// Policy for calculating expected cost from ranges (RFC-0001)
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub enum CostPolicy {
@@ -508,19 +761,61 @@ pub enum CostPolicy {
     Optimistic,
     /// Use maximum (pessimistic)
     Pessimistic,
+    /// Use a reproducible draw from a triangular distribution (mode at the
+    /// midpoint), keyed by the seed and the assignment (task + resource).
+    /// The same inputs always yield the same cost.
+    Triangular { seed: u64 },
 }
 
 impl CostPolicy {
     /// Calculate expected value from a range
+    ///
+    /// `Triangular` draws with an empty key; use [`CostPolicy::expected_for`]
+    /// to vary the draw per assignment.
     pub fn expected(&self, min: Decimal, max: Decimal) -> Decimal {
+        self.expected_for(min, max, "")
+    }
+
+    /// Calculate expected value from a range for the assignment identified
+    /// by `key` (only `Triangular` uses the key)
+    pub fn expected_for(&self, min: Decimal, max: Decimal, key: &str) -> Decimal {
         match self {
             CostPolicy::Midpoint => (min + max) / Decimal::from(2),
             CostPolicy::Optimistic => min,
             CostPolicy::Pessimistic => max,
+            CostPolicy::Triangular { seed } => {
+                use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+                let (lo, hi) = (min.to_f64().unwrap_or(0.0), max.to_f64().unwrap_or(0.0));
+                if hi <= lo {
+                    return min;
+                }
+                // Inverse CDF of the symmetric triangular distribution
+                let u = stable_unit_draw(*seed, key);
+                let x = if u < 0.5 {
+                    lo + (hi - lo) * (u / 2.0).sqrt()
+                } else {
+                    hi - (hi - lo) * ((1.0 - u) / 2.0).sqrt()
+                };
+                Decimal::from_f64(x).map_or(min, |d| d.round_dp(2))
+            }
         }
     }
 }
 
+/// Map a seed and key to [0, 1) with FNV-1a, which (unlike std's hasher) is
+/// stable across Rust releases and platforms
+fn stable_unit_draw(seed: u64, key: &str) -> f64 {
+    const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+    const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+    let mut hash = FNV_OFFSET;
+    for byte in seed.to_le_bytes().iter().chain(key.as_bytes()) {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+    // Top 53 bits give a uniformly spaced f64 in [0, 1)
+    (hash >> 11) as f64 / (1u64 << 53) as f64
+}
+
 // ============================================================================
 // Project
 // ============================================================================
@@ -535,14 +830,20 @@ pub struct Project {
     /// Project start date
     pub start: NaiveDate,
     /// Project end date (optional, can be computed)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub end: Option<NaiveDate>,
     /// Status date for progress-aware scheduling (RFC-0004)
     /// When set, remaining work schedules from this date
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status_date: Option<NaiveDate>,
     /// Default calendar for the project
     pub calendar: CalendarId,
     /// Currency for cost calculations
     pub currency: String,
+    /// IANA time zone the project's dates are in (e.g. "Europe/Rome");
+    /// `None` leaves dates floating
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
     /// All tasks in the project (may be hierarchical)
     pub tasks: Vec<Task>,
     /// All resources available to the project
@@ -550,25 +851,42 @@ pub struct Project {
     /// Calendar definitions
     pub calendars: Vec<Calendar>,
     /// Scenario definitions (for what-if analysis)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub scenarios: Vec<Scenario>,
-    /// Custom attributes (timezone, etc.)
+    /// Custom attributes
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     pub attributes: HashMap<String, String>,
 
     // RFC-0001: Progressive Resource Refinement fields
     /// Resource profiles (abstract roles/capabilities)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub profiles: Vec<ResourceProfile>,
     /// Trait definitions (rate modifiers)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub traits: Vec<Trait>,
     /// Policy for calculating expected cost from ranges
+    #[serde(default, skip_serializing_if = "is_default")]
     pub cost_policy: CostPolicy,
+    /// How leaf task progress is weighted into project progress
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub progress_weighting: ProgressWeighting,
 
     // RFC-0014 Phase 3: Optimal Leveling Configuration
     /// Leveling mode: optimal, heuristic, or none
+    #[serde(default, skip_serializing_if = "is_default")]
     pub leveling_mode: LevelingMode,
     /// Maximum cluster size for optimal CP solver (tasks)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub optimal_threshold: Option<usize>,
     /// Timeout per cluster solve (milliseconds)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub optimal_timeout_ms: Option<u64>,
+
+    /// Approved plan to measure variance against (RFC-0013). When set,
+    /// scheduled tasks take their baseline dates from it instead of from
+    /// the progress-free plan.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub baseline: Option<baseline::Baseline>,
 }
 
 impl Project {
@@ -582,6 +900,7 @@ impl Project {
             status_date: None,
             calendar: "default".into(),
             currency: "USD".into(),
+            timezone: None,
             tasks: Vec::new(),
             resources: Vec::new(),
             calendars: vec![Calendar::default()],
@@ -590,9 +909,11 @@ impl Project {
             profiles: Vec::new(),
             traits: Vec::new(),
             cost_policy: CostPolicy::default(),
+            progress_weighting: ProgressWeighting::default(),
             leveling_mode: LevelingMode::default(),
             optimal_threshold: None,
             optimal_timeout_ms: None,
+            baseline: None,
         }
     }
 
@@ -612,6 +933,81 @@ impl Project {
         find_task(&self.tasks, id)
     }
 
+    /// Get a mutable task by ID (searches nested tasks)
+    pub fn get_task_mut(&mut self, id: &str) -> Option<&mut Task> {
+        fn find_task<'a>(tasks: &'a mut [Task], id: &str) -> Option<&'a mut Task> {
+            for task in tasks {
+                if task.id == id {
+                    return Some(task);
+                }
+                if let Some(found) = find_task(&mut task.children, id) {
+                    return Some(found);
+                }
+            }
+            None
+        }
+        find_task(&mut self.tasks, id)
+    }
+
+    /// Get a task by its fully-qualified ID (e.g. `"phase1.design"`), as
+    /// used for schedule keys
+    pub fn get_task_by_path(&self, path: &str) -> Option<&Task> {
+        let mut segments = path.split('.');
+        let first = segments.next()?;
+        let mut task = self.tasks.iter().find(|t| t.id == first)?;
+        for segment in segments {
+            task = task.children.iter().find(|t| t.id == segment)?;
+        }
+        Some(task)
+    }
+
+    /// Calendar the project schedules on: the named project calendar, else
+    /// the first defined calendar, else the standard calendar
+    pub fn default_calendar(&self) -> Calendar {
+        self.calendars
+            .iter()
+            .find(|c| c.id == self.calendar)
+            .or_else(|| self.calendars.first())
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Get a mutable task by its fully-qualified ID (e.g. `"phase1.design"`)
+    pub fn get_task_by_path_mut(&mut self, path: &str) -> Option<&mut Task> {
+        let mut segments = path.split('.');
+        let first = segments.next()?;
+        let mut task = self.tasks.iter_mut().find(|t| t.id == first)?;
+        for segment in segments {
+            task = task.children.iter_mut().find(|t| t.id == segment)?;
+        }
+        Some(task)
+    }
+
+    /// A task ID of the form `{prefix}_{n}` that no task in the tree uses
+    ///
+    /// Scans tasks at every depth and returns one past the highest `n`
+    /// already taken for `prefix`, so generated IDs are sequential,
+    /// deterministic for a given project and never reuse a number. The
+    /// project is not modified: add the task before asking for the next.
+    pub fn next_task_id(&self, prefix: &str) -> TaskId {
+        fn highest(tasks: &[Task], prefix: &str) -> u64 {
+            tasks
+                .iter()
+                .map(|task| {
+                    let own = task
+                        .id
+                        .strip_prefix(prefix)
+                        .and_then(|rest| rest.strip_prefix('_'))
+                        .and_then(|n| n.parse::<u64>().ok())
+                        .unwrap_or(0);
+                    own.max(highest(&task.children, prefix))
+                })
+                .max()
+                .unwrap_or(0)
+        }
+        format!("{}_{}", prefix, highest(&self.tasks, prefix) + 1)
+    }
+
     /// Get a resource by ID
     pub fn get_resource(&self, id: &str) -> Option<&Resource> {
         self.resources.iter().find(|r| r.id == id)
@@ -642,6 +1038,173 @@ impl Project {
         collect_leaves(&self.tasks, &mut leaves);
         leaves
     }
+
+    /// Total effort across leaf tasks
+    ///
+    /// Containers are skipped so rolled-up effort is not counted twice.
+    pub fn total_effort(&self) -> Duration {
+        self.leaf_tasks()
+            .iter()
+            .filter_map(|task| task.effort)
+            .sum()
+    }
+
+    /// Effort still to be done across leaf tasks (`effort × (1 − complete%)`)
+    pub fn remaining_effort(&self) -> Duration {
+        let minutes: f64 = self
+            .leaf_tasks()
+            .iter()
+            .filter_map(|task| {
+                let effort = task.effort?;
+                let remaining =
+                    1.0 - f64::from(task.complete.unwrap_or(0.0)).clamp(0.0, 100.0) / 100.0;
+                Some(effort.minutes as f64 * remaining)
+            })
+            .sum();
+        Duration::minutes(minutes.round() as i64)
+    }
+
+    /// Assigned effort per resource (or profile) across leaf tasks
+    ///
+    /// Each leaf task's effort is split among its assignments in proportion
+    /// to `units × efficiency`, so a task split 100%/50% between two equally
+    /// efficient resources divides 2:1. Profile assignments are attributed to
+    /// the profile ID with an efficiency of 1. Tasks without effort or
+    /// assignments contribute nothing; containers are skipped. Measured in
+    /// the same units as [`Project::available_capacity`].
+    pub fn effort_by_resource(&self) -> HashMap<ResourceId, Duration> {
+        let mut minutes: HashMap<ResourceId, f64> = HashMap::new();
+        for task in self.leaf_tasks() {
+            let Some(effort) = task.effort else {
+                continue;
+            };
+            let weights: Vec<(&ResourceId, f64)> = task
+                .assigned
+                .iter()
+                .map(|assignment| {
+                    let efficiency = self
+                        .get_resource(&assignment.resource_id)
+                        .map_or(1.0, |r| f64::from(r.efficiency));
+                    (
+                        &assignment.resource_id,
+                        f64::from(assignment.units) * efficiency,
+                    )
+                })
+                .collect();
+            let total: f64 = weights.iter().map(|(_, w)| w).sum();
+            if total <= 0.0 {
+                continue;
+            }
+            for (resource_id, weight) in weights {
+                *minutes.entry(resource_id.clone()).or_default() +=
+                    effort.minutes as f64 * weight / total;
+            }
+        }
+        minutes
+            .into_iter()
+            .map(|(id, m)| (id, Duration::minutes(m.round() as i64)))
+            .collect()
+    }
+
+    /// Resource capacity available between `from` and `to` (inclusive)
+    ///
+    /// Counts each resource's working days in the window on its own calendar
+    /// (falling back to the project calendar), scaled by capacity,
+    /// availability and efficiency. Measured in the same units as effort:
+    /// one full working day is `Duration::days(1)`.
+    pub fn available_capacity(&self, from: NaiveDate, to: NaiveDate) -> Duration {
+        let project_calendar = self.default_calendar();
+
+        let minutes: f64 = self
+            .resources
+            .iter()
+            .filter(|resource| !resource.is_consumable() && !resource.is_material())
+            .map(|resource| {
+                let calendar = resource
+                    .calendar
+                    .as_ref()
+                    .and_then(|id| self.calendars.iter().find(|c| &c.id == id))
+                    .unwrap_or(&project_calendar);
+                let working_days = from
+                    .iter_days()
+                    .take_while(|date| *date <= to)
+                    .filter(|date| calendar.is_working_day(*date))
+                    .count();
+                let factor = f64::from(resource.capacity)
+                    * f64::from(resource.effective_availability())
+                    * f64::from(resource.efficiency);
+                working_days as f64 * Duration::days(1).minutes as f64 * factor
+            })
+            .sum();
+        Duration::minutes(minutes.round() as i64)
+    }
+
+    /// Total quantity of a consumable resource used across leaf tasks
+    ///
+    /// Each assignment's units count as the quantity the task consumes.
+    pub fn consumption(&self, resource_id: &str) -> f32 {
+        self.leaf_tasks()
+            .iter()
+            .flat_map(|task| &task.assigned)
+            .filter(|r| r.resource_id == resource_id)
+            .map(|r| r.units)
+            .sum()
+    }
+
+    /// Move every assignment of `from` to `to` across the task tree
+    ///
+    /// Works for resources and profiles alike; units are kept. Returns the
+    /// number of assignments changed.
+    pub fn reassign_resource(&mut self, from: &str, to: &str) -> usize {
+        fn reassign(tasks: &mut [Task], from: &str, to: &str) -> usize {
+            let mut changed = 0;
+            for task in tasks {
+                for r in task.assigned.iter_mut().filter(|r| r.resource_id == from) {
+                    r.resource_id = to.to_string();
+                    changed += 1;
+                }
+                changed += reassign(&mut task.children, from, to);
+            }
+            changed
+        }
+        reassign(&mut self.tasks, from, to)
+    }
+
+    /// Add `constraint` to every task in the tree matching `predicate`
+    ///
+    /// The predicate receives each task's fully-qualified ID (e.g.
+    /// `"phase1.design"`) and the task itself; containers are visited too.
+    /// Returns the number of tasks constrained.
+    pub fn apply_constraint_where<F>(
+        &mut self,
+        mut predicate: F,
+        constraint: TaskConstraint,
+    ) -> usize
+    where
+        F: FnMut(&str, &Task) -> bool,
+    {
+        fn apply<F: FnMut(&str, &Task) -> bool>(
+            tasks: &mut [Task],
+            prefix: Option<&str>,
+            predicate: &mut F,
+            constraint: &TaskConstraint,
+        ) -> usize {
+            let mut changed = 0;
+            for task in tasks {
+                let path = match prefix {
+                    Some(prefix) => format!("{}.{}", prefix, task.id),
+                    None => task.id.clone(),
+                };
+                if predicate(&path, task) {
+                    task.constraints.push(constraint.clone());
+                    changed += 1;
+                }
+                changed += apply(&mut task.children, Some(&path), predicate, constraint);
+            }
+            changed
+        }
+        apply(&mut self.tasks, None, &mut predicate, &constraint)
+    }
 }
 
 // ============================================================================
@@ -656,38 +1219,83 @@ pub struct Task {
     /// Human-readable description (from quoted string in DSL)
     pub name: String,
     /// Optional short display name (MS Project "Task Name" style)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub summary: Option<String>,
     /// Work effort required (person-time)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub effort: Option<Duration>,
     /// Calendar duration (overrides effort-based calculation)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub duration: Option<Duration>,
     /// Task dependencies
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub depends: Vec<Dependency>,
     /// Resource assignments
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub assigned: Vec<ResourceRef>,
     /// Scheduling priority (higher = scheduled first)
+    #[serde(
+        default = "default_priority",
+        skip_serializing_if = "is_default_priority"
+    )]
     pub priority: u32,
     /// Scheduling constraints
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub constraints: Vec<TaskConstraint>,
     /// Is this a milestone (zero duration)?
+    #[serde(default, skip_serializing_if = "is_default")]
     pub milestone: bool,
+    /// Whether the task takes part in scheduling
+    ///
+    /// Inactive (cancelled) tasks stay in the project for reference but
+    /// are left out of the schedule, its costs and the critical path,
+    /// together with their children. Dependencies on them are skipped.
+    #[serde(default = "default_true", skip_serializing_if = "is_true")]
+    pub active: bool,
+    /// Whether rescheduling keeps the task at its previously computed dates
+    ///
+    /// See `CpmSolver::reschedule_frozen` in the solver crate; ordinary
+    /// scheduling ignores the flag.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub frozen: bool,
     /// Child tasks (WBS hierarchy)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub children: Vec<Task>,
     /// Completion percentage (for tracking)
+    ///
+    /// Schedule completion: drives remaining-duration forecasting and
+    /// `project_progress`. Earned value uses it only when
+    /// `physical_complete` is not set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub complete: Option<f32>,
+    /// Physical completion percentage (how much of the deliverable is done)
+    ///
+    /// Used for earned value in place of `complete` when set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub physical_complete: Option<f32>,
     /// Actual start date (when work actually began)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub actual_start: Option<NaiveDate>,
     /// Actual finish date (when work actually completed)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub actual_finish: Option<NaiveDate>,
     /// Explicit remaining duration (overrides linear calculation from complete%)
     /// When set, this takes precedence over `duration * (1 - complete/100)`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub explicit_remaining: Option<Duration>,
     /// Task status for progress tracking
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<TaskStatus>,
     /// Explicit temporal regime (RFC-0012)
     /// When None, derived from `milestone` field: milestone → Event, otherwise → Work
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub regime: Option<TemporalRegime>,
+    /// Calendar override for this task (e.g., shift-specific work)
+    /// When None, falls back to the assigned resource's calendar, then the project calendar
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub calendar: Option<CalendarId>,
     /// Custom attributes
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     pub attributes: HashMap<String, String>,
 }
 
@@ -703,16 +1311,20 @@ impl Task {
             duration: None,
             depends: Vec::new(),
             assigned: Vec::new(),
-            priority: 500,
+            priority: default_priority(),
             constraints: Vec::new(),
             milestone: false,
+            active: true,
+            frozen: false,
             children: Vec::new(),
             complete: None,
+            physical_complete: None,
             actual_start: None,
             actual_finish: None,
             explicit_remaining: None,
             status: None,
             regime: None,
+            calendar: None,
             attributes: HashMap::new(),
         }
     }
@@ -747,6 +1359,7 @@ impl Task {
             predecessor: predecessor.into(),
             dep_type: DependencyType::FinishToStart,
             lag: None,
+            lag_calendar: false,
         });
         self
     }
@@ -792,6 +1405,18 @@ impl Task {
         self
     }
 
+    /// Mark the task as inactive, excluding it from scheduling (builder pattern)
+    pub fn inactive(mut self) -> Self {
+        self.active = false;
+        self
+    }
+
+    /// Keep the task at its prior dates when rescheduling (builder pattern)
+    pub fn frozen(mut self) -> Self {
+        self.frozen = true;
+        self
+    }
+
     /// Add a child task
     pub fn child(mut self, child: Task) -> Self {
         self.children.push(child);
@@ -804,11 +1429,71 @@ impl Task {
         self
     }
 
+    /// Set a calendar override for this task
+    pub fn calendar(mut self, calendar: impl Into<String>) -> Self {
+        self.calendar = Some(calendar.into());
+        self
+    }
+
+    /// Add a tag (stored in the comma-separated `tags` attribute, as the
+    /// parser writes it)
+    pub fn tag(mut self, tag: impl Into<String>) -> Self {
+        let tag = tag.into();
+        self.attributes
+            .entry("tags".to_string())
+            .and_modify(|tags| {
+                tags.push(',');
+                tags.push_str(&tag);
+            })
+            .or_insert(tag);
+        self
+    }
+
+    /// Tags from the `tags` attribute
+    pub fn tags(&self) -> impl Iterator<Item = &str> {
+        self.attributes
+            .get("tags")
+            .into_iter()
+            .flat_map(|tags| tags.split(','))
+            .map(str::trim)
+            .filter(|tag| !tag.is_empty())
+    }
+
     /// Check if this is a summary task (has children)
     pub fn is_summary(&self) -> bool {
         !self.children.is_empty()
     }
 
+    /// Work in person-days of `calendar`, as opposed to calendar duration
+    ///
+    /// Uses the explicit `effort` when set (converted at the calendar's
+    /// hours per day), otherwise duration times the total assigned units: a
+    /// 10-day task staffed by two people is 20 person-days. `None` for
+    /// containers, which have no effort of their own, and for unassigned
+    /// tasks without an explicit effort.
+    pub fn effort_person_days(&self, calendar: &Calendar) -> Option<f64> {
+        if self.is_container() {
+            return None;
+        }
+        if let Some(effort) = self.effort {
+            return Some(calendar.effort_days(effort));
+        }
+        if self.assigned.is_empty() {
+            return None;
+        }
+        let units: f64 = self.assigned.iter().map(|r| r.units as f64).sum();
+        self.duration.map(|duration| duration.as_days() * units)
+    }
+
+    /// Work in person-hours, at the calendar's hours per person-day
+    pub fn effort_person_hours(&self, calendar: &Calendar) -> Option<f64> {
+        let hours_per_day = Some(calendar.hours_per_day())
+            .filter(|hours| *hours > 0.0)
+            .unwrap_or(8.0);
+        self.effort_person_days(calendar)
+            .map(|days| days * hours_per_day)
+    }
+
     // ========================================================================
     // Progress Tracking Methods
     // ========================================================================
@@ -830,6 +1515,14 @@ impl Task {
             .unwrap_or(0)
     }
 
+    /// Get effective physical completion as u8 (0-100), for earned value.
+    /// Falls back to `complete` when `physical_complete` is not set.
+    pub fn effective_physical_complete(&self) -> u8 {
+        self.physical_complete
+            .map(|c| c.clamp(0.0, 100.0) as u8)
+            .unwrap_or_else(|| self.effective_percent_complete())
+    }
+
     /// Derive task status from actual dates and completion.
     /// Returns explicit status if set, otherwise derives from data.
     /// For containers, uses effective_progress() to derive status from children.
@@ -856,20 +1549,49 @@ impl Task {
         self
     }
 
+    /// Set the physical completion percentage (builder pattern)
+    pub fn physical_complete(mut self, pct: f32) -> Self {
+        self.physical_complete = Some(pct);
+        self
+    }
+
     /// Check if this task is a container (has children)
     pub fn is_container(&self) -> bool {
         !self.children.is_empty()
     }
 
+    /// Allocation units implied by effort over a fixed duration
+    ///
+    /// When both are set the duration wins and the effort no longer
+    /// drives scheduling; this exposes the staffing the pair assumes
+    /// (10 days of effort in 3 days implies ~3.3 units). Effort is
+    /// counted in days of `calendar`. Returns `None` unless both are set
+    /// and the duration is positive.
+    pub fn implied_units(&self, calendar: &Calendar) -> Option<f64> {
+        let effort = self.effort?;
+        let duration = self.duration?;
+        (duration.minutes > 0).then(|| calendar.effort_days(effort) / duration.as_days())
+    }
+
     /// Calculate container progress as weighted average of children by duration.
     /// Returns None if not a container or if no children have duration.
     /// Formula: Σ(child.percent_complete × child.duration) / Σ(child.duration)
     pub fn container_progress(&self) -> Option<u8> {
+        self.weighted_container_progress(Task::effective_percent_complete)
+    }
+
+    /// Physical counterpart of `container_progress`, for earned value.
+    /// Weights each leaf's `effective_physical_complete` by duration.
+    pub fn container_physical_progress(&self) -> Option<u8> {
+        self.weighted_container_progress(Task::effective_physical_complete)
+    }
+
+    fn weighted_container_progress(&self, leaf_pct: fn(&Task) -> u8) -> Option<u8> {
         if self.children.is_empty() {
             return None;
         }
 
-        let (total_weighted, total_duration) = self.calculate_weighted_progress();
+        let (total_weighted, total_duration) = self.calculate_weighted_progress(leaf_pct);
 
         if total_duration == 0 {
             return None;
@@ -880,21 +1602,21 @@ impl Task {
 
     /// Helper to recursively calculate weighted progress from all descendants.
     /// Returns (weighted_sum, total_duration_minutes)
-    fn calculate_weighted_progress(&self) -> (i64, i64) {
+    fn calculate_weighted_progress(&self, leaf_pct: fn(&Task) -> u8) -> (i64, i64) {
         let mut total_weighted: i64 = 0;
         let mut total_duration: i64 = 0;
 
         for child in &self.children {
             if child.is_container() {
                 // Recursively get progress from nested containers
-                let (child_weighted, child_duration) = child.calculate_weighted_progress();
+                let (child_weighted, child_duration) = child.calculate_weighted_progress(leaf_pct);
                 total_weighted += child_weighted;
                 total_duration += child_duration;
             } else {
                 // Leaf task - use its duration and progress
                 let duration = child.duration.or(child.effort).unwrap_or(Duration::zero());
                 let duration_mins = duration.minutes;
-                let pct = child.effective_percent_complete() as i64;
+                let pct = leaf_pct(child) as i64;
 
                 total_weighted += pct * duration_mins;
                 total_duration += duration_mins;
@@ -940,6 +1662,54 @@ impl Task {
         }
     }
 
+    /// Contradictions between the task's explicit progress fields
+    ///
+    /// Cross-checks `status`, `complete`, `actual_start` and `actual_finish`,
+    /// since [`Task::derived_status`] lets an explicit status win and hides
+    /// the conflict. Each entry names the two conflicting fields as they
+    /// would be written, e.g. `("status: Complete", "complete: 50%")`.
+    pub fn progress_contradictions(&self) -> Vec<(String, String)> {
+        let mut contradictions = Vec::new();
+        let status = self.status.as_ref().map(|s| format!("status: {}", s));
+        let complete = self.complete.map(|c| format!("complete: {}%", c));
+        let actual_start = self.actual_start.map(|d| format!("actual_start: {}", d));
+        let actual_finish = self.actual_finish.map(|d| format!("actual_finish: {}", d));
+        let pct = self.complete.unwrap_or(0.0);
+
+        let mut conflict = |a: &Option<String>, b: &Option<String>| {
+            if let (Some(a), Some(b)) = (a, b) {
+                contradictions.push((a.clone(), b.clone()));
+            }
+        };
+
+        match self.status {
+            Some(TaskStatus::Complete) if pct < 100.0 => conflict(&status, &complete),
+            Some(TaskStatus::NotStarted) => {
+                if pct > 0.0 {
+                    conflict(&status, &complete);
+                }
+                conflict(&status, &actual_start);
+                conflict(&status, &actual_finish);
+            }
+            Some(TaskStatus::InProgress) => {
+                if pct >= 100.0 {
+                    conflict(&status, &complete);
+                }
+                conflict(&status, &actual_finish);
+            }
+            _ => {}
+        }
+        if pct < 100.0 {
+            conflict(&complete, &actual_finish);
+        }
+        if let (Some(start), Some(finish)) = (self.actual_start, self.actual_finish) {
+            if finish < start {
+                conflict(&actual_start, &actual_finish);
+            }
+        }
+        contradictions
+    }
+
     /// Set the actual start date (builder pattern)
     pub fn actual_start(mut self, date: NaiveDate) -> Self {
         self.actual_start = Some(date);
@@ -1015,13 +1785,28 @@ pub struct Dependency {
     /// ID of the predecessor task
     pub predecessor: TaskId,
     /// Type of dependency
+    #[serde(default, skip_serializing_if = "is_default")]
     pub dep_type: DependencyType,
     /// Lag time (positive) or lead time (negative)
+    ///
+    /// Scheduling is day-granular: sub-day lags are rounded to the nearest
+    /// working day (see [`Duration::round_days`]).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub lag: Option<Duration>,
+    /// Count `lag` in elapsed calendar days instead of working days
+    ///
+    /// For physical waiting time such as curing or drying: weekends and
+    /// holidays count toward the lag, and the successor moves to the next
+    /// working day once it has passed.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub lag_calendar: bool,
 }
 
 /// Types of task dependencies
+///
+/// Serialized as a snake_case string, e.g. `"finish_to_start"`.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum DependencyType {
     /// Finish-to-Start: successor starts after predecessor finishes
     #[default]
@@ -1034,6 +1819,39 @@ pub enum DependencyType {
     StartToFinish,
 }
 
+impl DependencyType {
+    /// Conventional two-letter code ("FS", "SS", "FF", "SF")
+    pub fn short_code(&self) -> &'static str {
+        match self {
+            DependencyType::FinishToStart => "FS",
+            DependencyType::StartToStart => "SS",
+            DependencyType::FinishToFinish => "FF",
+            DependencyType::StartToFinish => "SF",
+        }
+    }
+
+    /// The predecessor and successor dates a link of this type spans
+    ///
+    /// The working days from the first date up to (not including) the
+    /// second are the room the link leaves, to be compared with its lag.
+    /// Finish-to-start counts from the day after the predecessor finishes,
+    /// except after a milestone, which finishes at the start of its day.
+    /// `None` when a date would fall past the end of the calendar.
+    pub fn linked_dates(
+        &self,
+        pred: &ScheduledTask,
+        succ: &ScheduledTask,
+    ) -> Option<(NaiveDate, NaiveDate)> {
+        match self {
+            DependencyType::FinishToStart if pred.is_milestone => Some((pred.finish, succ.start)),
+            DependencyType::FinishToStart => Some((pred.finish.succ_opt()?, succ.start)),
+            DependencyType::StartToStart => Some((pred.start, succ.start)),
+            DependencyType::FinishToFinish => Some((pred.finish, succ.finish)),
+            DependencyType::StartToFinish => Some((pred.start, succ.finish.succ_opt()?)),
+        }
+    }
+}
+
 /// Task status for progress tracking
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TaskStatus {
@@ -1136,11 +1954,17 @@ pub struct ResourceRef {
     /// ID of the resource
     pub resource_id: ResourceId,
     /// Allocation units (1.0 = 100%)
+    #[serde(default = "default_one", skip_serializing_if = "is_one")]
     pub units: f32,
 }
 
 /// Constraint on task scheduling
+///
+/// Serialized in a tagged form with an ISO 8601 date, e.g.
+/// `{ "type": "start_no_earlier_than", "date": "2025-01-06" }`. A relative
+/// constraint has `anchor`, `offset` and `from` fields in place of the date.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(from = "TaskConstraintRepr", into = "TaskConstraintRepr")]
 pub enum TaskConstraint {
     /// Task must start on this date
     MustStartOn(NaiveDate),
@@ -1154,28 +1978,177 @@ pub enum TaskConstraint {
     FinishNoEarlierThan(NaiveDate),
     /// Task must finish by this date
     FinishNoLaterThan(NaiveDate),
+    /// Task must start `offset` working days after another task's start or
+    /// finish. A date anchor rather than a logical link: it is resolved once
+    /// the anchor is scheduled and then pins this task's start.
+    RelativeTo {
+        /// Qualified ID of the anchor task
+        anchor: TaskId,
+        /// Working time from the anchor date (may be negative)
+        offset: Duration,
+        /// Which of the anchor's dates to measure from
+        from: StartOrFinish,
+    },
 }
 
-// ============================================================================
-// Temporal Regimes (RFC-0012)
-// ============================================================================
+/// Serialized form of [`TaskConstraint`]
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum TaskConstraintRepr {
+    MustStartOn {
+        date: NaiveDate,
+    },
+    MustFinishOn {
+        date: NaiveDate,
+    },
+    StartNoEarlierThan {
+        date: NaiveDate,
+    },
+    StartNoLaterThan {
+        date: NaiveDate,
+    },
+    FinishNoEarlierThan {
+        date: NaiveDate,
+    },
+    FinishNoLaterThan {
+        date: NaiveDate,
+    },
+    RelativeTo {
+        anchor: TaskId,
+        offset: Duration,
+        from: StartOrFinish,
+    },
+}
 
-/// Temporal Regime defines how time behaves for a task (RFC-0012).
-///
-/// A regime determines:
-/// - Which days advance time (working days vs calendar days)
-/// - How constraints round (or don't)
-/// - How durations are interpreted
-///
-/// Tasks operate under exactly one regime, intrinsic to their nature.
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
-pub enum TemporalRegime {
-    /// Work regime (default): effort-bearing tasks.
-    /// - Advances on working days only
-    /// - Floor constraints (SNET, FNET) round forward to next working day
-    /// - Ceiling constraints (SNLT, FNLT) round backward to previous working day
-    /// - Duration means effort days
-    #[default]
+impl From<TaskConstraintRepr> for TaskConstraint {
+    fn from(repr: TaskConstraintRepr) -> Self {
+        match repr {
+            TaskConstraintRepr::MustStartOn { date } => TaskConstraint::MustStartOn(date),
+            TaskConstraintRepr::MustFinishOn { date } => TaskConstraint::MustFinishOn(date),
+            TaskConstraintRepr::StartNoEarlierThan { date } => {
+                TaskConstraint::StartNoEarlierThan(date)
+            }
+            TaskConstraintRepr::StartNoLaterThan { date } => TaskConstraint::StartNoLaterThan(date),
+            TaskConstraintRepr::FinishNoEarlierThan { date } => {
+                TaskConstraint::FinishNoEarlierThan(date)
+            }
+            TaskConstraintRepr::FinishNoLaterThan { date } => {
+                TaskConstraint::FinishNoLaterThan(date)
+            }
+            TaskConstraintRepr::RelativeTo {
+                anchor,
+                offset,
+                from,
+            } => TaskConstraint::RelativeTo {
+                anchor,
+                offset,
+                from,
+            },
+        }
+    }
+}
+
+impl From<TaskConstraint> for TaskConstraintRepr {
+    fn from(constraint: TaskConstraint) -> Self {
+        match constraint {
+            TaskConstraint::MustStartOn(date) => TaskConstraintRepr::MustStartOn { date },
+            TaskConstraint::MustFinishOn(date) => TaskConstraintRepr::MustFinishOn { date },
+            TaskConstraint::StartNoEarlierThan(date) => {
+                TaskConstraintRepr::StartNoEarlierThan { date }
+            }
+            TaskConstraint::StartNoLaterThan(date) => TaskConstraintRepr::StartNoLaterThan { date },
+            TaskConstraint::FinishNoEarlierThan(date) => {
+                TaskConstraintRepr::FinishNoEarlierThan { date }
+            }
+            TaskConstraint::FinishNoLaterThan(date) => {
+                TaskConstraintRepr::FinishNoLaterThan { date }
+            }
+            TaskConstraint::RelativeTo {
+                anchor,
+                offset,
+                from,
+            } => TaskConstraintRepr::RelativeTo {
+                anchor,
+                offset,
+                from,
+            },
+        }
+    }
+}
+
+impl TaskConstraint {
+    /// Whether this constraint pins a task with the given early and late dates
+    ///
+    /// A `MustStartOn`/`MustFinishOn` pins when both the early and late
+    /// date equal the constraint date; other constraints never pin.
+    pub fn pins(&self, es: NaiveDate, ef: NaiveDate, ls: NaiveDate, lf: NaiveDate) -> bool {
+        match self {
+            TaskConstraint::MustStartOn(date) => es == *date && ls == *date,
+            TaskConstraint::MustFinishOn(date) => ef == *date && lf == *date,
+            _ => false,
+        }
+    }
+
+    /// Start date a `RelativeTo` constraint pins, given the anchor's schedule
+    ///
+    /// Counts `offset` in whole working days of `calendar` from the anchor's
+    /// start or finish. `None` for absolute constraints, or when the anchor
+    /// is not in the schedule.
+    pub fn relative_start(&self, schedule: &Schedule, calendar: &Calendar) -> Option<NaiveDate> {
+        let TaskConstraint::RelativeTo {
+            anchor,
+            offset,
+            from,
+        } = self
+        else {
+            return None;
+        };
+        let anchor = schedule.tasks.get(anchor)?;
+        let date = match from {
+            StartOrFinish::Start => anchor.start,
+            StartOrFinish::Finish => anchor.finish,
+        };
+        Some(calendar.add_working_days(date, offset.round_days()))
+    }
+}
+
+/// Which end of a task a relative constraint measures from
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StartOrFinish {
+    Start,
+    Finish,
+}
+
+impl std::fmt::Display for StartOrFinish {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StartOrFinish::Start => write!(f, "start"),
+            StartOrFinish::Finish => write!(f, "finish"),
+        }
+    }
+}
+
+// ============================================================================
+// Temporal Regimes (RFC-0012)
+// ============================================================================
+
+/// Temporal Regime defines how time behaves for a task (RFC-0012).
+///
+/// A regime determines:
+/// - Which days advance time (working days vs calendar days)
+/// - How constraints round (or don't)
+/// - How durations are interpreted
+///
+/// Tasks operate under exactly one regime, intrinsic to their nature.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TemporalRegime {
+    /// Work regime (default): effort-bearing tasks.
+    /// - Advances on working days only
+    /// - Floor constraints (SNET, FNET) round forward to next working day
+    /// - Ceiling constraints (SNLT, FNLT) round backward to previous working day
+    /// - Duration means effort days
+    #[default]
     Work,
 
     /// Event regime: point-in-time occurrences.
@@ -1234,6 +2207,17 @@ impl std::str::FromStr for TemporalRegime {
 // Resource
 // ============================================================================
 
+/// How a resource is costed and scheduled
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResourceKind {
+    /// People or equipment doing the work, costed at `rate` per day
+    #[default]
+    Work,
+    /// An expense such as a license or a cloud bill, costed once at `cost`
+    Material,
+}
+
 /// A person or equipment that can be assigned to tasks
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Resource {
@@ -1242,22 +2226,42 @@ pub struct Resource {
     /// Human-readable name
     pub name: String,
     /// Cost rate (per time unit)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub rate: Option<Money>,
     /// Capacity (1.0 = full time, 0.5 = half time)
+    #[serde(default = "default_one", skip_serializing_if = "is_one")]
     pub capacity: f32,
     /// Custom calendar (overrides project default)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub calendar: Option<CalendarId>,
     /// Efficiency factor (default 1.0)
+    #[serde(default = "default_one", skip_serializing_if = "is_one")]
     pub efficiency: f32,
     /// Custom attributes
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     pub attributes: HashMap<String, String>,
 
     // RFC-0001: Progressive Resource Refinement fields
     /// Profile that this resource specializes (constraint refinement)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub specializes: Option<ProfileId>,
     /// Availability (0.0-1.0, multiplied with calendar hours)
     /// Separate from capacity for progressive refinement semantics
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub availability: Option<f32>,
+
+    /// Total quantity of a consumable (non-renewable) resource. `None` for
+    /// renewable resources, which are time-shared at `capacity` per day.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pool: Option<f32>,
+
+    /// Work (the default) or material
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub kind: ResourceKind,
+    /// One-time cost of a material resource, charged per assigned unit
+    /// regardless of the task's duration
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cost: Option<Money>,
 }
 
 impl Resource {
@@ -1274,6 +2278,9 @@ impl Resource {
             attributes: HashMap::new(),
             specializes: None,
             availability: None,
+            pool: None,
+            kind: ResourceKind::Work,
+            cost: None,
         }
     }
 
@@ -1321,10 +2328,46 @@ impl Resource {
         self.availability.unwrap_or(1.0)
     }
 
+    /// Daily capacity after availability (`capacity × availability`), used
+    /// for overallocation detection and leveling
+    pub fn effective_capacity(&self) -> f32 {
+        self.capacity * self.effective_availability()
+    }
+
     /// Check if this resource specializes a profile
     pub fn is_specialized(&self) -> bool {
         self.specializes.is_some()
     }
+
+    /// Make this a consumable resource with a project-wide pool
+    ///
+    /// Assignment units then count as quantity consumed by the task, and the
+    /// resource takes no part in daily capacity or leveling.
+    pub fn consumable(mut self, pool: f32) -> Self {
+        self.pool = Some(pool);
+        self
+    }
+
+    /// Check if this resource is consumed rather than time-shared
+    pub fn is_consumable(&self) -> bool {
+        self.pool.is_some()
+    }
+
+    /// Make this a material resource with a one-time cost
+    ///
+    /// Each assignment then costs `cost × units` once, whatever the task's
+    /// length, and the resource neither shortens effort-driven tasks nor
+    /// takes part in daily capacity or leveling.
+    pub fn material(mut self, cost: Money) -> Self {
+        self.kind = ResourceKind::Material;
+        self.cost = Some(cost);
+        self
+    }
+
+    /// Check if this resource is an expense rather than work
+    pub fn is_material(&self) -> bool {
+        self.kind == ResourceKind::Material
+    }
 }
 
 // ============================================================================
@@ -1376,8 +2419,26 @@ impl Calendar {
         self.working_hours.iter().map(|r| r.duration_hours()).sum()
     }
 
+    /// Effort in working days of this calendar, or 8-hour days when the
+    /// calendar has no working hours
+    pub fn effort_days(&self, effort: Duration) -> f64 {
+        let hours_per_day = self.hours_per_day();
+        if hours_per_day > 0.0 {
+            effort.as_hours() / hours_per_day
+        } else {
+            effort.as_days()
+        }
+    }
+
     /// Check if a date is a working day
+    ///
+    /// An exception for the date decides on its own: with working hours the
+    /// day is worked (even a weekend or holiday), without them it is not.
+    /// Otherwise the weekday must be a working day and not a holiday.
     pub fn is_working_day(&self, date: NaiveDate) -> bool {
+        if let Some(exception) = self.exceptions.iter().find(|e| e.date == date) {
+            return exception.working_hours.is_some();
+        }
         let weekday = date.weekday().num_days_from_sunday() as u8;
         if !self.working_days.contains(&weekday) {
             return false;
@@ -1387,6 +2448,350 @@ impl Calendar {
         }
         true
     }
+
+    /// The days both calendars work
+    ///
+    /// Keeps this calendar's identity and working hours. A day is working
+    /// only if it is working in both: weekdays must be working in both,
+    /// holidays from either apply, and dates with an exception in either
+    /// calendar become exceptions here (this calendar's hours for that date
+    /// when both work it, non-working otherwise).
+    pub fn intersect(&self, other: &Calendar) -> Calendar {
+        let mut dates: Vec<NaiveDate> = self
+            .exceptions
+            .iter()
+            .chain(&other.exceptions)
+            .map(|e| e.date)
+            .collect();
+        dates.sort();
+        dates.dedup();
+        let exceptions = dates
+            .into_iter()
+            .map(|date| CalendarException {
+                date,
+                working_hours: (self.is_working_day(date) && other.is_working_day(date)).then(
+                    || {
+                        self.exceptions
+                            .iter()
+                            .find(|e| e.date == date)
+                            .and_then(|e| e.working_hours.clone())
+                            .unwrap_or_else(|| self.working_hours.clone())
+                    },
+                ),
+            })
+            .collect();
+
+        Calendar {
+            id: self.id.clone(),
+            name: self.name.clone(),
+            working_hours: self.working_hours.clone(),
+            working_days: self
+                .working_days
+                .iter()
+                .copied()
+                .filter(|day| other.working_days.contains(day))
+                .collect(),
+            holidays: self
+                .holidays
+                .iter()
+                .chain(&other.holidays)
+                .cloned()
+                .collect(),
+            exceptions,
+        }
+    }
+
+    /// Whether any weekday is a working day
+    ///
+    /// Holidays cover finite date ranges, so a calendar with at least one
+    /// valid working weekday always reaches another working day. Without
+    /// one, [`Calendar::add_working_days`] would never return.
+    pub fn has_working_days(&self) -> bool {
+        self.working_days.iter().any(|day| *day < 7)
+    }
+
+    /// Working minutes in a regular working day
+    pub fn minutes_per_day(&self) -> i64 {
+        self.working_hours
+            .iter()
+            .map(|r| i64::from(r.end.saturating_sub(r.start)))
+            .sum()
+    }
+
+    /// Working minutes available on a date
+    ///
+    /// An exception for the date takes precedence (its hours, or zero when
+    /// it marks the day non-working); otherwise non-working days and
+    /// holidays have zero minutes and working days the regular day.
+    pub fn minutes_available(&self, date: NaiveDate) -> i64 {
+        if let Some(exception) = self.exceptions.iter().find(|e| e.date == date) {
+            return exception.working_hours.as_ref().map_or(0, |hours| {
+                hours
+                    .iter()
+                    .map(|r| i64::from(r.end.saturating_sub(r.start)))
+                    .sum()
+            });
+        }
+        if self.is_working_day(date) {
+            self.minutes_per_day()
+        } else {
+            0
+        }
+    }
+
+    /// Count working days in `[from, to)`, negated when `to` is before `from`
+    pub fn working_days_between(&self, from: NaiveDate, to: NaiveDate) -> i64 {
+        let (start, end, sign) = if from <= to {
+            (from, to, 1)
+        } else {
+            (to, from, -1)
+        };
+        let count = start
+            .iter_days()
+            .take_while(|d| *d < end)
+            .filter(|d| self.is_working_day(*d))
+            .count() as i64;
+        sign * count
+    }
+
+    /// Step `days` working days forward from `date` (backward if negative)
+    ///
+    /// Zero returns `date` unchanged, even when it is not a working day.
+    pub fn add_working_days(&self, date: NaiveDate, days: i64) -> NaiveDate {
+        let step = if days < 0 { -1 } else { 1 };
+        let mut current = date;
+        let mut remaining = days.abs();
+        while remaining > 0 {
+            current += chrono::TimeDelta::days(step);
+            if self.is_working_day(current) {
+                remaining -= 1;
+            }
+        }
+        current
+    }
+
+    /// Contradictory or duplicated date entries in this calendar
+    ///
+    /// Reports a holiday covering a date that also has a working-hours
+    /// exception (C012), since the exception silently wins and the day is
+    /// worked, and holidays whose ranges overlap (C024). Each
+    /// diagnostic names both entries. Needs only the calendar itself.
+    pub fn consistency_diagnostics(&self) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+
+        for holiday in &self.holidays {
+            for exception in &self.exceptions {
+                let Some(hours) = exception.working_hours.as_ref() else {
+                    continue;
+                };
+                if !holiday.contains(exception.date) {
+                    continue;
+                }
+                let hours: Vec<String> = hours.iter().map(format_time_range).collect();
+                diagnostics.push(
+                    Diagnostic::new(
+                        DiagnosticCode::C012HolidayExceptionConflict,
+                        format!(
+                            "calendar '{}' has holiday '{}' and a working exception on {}",
+                            self.id, holiday.name, exception.date
+                        ),
+                    )
+                    .with_note(format!(
+                        "holiday '{}': {} to {}",
+                        holiday.name, holiday.start, holiday.end
+                    ))
+                    .with_note(format!(
+                        "exception on {}: working {}",
+                        exception.date,
+                        hours.join(", ")
+                    ))
+                    .with_hint("remove the holiday or the exception"),
+                );
+            }
+        }
+
+        for (i, first) in self.holidays.iter().enumerate() {
+            for second in &self.holidays[i + 1..] {
+                if first.start > second.end || second.start > first.end {
+                    continue;
+                }
+                let relation = if first.start == second.start && first.end == second.end {
+                    "duplicates"
+                } else {
+                    "overlaps"
+                };
+                diagnostics.push(
+                    Diagnostic::new(
+                        DiagnosticCode::C024OverlappingHolidays,
+                        format!(
+                            "calendar '{}': holiday '{}' {} holiday '{}'",
+                            self.id, second.name, relation, first.name
+                        ),
+                    )
+                    .with_note(format!(
+                        "'{}': {} to {}, '{}': {} to {}",
+                        first.name, first.start, first.end, second.name, second.start, second.end
+                    ))
+                    .with_hint("merge the holidays into one entry"),
+                );
+            }
+        }
+
+        diagnostics
+    }
+
+    /// Start building a validated calendar with the given ID
+    pub fn builder(id: impl Into<String>) -> CalendarBuilder {
+        CalendarBuilder::new(id)
+    }
+}
+
+/// Builder for a validated [`Calendar`]
+///
+/// Starts with no working days and no working hours; `build()` rejects
+/// calendars that would leave the solver without working time.
+#[derive(Clone, Debug)]
+pub struct CalendarBuilder {
+    calendar: Calendar,
+}
+
+impl CalendarBuilder {
+    /// Create a builder for a calendar with the given ID
+    pub fn new(id: impl Into<String>) -> Self {
+        let id = id.into();
+        Self {
+            calendar: Calendar {
+                name: id.clone(),
+                id,
+                working_hours: Vec::new(),
+                working_days: Vec::new(),
+                holidays: Vec::new(),
+                exceptions: Vec::new(),
+            },
+        }
+    }
+
+    /// Set the calendar name
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.calendar.name = name.into();
+        self
+    }
+
+    /// Add a working weekday
+    pub fn working_day(mut self, weekday: Weekday) -> Self {
+        let day = weekday.num_days_from_sunday() as u8;
+        if !self.calendar.working_days.contains(&day) {
+            self.calendar.working_days.push(day);
+        }
+        self
+    }
+
+    /// Add a working time range (minutes from midnight)
+    pub fn hours(mut self, start: u16, end: u16) -> Self {
+        self.calendar.working_hours.push(TimeRange { start, end });
+        self
+    }
+
+    /// Add a holiday spanning `start..=end`
+    pub fn holiday(mut self, name: impl Into<String>, start: NaiveDate, end: NaiveDate) -> Self {
+        self.calendar.holidays.push(Holiday {
+            name: name.into(),
+            start,
+            end,
+        });
+        self
+    }
+
+    /// Add an exception for a specific date (`None` = non-working)
+    pub fn exception(mut self, date: NaiveDate, working_hours: Option<Vec<TimeRange>>) -> Self {
+        self.calendar.exceptions.push(CalendarException {
+            date,
+            working_hours,
+        });
+        self
+    }
+
+    /// Validate and build the calendar
+    ///
+    /// Working hours must be non-empty, each range must have `start < end`
+    /// within a single day, and ranges must not overlap (C001). At least one
+    /// working day is required (C002).
+    pub fn build(mut self) -> Result<Calendar, Vec<Diagnostic>> {
+        let id = self.calendar.id.clone();
+        let mut errors = Vec::new();
+
+        if self.calendar.working_hours.is_empty() {
+            errors.push(
+                Diagnostic::error(
+                    DiagnosticCode::C001ZeroWorkingHours,
+                    format!("calendar '{}' has no working hours defined", id),
+                )
+                .with_hint("add a working time range with hours(start, end)"),
+            );
+        }
+
+        for range in &self.calendar.working_hours {
+            if range.start >= range.end || range.end > 24 * 60 {
+                errors.push(
+                    Diagnostic::error(
+                        DiagnosticCode::C001ZeroWorkingHours,
+                        format!(
+                            "calendar '{}' has invalid working hours {}",
+                            id,
+                            format_time_range(range)
+                        ),
+                    )
+                    .with_note("ranges must start before they end, within one day"),
+                );
+            }
+        }
+
+        self.calendar.working_hours.sort_by_key(|r| r.start);
+        for pair in self.calendar.working_hours.windows(2) {
+            if pair[1].start < pair[0].end {
+                errors.push(
+                    Diagnostic::error(
+                        DiagnosticCode::C001ZeroWorkingHours,
+                        format!(
+                            "calendar '{}' has overlapping working hours {} and {}",
+                            id,
+                            format_time_range(&pair[0]),
+                            format_time_range(&pair[1])
+                        ),
+                    )
+                    .with_hint("merge or separate the overlapping ranges"),
+                );
+            }
+        }
+
+        if self.calendar.working_days.is_empty() {
+            errors.push(
+                Diagnostic::error(
+                    DiagnosticCode::C002NoWorkingDays,
+                    format!("calendar '{}' has no working days defined", id),
+                )
+                .with_hint("add at least one working_day(weekday)"),
+            );
+        }
+
+        if errors.is_empty() {
+            self.calendar.working_days.sort_unstable();
+            Ok(self.calendar)
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+/// Format a time range as `HH:MM-HH:MM`
+fn format_time_range(range: &TimeRange) -> String {
+    format!(
+        "{:02}:{:02}-{:02}:{:02}",
+        range.start / 60,
+        range.start % 60,
+        range.end / 60,
+        range.end % 60
+    )
 }
 
 /// Time range within a day (in minutes from midnight)
@@ -1437,7 +2842,11 @@ pub struct Scenario {
 }
 
 /// Override for a scenario
+///
+/// Serialized in a tagged form, e.g.
+/// `{ "type": "task_effort", "task_id": "design", "effort": { "minutes": 2400 } }`.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
 pub enum ScenarioOverride {
     TaskEffort {
         task_id: TaskId,
@@ -1474,7 +2883,8 @@ pub struct Schedule {
     pub total_cost_range: Option<CostRange>,
 
     // Project Status Fields (I004)
-    /// Overall project progress (0-100), weighted by task duration
+    /// Overall project progress (0-100), weighted per
+    /// `Project::progress_weighting` (task duration by default)
     pub project_progress: u8,
     /// Project baseline finish date (max of all baseline_finish)
     pub project_baseline_finish: NaiveDate,
@@ -1486,10 +2896,1190 @@ pub struct Schedule {
     // Earned Value Fields (I005)
     /// Planned Value at status date (0-100), weighted % of baseline work due
     pub planned_value: u8,
-    /// Earned Value (same as project_progress, 0-100)
+    /// Earned Value (0-100): each leaf's physical completion
+    /// (`Task::physical_complete`, else `complete`) weighted by duration.
+    /// Unlike `project_progress` it ignores `Project::progress_weighting`,
+    /// so it stays comparable with `planned_value`
     pub earned_value: u8,
     /// Schedule Performance Index (EV / PV), capped at 2.0
     pub spi: f64,
+    /// Status date progress and earned value are measured at (`None` for
+    /// schedules saved before it was recorded)
+    #[serde(default)]
+    pub status_date: Option<NaiveDate>,
+    /// Project end before resource leveling, with unlimited resources
+    /// (`None` when the schedule was not leveled)
+    #[serde(default)]
+    pub unconstrained_finish: Option<NaiveDate>,
+}
+
+impl Schedule {
+    /// Performance-based finish forecast (I005)
+    ///
+    /// Extrapolates the remaining work at the current schedule performance:
+    /// `status_date + remaining / SPI`, where remaining is the calendar time
+    /// from the status date to `project_forecast_finish`. Complete projects
+    /// return `project_forecast_finish`; with no earned progress (SPI of zero)
+    /// or no recorded status date there is no rate to extrapolate from, so the
+    /// plan-based forecast is returned.
+    pub fn spi_forecast_finish(&self) -> NaiveDate {
+        let Some(status_date) = self.status_date else {
+            return self.project_forecast_finish;
+        };
+        if self.project_progress >= 100 || self.spi <= 0.0 {
+            return self.project_forecast_finish;
+        }
+
+        let remaining_days = (self.project_forecast_finish - status_date)
+            .num_days()
+            .max(0);
+        let forecast_days = (remaining_days as f64 / self.spi).ceil() as i64;
+        status_date + chrono::TimeDelta::days(forecast_days)
+    }
+
+    /// Budget at completion (BAC): the expected total cost of the plan
+    ///
+    /// Returns `None` when the project has no cost baseline (no rated
+    /// assignments).
+    pub fn budget_at_completion(&self) -> Option<Money> {
+        self.total_cost_range
+            .as_ref()
+            .map(|range| Money::new(range.expected, range.currency.clone()))
+    }
+
+    /// Earned value in cost terms: `EV = BAC × earned_value%`
+    pub fn earned_value_cost(&self) -> Option<Money> {
+        let bac = self.budget_at_completion()?;
+        let earned = bac.amount * Decimal::from(self.earned_value) / Decimal::from(100);
+        Some(Money::new(earned, bac.currency))
+    }
+
+    /// Cost Performance Index: `CPI = EV / AC`
+    ///
+    /// Returns `None` without a cost baseline, when the currencies differ, or
+    /// when no actual cost has been incurred.
+    pub fn cpi(&self, actual_cost: &Money) -> Option<Decimal> {
+        let ev = self.earned_value_cost()?;
+        if ev.currency != actual_cost.currency || actual_cost.amount.is_zero() {
+            return None;
+        }
+        Some(ev.amount / actual_cost.amount)
+    }
+
+    /// Estimate at completion: `EAC = AC + (BAC − EV) / CPI`
+    ///
+    /// Returns `None` when CPI is unavailable or zero (no value earned yet,
+    /// so there is no cost performance to extrapolate).
+    pub fn eac(&self, actual_cost: &Money) -> Option<Money> {
+        let cpi = self.cpi(actual_cost)?;
+        if cpi.is_zero() {
+            return None;
+        }
+        let bac = self.budget_at_completion()?;
+        let ev = self.earned_value_cost()?;
+        let eac = actual_cost.amount + (bac.amount - ev.amount) / cpi;
+        Some(Money::new(eac, bac.currency))
+    }
+
+    /// Variance at completion: `VAC = BAC − EAC` (negative means over budget)
+    pub fn vac(&self, actual_cost: &Money) -> Option<Money> {
+        let bac = self.budget_at_completion()?;
+        let eac = self.eac(actual_cost)?;
+        Some(Money::new(bac.amount - eac.amount, bac.currency))
+    }
+
+    /// To-complete performance index: `TCPI = (BAC − EV) / (BAC − AC)`
+    ///
+    /// The cost efficiency the remaining work needs to finish on budget.
+    /// Returns `None` when the budget is already spent (`AC ≥ BAC`).
+    pub fn tcpi(&self, actual_cost: &Money) -> Option<Decimal> {
+        let bac = self.budget_at_completion()?;
+        let ev = self.earned_value_cost()?;
+        if bac.currency != actual_cost.currency || actual_cost.amount >= bac.amount {
+            return None;
+        }
+        Some((bac.amount - ev.amount) / (bac.amount - actual_cost.amount))
+    }
+
+    /// Chain of tasks driving `task_id`'s early start
+    ///
+    /// Walks back through each task's `driving_predecessor` until a task
+    /// with no binding predecessor, returning the chain in start-to-finish
+    /// order and ending with `task_id` itself. For a critical task this is
+    /// the critical path up to that task. Unknown tasks yield an empty chain.
+    pub fn driving_path_to(&self, task_id: &str) -> Vec<TaskId> {
+        let mut path: Vec<TaskId> = Vec::new();
+        let mut current = self.tasks.get(task_id);
+        while let Some(task) = current {
+            if path.contains(&task.task_id) {
+                break;
+            }
+            path.push(task.task_id.clone());
+            current = task
+                .driving_predecessor
+                .as_ref()
+                .and_then(|pred| self.tasks.get(pred));
+        }
+        path.reverse();
+        path
+    }
+
+    /// Whether any scheduled task is nested under `task_id`
+    pub(crate) fn has_children(&self, task_id: &str) -> bool {
+        let prefix = format!("{}.", task_id);
+        self.tasks.keys().any(|id| id.starts_with(&prefix))
+    }
+
+    /// Tasks whose finish sets the project end date
+    ///
+    /// The leaf tasks finishing on `project_end`, sorted by ID. More than
+    /// one is returned when parallel chains tie for the latest finish.
+    /// Containers are skipped since their finish comes from a child.
+    pub fn finish_drivers(&self) -> Vec<TaskId> {
+        let mut drivers: Vec<TaskId> = self
+            .tasks
+            .values()
+            .filter(|task| task.finish == self.project_end)
+            .filter(|task| !self.has_children(&task.task_id))
+            .map(|task| task.task_id.clone())
+            .collect();
+        drivers.sort();
+        drivers
+    }
+
+    /// Driving chain from project start to each of [`Schedule::finish_drivers`]
+    ///
+    /// These are the critical paths that end on the project finish: the
+    /// work to compress to finish sooner.
+    pub fn finish_driving_paths(&self) -> Vec<Vec<TaskId>> {
+        self.finish_drivers()
+            .iter()
+            .map(|id| self.driving_path_to(id))
+            .collect()
+    }
+
+    /// Critical path of the work still ahead as of `as_of`
+    ///
+    /// Tasks that are complete, or forecast to finish before `as_of`, drop
+    /// out even if they were critical. Criticality is re-derived over what
+    /// remains: the remaining leaves forecast to finish last, plus the
+    /// chain driving each of them back to the first completed task. Sorted
+    /// by forecast start, then ID. Use on a schedule replanned from
+    /// `as_of` so forecasts reflect progress to date.
+    pub fn remaining_critical_path(&self, as_of: NaiveDate) -> Vec<TaskId> {
+        let is_remaining = |task: &ScheduledTask| {
+            task.status != TaskStatus::Complete
+                && task.percent_complete < 100
+                && task.forecast_finish >= as_of
+        };
+        let Some(remaining_finish) = self
+            .tasks
+            .values()
+            .filter(|task| is_remaining(task) && !self.has_children(&task.task_id))
+            .map(|task| task.forecast_finish)
+            .max()
+        else {
+            return Vec::new();
+        };
+
+        let mut path: Vec<&ScheduledTask> = Vec::new();
+        let mut drivers: Vec<&ScheduledTask> = self
+            .tasks
+            .values()
+            .filter(|task| is_remaining(task) && task.forecast_finish == remaining_finish)
+            .filter(|task| !self.has_children(&task.task_id))
+            .collect();
+        while let Some(task) = drivers.pop() {
+            if path.iter().any(|seen| seen.task_id == task.task_id) {
+                continue;
+            }
+            path.push(task);
+            if let Some(pred) = task
+                .driving_predecessor
+                .as_ref()
+                .and_then(|pred| self.tasks.get(pred))
+                .filter(|pred| is_remaining(pred))
+            {
+                drivers.push(pred);
+            }
+        }
+
+        path.sort_by(|a, b| {
+            a.forecast_start
+                .cmp(&b.forecast_start)
+                .then_with(|| a.task_id.cmp(&b.task_id))
+        });
+        path.into_iter().map(|task| task.task_id.clone()).collect()
+    }
+
+    /// Critical tasks active in the inclusive window `from..=to`
+    ///
+    /// Filters `critical_path` to the tasks whose scheduled dates overlap
+    /// the window, keeping its topological order. Tasks spanning either
+    /// boundary are included.
+    pub fn critical_path_in(&self, from: NaiveDate, to: NaiveDate) -> Vec<TaskId> {
+        self.critical_path
+            .iter()
+            .filter(|id| {
+                self.tasks
+                    .get(*id)
+                    .is_some_and(|task| task.overlaps(from, to))
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Milestones only, ordered by start date then task ID
+    ///
+    /// Uses the `is_milestone` flag carried from the task, so zero-duration
+    /// work that is not declared a milestone is left out.
+    pub fn milestones(&self) -> Vec<&ScheduledTask> {
+        self.query().milestones().collect()
+    }
+
+    /// Forecast movement of each milestone against a baseline
+    ///
+    /// Compares every milestone's forecast finish with its baselined finish;
+    /// milestones added since the baseline are skipped. Positive slip is
+    /// later than baseline, negative is pulled in. Sorted by absolute slip,
+    /// worst movers first, then by task ID.
+    pub fn milestone_slips(&self, baseline: &baseline::Baseline) -> Vec<baseline::MilestoneSlip> {
+        let mut slips: Vec<baseline::MilestoneSlip> = self
+            .tasks
+            .values()
+            .filter(|task| task.is_milestone)
+            .filter_map(|task| {
+                let snapshot = baseline.tasks.get(&task.task_id)?;
+                Some(baseline::MilestoneSlip::new(
+                    &task.task_id,
+                    snapshot.finish,
+                    task.forecast_finish,
+                ))
+            })
+            .collect();
+        slips.sort_by(|a, b| {
+            b.slip_days
+                .abs()
+                .cmp(&a.slip_days.abs())
+                .then_with(|| a.task_id.cmp(&b.task_id))
+        });
+        slips
+    }
+
+    /// How brittle the schedule is against its date constraints
+    ///
+    /// Counts zero-slack tasks whose late start or finish sits exactly on
+    /// one of their own ceiling constraints (the capped-late and pinned
+    /// cases of constraint effect analysis, W005), and measures the buffer
+    /// between the scheduled finish and the project end date. A non-zero
+    /// score with no buffer means any slip on those tasks misses a date.
+    pub fn fragility(&self, project: &Project) -> ScheduleFragility {
+        let mut constraint_critical: Vec<TaskId> = self
+            .tasks
+            .values()
+            .filter(|scheduled| scheduled.slack == Duration::zero())
+            .filter(|scheduled| {
+                project
+                    .get_task_by_path(&scheduled.task_id)
+                    .is_some_and(|task| {
+                        task.constraints.iter().any(|c| match c {
+                            TaskConstraint::MustStartOn(date)
+                            | TaskConstraint::StartNoLaterThan(date) => {
+                                scheduled.late_start == *date
+                            }
+                            TaskConstraint::MustFinishOn(date)
+                            | TaskConstraint::FinishNoLaterThan(date) => {
+                                scheduled.late_finish == *date
+                            }
+                            TaskConstraint::RelativeTo { .. } => c
+                                .relative_start(self, &project.default_calendar())
+                                .is_some_and(|date| scheduled.late_start == date),
+                            TaskConstraint::StartNoEarlierThan(_)
+                            | TaskConstraint::FinishNoEarlierThan(_) => false,
+                        })
+                    })
+            })
+            .map(|scheduled| scheduled.task_id.clone())
+            .collect();
+        constraint_critical.sort();
+
+        ScheduleFragility {
+            constraint_critical,
+            deadline_buffer_days: project.end.map(|end| (end - self.project_end).num_days()),
+        }
+    }
+
+    /// How much room the network leaves to slip, in 0–1
+    ///
+    /// `Σ slack / (leaf count × project_duration)`: the average leaf
+    /// task's total float as a share of the project length. 0 means every
+    /// task is critical; 0.5 means tasks can slip half the project length
+    /// on average. Containers are skipped since they only restate
+    /// children. Empty or zero-length schedules score 0.
+    pub fn flexibility(&self) -> f64 {
+        let slacks: Vec<f64> = self
+            .tasks
+            .values()
+            .filter(|task| !self.has_children(&task.task_id))
+            .map(|task| task.slack.as_days())
+            .collect();
+        let span = slacks.len() as f64 * self.project_duration.as_days();
+        if span <= 0.0 {
+            return 0.0;
+        }
+        slacks.iter().sum::<f64>() / span
+    }
+
+    /// How each of a task's constraints shaped its scheduled dates
+    ///
+    /// Classifies every constraint on the task at `task_id` (its qualified
+    /// ID) as pinned, pushing the start, capping the late dates, or
+    /// redundant, comparing the constraint date with the task's early and
+    /// late dates. Empty when the task is unknown or not scheduled.
+    pub fn constraint_effects(&self, project: &Project, task_id: &str) -> Vec<ConstraintEffect> {
+        let (Some(task), Some(scheduled_task)) =
+            (project.get_task_by_path(task_id), self.tasks.get(task_id))
+        else {
+            return Vec::new();
+        };
+        let calendar = project.default_calendar();
+        let es = scheduled_task.start;
+        let ef = scheduled_task.finish;
+        let ls = scheduled_task.late_start;
+        let lf = scheduled_task.late_finish;
+        let slack = scheduled_task.slack;
+        let zero_slack = Duration::zero();
+
+        task.constraints
+            .iter()
+            .map(|c| {
+                let (effect, description) = match c {
+                    TaskConstraint::MustStartOn(date) => {
+                        if c.pins(es, ef, ls, lf) {
+                            (
+                                ConstraintEffectType::Pinned,
+                                format!("Task pinned to start on {}", date),
+                            )
+                        } else if es == *date {
+                            (
+                                ConstraintEffectType::PushedStart,
+                                format!("Constraint pushed early start to {}", date),
+                            )
+                        } else if es > *date {
+                            (
+                                ConstraintEffectType::Redundant,
+                                format!(
+                                    "Constraint date {} superseded by dependencies (ES={})",
+                                    date, es
+                                ),
+                            )
+                        } else {
+                            (
+                                ConstraintEffectType::CappedLate,
+                                format!("Constraint capped late start at {}", date),
+                            )
+                        }
+                    }
+                    TaskConstraint::MustFinishOn(date) => {
+                        if c.pins(es, ef, ls, lf) {
+                            (
+                                ConstraintEffectType::Pinned,
+                                format!("Task pinned to finish on {}", date),
+                            )
+                        } else if ef == *date {
+                            (
+                                ConstraintEffectType::PushedStart,
+                                format!("Constraint pushed early finish to {}", date),
+                            )
+                        } else if ef > *date {
+                            (
+                                ConstraintEffectType::Redundant,
+                                format!(
+                                    "Constraint date {} superseded by dependencies (EF={})",
+                                    date, ef
+                                ),
+                            )
+                        } else {
+                            (
+                                ConstraintEffectType::CappedLate,
+                                format!("Constraint capped late finish at {}", date),
+                            )
+                        }
+                    }
+                    TaskConstraint::StartNoEarlierThan(date) => {
+                        if es == *date {
+                            (
+                                ConstraintEffectType::PushedStart,
+                                format!("Task starts exactly on constraint boundary {}", date),
+                            )
+                        } else if es > *date {
+                            (
+                                ConstraintEffectType::Redundant,
+                                format!(
+                                    "Constraint {} redundant (dependencies already push ES to {})",
+                                    date, es
+                                ),
+                            )
+                        } else {
+                            // es < date shouldn't happen if scheduling is correct
+                            (
+                                ConstraintEffectType::PushedStart,
+                                format!("Constraint pushed early start to {}", date),
+                            )
+                        }
+                    }
+                    TaskConstraint::StartNoLaterThan(date) => {
+                        if ls == *date {
+                            if slack == zero_slack {
+                                (
+                                    ConstraintEffectType::CappedLate,
+                                    format!(
+                                        "Constraint made task critical (LS capped at {})",
+                                        date
+                                    ),
+                                )
+                            } else {
+                                (
+                                    ConstraintEffectType::CappedLate,
+                                    format!("Constraint capped late start at {}", date),
+                                )
+                            }
+                        } else if ls < *date {
+                            (
+                                ConstraintEffectType::Redundant,
+                                format!(
+                                    "Constraint {} redundant (successors already require LS={})",
+                                    date, ls
+                                ),
+                            )
+                        } else {
+                            (
+                                ConstraintEffectType::CappedLate,
+                                format!("Constraint caps late start at {}", date),
+                            )
+                        }
+                    }
+                    TaskConstraint::FinishNoEarlierThan(date) => {
+                        if ef == *date {
+                            (
+                                ConstraintEffectType::PushedStart,
+                                format!("Task finishes exactly on constraint boundary {}", date),
+                            )
+                        } else if ef > *date {
+                            (
+                                ConstraintEffectType::Redundant,
+                                format!(
+                                    "Constraint {} redundant (dependencies already push EF to {})",
+                                    date, ef
+                                ),
+                            )
+                        } else {
+                            (
+                                ConstraintEffectType::PushedStart,
+                                format!("Constraint pushed early finish to {}", date),
+                            )
+                        }
+                    }
+                    TaskConstraint::FinishNoLaterThan(date) => {
+                        if lf == *date {
+                            if slack == zero_slack {
+                                (
+                                    ConstraintEffectType::CappedLate,
+                                    format!(
+                                        "Constraint made task critical (LF capped at {})",
+                                        date
+                                    ),
+                                )
+                            } else {
+                                (
+                                    ConstraintEffectType::CappedLate,
+                                    format!("Constraint capped late finish at {}", date),
+                                )
+                            }
+                        } else if lf < *date {
+                            (
+                                ConstraintEffectType::Redundant,
+                                format!(
+                                    "Constraint {} redundant (successors already require LF={})",
+                                    date, lf
+                                ),
+                            )
+                        } else {
+                            (
+                                ConstraintEffectType::CappedLate,
+                                format!("Constraint caps late finish at {}", date),
+                            )
+                        }
+                    }
+                    TaskConstraint::RelativeTo {
+                        anchor,
+                        offset,
+                        from,
+                    } => {
+                        let days = offset.round_days();
+                        let relation = match days.cmp(&0) {
+                            std::cmp::Ordering::Less => format!("{}d before", -days),
+                            std::cmp::Ordering::Equal => "on the day".to_string(),
+                            std::cmp::Ordering::Greater => format!("{}d after", days),
+                        };
+                        let event = match from {
+                            StartOrFinish::Start => "starts",
+                            StartOrFinish::Finish => "finishes",
+                        };
+                        let anchor_desc = format!("{} '{}' {}", relation, anchor, event);
+                        match c.relative_start(self, &calendar) {
+                            None => (
+                                ConstraintEffectType::Redundant,
+                                format!("Anchor '{}' is not scheduled", anchor),
+                            ),
+                            Some(date) if es == date && ls == date => (
+                                ConstraintEffectType::Pinned,
+                                format!("Task pinned to start on {}, {}", date, anchor_desc),
+                            ),
+                            Some(date) if es == date => (
+                                ConstraintEffectType::PushedStart,
+                                format!("Anchor pushed early start to {}, {}", date, anchor_desc),
+                            ),
+                            Some(date) if es > date => (
+                                ConstraintEffectType::Redundant,
+                                format!(
+                                    "Anchor date {} ({}) superseded by dependencies (ES={})",
+                                    date, anchor_desc, es
+                                ),
+                            ),
+                            Some(date) => (
+                                ConstraintEffectType::CappedLate,
+                                format!("Anchor capped late start at {}, {}", date, anchor_desc),
+                            ),
+                        }
+                    }
+                };
+
+                ConstraintEffect {
+                    constraint: c.clone(),
+                    effect,
+                    description,
+                }
+            })
+            .collect()
+    }
+
+    /// Constraint effects for every constrained task in the project
+    ///
+    /// One entry per constraint, as classified by
+    /// [`Schedule::constraint_effects`], paired with the task's qualified
+    /// ID and sorted by ID (constraints keep their declared order).
+    pub fn constraint_report(&self, project: &Project) -> Vec<(TaskId, ConstraintEffect)> {
+        let mut task_ids: Vec<&TaskId> = self.tasks.keys().collect();
+        task_ids.sort();
+        task_ids
+            .into_iter()
+            .flat_map(|id| {
+                self.constraint_effects(project, id)
+                    .into_iter()
+                    .map(move |effect| (id.clone(), effect))
+            })
+            .collect()
+    }
+
+    /// Per-task risk score in 0–1 with the default [`RiskWeights`]
+    ///
+    /// See [`Schedule::risk_scores_with`].
+    pub fn risk_scores(&self) -> HashMap<TaskId, f64> {
+        self.risk_scores_with(&RiskWeights::default())
+    }
+
+    /// Per-task risk score in 0–1, higher is riskier
+    ///
+    /// Blends three signals, each normalized across the schedule's leaf
+    /// tasks: slack (the least slack scores 1), cost spread (the widest
+    /// `cost_range.spread_percent` scores 1) and criticality (critical
+    /// tasks score by the share of [`Schedule::finish_driving_paths`] they
+    /// lie on). Containers are skipped since they only restate children.
+    pub fn risk_scores_with(&self, weights: &RiskWeights) -> HashMap<TaskId, f64> {
+        let leaves: Vec<&ScheduledTask> = self
+            .tasks
+            .values()
+            .filter(|task| !self.has_children(&task.task_id))
+            .collect();
+        let max_slack = leaves
+            .iter()
+            .map(|task| task.slack.as_days())
+            .fold(0.0, f64::max);
+        let spread = |task: &ScheduledTask| {
+            task.cost_range
+                .as_ref()
+                .map_or(0.0, |range| range.spread_percent())
+        };
+        let max_spread = leaves.iter().map(|task| spread(task)).fold(0.0, f64::max);
+        let paths = self.finish_driving_paths();
+
+        leaves
+            .iter()
+            .map(|task| {
+                let slack = if max_slack > 0.0 {
+                    1.0 - task.slack.as_days().max(0.0) / max_slack
+                } else {
+                    1.0
+                };
+                let cost_spread = if max_spread > 0.0 {
+                    spread(task) / max_spread
+                } else {
+                    0.0
+                };
+                let criticality = if task.is_critical {
+                    let on_paths = paths
+                        .iter()
+                        .filter(|path| path.contains(&task.task_id))
+                        .count();
+                    (1 + on_paths) as f64 / (1 + paths.len()) as f64
+                } else {
+                    0.0
+                };
+                (
+                    task.task_id.clone(),
+                    weights.combine(slack, cost_spread, criticality),
+                )
+            })
+            .collect()
+    }
+
+    /// Project risk index in 0–1 with the default [`RiskWeights`]
+    pub fn risk_index(&self) -> f64 {
+        self.risk_index_with(&RiskWeights::default())
+    }
+
+    /// Project risk index in 0–1: the mean of the per-task risk scores
+    ///
+    /// Zero for a schedule with no tasks.
+    pub fn risk_index_with(&self, weights: &RiskWeights) -> f64 {
+        let scores = self.risk_scores_with(weights);
+        if scores.is_empty() {
+            return 0.0;
+        }
+        scores.values().sum::<f64>() / scores.len() as f64
+    }
+
+    /// Working days between a predecessor's finish and a successor's start
+    ///
+    /// Counts the idle working days after `pred` finishes and before `succ`
+    /// starts on the project calendar, so an FS link with no lag gives zero
+    /// and a 2-day lag gives two. Overlap (leads, SS/FF links) is negative.
+    /// Returns `None` unless `succ` depends directly on `pred`; both are
+    /// fully-qualified schedule IDs.
+    pub fn gap_between(&self, project: &Project, pred: &str, succ: &str) -> Option<Duration> {
+        let depends_on_pred = project
+            .get_task_by_path(succ)?
+            .depends
+            .iter()
+            .any(|dep| dependency_refers_to(dep, succ, pred));
+        if !depends_on_pred {
+            return None;
+        }
+
+        let pred_finish = self.tasks.get(pred)?.finish;
+        let succ_start = self.tasks.get(succ)?.start;
+        let after_finish = pred_finish.succ_opt()?;
+
+        Some(Duration::days(
+            project
+                .default_calendar()
+                .working_days_between(after_finish, succ_start),
+        ))
+    }
+
+    /// Free slack: working days a task can slip without delaying any
+    /// direct successor
+    ///
+    /// Each successor link allows the room between the linked dates (per
+    /// dependency type, less the lag) on the project calendar. The result
+    /// is the smallest room, capped at the task's total slack; tasks without
+    /// successors have free slack equal to total slack. Returns `None` for
+    /// an unknown task ID.
+    pub fn free_slack(&self, project: &Project, task_id: &str) -> Option<Duration> {
+        let task = self.tasks.get(task_id)?;
+        let calendar = project.default_calendar();
+        let mut free = task.slack.round_days();
+
+        for (succ_id, succ) in &self.tasks {
+            let Some(succ_task) = project.get_task_by_path(succ_id) else {
+                continue;
+            };
+            for dep in &succ_task.depends {
+                if !dependency_refers_to(dep, succ_id, task_id) {
+                    continue;
+                }
+                let (from, to) = dep.dep_type.linked_dates(task, succ)?;
+                let lag = dep.lag.map_or(0, |l| l.round_days());
+                free = free.min(calendar.working_days_between(from, to) - lag);
+            }
+        }
+
+        Some(Duration::days(free.max(0)))
+    }
+
+    /// Where dependency lags sit in the schedule and which ones bite
+    ///
+    /// One entry per dependency with a non-zero lag (or lead). A lag is
+    /// applied in full when it binds the successor, i.e. the successor sits
+    /// exactly the lag away from the linked predecessor date (per
+    /// dependency type, on the project calendar or in calendar days for
+    /// calendar lags); it is not applied when something else holds the
+    /// successor later. Sorted by successor, then predecessor.
+    pub fn lag_impact(&self, project: &Project) -> Vec<LagImpact> {
+        let calendar = project.default_calendar();
+        let (task_map, context_map) = graph::flatten_tasks_with_context(&project.tasks);
+        let mut impacts = Vec::new();
+
+        for (succ_id, succ) in &self.tasks {
+            let Some(succ_task) = task_map.get(succ_id) else {
+                continue;
+            };
+            for dep in &succ_task.depends {
+                let lag_days = dep.lag.map_or(0, |l| l.round_days());
+                if lag_days == 0 {
+                    continue;
+                }
+                let Some(pred_id) = graph::resolve_dependency_path(
+                    &dep.predecessor,
+                    succ_id,
+                    &context_map,
+                    &task_map,
+                ) else {
+                    continue;
+                };
+                let Some(pred) = self.tasks.get(&pred_id) else {
+                    continue;
+                };
+                let Some((from, to)) = dep.dep_type.linked_dates(pred, succ) else {
+                    continue;
+                };
+                let gap = if dep.lag_calendar {
+                    (to - from).num_days()
+                } else {
+                    calendar.working_days_between(from, to)
+                };
+                impacts.push(LagImpact {
+                    predecessor: pred_id,
+                    successor: succ_id.clone(),
+                    dep_type: dep.dep_type,
+                    lag_days,
+                    applied_days: if gap <= lag_days { lag_days } else { 0 },
+                });
+            }
+        }
+
+        impacts.sort_by(|a, b| {
+            a.successor
+                .cmp(&b.successor)
+                .then_with(|| a.predecessor.cmp(&b.predecessor))
+        });
+        impacts
+    }
+
+    /// Total work in person-days across leaf tasks
+    ///
+    /// Each assignment contributes its explicit `effort_days` or, failing
+    /// that, the task's working duration times its units. Containers are
+    /// skipped since their leaves already carry the work.
+    pub fn total_effort_person_days(&self) -> f64 {
+        self.tasks
+            .values()
+            .filter(|task| !self.has_children(&task.task_id))
+            .flat_map(|task| {
+                task.assignments.iter().map(move |assignment| {
+                    assignment
+                        .effort_days
+                        .unwrap_or_else(|| task.duration.as_days() * assignment.units as f64)
+                })
+            })
+            .sum()
+    }
+
+    /// Total cost per resource (or profile) across the project
+    ///
+    /// Sums each assignment's cost range, so profile assignments keep their
+    /// spread and concrete resources total to a fixed amount. Resources whose
+    /// assignments carry no cost (no rate) are omitted. When a resource's
+    /// assignments use more than one currency, only amounts in the first
+    /// currency (by task ID) are summed and `mixed_currencies` is set.
+    pub fn cost_by_resource(&self) -> HashMap<ResourceId, ResourceCost> {
+        let mut task_ids: Vec<&TaskId> = self.tasks.keys().collect();
+        task_ids.sort();
+
+        let mut task_counts: HashMap<&str, usize> = HashMap::new();
+        let mut rollup: HashMap<ResourceId, ResourceCost> = HashMap::new();
+        for task_id in task_ids {
+            for assignment in &self.tasks[task_id].assignments {
+                *task_counts.entry(&assignment.resource_id).or_default() += 1;
+
+                let cost = match (&assignment.cost_range, &assignment.cost) {
+                    (Some(range), _) => range.clone(),
+                    (None, Some(money)) => CostRange::fixed(money.amount, &money.currency),
+                    (None, None) => continue,
+                };
+                match rollup.get_mut(&assignment.resource_id) {
+                    Some(entry) if entry.cost.currency == cost.currency => {
+                        entry.cost = entry.cost.add(&cost);
+                    }
+                    Some(entry) => entry.mixed_currencies = true,
+                    None => {
+                        rollup.insert(
+                            assignment.resource_id.clone(),
+                            ResourceCost {
+                                cost,
+                                task_count: 0,
+                                mixed_currencies: false,
+                            },
+                        );
+                    }
+                }
+            }
+        }
+
+        for (resource_id, entry) in &mut rollup {
+            entry.task_count = task_counts[resource_id.as_str()];
+        }
+        rollup
+    }
+
+    /// Tasks a resource (or profile) is assigned to, with dates and units
+    ///
+    /// Returns `(task, start, finish, units)` from each assignment, sorted
+    /// by start date then task ID. Overlapping entries are kept as they
+    /// are, so a double-booked resource shows every booking.
+    pub fn resource_timeline(&self, resource_id: &str) -> Vec<(TaskId, NaiveDate, NaiveDate, f32)> {
+        let mut timeline: Vec<(TaskId, NaiveDate, NaiveDate, f32)> = self
+            .tasks
+            .values()
+            .flat_map(|task| {
+                task.assignments
+                    .iter()
+                    .filter(|a| a.resource_id == resource_id)
+                    .map(|a| (task.task_id.clone(), a.start, a.finish, a.units))
+            })
+            .collect();
+        timeline.sort_by(|a, b| a.1.cmp(&b.1).then_with(|| a.0.cmp(&b.0)));
+        timeline
+    }
+
+    /// Staffing need per abstract profile, before profiles are refined to
+    /// people
+    ///
+    /// Only abstract (profile) assignments on leaf tasks count. Units of
+    /// overlapping assignments add up day by day to give the peak concurrent
+    /// demand; total work uses each assignment's explicit `effort_days` or,
+    /// failing that, the task's working duration times its units.
+    pub fn profile_demand(&self) -> HashMap<ProfileId, ProfileDemand> {
+        // Unit changes per profile and day: +units on the first day of an
+        // assignment, -units the day after its last
+        let mut changes: HashMap<&str, std::collections::BTreeMap<NaiveDate, f32>> = HashMap::new();
+        let mut demand: HashMap<ProfileId, ProfileDemand> = HashMap::new();
+
+        for task in self.tasks.values() {
+            if self.has_children(&task.task_id) {
+                continue;
+            }
+            for assignment in task.assignments.iter().filter(|a| a.is_abstract) {
+                let days = assignment
+                    .effort_days
+                    .unwrap_or_else(|| task.duration.as_days() * assignment.units as f64);
+                let entry = demand
+                    .entry(assignment.resource_id.clone())
+                    .or_insert(ProfileDemand {
+                        peak_units: 0.0,
+                        peak_date: assignment.start,
+                        first_needed: assignment.start,
+                        total_days: 0.0,
+                    });
+                entry.first_needed = entry.first_needed.min(assignment.start);
+                entry.total_days += days;
+
+                let profile = changes.entry(assignment.resource_id.as_str()).or_default();
+                *profile.entry(assignment.start).or_default() += assignment.units;
+                if let Some(after) = assignment.finish.succ_opt() {
+                    *profile.entry(after).or_default() -= assignment.units;
+                }
+            }
+        }
+
+        for (profile, days) in changes {
+            let Some(entry) = demand.get_mut(profile) else {
+                continue;
+            };
+            let mut units = 0.0;
+            for (date, change) in days {
+                units += change;
+                if units > entry.peak_units {
+                    entry.peak_units = units;
+                    entry.peak_date = date;
+                }
+            }
+        }
+        demand
+    }
+
+    /// Projected cash disbursements per period
+    ///
+    /// Each leaf task's expected cost is incurred evenly across its calendar
+    /// days (start to finish), shifted by the payment lag, then summed into
+    /// periods of `interval`. Returns one entry per period, keyed by the
+    /// period's first day, from the first payment to the last (empty periods
+    /// included as zero). Only amounts in the project's cost currency are
+    /// included; returns an empty list without a cost baseline.
+    pub fn cash_flow(
+        &self,
+        terms: PaymentTerms,
+        interval: ReportInterval,
+    ) -> Vec<(NaiveDate, Money)> {
+        let Some(currency) = self.total_cost_range.as_ref().map(|r| r.currency.clone()) else {
+            return Vec::new();
+        };
+
+        let mut buckets: std::collections::BTreeMap<NaiveDate, Decimal> =
+            std::collections::BTreeMap::new();
+        for task in self.tasks.values() {
+            // Containers carry their children's costs rolled up
+            if self.has_children(&task.task_id) {
+                continue;
+            }
+            let Some(cost) = task.cost_range.as_ref() else {
+                continue;
+            };
+            if cost.currency != currency || cost.expected.is_zero() {
+                continue;
+            }
+            let days = (task.finish - task.start).num_days().max(0) + 1;
+            let per_day = cost.expected / Decimal::from(days);
+            for offset in 0..days {
+                let paid = task.start + chrono::Duration::days(offset + terms.net_days);
+                *buckets.entry(interval.period_start(paid)).or_default() += per_day;
+            }
+        }
+
+        let (Some(&first), Some(&last)) = (buckets.keys().next(), buckets.keys().next_back())
+        else {
+            return Vec::new();
+        };
+        let mut flow = Vec::new();
+        let mut period = first;
+        while period <= last {
+            let amount = buckets.get(&period).copied().unwrap_or_default();
+            flow.push((period, Money::new(amount, currency.clone())));
+            period = interval.next_period(period);
+        }
+        flow
+    }
+}
+
+/// When incurred costs are paid (see [`Schedule::cash_flow`])
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PaymentTerms {
+    /// Calendar days between incurring a cost and paying it
+    pub net_days: i64,
+}
+
+impl PaymentTerms {
+    /// Costs are paid as they are incurred
+    pub fn immediate() -> Self {
+        Self::default()
+    }
+
+    /// Costs are paid `days` calendar days after they are incurred (net-N)
+    pub fn net(days: i64) -> Self {
+        Self { net_days: days }
+    }
+}
+
+/// Period length for time-bucketed reports
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReportInterval {
+    /// One period per calendar day
+    Day,
+    /// Monday-based weeks
+    Week,
+    /// Calendar months
+    Month,
+}
+
+impl ReportInterval {
+    /// First day of the period containing `date`
+    pub fn period_start(&self, date: NaiveDate) -> NaiveDate {
+        match self {
+            ReportInterval::Day => date,
+            ReportInterval::Week => {
+                date - chrono::Duration::days(date.weekday().num_days_from_monday() as i64)
+            }
+            ReportInterval::Month => date.with_day(1).unwrap_or(date),
+        }
+    }
+
+    /// First day of the period after the one starting at `period`
+    pub fn next_period(&self, period: NaiveDate) -> NaiveDate {
+        match self {
+            ReportInterval::Day => period + chrono::Duration::days(1),
+            ReportInterval::Week => period + chrono::Duration::days(7),
+            ReportInterval::Month => period
+                .checked_add_months(chrono::Months::new(1))
+                .unwrap_or(period),
+        }
+    }
+}
+
+/// Day-count convention for turning a date span into years
+///
+/// Every feature that discounts, escalates or accrues over time goes through
+/// [`years_between`] (or [`DayCount::years_between`]) so they all agree on
+/// the length of a year.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DayCount {
+    /// Actual calendar days over 365.25 (leap years averaged in)
+    #[default]
+    Actual365_25,
+    /// Actual calendar days over 365
+    Actual365,
+    /// Actual calendar days over 360
+    Actual360,
+}
+
+impl DayCount {
+    /// Days in a year under this convention
+    pub fn days_per_year(&self) -> f64 {
+        match self {
+            DayCount::Actual365_25 => 365.25,
+            DayCount::Actual365 => 365.0,
+            DayCount::Actual360 => 360.0,
+        }
+    }
+
+    /// Years from `from` to `to` (negative when `to` is earlier)
+    pub fn years_between(&self, from: NaiveDate, to: NaiveDate) -> f64 {
+        (to - from).num_days() as f64 / self.days_per_year()
+    }
+}
+
+/// Years from `from` to `to` under the default actual/365.25 convention
+pub fn years_between(from: NaiveDate, to: NaiveDate) -> f64 {
+    DayCount::default().years_between(from, to)
+}
+
+/// Whether a dependency declared on `succ` points at `pred`, resolving
+/// sibling-relative references against `succ`'s container (qualified IDs)
+fn dependency_refers_to(dep: &Dependency, succ: &str, pred: &str) -> bool {
+    dep.predecessor == pred
+        || succ
+            .rsplit_once('.')
+            .is_some_and(|(container, _)| format!("{}.{}", container, dep.predecessor) == pred)
+}
+
+/// A lagged dependency and the delay it puts on the schedule (see
+/// [`Schedule::lag_impact`])
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LagImpact {
+    /// Predecessor (fully-qualified ID)
+    pub predecessor: TaskId,
+    /// Successor (fully-qualified ID)
+    pub successor: TaskId,
+    /// Dependency type
+    pub dep_type: DependencyType,
+    /// Lag on the dependency in days (negative for a lead)
+    pub lag_days: i64,
+    /// Days of the lag that push the successor: the full lag when the
+    /// dependency binds the successor, zero otherwise
+    pub applied_days: i64,
+}
+
+/// Cost rollup for one resource or profile (see [`Schedule::cost_by_resource`])
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ResourceCost {
+    /// Total cost: fixed for concrete resources, a range for profiles
+    pub cost: CostRange,
+    /// Number of tasks the resource is assigned to
+    pub task_count: usize,
+    /// Whether assignments were priced in more than one currency
+    pub mixed_currencies: bool,
+}
+
+/// Staffing need for one abstract profile (see [`Schedule::profile_demand`])
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ProfileDemand {
+    /// Highest total units of the profile assigned on any one day
+    pub peak_units: f32,
+    /// First day the peak is reached
+    pub peak_date: NaiveDate,
+    /// First day the profile is needed
+    pub first_needed: NaiveDate,
+    /// Total work in profile-days across all its assignments
+    pub total_days: f64,
+}
+
+/// Constraint-induced brittleness of a schedule (see [`Schedule::fragility`])
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScheduleFragility {
+    /// Zero-slack tasks whose late dates are set by their own date
+    /// constraint rather than by successors, sorted by ID
+    pub constraint_critical: Vec<TaskId>,
+    /// Calendar days from the scheduled finish to the project end date,
+    /// negative when late (`None` when the project has no end date)
+    pub deadline_buffer_days: Option<i64>,
+}
+
+impl ScheduleFragility {
+    /// Fragility score: the number of constraint-critical tasks
+    pub fn score(&self) -> usize {
+        self.constraint_critical.len()
+    }
+}
+
+/// Relative weights of the signals in [`Schedule::risk_scores_with`]
+///
+/// Only the ratios matter: scores are divided by the total weight.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RiskWeights {
+    /// Weight of low slack
+    pub slack: f64,
+    /// Weight of a wide cost range
+    pub cost_spread: f64,
+    /// Weight of lying on finish-driving paths
+    pub criticality: f64,
+}
+
+impl Default for RiskWeights {
+    fn default() -> Self {
+        Self {
+            slack: 0.5,
+            cost_spread: 0.25,
+            criticality: 0.25,
+        }
+    }
+}
+
+impl RiskWeights {
+    /// Weighted mean of normalized signals (zero when all weights are zero)
+    fn combine(&self, slack: f64, cost_spread: f64, criticality: f64) -> f64 {
+        let total = self.slack + self.cost_spread + self.criticality;
+        if total <= 0.0 {
+            return 0.0;
+        }
+        (self.slack * slack + self.cost_spread * cost_spread + self.criticality * criticality)
+            / total
+    }
+}
+
+/// Why a task has zero slack
+///
+/// Network-critical work is shortened by compressing the chain; a
+/// constraint- or deadline-critical task is relieved by relaxing a date.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CriticalReason {
+    /// Successor pressure back from the project finish
+    NetworkDriven,
+    /// A ceiling constraint (SNLT, FNLT, MSO, MFO) capped the late dates
+    ConstraintDriven,
+    /// A ceiling constraint on a deadline-regime task capped the late dates
+    DeadlineDriven,
 }
 
 /// A task with computed schedule information
@@ -1507,6 +4097,10 @@ pub struct ScheduledTask {
     pub assignments: Vec<Assignment>,
     /// Slack/float time
     pub slack: Duration,
+    /// Free slack: how far the task can slip without delaying any direct
+    /// successor (at most `slack`)
+    #[serde(default = "Duration::zero")]
+    pub free_slack: Duration,
     /// Is this task on the critical path?
     pub is_critical: bool,
     /// Early start date
@@ -1547,10 +4141,28 @@ pub struct ScheduledTask {
     // ========================================================================
     // RFC-0001: Cost Range Fields
     // ========================================================================
-    /// Task cost range (aggregated from all assignments)
+    /// Task cost range (aggregated from all assignments; for containers,
+    /// also the sum of their children's ranges)
     pub cost_range: Option<CostRange>,
     /// Whether this task has any abstract (profile) assignments
     pub has_abstract_assignments: bool,
+
+    /// Predecessor whose dependency determined this task's early start in
+    /// the forward pass (`None` when project start or a constraint binds)
+    pub driving_predecessor: Option<TaskId>,
+    /// Whether the task is a milestone
+    #[serde(default)]
+    pub is_milestone: bool,
+    /// Why the task is critical (`None` when it has slack)
+    #[serde(default)]
+    pub critical_reason: Option<CriticalReason>,
+    /// Whether a `MustStartOn`/`MustFinishOn` constraint fixes the task's
+    /// dates (early and late dates both equal the constraint date)
+    #[serde(default)]
+    pub is_pinned: bool,
+    /// Tags copied from the task
+    #[serde(default)]
+    pub tags: Vec<String>,
 }
 
 impl ScheduledTask {
@@ -1573,6 +4185,7 @@ impl ScheduledTask {
             duration,
             assignments: Vec::new(),
             slack,
+            free_slack: slack,
             is_critical,
             early_start: start,
             early_finish: finish,
@@ -1589,8 +4202,30 @@ impl ScheduledTask {
             finish_variance_days: 0,
             cost_range: None,
             has_abstract_assignments: false,
+            driving_predecessor: None,
+            is_milestone: false,
+            critical_reason: None,
+            is_pinned: false,
+            tags: Vec::new(),
         }
     }
+
+    /// Whether the resource (or profile) has an assignment on this task
+    pub fn is_assigned_to(&self, resource_id: &str) -> bool {
+        self.assignments
+            .iter()
+            .any(|a| a.resource_id == resource_id)
+    }
+
+    /// Whether the task carries the tag
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t == tag)
+    }
+
+    /// Whether the task's scheduled dates overlap the inclusive range
+    pub fn overlaps(&self, from: NaiveDate, to: NaiveDate) -> bool {
+        self.start <= to && self.finish >= from
+    }
 }
 
 /// Resource assignment for a specific period
@@ -1609,6 +4244,36 @@ pub struct Assignment {
     /// When set, this overrides the calculated effort (duration × units).
     /// Used when task has explicit `effort:` attribute different from duration.
     pub effort_days: Option<f64>,
+    /// RFC-0001: How a profile assignment's rate was derived
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cost_explanation: Option<CostExplanation>,
+    /// Time of day work starts, for a single-day assignment tracked below
+    /// day granularity. With `finish_time`, resource timelines compare
+    /// minute-level windows instead of booking the whole day. The solver
+    /// always leaves both unset; set them on a schedule's assignments
+    /// before leveling or conflict checks to book time windows. A window
+    /// whose finish is not after its start books the whole day.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<NaiveTime>,
+    /// Time of day work ends (see `start_time`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub finish_time: Option<NaiveTime>,
+}
+
+/// Breakdown of a profile's daily rate (RFC-0001)
+///
+/// Trait multipliers compose multiplicatively, so `rate` is `base_rate`
+/// scaled by the product of the listed multipliers.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CostExplanation {
+    /// Rate range before traits, from the profile or the nearest profile it
+    /// specializes
+    pub base_rate: RateRange,
+    /// Trait multipliers in the order they were applied; unknown traits are
+    /// left out
+    pub trait_multipliers: Vec<(TraitId, f64)>,
+    /// Rate range after all multipliers
+    pub rate: RateRange,
 }
 
 // ============================================================================
@@ -1737,6 +4402,24 @@ pub struct Explanation {
     pub calendar_impact: Option<CalendarImpact>,
     /// Diagnostics relevant to this task's scheduling
     pub related_diagnostics: Vec<DiagnosticCode>,
+    /// Each dependency with the earliest start it implies
+    pub dependencies: Vec<DependencyExplanation>,
+}
+
+/// How one dependency constrains a task (see [`Explanation::dependencies`])
+#[derive(Clone, Debug, PartialEq)]
+pub struct DependencyExplanation {
+    /// Predecessor as written in the dependency
+    pub predecessor: TaskId,
+    /// Type of dependency
+    pub dep_type: DependencyType,
+    /// Lag (positive) or lead (negative), if any
+    pub lag: Option<Duration>,
+    /// Earliest start this dependency alone allows, from the predecessor's
+    /// scheduled dates (`None` when the predecessor could not be scheduled)
+    pub earliest_start: Option<NaiveDate>,
+    /// Human-readable form, e.g. "SS from design + 2d → earliest 2025-01-08"
+    pub description: String,
 }
 
 /// Constraint for what-if analysis
@@ -1759,6 +4442,42 @@ pub enum Constraint {
     },
 }
 
+impl Constraint {
+    /// Apply this change to a project in place
+    ///
+    /// Tasks are looked up like [`Project::get_task`]. A deadline sets the
+    /// project end date.
+    pub fn apply_to(&self, project: &mut Project) -> Result<(), ScheduleError> {
+        match self {
+            Constraint::TaskEffort { task_id, effort } => {
+                let task = project
+                    .get_task_mut(task_id)
+                    .ok_or_else(|| ScheduleError::TaskNotFound(task_id.clone()))?;
+                task.effort = Some(*effort);
+            }
+            Constraint::TaskDuration { task_id, duration } => {
+                let task = project
+                    .get_task_mut(task_id)
+                    .ok_or_else(|| ScheduleError::TaskNotFound(task_id.clone()))?;
+                task.duration = Some(*duration);
+            }
+            Constraint::ResourceCapacity {
+                resource_id,
+                capacity,
+            } => {
+                let resource = project
+                    .resources
+                    .iter_mut()
+                    .find(|r| &r.id == resource_id)
+                    .ok_or_else(|| ScheduleError::ResourceNotFound(resource_id.clone()))?;
+                resource.capacity = *capacity;
+            }
+            Constraint::Deadline { date } => project.end = Some(*date),
+        }
+        Ok(())
+    }
+}
+
 /// Result of what-if analysis
 #[derive(Clone, Debug)]
 pub struct WhatIfReport {
@@ -1766,8 +4485,13 @@ pub struct WhatIfReport {
     pub solutions_before: num_bigint::BigUint,
     pub solutions_after: num_bigint::BigUint,
     pub newly_critical: Vec<TaskId>,
+    /// The set of critical tasks differs from the unchanged plan's
+    pub critical_path_changed: bool,
     pub schedule_delta: Option<Duration>,
     pub cost_delta: Option<Money>,
+    /// A batch of changes is infeasible although each change is feasible
+    /// on its own
+    pub infeasible_in_combination: bool,
 }
 
 // ============================================================================
@@ -1789,6 +4513,9 @@ pub enum ScheduleError {
     #[error("Calendar not found: {0}")]
     CalendarNotFound(CalendarId),
 
+    #[error("Invalid calendar: {0} has no working days")]
+    InvalidCalendar(CalendarId),
+
     #[error("Infeasible schedule: {0}")]
     Infeasible(String),
 
@@ -1823,7 +4550,8 @@ pub enum RenderError {
 /// - Warning: Likely problem, becomes error in --strict mode
 /// - Hint: Suggestion, becomes warning in --strict mode
 /// - Info: Informational, unchanged in --strict mode
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Severity {
     Error,
     Warning,
@@ -1868,6 +4596,8 @@ pub enum DiagnosticCode {
     E003InfeasibleConstraint,
     /// Duplicate task ID among sibling tasks
     E004DuplicateTaskId,
+    /// Costs in different currencies would be summed into one total
+    E005MixedCurrency,
     /// Rate range is inverted (min > max) - RFC-0004 R102
     R102InvertedRateRange,
     /// Unknown profile referenced in specialization - RFC-0004 R104
@@ -1896,14 +4626,36 @@ pub enum DiagnosticCode {
     W006ScheduleVariance,
     /// Dependency references a task that does not exist
     W007UnresolvedDependency,
+    /// Remaining effort far exceeds resource capacity in the planning window
+    W008CapacityShortfall,
+    /// Resource and profile share an ID, so the profile is never assigned
+    W009ProfileShadowed,
+    /// Assignments consume more of a consumable resource than its pool holds
+    W010ConsumablePoolExceeded,
+    /// Leaf task has no effort or duration and is not a milestone
+    W011UnsizedTask,
+    /// Relative dependency name matches more than one task in the tree
+    W012AmbiguousDependency,
+    /// Container task sets effort or duration, which its children override
+    W013ContainerEffort,
     /// Container has dependencies but child task has none (MS Project compatibility)
     W014ContainerDependency,
+    /// Active task depends on an inactive one
+    W015InactiveDependency,
+    /// Frozen task cannot keep its prior start when rescheduled
+    W016FrozenTaskMoved,
+    /// Dependency lead reaches back before the project start
+    W017DegenerateDependency,
+    /// Single assignment asks for more units than the resource has
+    W018AssignmentExceedsCapacity,
 
     // Calendar Warnings (C010-C019)
     /// Task scheduled on non-working day
     C010NonWorkingDay,
     /// Task and assigned resource use different calendars
     C011CalendarMismatch,
+    /// Holiday covers a date that also has a working-hours exception
+    C012HolidayExceptionConflict,
 
     // Hints (H) - Suggestions
     /// Task has both concrete and abstract assignments
@@ -1914,6 +4666,8 @@ pub enum DiagnosticCode {
     H003UnusedTrait,
     /// Task has no predecessors or date constraints (dangling/orphan task)
     H004TaskUnconstrained,
+    /// Task has no path of successors leading to the project end
+    H005TaskDisconnected,
 
     // Calendar Hints (C020-C029)
     /// Calendar has low availability (< 40% working days)
@@ -1924,6 +4678,8 @@ pub enum DiagnosticCode {
     C022SuspiciousHours,
     /// Holiday falls on non-working day (redundant)
     C023RedundantHoliday,
+    /// Holidays with duplicate or overlapping date ranges
+    C024OverlappingHolidays,
 
     // Info (I) - Informational
     /// Project scheduling summary
@@ -1936,6 +4692,8 @@ pub enum DiagnosticCode {
     I004ProjectStatus,
     /// Earned value summary (EV, PV, SPI)
     I005EarnedValueSummary,
+    /// Tasks made critical by date constraints, with deadline buffer
+    I006ScheduleFragility,
 
     // Leveling (L) - Resource leveling diagnostics
     /// Resource overallocation resolved by delaying task
@@ -1958,6 +4716,8 @@ pub enum DiagnosticCode {
     P005RemainingCompleteConflict,
     /// Container's explicit progress conflicts with weighted children average
     P006ContainerProgressMismatch,
+    /// Explicit status, complete%, and actual dates contradict each other
+    P007ProgressFieldConflict,
 
     // Temporal Regimes (R) - RFC-0012 diagnostics
     /// Event regime task has non-zero duration (events are typically point-in-time)
@@ -1993,6 +4753,81 @@ pub enum DiagnosticCode {
 }
 
 impl DiagnosticCode {
+    /// Every diagnostic code, in declaration order
+    pub const ALL: &'static [DiagnosticCode] = &[
+        DiagnosticCode::E001CircularSpecialization,
+        DiagnosticCode::E002ProfileWithoutRate,
+        DiagnosticCode::E003InfeasibleConstraint,
+        DiagnosticCode::E004DuplicateTaskId,
+        DiagnosticCode::E005MixedCurrency,
+        DiagnosticCode::R102InvertedRateRange,
+        DiagnosticCode::R104UnknownProfile,
+        DiagnosticCode::C001ZeroWorkingHours,
+        DiagnosticCode::C002NoWorkingDays,
+        DiagnosticCode::W001AbstractAssignment,
+        DiagnosticCode::W002WideCostRange,
+        DiagnosticCode::W003UnknownTrait,
+        DiagnosticCode::R012TraitMultiplierStack,
+        DiagnosticCode::W004ApproximateLeveling,
+        DiagnosticCode::W005ConstraintZeroSlack,
+        DiagnosticCode::W006ScheduleVariance,
+        DiagnosticCode::W007UnresolvedDependency,
+        DiagnosticCode::W008CapacityShortfall,
+        DiagnosticCode::W009ProfileShadowed,
+        DiagnosticCode::W010ConsumablePoolExceeded,
+        DiagnosticCode::W011UnsizedTask,
+        DiagnosticCode::W012AmbiguousDependency,
+        DiagnosticCode::W013ContainerEffort,
+        DiagnosticCode::W014ContainerDependency,
+        DiagnosticCode::W015InactiveDependency,
+        DiagnosticCode::W016FrozenTaskMoved,
+        DiagnosticCode::W017DegenerateDependency,
+        DiagnosticCode::W018AssignmentExceedsCapacity,
+        DiagnosticCode::C010NonWorkingDay,
+        DiagnosticCode::C011CalendarMismatch,
+        DiagnosticCode::C012HolidayExceptionConflict,
+        DiagnosticCode::H001MixedAbstraction,
+        DiagnosticCode::H002UnusedProfile,
+        DiagnosticCode::H003UnusedTrait,
+        DiagnosticCode::H004TaskUnconstrained,
+        DiagnosticCode::H005TaskDisconnected,
+        DiagnosticCode::C020LowAvailability,
+        DiagnosticCode::C021MissingCommonHoliday,
+        DiagnosticCode::C022SuspiciousHours,
+        DiagnosticCode::C023RedundantHoliday,
+        DiagnosticCode::C024OverlappingHolidays,
+        DiagnosticCode::I001ProjectCostSummary,
+        DiagnosticCode::I002RefinementProgress,
+        DiagnosticCode::I003ResourceUtilization,
+        DiagnosticCode::I004ProjectStatus,
+        DiagnosticCode::I005EarnedValueSummary,
+        DiagnosticCode::I006ScheduleFragility,
+        DiagnosticCode::L001OverallocationResolved,
+        DiagnosticCode::L002UnresolvableConflict,
+        DiagnosticCode::L003DurationIncreased,
+        DiagnosticCode::L004MilestoneDelayed,
+        DiagnosticCode::L005OptimalSolution,
+        DiagnosticCode::L006ThresholdExceeded,
+        DiagnosticCode::L007SolverTimeout,
+        DiagnosticCode::P005RemainingCompleteConflict,
+        DiagnosticCode::P006ContainerProgressMismatch,
+        DiagnosticCode::P007ProgressFieldConflict,
+        DiagnosticCode::R001EventNonZeroDuration,
+        DiagnosticCode::R002WorkConstraintOnNonWorkingDay,
+        DiagnosticCode::R003DeadlineWithoutConstraint,
+        DiagnosticCode::R004ImplicitEventRegime,
+        DiagnosticCode::R005MixedRegimeDependency,
+        DiagnosticCode::B001BaselineSaved,
+        DiagnosticCode::B002TaskLacksId,
+        DiagnosticCode::B003BaselineExists,
+        DiagnosticCode::B004BaselineNotFound,
+        DiagnosticCode::B005TaskRemoved,
+        DiagnosticCode::B006TaskAdded,
+        DiagnosticCode::B007NoBaselinesFile,
+        DiagnosticCode::B008ContainerExcluded,
+        DiagnosticCode::B009NoTaskIds,
+    ];
+
     /// Returns the short code string (e.g., "E001", "W002")
     pub fn as_str(&self) -> &'static str {
         match self {
@@ -2000,6 +4835,7 @@ impl DiagnosticCode {
             DiagnosticCode::E002ProfileWithoutRate => "E002",
             DiagnosticCode::E003InfeasibleConstraint => "E003",
             DiagnosticCode::E004DuplicateTaskId => "E004",
+            DiagnosticCode::E005MixedCurrency => "E005",
             DiagnosticCode::R102InvertedRateRange => "R102",
             DiagnosticCode::R104UnknownProfile => "R104",
             DiagnosticCode::C001ZeroWorkingHours => "C001",
@@ -2012,22 +4848,36 @@ impl DiagnosticCode {
             DiagnosticCode::W005ConstraintZeroSlack => "W005",
             DiagnosticCode::W006ScheduleVariance => "W006",
             DiagnosticCode::W007UnresolvedDependency => "W007",
+            DiagnosticCode::W008CapacityShortfall => "W008",
+            DiagnosticCode::W009ProfileShadowed => "W009",
+            DiagnosticCode::W010ConsumablePoolExceeded => "W010",
+            DiagnosticCode::W011UnsizedTask => "W011",
+            DiagnosticCode::W012AmbiguousDependency => "W012",
+            DiagnosticCode::W013ContainerEffort => "W013",
             DiagnosticCode::W014ContainerDependency => "W014",
+            DiagnosticCode::W015InactiveDependency => "W015",
+            DiagnosticCode::W016FrozenTaskMoved => "W016",
+            DiagnosticCode::W017DegenerateDependency => "W017",
+            DiagnosticCode::W018AssignmentExceedsCapacity => "W018",
             DiagnosticCode::C010NonWorkingDay => "C010",
             DiagnosticCode::C011CalendarMismatch => "C011",
+            DiagnosticCode::C012HolidayExceptionConflict => "C012",
             DiagnosticCode::H001MixedAbstraction => "H001",
             DiagnosticCode::H002UnusedProfile => "H002",
             DiagnosticCode::H003UnusedTrait => "H003",
             DiagnosticCode::H004TaskUnconstrained => "H004",
+            DiagnosticCode::H005TaskDisconnected => "H005",
             DiagnosticCode::C020LowAvailability => "C020",
             DiagnosticCode::C021MissingCommonHoliday => "C021",
             DiagnosticCode::C022SuspiciousHours => "C022",
             DiagnosticCode::C023RedundantHoliday => "C023",
+            DiagnosticCode::C024OverlappingHolidays => "C024",
             DiagnosticCode::I001ProjectCostSummary => "I001",
             DiagnosticCode::I002RefinementProgress => "I002",
             DiagnosticCode::I003ResourceUtilization => "I003",
             DiagnosticCode::I004ProjectStatus => "I004",
             DiagnosticCode::I005EarnedValueSummary => "I005",
+            DiagnosticCode::I006ScheduleFragility => "I006",
             DiagnosticCode::L001OverallocationResolved => "L001",
             DiagnosticCode::L002UnresolvableConflict => "L002",
             DiagnosticCode::L003DurationIncreased => "L003",
@@ -2037,6 +4887,7 @@ impl DiagnosticCode {
             DiagnosticCode::L007SolverTimeout => "L007",
             DiagnosticCode::P005RemainingCompleteConflict => "P005",
             DiagnosticCode::P006ContainerProgressMismatch => "P006",
+            DiagnosticCode::P007ProgressFieldConflict => "P007",
             DiagnosticCode::R001EventNonZeroDuration => "R001",
             DiagnosticCode::R002WorkConstraintOnNonWorkingDay => "R002",
             DiagnosticCode::R003DeadlineWithoutConstraint => "R003",
@@ -2062,6 +4913,7 @@ impl DiagnosticCode {
             DiagnosticCode::E002ProfileWithoutRate => Severity::Warning, // Error in strict mode
             DiagnosticCode::E003InfeasibleConstraint => Severity::Error,
             DiagnosticCode::E004DuplicateTaskId => Severity::Error,
+            DiagnosticCode::E005MixedCurrency => Severity::Error,
             DiagnosticCode::R102InvertedRateRange => Severity::Error,
             DiagnosticCode::R104UnknownProfile => Severity::Error,
             DiagnosticCode::C001ZeroWorkingHours => Severity::Error,
@@ -2074,22 +4926,36 @@ impl DiagnosticCode {
             DiagnosticCode::W005ConstraintZeroSlack => Severity::Warning,
             DiagnosticCode::W006ScheduleVariance => Severity::Warning,
             DiagnosticCode::W007UnresolvedDependency => Severity::Warning,
+            DiagnosticCode::W008CapacityShortfall => Severity::Warning,
+            DiagnosticCode::W009ProfileShadowed => Severity::Warning,
+            DiagnosticCode::W010ConsumablePoolExceeded => Severity::Warning,
+            DiagnosticCode::W011UnsizedTask => Severity::Warning,
+            DiagnosticCode::W012AmbiguousDependency => Severity::Warning,
+            DiagnosticCode::W013ContainerEffort => Severity::Warning,
             DiagnosticCode::W014ContainerDependency => Severity::Warning,
+            DiagnosticCode::W015InactiveDependency => Severity::Warning,
+            DiagnosticCode::W016FrozenTaskMoved => Severity::Warning,
+            DiagnosticCode::W017DegenerateDependency => Severity::Warning,
+            DiagnosticCode::W018AssignmentExceedsCapacity => Severity::Warning,
             DiagnosticCode::C010NonWorkingDay => Severity::Warning,
             DiagnosticCode::C011CalendarMismatch => Severity::Warning,
+            DiagnosticCode::C012HolidayExceptionConflict => Severity::Warning,
             DiagnosticCode::H001MixedAbstraction => Severity::Hint,
             DiagnosticCode::H002UnusedProfile => Severity::Hint,
             DiagnosticCode::H003UnusedTrait => Severity::Hint,
             DiagnosticCode::H004TaskUnconstrained => Severity::Hint,
+            DiagnosticCode::H005TaskDisconnected => Severity::Hint,
             DiagnosticCode::C020LowAvailability => Severity::Hint,
             DiagnosticCode::C021MissingCommonHoliday => Severity::Hint,
             DiagnosticCode::C022SuspiciousHours => Severity::Hint,
             DiagnosticCode::C023RedundantHoliday => Severity::Hint,
+            DiagnosticCode::C024OverlappingHolidays => Severity::Hint,
             DiagnosticCode::I001ProjectCostSummary => Severity::Info,
             DiagnosticCode::I002RefinementProgress => Severity::Info,
             DiagnosticCode::I003ResourceUtilization => Severity::Info,
             DiagnosticCode::I004ProjectStatus => Severity::Info,
             DiagnosticCode::I005EarnedValueSummary => Severity::Info,
+            DiagnosticCode::I006ScheduleFragility => Severity::Info,
             // Leveling diagnostics (L001-L007)
             DiagnosticCode::L001OverallocationResolved => Severity::Hint,
             DiagnosticCode::L002UnresolvableConflict => Severity::Warning,
@@ -2098,9 +4964,10 @@ impl DiagnosticCode {
             DiagnosticCode::L005OptimalSolution => Severity::Info,
             DiagnosticCode::L006ThresholdExceeded => Severity::Hint,
             DiagnosticCode::L007SolverTimeout => Severity::Warning,
-            // Progress diagnostics (P005-P006)
+            // Progress diagnostics (P005-P007)
             DiagnosticCode::P005RemainingCompleteConflict => Severity::Warning,
             DiagnosticCode::P006ContainerProgressMismatch => Severity::Warning,
+            DiagnosticCode::P007ProgressFieldConflict => Severity::Warning,
             // Temporal Regimes diagnostics (R001-R005)
             DiagnosticCode::R001EventNonZeroDuration => Severity::Info,
             DiagnosticCode::R002WorkConstraintOnNonWorkingDay => Severity::Info,
@@ -2130,6 +4997,7 @@ impl DiagnosticCode {
             DiagnosticCode::E002ProfileWithoutRate => 1,
             DiagnosticCode::E003InfeasibleConstraint => 2,
             DiagnosticCode::E004DuplicateTaskId => 3,
+            DiagnosticCode::E005MixedCurrency => 3,
             DiagnosticCode::R102InvertedRateRange => 4,
             DiagnosticCode::R104UnknownProfile => 5,
             // Calendar errors
@@ -2139,36 +5007,50 @@ impl DiagnosticCode {
             DiagnosticCode::W002WideCostRange => 10,
             DiagnosticCode::R012TraitMultiplierStack => 11,
             DiagnosticCode::W004ApproximateLeveling => 12,
+            DiagnosticCode::W008CapacityShortfall => 12,
+            DiagnosticCode::W010ConsumablePoolExceeded => 12,
             // Constraint warnings
             DiagnosticCode::W005ConstraintZeroSlack => 12,
             // Schedule variance warnings
             DiagnosticCode::W006ScheduleVariance => 13,
             // Dependency warnings
             DiagnosticCode::W007UnresolvedDependency => 14,
+            DiagnosticCode::W011UnsizedTask => 14,
+            DiagnosticCode::W012AmbiguousDependency => 14,
+            DiagnosticCode::W013ContainerEffort => 14,
+            DiagnosticCode::W015InactiveDependency => 14,
+            DiagnosticCode::W016FrozenTaskMoved => 14,
+            DiagnosticCode::W017DegenerateDependency => 14,
+            DiagnosticCode::W018AssignmentExceedsCapacity => 14,
             // MS Project compatibility warnings
             DiagnosticCode::W014ContainerDependency => 15,
             // Calendar warnings
             DiagnosticCode::C010NonWorkingDay => 15,
             DiagnosticCode::C011CalendarMismatch => 16,
+            DiagnosticCode::C012HolidayExceptionConflict => 16,
             // Assignment-related warnings
             DiagnosticCode::W001AbstractAssignment => 20,
             DiagnosticCode::W003UnknownTrait => 21,
+            DiagnosticCode::W009ProfileShadowed => 22,
             // Hints
             DiagnosticCode::H001MixedAbstraction => 30,
             DiagnosticCode::H002UnusedProfile => 31,
             DiagnosticCode::H003UnusedTrait => 32,
             DiagnosticCode::H004TaskUnconstrained => 33,
+            DiagnosticCode::H005TaskDisconnected => 33,
             // Calendar hints
             DiagnosticCode::C020LowAvailability => 34,
             DiagnosticCode::C021MissingCommonHoliday => 35,
             DiagnosticCode::C022SuspiciousHours => 36,
             DiagnosticCode::C023RedundantHoliday => 37,
+            DiagnosticCode::C024OverlappingHolidays => 38,
             // Info last
             DiagnosticCode::I001ProjectCostSummary => 40,
             DiagnosticCode::I002RefinementProgress => 41,
             DiagnosticCode::I003ResourceUtilization => 42,
             DiagnosticCode::I004ProjectStatus => 43,
             DiagnosticCode::I005EarnedValueSummary => 44,
+            DiagnosticCode::I006ScheduleFragility => 45,
             // Leveling diagnostics (after info, grouped together)
             DiagnosticCode::L001OverallocationResolved => 50,
             DiagnosticCode::L002UnresolvableConflict => 51,
@@ -2180,6 +5062,7 @@ impl DiagnosticCode {
             // Progress diagnostics (grouped with schedule variance)
             DiagnosticCode::P005RemainingCompleteConflict => 17,
             DiagnosticCode::P006ContainerProgressMismatch => 18,
+            DiagnosticCode::P007ProgressFieldConflict => 18,
             // Temporal Regimes diagnostics (after leveling, grouped together)
             DiagnosticCode::R001EventNonZeroDuration => 55,
             DiagnosticCode::R002WorkConstraintOnNonWorkingDay => 56,
@@ -2216,6 +5099,10 @@ impl DiagnosticCode {
             DiagnosticCode::E004DuplicateTaskId =>
                 "Two or more sibling tasks share the same ID. Task IDs must be unique \
                  within the same container. Rename one of the duplicates.",
+            DiagnosticCode::E005MixedCurrency =>
+                "Resource rates or profile rate ranges use more than one currency, so task and \
+                 project cost totals cannot be added up and are left out. Express every rate in \
+                 one currency.",
 
             // Rate/Profile errors
             DiagnosticCode::R102InvertedRateRange =>
@@ -2251,9 +5138,42 @@ impl DiagnosticCode {
                  Current forecast exceeds baseline by more than configured limit.",
             DiagnosticCode::W007UnresolvedDependency =>
                 "Task depends on a task that does not exist. Check the depends: reference for typos.",
+            DiagnosticCode::W008CapacityShortfall =>
+                "Remaining effort far exceeds the capacity of the project's resources \
+                 in the planning window. The schedule is unlikely to hold once resources are leveled.",
+            DiagnosticCode::W009ProfileShadowed =>
+                "A resource and a profile share an ID. Assignments resolve to the resource, \
+                 so the profile is never used. Rename one of them.",
+            DiagnosticCode::W010ConsumablePoolExceeded =>
+                "Tasks consume more of a consumable resource than its pool holds. \
+                 Increase the pool or reduce what the tasks consume.",
+            DiagnosticCode::W011UnsizedTask =>
+                "Task has no effort or duration, so it is scheduled with zero length. \
+                 Add effort or duration, or declare it as a milestone.",
+            DiagnosticCode::W012AmbiguousDependency =>
+                "A dependency written without a dot matches tasks in several containers. \
+                 Relative resolution picks one silently; use a qualified path to say which.",
+            DiagnosticCode::W013ContainerEffort =>
+                "Container task sets effort or duration, but its dates are rolled up from its \
+                 children, so the value is ignored. Move the estimate to a child task or remove it.",
             DiagnosticCode::W014ContainerDependency =>
                 "Container task has dependencies but its children don't inherit them. \
                  Consider moving dependencies to leaf tasks for MS Project compatibility.",
+            DiagnosticCode::W015InactiveDependency =>
+                "Task depends on an inactive task. The dependency is skipped, so the task \
+                 may start earlier than intended; drop the dependency or reactivate the task.",
+            DiagnosticCode::W016FrozenTaskMoved =>
+                "Frozen task could not keep its previously scheduled start because its \
+                 predecessors now finish later, so it was released and moved. Unfreeze it or \
+                 recover the time upstream.",
+            DiagnosticCode::W017DegenerateDependency =>
+                "Dependency lead is so large that the successor would have to start (or, for FF \
+                 and SF links, finish) before the project starts. The date is clamped to the \
+                 project start, so the link has no effect; shorten the lead or check the type.",
+            DiagnosticCode::W018AssignmentExceedsCapacity =>
+                "A single assignment asks for more units than the resource's capacity (or, for a \
+                 consumable, its whole pool), which no schedule can provide. Lower the units or \
+                 raise the resource's capacity.",
 
             // Calendar warnings
             DiagnosticCode::C010NonWorkingDay =>
@@ -2262,6 +5182,9 @@ impl DiagnosticCode {
             DiagnosticCode::C011CalendarMismatch =>
                 "Task and its assigned resource use different calendars. \
                  This may cause scheduling inconsistencies.",
+            DiagnosticCode::C012HolidayExceptionConflict =>
+                "A date is both a holiday and a working-hours exception. \
+                 Scheduling and capacity checks may disagree about that day.",
 
             // Hints
             DiagnosticCode::H001MixedAbstraction =>
@@ -2274,6 +5197,10 @@ impl DiagnosticCode {
             DiagnosticCode::H004TaskUnconstrained =>
                 "Task has no constraints or dependencies, allowing maximum flexibility. \
                  Consider adding constraints if a specific schedule is required.",
+            DiagnosticCode::H005TaskDisconnected =>
+                "No chain of successors leads from this task to the tasks that finish the \
+                 project, so nothing downstream waits for it. Link it to the work it feeds, \
+                 or remove it if it was left behind by a restructuring.",
 
             // Calendar hints
             DiagnosticCode::C020LowAvailability =>
@@ -2284,6 +5211,8 @@ impl DiagnosticCode {
                 "Calendar working hours seem unusual (e.g., >12h/day). Verify this is intentional.",
             DiagnosticCode::C023RedundantHoliday =>
                 "Holiday falls on a non-working day (already excluded). This has no scheduling effect.",
+            DiagnosticCode::C024OverlappingHolidays =>
+                "Two holidays cover some of the same dates. Merge them so each day is declared once.",
 
             // Info
             DiagnosticCode::I001ProjectCostSummary =>
@@ -2296,6 +5225,9 @@ impl DiagnosticCode {
                 "Project progress status showing completion percentage and variance.",
             DiagnosticCode::I005EarnedValueSummary =>
                 "Earned value summary with Schedule Performance Index (SPI).",
+            DiagnosticCode::I006ScheduleFragility =>
+                "Tasks with zero slack only because of their date constraints, and the buffer \
+                 left before the project end date. Any slip on these tasks misses a date.",
 
             // Leveling diagnostics
             DiagnosticCode::L001OverallocationResolved =>
@@ -2327,6 +5259,10 @@ impl DiagnosticCode {
             DiagnosticCode::P006ContainerProgressMismatch =>
                 "Container's explicit complete% differs from calculated child rollup by >10%. \
                  Update the container's complete% or check child task progress.",
+            DiagnosticCode::P007ProgressFieldConflict =>
+                "Task's explicit status, complete%, and actual dates contradict each other, \
+                 e.g. status complete at 50%, or not started with an actual start. An explicit \
+                 status overrides the derived one, so correct whichever field is stale.",
 
             // Temporal Regime diagnostics
             DiagnosticCode::R001EventNonZeroDuration =>
@@ -2383,8 +5319,35 @@ impl std::fmt::Display for DiagnosticCode {
     }
 }
 
+impl std::str::FromStr for DiagnosticCode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|code| code.as_str() == s)
+            .ok_or_else(|| format!("unknown diagnostic code: '{}'", s))
+    }
+}
+
+// Codes serialize as their short string ("W001") so saved reports stay
+// readable across renames of the variants
+impl Serialize for DiagnosticCode {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for DiagnosticCode {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let code = String::deserialize(deserializer)?;
+        code.parse().map_err(serde::de::Error::custom)
+    }
+}
+
 /// Source location span for diagnostic highlighting
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SourceSpan {
     /// Line number (1-based)
     pub line: usize,
@@ -2431,7 +5394,7 @@ impl SourceSpan {
 /// assert_eq!(diagnostic.severity, Severity::Warning);
 /// assert_eq!(diagnostic.code.as_str(), "W001");
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Diagnostic {
     /// The diagnostic code
     pub code: DiagnosticCode,
@@ -2627,6 +5590,16 @@ impl DiagnosticEmitter for CollectingEmitter {
     }
 }
 
+/// A schedule bundled with the diagnostics raised while producing it
+///
+/// Serializes as one document, so a saved run or CI artifact keeps its
+/// warnings alongside the dates.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduleReport {
+    pub schedule: Schedule,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -2643,6 +5616,69 @@ mod tests {
         assert_eq!((d1 - d2).as_days(), 2.0);
     }
 
+    #[test]
+    fn duration_ordering() {
+        let mut slacks = vec![Duration::days(3), Duration::hours(4), Duration::days(-1)];
+        slacks.sort();
+        assert_eq!(
+            slacks,
+            vec![Duration::days(-1), Duration::hours(4), Duration::days(3)]
+        );
+
+        assert!(Duration::hours(7) < Duration::days(1));
+        assert!(Duration::weeks(1) >= Duration::days(5));
+        assert_eq!(
+            Duration::max(Duration::days(2), Duration::hours(20)),
+            Duration::hours(20)
+        );
+        assert_eq!(
+            Duration::days(2).min(Duration::hours(20)),
+            Duration::days(2)
+        );
+    }
+
+    #[test]
+    fn duration_round_days() {
+        assert_eq!(Duration::hours(4).round_days(), 1);
+        assert_eq!(Duration::hours(3).round_days(), 0);
+        assert_eq!(Duration::hours(12).round_days(), 2);
+        assert_eq!(Duration::hours(-4).round_days(), -1);
+        assert_eq!(Duration::days(-2).round_days(), -2);
+    }
+
+    #[test]
+    fn duration_sum_of_many_tasks() {
+        let durations = vec![Duration::days(3); 10_000];
+
+        assert_eq!(
+            Duration::sum(durations.iter().copied()),
+            Duration::days(30_000)
+        );
+        assert_eq!(durations.iter().sum::<Duration>(), Duration::days(30_000));
+        assert_eq!(
+            Duration::sum_with_overflow(durations),
+            (Duration::days(30_000), false)
+        );
+        assert_eq!(Duration::sum(Vec::new()), Duration::zero());
+    }
+
+    #[test]
+    fn duration_sum_saturates_at_overflow_boundary() {
+        let near_max = Duration::minutes(i64::MAX - 10);
+        let (total, overflowed) = Duration::sum_with_overflow([near_max, Duration::minutes(10)]);
+        assert_eq!(total.minutes, i64::MAX);
+        assert!(!overflowed);
+
+        let (total, overflowed) =
+            Duration::sum_with_overflow([near_max, Duration::days(1), Duration::days(1)]);
+        assert_eq!(total.minutes, i64::MAX);
+        assert!(overflowed);
+
+        let near_min = Duration::minutes(i64::MIN + 10);
+        let total: Duration = [near_min, Duration::days(-1)].into_iter().sum();
+        assert_eq!(total.minutes, i64::MIN);
+    }
+
     #[test]
     fn task_builder() {
         let task = Task::new("impl")
@@ -2673,6 +5709,160 @@ mod tests {
         assert!(!cal.is_working_day(saturday));
     }
 
+    #[test]
+    fn calendar_minutes_available_respects_exceptions() {
+        let mut cal = Calendar::default();
+        let monday = NaiveDate::from_ymd_opt(2025, 2, 3).unwrap();
+        let tuesday = NaiveDate::from_ymd_opt(2025, 2, 4).unwrap();
+        let wednesday = NaiveDate::from_ymd_opt(2025, 2, 5).unwrap();
+        let saturday = NaiveDate::from_ymd_opt(2025, 2, 1).unwrap();
+        cal.exceptions.push(CalendarException {
+            date: tuesday,
+            working_hours: Some(vec![TimeRange {
+                start: 9 * 60,
+                end: 13 * 60,
+            }]),
+        });
+        cal.exceptions.push(CalendarException {
+            date: wednesday,
+            working_hours: None,
+        });
+
+        assert_eq!(cal.minutes_per_day(), 7 * 60);
+        assert_eq!(cal.minutes_available(monday), 7 * 60);
+        assert_eq!(cal.minutes_available(tuesday), 4 * 60);
+        assert_eq!(cal.minutes_available(wednesday), 0);
+        assert_eq!(cal.minutes_available(saturday), 0);
+    }
+
+    #[test]
+    fn calendar_intersection_works_only_common_days() {
+        let mut four_day = Calendar {
+            id: "four_day".into(),
+            working_days: vec![1, 2, 3, 4],
+            ..Calendar::default()
+        };
+        let saturday = NaiveDate::from_ymd_opt(2025, 2, 8).unwrap();
+        four_day.exceptions.push(CalendarException {
+            date: saturday,
+            working_hours: Some(vec![TimeRange {
+                start: 9 * 60,
+                end: 13 * 60,
+            }]),
+        });
+        let mut project = Calendar::default();
+        let wednesday = NaiveDate::from_ymd_opt(2025, 2, 5).unwrap();
+        project.holidays.push(Holiday {
+            name: "Shutdown".into(),
+            start: wednesday,
+            end: wednesday,
+        });
+
+        let both = four_day.intersect(&project);
+        assert_eq!(both.id, "four_day");
+        assert_eq!(both.working_days, vec![1, 2, 3, 4]);
+        assert!(both.is_working_day(NaiveDate::from_ymd_opt(2025, 2, 4).unwrap()));
+        assert!(!both.is_working_day(wednesday));
+        assert!(!both.is_working_day(NaiveDate::from_ymd_opt(2025, 2, 7).unwrap()));
+        // The overtime Saturday is not a project working day
+        assert!(!both.is_working_day(saturday));
+    }
+
+    #[test]
+    fn calendar_exception_adds_working_saturday() {
+        let mut cal = Calendar::default();
+        let saturday = NaiveDate::from_ymd_opt(2025, 2, 8).unwrap();
+        let friday = NaiveDate::from_ymd_opt(2025, 2, 7).unwrap();
+        cal.exceptions.push(CalendarException {
+            date: saturday,
+            working_hours: Some(vec![TimeRange {
+                start: 9 * 60,
+                end: 13 * 60,
+            }]),
+        });
+
+        assert!(cal.is_working_day(saturday));
+        assert!(!cal.is_working_day(saturday.succ_opt().unwrap()));
+        assert_eq!(cal.add_working_days(friday, 1), saturday);
+    }
+
+    #[test]
+    fn calendar_exception_removes_working_wednesday() {
+        let mut cal = Calendar::default();
+        let tuesday = NaiveDate::from_ymd_opt(2025, 2, 4).unwrap();
+        let wednesday = NaiveDate::from_ymd_opt(2025, 2, 5).unwrap();
+        cal.exceptions.push(CalendarException {
+            date: wednesday,
+            working_hours: None,
+        });
+
+        assert!(!cal.is_working_day(wednesday));
+        assert_eq!(
+            cal.add_working_days(tuesday, 1),
+            NaiveDate::from_ymd_opt(2025, 2, 6).unwrap()
+        );
+    }
+
+    #[test]
+    fn calendar_working_days_between_skips_weekends() {
+        let cal = Calendar::default();
+        let friday = NaiveDate::from_ymd_opt(2025, 1, 31).unwrap();
+        let next_wednesday = NaiveDate::from_ymd_opt(2025, 2, 5).unwrap();
+
+        // Fri, Mon, Tue
+        assert_eq!(cal.working_days_between(friday, next_wednesday), 3);
+        assert_eq!(cal.working_days_between(next_wednesday, friday), -3);
+        assert_eq!(cal.working_days_between(friday, friday), 0);
+    }
+
+    #[test]
+    fn task_tags_read_parser_attribute() {
+        let mut task = Task::new("build");
+        assert_eq!(task.tags().count(), 0);
+        task.attributes
+            .insert("tags".to_string(), "backend, urgent".to_string());
+        let task = task.tag("review");
+        assert_eq!(
+            task.tags().collect::<Vec<_>>(),
+            vec!["backend", "urgent", "review"]
+        );
+    }
+
+    #[test]
+    fn project_get_task_by_path() {
+        let mut project = Project::new("Test");
+        project.tasks = vec![Task::new("phase1").child(Task::new("design"))];
+
+        assert_eq!(
+            project
+                .get_task_by_path("phase1.design")
+                .map(|t| t.id.as_str()),
+            Some("design")
+        );
+        assert!(project.get_task_by_path("design").is_none());
+        assert!(project.get_task_by_path("phase1.missing").is_none());
+    }
+
+    #[test]
+    fn project_next_task_id_is_unique_across_tree() {
+        let mut project = Project::new("Test");
+        project.tasks = vec![
+            Task::new("phase1").child(Task::new("buffer_2")),
+            Task::new("buffer_x"),
+        ];
+
+        let first = project.next_task_id("buffer");
+        assert_eq!(first, "buffer_3");
+        assert_eq!(project.next_task_id("buffer"), first, "deterministic");
+
+        project.tasks.push(Task::new(first.clone()));
+        let second = project.next_task_id("buffer");
+        assert_eq!(second, "buffer_4");
+        assert!(project.get_task(&second).is_none());
+
+        assert_eq!(project.next_task_id("split"), "split_1");
+    }
+
     #[test]
     fn project_leaf_tasks() {
         let project = Project {
@@ -2683,6 +5873,7 @@ mod tests {
             status_date: None,
             calendar: "default".into(),
             currency: "USD".into(),
+            timezone: None,
             tasks: vec![
                 Task::new("parent")
                     .child(Task::new("child1"))
@@ -2696,9 +5887,11 @@ mod tests {
             profiles: Vec::new(),
             traits: Vec::new(),
             cost_policy: CostPolicy::default(),
+            progress_weighting: ProgressWeighting::default(),
             leveling_mode: LevelingMode::default(),
             optimal_threshold: None,
             optimal_timeout_ms: None,
+            baseline: None,
         };
 
         let leaves = project.leaf_tasks();
@@ -2726,6 +5919,67 @@ mod tests {
         assert_eq!(d_weeks.as_days(), 5.0);
     }
 
+    #[test]
+    fn project_available_capacity_scales_by_resource_factors() {
+        let mut project = Project::new("Capacity");
+        project.calendars.push(Calendar {
+            id: "weekend".into(),
+            working_days: vec![0, 6],
+            ..Calendar::default()
+        });
+        let mut part_timer = Resource::new("part").capacity(0.5);
+        part_timer.efficiency = 2.0;
+        let mut weekender = Resource::new("weekend").availability(0.5);
+        weekender.calendar = Some("weekend".into());
+        project.resources = vec![Resource::new("full"), part_timer, weekender];
+
+        // Mon 2025-01-06 .. Sun 2025-01-12: 5 weekdays, 2 weekend days
+        let monday = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+        let sunday = NaiveDate::from_ymd_opt(2025, 1, 12).unwrap();
+
+        // full: 5, part: 5 × 0.5 × 2.0 = 5, weekend: 2 × 0.5 = 1
+        assert_eq!(
+            project.available_capacity(monday, sunday),
+            Duration::days(11)
+        );
+        assert_eq!(project.available_capacity(sunday, monday), Duration::zero());
+    }
+
+    #[test]
+    fn project_effort_by_resource_splits_by_units_and_efficiency() {
+        let mut project = Project::new("Workload");
+        let mut carol = Resource::new("carol");
+        carol.efficiency = 2.0;
+        project.resources = vec![Resource::new("alice"), Resource::new("bob"), carol];
+        project.profiles.push(ResourceProfile::new("dev"));
+        project.tasks = vec![
+            Task::new("phase")
+                .effort(Duration::days(100))
+                .assign("alice")
+                .child(
+                    Task::new("build")
+                        .effort(Duration::days(30))
+                        .assign("alice")
+                        .assign_with_units("bob", 0.5),
+                ),
+            Task::new("review")
+                .effort(Duration::days(6))
+                .assign("alice")
+                .assign("carol"),
+            Task::new("spike").effort(Duration::days(4)).assign("dev"),
+            Task::new("unassigned").effort(Duration::days(9)),
+        ];
+
+        let effort = project.effort_by_resource();
+
+        // build 2:1 (20 + 10), review 1:2 by efficiency (2 + 4); container skipped
+        assert_eq!(effort["alice"], Duration::days(22));
+        assert_eq!(effort["bob"], Duration::days(10));
+        assert_eq!(effort["carol"], Duration::days(4));
+        assert_eq!(effort["dev"], Duration::days(4));
+        assert_eq!(effort.len(), 4);
+    }
+
     #[test]
     fn project_get_task_nested() {
         let project = Project {
@@ -2736,6 +5990,7 @@ mod tests {
             status_date: None,
             calendar: "default".into(),
             currency: "USD".into(),
+            timezone: None,
             tasks: vec![
                 Task::new("parent")
                     .name("Parent Task")
@@ -2754,9 +6009,11 @@ mod tests {
             profiles: Vec::new(),
             traits: Vec::new(),
             cost_policy: CostPolicy::default(),
+            progress_weighting: ProgressWeighting::default(),
             leveling_mode: LevelingMode::default(),
             optimal_threshold: None,
             optimal_timeout_ms: None,
+            baseline: None,
         };
 
         // Find top-level task
@@ -2789,6 +6046,7 @@ mod tests {
             status_date: None,
             calendar: "default".into(),
             currency: "USD".into(),
+            timezone: None,
             tasks: Vec::new(),
             resources: vec![
                 Resource::new("dev1").name("Developer 1"),
@@ -2800,9 +6058,11 @@ mod tests {
             profiles: Vec::new(),
             traits: Vec::new(),
             cost_policy: CostPolicy::default(),
+            progress_weighting: ProgressWeighting::default(),
             leveling_mode: LevelingMode::default(),
             optimal_threshold: None,
             optimal_timeout_ms: None,
+            baseline: None,
         };
 
         let dev = project.get_resource("dev1");
@@ -2920,6 +6180,7 @@ mod tests {
             predecessor: "other".into(),
             dep_type: DependencyType::StartToStart,
             lag: Some(Duration::days(2)),
+            lag_calendar: false,
         };
         let task = Task::new("task").with_dependency(dep);
 
@@ -3197,6 +6458,26 @@ mod tests {
         assert!(container.progress_mismatch(60).is_none());
     }
 
+    #[test]
+    fn physical_progress_falls_back_to_complete() {
+        let task = Task::new("a").complete(90.0);
+        assert_eq!(task.effective_physical_complete(), 90);
+        let task = task.physical_complete(50.0);
+        assert_eq!(task.effective_physical_complete(), 50);
+        assert_eq!(task.effective_percent_complete(), 90);
+
+        let container = Task::new("dev")
+            .child(
+                Task::new("a")
+                    .duration(Duration::days(10))
+                    .complete(90.0)
+                    .physical_complete(50.0),
+            )
+            .child(Task::new("b").duration(Duration::days(10)).complete(30.0));
+        assert_eq!(container.container_progress(), Some(60));
+        assert_eq!(container.container_physical_progress(), Some(40));
+    }
+
     #[test]
     fn container_progress_uses_effort_fallback() {
         // When duration not set, should use effort
@@ -3284,6 +6565,148 @@ mod tests {
         assert!(cal.is_working_day(jan2));
     }
 
+    #[test]
+    fn calendar_builder_valid() {
+        let cal = Calendar::builder("ops")
+            .name("Operations")
+            .working_day(Weekday::Fri)
+            .working_day(Weekday::Mon)
+            .working_day(Weekday::Mon)
+            .hours(13 * 60, 17 * 60)
+            .hours(9 * 60, 12 * 60)
+            .holiday(
+                "New Year",
+                NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+            )
+            .exception(NaiveDate::from_ymd_opt(2025, 1, 4).unwrap(), None)
+            .build()
+            .expect("valid calendar");
+
+        assert_eq!(cal.id, "ops");
+        assert_eq!(cal.name, "Operations");
+        assert_eq!(cal.working_days, vec![1, 5]);
+        assert_eq!(cal.working_hours[0].start, 9 * 60);
+        assert_eq!(cal.hours_per_day(), 7.0);
+        assert_eq!(cal.holidays.len(), 1);
+        assert_eq!(cal.exceptions.len(), 1);
+    }
+
+    #[test]
+    fn calendar_builder_rejects_overlapping_hours() {
+        let errors = Calendar::builder("overlap")
+            .working_day(Weekday::Mon)
+            .hours(9 * 60, 13 * 60)
+            .hours(12 * 60, 17 * 60)
+            .build()
+            .unwrap_err();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, DiagnosticCode::C001ZeroWorkingHours);
+        assert!(errors[0].message.contains("09:00-13:00"));
+        assert!(errors[0].message.contains("12:00-17:00"));
+    }
+
+    #[test]
+    fn calendar_builder_rejects_inverted_hours() {
+        let errors = Calendar::builder("inverted")
+            .working_day(Weekday::Mon)
+            .hours(17 * 60, 9 * 60)
+            .build()
+            .unwrap_err();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, DiagnosticCode::C001ZeroWorkingHours);
+        assert!(errors[0].message.contains("17:00-09:00"));
+    }
+
+    #[test]
+    fn calendar_builder_rejects_empty_working_days() {
+        let errors = Calendar::builder("no_days")
+            .hours(9 * 60, 17 * 60)
+            .build()
+            .unwrap_err();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, DiagnosticCode::C002NoWorkingDays);
+        assert_eq!(errors[0].severity, Severity::Error);
+    }
+
+    #[test]
+    fn calendar_builder_reports_all_problems() {
+        let errors = Calendar::builder("empty").build().unwrap_err();
+        let codes: Vec<_> = errors.iter().map(|d| d.code.clone()).collect();
+        assert_eq!(
+            codes,
+            vec![
+                DiagnosticCode::C001ZeroWorkingHours,
+                DiagnosticCode::C002NoWorkingDays
+            ]
+        );
+    }
+
+    #[test]
+    fn calendar_holiday_contradicting_working_exception() {
+        let date = NaiveDate::from_ymd_opt(2025, 12, 26).unwrap();
+        let mut cal = Calendar::default();
+        cal.holidays.push(Holiday {
+            name: "Boxing Day".into(),
+            start: date,
+            end: date,
+        });
+        cal.exceptions.push(CalendarException {
+            date,
+            working_hours: Some(vec![TimeRange {
+                start: 9 * 60,
+                end: 13 * 60,
+            }]),
+        });
+        // A non-working exception agrees with the holiday
+        cal.exceptions.push(CalendarException {
+            date,
+            working_hours: None,
+        });
+
+        let diagnostics = cal.consistency_diagnostics();
+        assert_eq!(diagnostics.len(), 1);
+        let d = &diagnostics[0];
+        assert_eq!(d.code, DiagnosticCode::C012HolidayExceptionConflict);
+        assert!(d.message.contains("Boxing Day"));
+        assert!(d.notes.iter().any(|n| n.contains("09:00-13:00")));
+    }
+
+    #[test]
+    fn calendar_overlapping_and_duplicate_holidays() {
+        let date = |day| NaiveDate::from_ymd_opt(2025, 12, day).unwrap();
+        let holiday = |name: &str, start, end| Holiday {
+            name: name.into(),
+            start: date(start),
+            end: date(end),
+        };
+        let mut cal = Calendar::default();
+        cal.holidays = vec![
+            holiday("Christmas", 25, 25),
+            holiday("Winter Break", 22, 31),
+            holiday("Xmas", 25, 25),
+            holiday("Solstice", 21, 21),
+        ];
+
+        let messages: Vec<String> = cal
+            .consistency_diagnostics()
+            .into_iter()
+            .inspect(|d| assert_eq!(d.code, DiagnosticCode::C024OverlappingHolidays))
+            .map(|d| d.message)
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                "calendar 'default': holiday 'Winter Break' overlaps holiday 'Christmas'",
+                "calendar 'default': holiday 'Xmas' duplicates holiday 'Christmas'",
+                "calendar 'default': holiday 'Xmas' overlaps holiday 'Winter Break'",
+            ]
+        );
+    }
+
     #[test]
     fn scheduled_task_test_new() {
         let start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
@@ -3480,6 +6903,91 @@ mod tests {
         assert!((spread - 20.0).abs() < 0.1);
     }
 
+    #[test]
+    fn money_format_usd() {
+        use rust_decimal::Decimal;
+        let fee = Money::new(Decimal::new(123456, 2), "USD");
+
+        assert_eq!(fee.format(&CurrencyFormat::default()), "$1,234.56");
+        assert_eq!(fee.format(&CurrencyFormat::code()), "USD 1,234.56");
+        assert_eq!(
+            Money::new(Decimal::from(1_250_000), "USD")
+                .format(&CurrencyFormat::default().decimal_places(0)),
+            "$1,250,000"
+        );
+        assert_eq!(
+            Money::new(Decimal::new(-9995, 1), "USD").format(&CurrencyFormat::default()),
+            "-$999.50"
+        );
+        assert_eq!(
+            Money::new(Decimal::new(-9995, 1), "USD").format(&CurrencyFormat::code()),
+            "-USD 999.50"
+        );
+    }
+
+    #[test]
+    fn money_format_eur() {
+        use rust_decimal::Decimal;
+        let fee = Money::new(Decimal::new(1234565, 3), "EUR");
+
+        // Midpoints round away from zero
+        assert_eq!(fee.format(&CurrencyFormat::default()), "€1,234.57");
+        assert_eq!(
+            Money::new(Decimal::from(500), "EUR").format(&CurrencyFormat::default()),
+            "€500.00"
+        );
+    }
+
+    #[test]
+    fn money_format_unknown_currency_uses_code() {
+        use rust_decimal::Decimal;
+        let fee = Money::new(Decimal::new(123456, 2), "CHF");
+
+        assert_eq!(fee.format(&CurrencyFormat::default()), "CHF 1,234.56");
+    }
+
+    #[test]
+    fn years_between_spans_a_leap_day() {
+        let from = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let to = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+
+        // 2024 has 366 days
+        assert!((years_between(from, to) - 366.0 / 365.25).abs() < 1e-12);
+        assert!((DayCount::Actual365.years_between(from, to) - 366.0 / 365.0).abs() < 1e-12);
+        // Four years, one of them leap, is exactly four years
+        let four_on = NaiveDate::from_ymd_opt(2028, 1, 1).unwrap();
+        assert!((years_between(from, four_on) - 4.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn years_between_fractional_year() {
+        // Feb 1 to Mar 1 crosses Feb 29 in 2024
+        let from = NaiveDate::from_ymd_opt(2024, 2, 1).unwrap();
+        let to = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+
+        assert!((years_between(from, to) - 29.0 / 365.25).abs() < 1e-12);
+        assert!((DayCount::Actual360.years_between(from, to) - 29.0 / 360.0).abs() < 1e-12);
+        assert!((years_between(to, from) + 29.0 / 365.25).abs() < 1e-12);
+    }
+
+    #[test]
+    fn cost_range_format() {
+        use rust_decimal::Decimal;
+        let range = CostRange::new(
+            Decimal::from(40000),
+            Decimal::from(50000),
+            Decimal::from(60000),
+            "GBP",
+        );
+        assert_eq!(
+            range.format(&CurrencyFormat::default()),
+            "£40,000.00 - £60,000.00 (expected: £50,000.00)"
+        );
+
+        let fixed = CostRange::fixed(Decimal::from(2500), "USD");
+        assert_eq!(fixed.format(&CurrencyFormat::code()), "USD 2,500.00");
+    }
+
     #[test]
     fn cost_range_add() {
         use rust_decimal::Decimal;
@@ -3526,6 +7034,29 @@ mod tests {
         assert_eq!(expected, Decimal::from(200));
     }
 
+    #[test]
+    fn cost_policy_triangular_is_deterministic_and_in_range() {
+        let (min, max) = (Decimal::from(100), Decimal::from(200));
+        let policy = CostPolicy::Triangular { seed: 42 };
+
+        let a = policy.expected_for(min, max, "design:dev");
+        assert_eq!(a, policy.expected_for(min, max, "design:dev"));
+        assert!(a >= min && a <= max);
+
+        // Different keys and seeds give different draws
+        let draws: std::collections::HashSet<Decimal> = (0..20)
+            .map(|i| policy.expected_for(min, max, &format!("task{}:dev", i)))
+            .collect();
+        assert!(draws.len() > 1);
+        assert!(draws.iter().all(|d| *d >= min && *d <= max));
+        let reseeded = CostPolicy::Triangular { seed: 43 }.expected_for(min, max, "design:dev");
+        assert_ne!(a, reseeded);
+        assert!(reseeded >= min && reseeded <= max);
+
+        // Degenerate range collapses to min
+        assert_eq!(policy.expected_for(min, min, "x"), min);
+    }
+
     #[test]
     fn cost_policy_default_is_midpoint() {
         assert_eq!(CostPolicy::default(), CostPolicy::Midpoint);
@@ -3538,18 +7069,120 @@ mod tests {
             .specializes("backend_senior")
             .availability(0.8);
 
-        assert_eq!(resource.specializes, Some("backend_senior".into()));
-        assert_eq!(resource.availability, Some(0.8));
-        assert!(resource.is_specialized());
+        assert_eq!(resource.specializes, Some("backend_senior".into()));
+        assert_eq!(resource.availability, Some(0.8));
+        assert!(resource.is_specialized());
+    }
+
+    #[test]
+    fn resource_effective_availability() {
+        let full_time = Resource::new("dev1");
+        assert_eq!(full_time.effective_availability(), 1.0);
+
+        let part_time = Resource::new("dev2").availability(0.5);
+        assert_eq!(part_time.effective_availability(), 0.5);
+    }
+
+    #[test]
+    fn resource_effective_capacity() {
+        assert_eq!(Resource::new("dev1").effective_capacity(), 1.0);
+
+        let part_time = Resource::new("dev2").capacity(2.0).availability(0.5);
+        assert_eq!(part_time.effective_capacity(), 1.0);
+    }
+
+    #[test]
+    fn project_consumption_sums_consumable_units() {
+        let mut project = Project::new("Consumable");
+        project.resources = vec![
+            Resource::new("dev"),
+            Resource::new("points").consumable(100.0),
+        ];
+        project.tasks = vec![
+            Task::new("phase")
+                .child(Task::new("a").assign_with_units("points", 40.0))
+                .child(Task::new("b").assign_with_units("points", 30.0)),
+            Task::new("c")
+                .assign("dev")
+                .assign_with_units("points", 5.0),
+        ];
+
+        assert!(project.resources[1].is_consumable());
+        assert!(!project.resources[0].is_consumable());
+        assert_eq!(project.consumption("points"), 75.0);
+        assert_eq!(project.consumption("dev"), 1.0);
+    }
+
+    #[test]
+    fn project_reassign_resource_rewrites_nested_assignments() {
+        let mut project = Project::new("Handover");
+        project.tasks = vec![
+            Task::new("phase")
+                .child(Task::new("a").assign_with_units("alice", 0.5))
+                .child(
+                    Task::new("sub")
+                        .child(Task::new("b").assign("alice").assign("bob"))
+                        .child(Task::new("c").assign("developer")),
+                ),
+            Task::new("d").assign("bob"),
+        ];
+
+        assert_eq!(project.reassign_resource("alice", "carol"), 2);
+
+        let a = project.get_task("a").unwrap();
+        assert_eq!(a.assigned[0].resource_id, "carol");
+        assert_eq!(a.assigned[0].units, 0.5);
+        let b = project.get_task("b").unwrap();
+        assert_eq!(b.assigned[0].resource_id, "carol");
+        assert_eq!(b.assigned[1].resource_id, "bob");
+        assert_eq!(
+            project.get_task("c").unwrap().assigned[0].resource_id,
+            "developer"
+        );
+        assert_eq!(
+            project.get_task("d").unwrap().assigned[0].resource_id,
+            "bob"
+        );
+
+        assert_eq!(project.reassign_resource("developer", "dave"), 1);
+        assert_eq!(project.reassign_resource("alice", "carol"), 0);
     }
 
     #[test]
-    fn resource_effective_availability() {
-        let full_time = Resource::new("dev1");
-        assert_eq!(full_time.effective_availability(), 1.0);
+    fn project_apply_constraint_where_constrains_orphan_tasks() {
+        let kickoff = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+        let mut project = Project::new("Kickoff Gate");
+        project.tasks = vec![
+            Task::new("design"),
+            Task::new("phase")
+                .child(Task::new("spike"))
+                .child(Task::new("build").depends_on("spike")),
+            Task::new("release").depends_on("design"),
+        ];
+
+        let mut seen = Vec::new();
+        let changed = project.apply_constraint_where(
+            |path, task| {
+                seen.push(path.to_string());
+                task.children.is_empty() && task.depends.is_empty()
+            },
+            TaskConstraint::StartNoEarlierThan(kickoff),
+        );
 
-        let part_time = Resource::new("dev2").availability(0.5);
-        assert_eq!(part_time.effective_availability(), 0.5);
+        assert_eq!(changed, 2);
+        assert_eq!(
+            seen,
+            ["design", "phase", "phase.spike", "phase.build", "release"]
+        );
+        for id in ["design", "phase.spike"] {
+            assert!(matches!(
+                project.get_task_by_path(id).unwrap().constraints[..],
+                [TaskConstraint::StartNoEarlierThan(date)] if date == kickoff
+            ));
+        }
+        for id in ["phase", "phase.build", "release"] {
+            assert!(project.get_task_by_path(id).unwrap().constraints.is_empty());
+        }
     }
 
     #[test]
@@ -3790,19 +7423,48 @@ mod tests {
         // Test all diagnostic codes have correct string representation
         assert_eq!(DiagnosticCode::E002ProfileWithoutRate.as_str(), "E002");
         assert_eq!(DiagnosticCode::E003InfeasibleConstraint.as_str(), "E003");
+        assert_eq!(DiagnosticCode::E005MixedCurrency.as_str(), "E005");
         assert_eq!(DiagnosticCode::W002WideCostRange.as_str(), "W002");
         assert_eq!(DiagnosticCode::W003UnknownTrait.as_str(), "W003");
         assert_eq!(DiagnosticCode::W004ApproximateLeveling.as_str(), "W004");
         assert_eq!(DiagnosticCode::W005ConstraintZeroSlack.as_str(), "W005");
         assert_eq!(DiagnosticCode::W006ScheduleVariance.as_str(), "W006");
+        assert_eq!(DiagnosticCode::W008CapacityShortfall.as_str(), "W008");
+        assert_eq!(DiagnosticCode::W009ProfileShadowed.as_str(), "W009");
+        assert_eq!(DiagnosticCode::W010ConsumablePoolExceeded.as_str(), "W010");
+        assert_eq!(DiagnosticCode::W011UnsizedTask.as_str(), "W011");
+        assert_eq!(DiagnosticCode::W012AmbiguousDependency.as_str(), "W012");
+        assert_eq!(DiagnosticCode::W013ContainerEffort.as_str(), "W013");
+        assert_eq!(
+            DiagnosticCode::C012HolidayExceptionConflict.as_str(),
+            "C012"
+        );
+        assert_eq!(DiagnosticCode::C024OverlappingHolidays.as_str(), "C024");
         assert_eq!(DiagnosticCode::W014ContainerDependency.as_str(), "W014");
+        assert_eq!(DiagnosticCode::W015InactiveDependency.as_str(), "W015");
+        assert_eq!(DiagnosticCode::W016FrozenTaskMoved.as_str(), "W016");
+        assert_eq!(DiagnosticCode::W017DegenerateDependency.as_str(), "W017");
+        assert_eq!(
+            DiagnosticCode::W018AssignmentExceedsCapacity.as_str(),
+            "W018"
+        );
         assert_eq!(DiagnosticCode::H002UnusedProfile.as_str(), "H002");
         assert_eq!(DiagnosticCode::H003UnusedTrait.as_str(), "H003");
         assert_eq!(DiagnosticCode::H004TaskUnconstrained.as_str(), "H004");
+        assert_eq!(DiagnosticCode::H005TaskDisconnected.as_str(), "H005");
         assert_eq!(DiagnosticCode::I002RefinementProgress.as_str(), "I002");
         assert_eq!(DiagnosticCode::I003ResourceUtilization.as_str(), "I003");
         assert_eq!(DiagnosticCode::I004ProjectStatus.as_str(), "I004");
         assert_eq!(DiagnosticCode::I005EarnedValueSummary.as_str(), "I005");
+        assert_eq!(DiagnosticCode::I006ScheduleFragility.as_str(), "I006");
+    }
+
+    #[test]
+    fn diagnostic_code_parses_from_its_string() {
+        for code in DiagnosticCode::ALL {
+            assert_eq!(code.as_str().parse::<DiagnosticCode>(), Ok(*code));
+        }
+        assert!("W999".parse::<DiagnosticCode>().is_err());
     }
 
     #[test]
@@ -3812,6 +7474,10 @@ mod tests {
             DiagnosticCode::E003InfeasibleConstraint.default_severity(),
             Severity::Error
         );
+        assert_eq!(
+            DiagnosticCode::E005MixedCurrency.default_severity(),
+            Severity::Error
+        );
         // Warnings (W002 onwards - E002 is warning by default, error in strict)
         assert_eq!(
             DiagnosticCode::E002ProfileWithoutRate.default_severity(),
@@ -3829,6 +7495,50 @@ mod tests {
             DiagnosticCode::W006ScheduleVariance.default_severity(),
             Severity::Warning
         );
+        assert_eq!(
+            DiagnosticCode::W008CapacityShortfall.default_severity(),
+            Severity::Warning
+        );
+        assert_eq!(
+            DiagnosticCode::W010ConsumablePoolExceeded.default_severity(),
+            Severity::Warning
+        );
+        assert_eq!(
+            DiagnosticCode::W011UnsizedTask.default_severity(),
+            Severity::Warning
+        );
+        assert_eq!(
+            DiagnosticCode::W012AmbiguousDependency.default_severity(),
+            Severity::Warning
+        );
+        assert_eq!(
+            DiagnosticCode::W013ContainerEffort.default_severity(),
+            Severity::Warning
+        );
+        assert_eq!(
+            DiagnosticCode::W015InactiveDependency.default_severity(),
+            Severity::Warning
+        );
+        assert_eq!(
+            DiagnosticCode::W016FrozenTaskMoved.default_severity(),
+            Severity::Warning
+        );
+        assert_eq!(
+            DiagnosticCode::W017DegenerateDependency.default_severity(),
+            Severity::Warning
+        );
+        assert_eq!(
+            DiagnosticCode::W018AssignmentExceedsCapacity.default_severity(),
+            Severity::Warning
+        );
+        assert_eq!(
+            DiagnosticCode::C012HolidayExceptionConflict.default_severity(),
+            Severity::Warning
+        );
+        assert_eq!(
+            DiagnosticCode::C024OverlappingHolidays.default_severity(),
+            Severity::Hint
+        );
         // Hints
         assert_eq!(
             DiagnosticCode::H002UnusedProfile.default_severity(),
@@ -3842,6 +7552,10 @@ mod tests {
             DiagnosticCode::H004TaskUnconstrained.default_severity(),
             Severity::Hint
         );
+        assert_eq!(
+            DiagnosticCode::H005TaskDisconnected.default_severity(),
+            Severity::Hint
+        );
         // Info
         assert_eq!(
             DiagnosticCode::I002RefinementProgress.default_severity(),
@@ -3859,6 +7573,10 @@ mod tests {
             DiagnosticCode::I005EarnedValueSummary.default_severity(),
             Severity::Info
         );
+        assert_eq!(
+            DiagnosticCode::I006ScheduleFragility.default_severity(),
+            Severity::Info
+        );
     }
 
     #[test]
@@ -3867,6 +7585,7 @@ mod tests {
         assert!(DiagnosticCode::E002ProfileWithoutRate.ordering_priority() < 10);
         assert!(DiagnosticCode::E003InfeasibleConstraint.ordering_priority() < 10);
         assert!(DiagnosticCode::E004DuplicateTaskId.ordering_priority() < 10);
+        assert!(DiagnosticCode::E005MixedCurrency.ordering_priority() < 10);
         assert!(DiagnosticCode::R102InvertedRateRange.ordering_priority() < 10);
         assert!(DiagnosticCode::R104UnknownProfile.ordering_priority() < 10);
         // Cost warnings
@@ -3883,17 +7602,53 @@ mod tests {
             DiagnosticCode::W005ConstraintZeroSlack.ordering_priority(),
             12
         );
+        assert_eq!(
+            DiagnosticCode::W008CapacityShortfall.ordering_priority(),
+            12
+        );
+        assert_eq!(
+            DiagnosticCode::W010ConsumablePoolExceeded.ordering_priority(),
+            12
+        );
         assert_eq!(DiagnosticCode::W006ScheduleVariance.ordering_priority(), 13);
         assert_eq!(
             DiagnosticCode::W007UnresolvedDependency.ordering_priority(),
             14
         );
+        assert_eq!(DiagnosticCode::W011UnsizedTask.ordering_priority(), 14);
+        assert_eq!(
+            DiagnosticCode::W012AmbiguousDependency.ordering_priority(),
+            14
+        );
+        assert_eq!(DiagnosticCode::W013ContainerEffort.ordering_priority(), 14);
+        assert_eq!(
+            DiagnosticCode::W015InactiveDependency.ordering_priority(),
+            14
+        );
+        assert_eq!(DiagnosticCode::W016FrozenTaskMoved.ordering_priority(), 14);
+        assert_eq!(
+            DiagnosticCode::W017DegenerateDependency.ordering_priority(),
+            14
+        );
+        assert_eq!(
+            DiagnosticCode::W018AssignmentExceedsCapacity.ordering_priority(),
+            14
+        );
+        assert_eq!(
+            DiagnosticCode::C012HolidayExceptionConflict.ordering_priority(),
+            16
+        );
+        assert_eq!(
+            DiagnosticCode::C024OverlappingHolidays.ordering_priority(),
+            38
+        );
         assert_eq!(
             DiagnosticCode::W014ContainerDependency.ordering_priority(),
             15
         );
         // Assignment warnings
         assert_eq!(DiagnosticCode::W003UnknownTrait.ordering_priority(), 21);
+        assert_eq!(DiagnosticCode::W009ProfileShadowed.ordering_priority(), 22);
         // Hints
         assert_eq!(DiagnosticCode::H002UnusedProfile.ordering_priority(), 31);
         assert_eq!(DiagnosticCode::H003UnusedTrait.ordering_priority(), 32);
@@ -3901,6 +7656,7 @@ mod tests {
             DiagnosticCode::H004TaskUnconstrained.ordering_priority(),
             33
         );
+        assert_eq!(DiagnosticCode::H005TaskDisconnected.ordering_priority(), 33);
         // Info (highest priority = emitted last)
         assert_eq!(
             DiagnosticCode::I002RefinementProgress.ordering_priority(),
@@ -3915,6 +7671,10 @@ mod tests {
             DiagnosticCode::I005EarnedValueSummary.ordering_priority(),
             44
         );
+        assert_eq!(
+            DiagnosticCode::I006ScheduleFragility.ordering_priority(),
+            45
+        );
     }
 
     #[test]
@@ -4002,6 +7762,48 @@ mod tests {
         assert_eq!(task.assigned[0].units, 0.5);
     }
 
+    #[test]
+    fn task_implied_units_from_effort_and_duration() {
+        // 7-hour days
+        let cal = Calendar::default();
+        let crammed = Task::new("crammed")
+            .effort(Duration::hours(70))
+            .duration(Duration::days(3));
+        assert!((crammed.implied_units(&cal).unwrap() - 10.0 / 3.0).abs() < 1e-9);
+
+        let even = Task::new("even")
+            .effort(Duration::hours(28))
+            .duration(Duration::days(4));
+        assert_eq!(even.implied_units(&cal), Some(1.0));
+
+        assert_eq!(
+            Task::new("effort")
+                .effort(Duration::hours(28))
+                .implied_units(&cal),
+            None
+        );
+        assert_eq!(
+            Task::new("fixed")
+                .duration(Duration::days(4))
+                .implied_units(&cal),
+            None
+        );
+        assert_eq!(
+            Task::new("milestone")
+                .effort(Duration::days(1))
+                .duration(Duration::zero())
+                .implied_units(&cal),
+            None
+        );
+    }
+
+    #[test]
+    fn task_builder_calendar() {
+        let task = Task::new("task1").calendar("night_shift");
+        assert_eq!(task.calendar, Some("night_shift".to_string()));
+        assert_eq!(Task::new("task2").calendar, None);
+    }
+
     #[test]
     fn source_span_with_label_and_display() {
         let span = SourceSpan::new(10, 5, 8).with_label("highlight");
@@ -4071,6 +7873,428 @@ mod tests {
         assert!(caps.utilization);
         assert!(caps.cost_tracking);
     }
+
+    // =========================================================================
+    // SPI Forecast Tests
+    // =========================================================================
+
+    fn ev_schedule(progress: u8, spi: f64) -> Schedule {
+        let d = |m, day| NaiveDate::from_ymd_opt(2025, m, day).unwrap();
+        Schedule {
+            tasks: HashMap::new(),
+            critical_path: vec![],
+            project_duration: Duration::days(40),
+            project_end: d(3, 1),
+            total_cost: None,
+            total_cost_range: None,
+            project_progress: progress,
+            project_baseline_finish: d(3, 1),
+            project_forecast_finish: d(3, 1),
+            project_variance_days: 0,
+            planned_value: 50,
+            earned_value: progress,
+            spi,
+            status_date: Some(d(2, 1)),
+            unconstrained_finish: None,
+        }
+    }
+
+    #[test]
+    fn spi_forecast_extrapolates_remaining_work() {
+        // 28 days remaining at SPI 0.5 takes 56 days
+        let schedule = ev_schedule(25, 0.5);
+        assert_eq!(
+            schedule.spi_forecast_finish(),
+            NaiveDate::from_ymd_opt(2025, 3, 29).unwrap()
+        );
+    }
+
+    #[test]
+    fn spi_forecast_matches_plan_at_spi_one() {
+        let schedule = ev_schedule(50, 1.0);
+        assert_eq!(
+            schedule.spi_forecast_finish(),
+            schedule.project_forecast_finish
+        );
+    }
+
+    #[test]
+    fn spi_forecast_zero_spi_falls_back_to_plan() {
+        let schedule = ev_schedule(0, 0.0);
+        assert_eq!(
+            schedule.spi_forecast_finish(),
+            schedule.project_forecast_finish
+        );
+    }
+
+    #[test]
+    fn spi_forecast_complete_project_returns_forecast_finish() {
+        let schedule = ev_schedule(100, 2.0);
+        assert_eq!(
+            schedule.spi_forecast_finish(),
+            schedule.project_forecast_finish
+        );
+    }
+
+    #[test]
+    fn spi_forecast_without_status_date_falls_back_to_plan() {
+        // Schedules saved before the status date was recorded lack the field
+        let mut json = serde_json::to_value(ev_schedule(25, 0.5)).unwrap();
+        json.as_object_mut().unwrap().remove("status_date");
+        let schedule: Schedule = serde_json::from_value(json).unwrap();
+
+        assert_eq!(schedule.status_date, None);
+        assert_eq!(
+            schedule.spi_forecast_finish(),
+            schedule.project_forecast_finish
+        );
+    }
+
+    fn costed_schedule(earned_value: u8) -> Schedule {
+        let mut schedule = ev_schedule(earned_value, 1.0);
+        schedule.total_cost_range = Some(CostRange::fixed(Decimal::from(10_000), "USD"));
+        schedule
+    }
+
+    fn usd(amount: i64) -> Money {
+        Money::new(Decimal::from(amount), "USD")
+    }
+
+    #[test]
+    fn cost_forecasts_follow_standard_formulas() {
+        // BAC 10,000; 40% earned → EV 4,000; AC 5,000 → CPI 0.8
+        let schedule = costed_schedule(40);
+        let ac = usd(5_000);
+
+        assert_eq!(schedule.budget_at_completion(), Some(usd(10_000)));
+        assert_eq!(schedule.earned_value_cost(), Some(usd(4_000)));
+        assert_eq!(schedule.cpi(&ac), Some(Decimal::new(8, 1)));
+        // EAC = 5,000 + 6,000 / 0.8 = 12,500
+        assert_eq!(schedule.eac(&ac), Some(usd(12_500)));
+        assert_eq!(schedule.vac(&ac), Some(usd(-2_500)));
+        // TCPI = 6,000 / 5,000 = 1.2
+        assert_eq!(schedule.tcpi(&ac), Some(Decimal::new(12, 1)));
+    }
+
+    #[test]
+    fn cost_forecasts_need_a_cost_baseline() {
+        let schedule = ev_schedule(40, 1.0);
+        let ac = usd(5_000);
+
+        assert_eq!(schedule.budget_at_completion(), None);
+        assert_eq!(schedule.eac(&ac), None);
+        assert_eq!(schedule.vac(&ac), None);
+        assert_eq!(schedule.tcpi(&ac), None);
+    }
+
+    #[test]
+    fn cost_forecasts_handle_degenerate_inputs() {
+        // Nothing earned yet: CPI is zero, EAC undefined
+        let schedule = costed_schedule(0);
+        assert_eq!(schedule.eac(&usd(1_000)), None);
+
+        // No actual cost: CPI undefined
+        let schedule = costed_schedule(40);
+        assert_eq!(schedule.cpi(&usd(0)), None);
+
+        // Budget exhausted: TCPI undefined
+        assert_eq!(schedule.tcpi(&usd(10_000)), None);
+
+        // Currency mismatch
+        assert_eq!(schedule.eac(&Money::new(Decimal::from(5_000), "EUR")), None);
+    }
+
+    #[test]
+    fn cost_by_resource_flags_mixed_currencies() {
+        let d = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+        let priced = |task_id: &str, amount: i64, currency: &str| {
+            let mut task =
+                ScheduledTask::test_new(task_id, d, d, Duration::days(1), Duration::zero(), false);
+            task.assignments.push(Assignment {
+                resource_id: "vendor".into(),
+                start: d,
+                finish: d,
+                units: 1.0,
+                cost: Some(Money::new(Decimal::from(amount), currency)),
+                cost_range: None,
+                is_abstract: false,
+                effort_days: None,
+                cost_explanation: None,
+                start_time: None,
+                finish_time: None,
+            });
+            (task_id.to_string(), task)
+        };
+        let mut schedule = ev_schedule(0, 1.0);
+        schedule.tasks = [
+            priced("a", 100, "USD"),
+            priced("b", 200, "EUR"),
+            priced("c", 50, "USD"),
+        ]
+        .into_iter()
+        .collect();
+
+        let vendor = &schedule.cost_by_resource()["vendor"];
+        assert!(vendor.mixed_currencies);
+        assert_eq!(vendor.task_count, 3);
+        assert_eq!(vendor.cost.currency, "USD");
+        assert_eq!(vendor.cost.expected, Decimal::from(150));
+    }
+
+    // =========================================================================
+    // Serialization Format Tests
+    // =========================================================================
+
+    fn constraint_date(constraint: &TaskConstraint) -> NaiveDate {
+        match constraint {
+            TaskConstraint::MustStartOn(d)
+            | TaskConstraint::MustFinishOn(d)
+            | TaskConstraint::StartNoEarlierThan(d)
+            | TaskConstraint::StartNoLaterThan(d)
+            | TaskConstraint::FinishNoEarlierThan(d)
+            | TaskConstraint::FinishNoLaterThan(d) => *d,
+            TaskConstraint::RelativeTo { .. } => panic!("relative constraints carry no date"),
+        }
+    }
+
+    #[test]
+    fn task_constraint_tagged_json_round_trip() {
+        let date = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+        let cases = [
+            (TaskConstraint::MustStartOn(date), "must_start_on"),
+            (TaskConstraint::MustFinishOn(date), "must_finish_on"),
+            (
+                TaskConstraint::StartNoEarlierThan(date),
+                "start_no_earlier_than",
+            ),
+            (
+                TaskConstraint::StartNoLaterThan(date),
+                "start_no_later_than",
+            ),
+            (
+                TaskConstraint::FinishNoEarlierThan(date),
+                "finish_no_earlier_than",
+            ),
+            (
+                TaskConstraint::FinishNoLaterThan(date),
+                "finish_no_later_than",
+            ),
+        ];
+
+        for (constraint, tag) in cases {
+            let json = serde_json::to_string(&constraint).unwrap();
+            assert_eq!(json, format!(r#"{{"type":"{}","date":"2025-01-06"}}"#, tag));
+
+            let parsed: TaskConstraint = serde_json::from_str(&json).unwrap();
+            assert_eq!(
+                std::mem::discriminant(&parsed),
+                std::mem::discriminant(&constraint)
+            );
+            assert_eq!(constraint_date(&parsed), date);
+        }
+    }
+
+    #[test]
+    fn relative_constraint_json_round_trip() {
+        let constraint = TaskConstraint::RelativeTo {
+            anchor: "release".into(),
+            offset: Duration::weeks(2),
+            from: StartOrFinish::Finish,
+        };
+
+        let json = serde_json::to_string(&constraint).unwrap();
+        assert_eq!(
+            json,
+            r#"{"type":"relative_to","anchor":"release","offset":{"minutes":4800},"from":"finish"}"#
+        );
+
+        let parsed: TaskConstraint = serde_json::from_str(&json).unwrap();
+        assert!(
+            matches!(parsed, TaskConstraint::RelativeTo { anchor, offset, from: StartOrFinish::Finish }
+            if anchor == "release" && offset == Duration::weeks(2))
+        );
+    }
+
+    #[test]
+    fn calendar_add_working_days_skips_weekends() {
+        let calendar = Calendar::default();
+        let friday = NaiveDate::from_ymd_opt(2025, 1, 10).unwrap();
+
+        assert_eq!(calendar.add_working_days(friday, 0), friday);
+        assert_eq!(
+            calendar.add_working_days(friday, 1),
+            NaiveDate::from_ymd_opt(2025, 1, 13).unwrap()
+        );
+        assert_eq!(
+            calendar.add_working_days(friday, 10),
+            NaiveDate::from_ymd_opt(2025, 1, 24).unwrap()
+        );
+        assert_eq!(
+            calendar.add_working_days(friday, -5),
+            NaiveDate::from_ymd_opt(2025, 1, 3).unwrap()
+        );
+    }
+
+    #[test]
+    fn task_constraint_accepts_hand_written_json() {
+        let json = r#"{ "type": "start_no_earlier_than", "date": "2025-01-06" }"#;
+        let parsed: TaskConstraint = serde_json::from_str(json).unwrap();
+        assert!(matches!(parsed, TaskConstraint::StartNoEarlierThan(d)
+            if d == NaiveDate::from_ymd_opt(2025, 1, 6).unwrap()));
+    }
+
+    #[test]
+    fn dependency_type_json_round_trip() {
+        let cases = [
+            (DependencyType::FinishToStart, "\"finish_to_start\""),
+            (DependencyType::StartToStart, "\"start_to_start\""),
+            (DependencyType::FinishToFinish, "\"finish_to_finish\""),
+            (DependencyType::StartToFinish, "\"start_to_finish\""),
+        ];
+
+        for (dep_type, expected) in cases {
+            let json = serde_json::to_string(&dep_type).unwrap();
+            assert_eq!(json, expected);
+            let parsed: DependencyType = serde_json::from_str(&json).unwrap();
+            assert_eq!(parsed, dep_type);
+        }
+    }
+
+    #[test]
+    fn minimal_task_serializes_to_minimal_object() {
+        let task = Task::new("design");
+        let json = serde_json::to_string(&task).unwrap();
+        assert_eq!(json, r#"{"id":"design","name":"design"}"#);
+
+        let parsed: Task = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.priority, 500);
+        assert!(!parsed.milestone);
+        assert!(parsed.depends.is_empty());
+        assert_eq!(serde_json::to_string(&parsed).unwrap(), json);
+    }
+
+    #[test]
+    fn material_resource_round_trips_its_kind_and_cost() {
+        let license = Resource::new("license").material(Money::new(Decimal::from(5000), "USD"));
+        assert!(license.is_material());
+        assert!(!Resource::new("alice").is_material());
+
+        let json = serde_json::to_value(&license).unwrap();
+        assert_eq!(json["kind"], "material");
+        assert_eq!(json["cost"]["amount"], "5000");
+        // Work is the default and left out
+        let alice = serde_json::to_value(Resource::new("alice")).unwrap();
+        assert!(alice.get("kind").is_none());
+
+        let parsed: Resource = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed.kind, ResourceKind::Material);
+        assert_eq!(parsed.cost.unwrap().amount, Decimal::from(5000));
+    }
+
+    #[test]
+    fn non_default_fields_survive_json_round_trip() {
+        let task = Task::new("build")
+            .name("Build")
+            .effort(Duration::days(5))
+            .with_dependency(Dependency {
+                predecessor: "design".into(),
+                dep_type: DependencyType::StartToStart,
+                lag: Some(Duration::days(1)),
+                lag_calendar: false,
+            })
+            .depends_on("spec")
+            .assign("alice")
+            .assign_with_units("bob", 0.5)
+            .priority(700)
+            .complete(40.0);
+        let json = serde_json::to_value(&task).unwrap();
+        // Default dependency type and full-time units are left out
+        assert_eq!(
+            json["depends"][1],
+            serde_json::json!({"predecessor": "spec"})
+        );
+        assert_eq!(
+            json["assigned"][0],
+            serde_json::json!({"resource_id": "alice"})
+        );
+
+        let parsed: Task = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(parsed.depends[1].dep_type, DependencyType::FinishToStart);
+        assert_eq!(parsed.assigned[0].units, 1.0);
+        assert_eq!(parsed.assigned[1].units, 0.5);
+        assert_eq!(serde_json::to_value(&parsed).unwrap(), json);
+
+        let resource = Resource::new("alice").capacity(0.5);
+        let json = serde_json::to_value(&resource).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"id": "alice", "name": "alice", "capacity": 0.5})
+        );
+        let parsed: Resource = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed.efficiency, 1.0);
+        assert_eq!(parsed.capacity, 0.5);
+
+        let profile = ResourceProfile::new("developer").skill("rust");
+        let json = serde_json::to_value(&profile).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"id": "developer", "name": "developer", "skills": ["rust"]})
+        );
+    }
+
+    #[test]
+    fn scenario_override_tagged_json_round_trip() {
+        let effort = ScenarioOverride::TaskEffort {
+            task_id: "design".into(),
+            effort: Duration::days(5),
+        };
+        let json = serde_json::to_string(&effort).unwrap();
+        assert_eq!(
+            json,
+            r#"{"type":"task_effort","task_id":"design","effort":{"minutes":2400}}"#
+        );
+        match serde_json::from_str(&json).unwrap() {
+            ScenarioOverride::TaskEffort { task_id, effort } => {
+                assert_eq!(task_id, "design");
+                assert_eq!(effort, Duration::days(5));
+            }
+            other => panic!("unexpected override: {:?}", other),
+        }
+
+        let duration = ScenarioOverride::TaskDuration {
+            task_id: "build".into(),
+            duration: Duration::days(2),
+        };
+        let json = serde_json::to_string(&duration).unwrap();
+        assert!(json.starts_with(r#"{"type":"task_duration""#));
+        match serde_json::from_str(&json).unwrap() {
+            ScenarioOverride::TaskDuration { task_id, duration } => {
+                assert_eq!(task_id, "build");
+                assert_eq!(duration, Duration::days(2));
+            }
+            other => panic!("unexpected override: {:?}", other),
+        }
+
+        let capacity = ScenarioOverride::ResourceCapacity {
+            resource_id: "dev".into(),
+            capacity: 0.5,
+        };
+        let json = serde_json::to_string(&capacity).unwrap();
+        assert_eq!(
+            json,
+            r#"{"type":"resource_capacity","resource_id":"dev","capacity":0.5}"#
+        );
+        match serde_json::from_str(&json).unwrap() {
+            ScenarioOverride::ResourceCapacity {
+                resource_id,
+                capacity,
+            } => {
+                assert_eq!(resource_id, "dev");
+                assert_eq!(capacity, 0.5);
+            }
+            other => panic!("unexpected override: {:?}", other),
+        }
+    }
 }
 
 // ============================================================================
@@ -4207,6 +8431,8 @@ mod classifier_tests {
             planned_value: 0,
             earned_value: 0,
             spi: 1.0,
+            status_date: Some(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap()),
+            unconstrained_finish: None,
         }
     }
 
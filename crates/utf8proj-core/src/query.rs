@@ -0,0 +1,98 @@
+//! Schedule queries
+//!
+//! A small builder for filtering scheduled tasks without hand-written loops
+//! over `schedule.tasks`. Predicates are recorded as they are chained and
+//! only evaluated by [`ScheduleQuery::collect`].
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! let alice_critical = schedule
+//!     .query()
+//!     .critical()
+//!     .between(from, to)
+//!     .assigned_to("alice")
+//!     .collect();
+//! ```
+
+use crate::{Schedule, ScheduledTask, TaskStatus};
+use chrono::NaiveDate;
+
+type Predicate<'a> = Box<dyn Fn(&ScheduledTask) -> bool + 'a>;
+
+/// Chainable filter over a schedule's tasks
+pub struct ScheduleQuery<'a> {
+    schedule: &'a Schedule,
+    predicates: Vec<Predicate<'a>>,
+}
+
+impl Schedule {
+    /// Start a query over all scheduled tasks
+    pub fn query(&self) -> ScheduleQuery<'_> {
+        ScheduleQuery {
+            schedule: self,
+            predicates: Vec::new(),
+        }
+    }
+}
+
+impl<'a> ScheduleQuery<'a> {
+    /// Keep only tasks on the critical path
+    pub fn critical(self) -> Self {
+        self.filter(|task| task.is_critical)
+    }
+
+    /// Keep only tasks whose dates overlap the inclusive range
+    pub fn between(self, from: NaiveDate, to: NaiveDate) -> Self {
+        self.filter(move |task| task.overlaps(from, to))
+    }
+
+    /// Keep only tasks with an assignment to the resource or profile
+    pub fn assigned_to(self, resource_id: impl Into<String>) -> Self {
+        let resource_id = resource_id.into();
+        self.filter(move |task| task.is_assigned_to(&resource_id))
+    }
+
+    /// Keep only tasks with the given status
+    pub fn status(self, status: TaskStatus) -> Self {
+        self.filter(move |task| task.status == status)
+    }
+
+    /// Keep only tasks carrying the tag
+    pub fn tagged(self, tag: impl Into<String>) -> Self {
+        let tag = tag.into();
+        self.filter(move |task| task.has_tag(&tag))
+    }
+
+    /// Keep only milestones
+    pub fn milestones(self) -> Self {
+        self.filter(|task| task.is_milestone)
+    }
+
+    /// Keep only tasks matching a custom predicate
+    pub fn filter(mut self, predicate: impl Fn(&ScheduledTask) -> bool + 'a) -> Self {
+        self.predicates.push(Box::new(predicate));
+        self
+    }
+
+    /// Run the query, returning matches ordered by start date then task ID
+    pub fn collect(self) -> Vec<&'a ScheduledTask> {
+        let mut matches: Vec<&ScheduledTask> = self
+            .schedule
+            .tasks
+            .values()
+            .filter(|task| self.predicates.iter().all(|p| p(task)))
+            .collect();
+        matches.sort_by(|a, b| {
+            a.start
+                .cmp(&b.start)
+                .then_with(|| a.task_id.cmp(&b.task_id))
+        });
+        matches
+    }
+
+    /// Number of matching tasks
+    pub fn count(self) -> usize {
+        self.collect().len()
+    }
+}
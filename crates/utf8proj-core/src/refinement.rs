@@ -0,0 +1,216 @@
+//! Assignment Refinement (RFC-0004)
+//!
+//! Early plans assign abstract profiles ("a developer") to tasks; later the
+//! profile is replaced by the person who will actually do the work. This
+//! module performs that replacement and reports how it narrows the rate.
+//!
+//! # Skill Matching
+//!
+//! Specialization is constraint refinement, so a profile's skills include
+//! those of every profile it specializes. A resource has the skills of the
+//! profile it specializes. A resource satisfies a profile when it has every
+//! skill the profile requires; a profile without skills accepts anyone.
+//!
+//! # Example
+//!
+//! ```rust
+//! use rust_decimal::Decimal;
+//! use utf8proj_core::{Money, Project, RateRange, Resource, ResourceProfile, Task};
+//!
+//! let mut project = Project::new("Refinement");
+//! project.profiles.push(
+//!     ResourceProfile::new("developer")
+//!         .skill("rust")
+//!         .rate_range(RateRange::new(Decimal::from(400), Decimal::from(800))),
+//! );
+//! project.profiles.push(ResourceProfile::new("rustacean").skill("rust"));
+//! project.resources.push(
+//!     Resource::new("alice")
+//!         .specializes("rustacean")
+//!         .rate(Money::new(Decimal::from(700), "USD")),
+//! );
+//! project.tasks.push(Task::new("api").assign("developer"));
+//!
+//! let refinement = project.refine_assignment("api", "developer", "alice").unwrap();
+//! assert_eq!(refinement.expected_rate_change(), Some(Decimal::from(100)));
+//! ```
+
+use crate::{Money, ProfileId, Project, RateRange, ResourceId, ResourceRate, TaskId};
+use rust_decimal::Decimal;
+use std::collections::HashSet;
+use thiserror::Error;
+
+/// Outcome of replacing a profile assignment with a concrete resource
+#[derive(Clone, Debug, PartialEq)]
+pub struct AssignmentRefinement {
+    /// Task whose assignment was refined
+    pub task_id: TaskId,
+    /// Profile that was assigned before
+    pub profile_id: ProfileId,
+    /// Resource assigned now
+    pub resource_id: ResourceId,
+    /// Allocation units, carried over from the profile assignment
+    pub units: f32,
+    /// Profile rate range, inherited and trait-adjusted
+    pub profile_rate: Option<RateRange>,
+    /// Resource's fixed rate
+    pub resource_rate: Option<Money>,
+}
+
+impl AssignmentRefinement {
+    /// Resource rate minus the profile's expected rate
+    ///
+    /// Positive when the person costs more than planned. `None` unless both
+    /// rates are known.
+    pub fn expected_rate_change(&self) -> Option<Decimal> {
+        let profile_rate = self.profile_rate.as_ref()?;
+        let resource_rate = self.resource_rate.as_ref()?;
+        Some(resource_rate.amount - profile_rate.expected())
+    }
+}
+
+/// Errors that can occur while refining an assignment
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum RefinementError {
+    #[error("Task not found: {0}")]
+    TaskNotFound(TaskId),
+
+    #[error("Profile not found: {0}")]
+    ProfileNotFound(ProfileId),
+
+    #[error("Resource not found: {0}")]
+    ResourceNotFound(ResourceId),
+
+    #[error("Profile '{profile_id}' is not assigned to task '{task_id}'")]
+    NotAssigned {
+        task_id: TaskId,
+        profile_id: ProfileId,
+    },
+
+    #[error("Resource '{resource_id}' does not satisfy profile '{profile_id}': missing skills {missing:?}")]
+    MissingSkills {
+        resource_id: ResourceId,
+        profile_id: ProfileId,
+        missing: Vec<String>,
+    },
+}
+
+impl Project {
+    /// Replace a profile assignment on a task with a concrete resource
+    ///
+    /// The resource must have every skill the profile requires. Allocation
+    /// units are kept. Returns the rates before and after so callers can
+    /// report how the cost range collapsed.
+    pub fn refine_assignment(
+        &mut self,
+        task_id: &str,
+        profile_id: &str,
+        resource_id: &str,
+    ) -> Result<AssignmentRefinement, RefinementError> {
+        let profile = self
+            .get_profile(profile_id)
+            .ok_or_else(|| RefinementError::ProfileNotFound(profile_id.to_string()))?;
+        let resource = self
+            .get_resource(resource_id)
+            .ok_or_else(|| RefinementError::ResourceNotFound(resource_id.to_string()))?;
+
+        let available = resource
+            .specializes
+            .as_deref()
+            .map(|id| self.profile_skills(id))
+            .unwrap_or_default();
+        let missing: Vec<String> = self
+            .profile_skills(&profile.id)
+            .into_iter()
+            .filter(|skill| !available.contains(skill))
+            .collect();
+        if !missing.is_empty() {
+            return Err(RefinementError::MissingSkills {
+                resource_id: resource_id.to_string(),
+                profile_id: profile_id.to_string(),
+                missing,
+            });
+        }
+
+        let profile_rate = self.profile_rate(profile_id);
+        let resource_rate = resource.rate.clone();
+
+        let task = self
+            .get_task_mut(task_id)
+            .ok_or_else(|| RefinementError::TaskNotFound(task_id.to_string()))?;
+        let assignment = task
+            .assigned
+            .iter_mut()
+            .find(|r| r.resource_id == profile_id)
+            .ok_or_else(|| RefinementError::NotAssigned {
+                task_id: task_id.to_string(),
+                profile_id: profile_id.to_string(),
+            })?;
+        assignment.resource_id = resource_id.to_string();
+
+        Ok(AssignmentRefinement {
+            task_id: task_id.to_string(),
+            profile_id: profile_id.to_string(),
+            resource_id: resource_id.to_string(),
+            units: assignment.units,
+            profile_rate,
+            resource_rate,
+        })
+    }
+
+    /// Skills required by a profile, including those of the profiles it
+    /// specializes, in chain order
+    pub fn profile_skills(&self, profile_id: &str) -> Vec<String> {
+        let mut skills: Vec<String> = Vec::new();
+        let mut visited = HashSet::new();
+        let mut current = self.get_profile(profile_id);
+        while let Some(profile) = current {
+            if !visited.insert(profile.id.as_str()) {
+                break;
+            }
+            for skill in &profile.skills {
+                if !skills.contains(skill) {
+                    skills.push(skill.clone());
+                }
+            }
+            current = profile
+                .specializes
+                .as_deref()
+                .and_then(|id| self.get_profile(id));
+        }
+        skills
+    }
+
+    /// Rate range of a profile: its own or the nearest inherited one, scaled
+    /// by the profile's trait multipliers
+    fn profile_rate(&self, profile_id: &str) -> Option<RateRange> {
+        let profile = self.get_profile(profile_id)?;
+        let mut visited = HashSet::new();
+        let mut current = Some(profile);
+        let base = loop {
+            let candidate = current?;
+            if !visited.insert(candidate.id.as_str()) {
+                return None;
+            }
+            match &candidate.rate {
+                Some(ResourceRate::Range(range)) => break range.clone(),
+                Some(ResourceRate::Fixed(money)) => {
+                    break RateRange::new(money.amount, money.amount)
+                }
+                None => {
+                    current = candidate
+                        .specializes
+                        .as_deref()
+                        .and_then(|id| self.get_profile(id));
+                }
+            }
+        };
+        let multiplier: f64 = profile
+            .traits
+            .iter()
+            .filter_map(|id| self.get_trait(id))
+            .map(|t| t.rate_multiplier)
+            .product();
+        Some(base.apply_multiplier(multiplier))
+    }
+}
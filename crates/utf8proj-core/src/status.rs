@@ -258,6 +258,7 @@ mod tests {
             duration: Duration::days((finish - start).num_days()),
             assignments: Vec::new(),
             slack: Duration::zero(),
+            free_slack: Duration::zero(),
             is_critical: false,
             early_start: start,
             early_finish: finish,
@@ -278,6 +279,11 @@ mod tests {
             finish_variance_days,
             cost_range: None,
             has_abstract_assignments: false,
+            driving_predecessor: None,
+            is_milestone: false,
+            critical_reason: None,
+            is_pinned: false,
+            tags: Vec::new(),
         }
     }
 
@@ -526,6 +532,8 @@ mod tests {
             planned_value: 0,
             earned_value: 0,
             spi: 1.0,
+            status_date: Some(date(2026, 2, 10)),
+            unconstrained_finish: None,
         };
 
         let status = ProjectStatus::from_schedule(&project, &schedule, date(2026, 2, 10));
@@ -582,6 +590,8 @@ mod tests {
             planned_value: 50,
             earned_value: 40,
             spi: 0.8,
+            status_date: Some(date(2026, 1, 13)),
+            unconstrained_finish: None,
         };
 
         let status = ProjectStatus::from_schedule(&project, &schedule, date(2026, 1, 13));
@@ -624,6 +634,8 @@ mod tests {
             planned_value: 100,
             earned_value: 100,
             spi: 1.0,
+            status_date: Some(date(2026, 1, 16)),
+            unconstrained_finish: None,
         };
 
         let status = ProjectStatus::from_schedule(&project, &schedule, date(2026, 1, 16));
@@ -659,6 +671,8 @@ mod tests {
             planned_value: 50,
             earned_value: 50,
             spi: 1.0,
+            status_date: Some(date(2026, 1, 10)),
+            unconstrained_finish: None,
         };
 
         let status = ProjectStatus::from_schedule(&project, &schedule, date(2026, 1, 10));
@@ -700,6 +714,8 @@ mod tests {
             planned_value: 60,
             earned_value: 53,
             spi: 0.88,
+            status_date: Some(date(2026, 1, 10)),
+            unconstrained_finish: None,
         };
 
         let status = ProjectStatus::from_schedule(&project, &schedule, date(2026, 1, 10));
@@ -0,0 +1,225 @@
+//! Internal consistency checks over a produced schedule
+//!
+//! [`Schedule::verify`] re-reads a schedule against its project and reports
+//! every broken invariant: CPM dates that disagree with each other, slack
+//! that does not match the late dates, unsatisfied dependencies and
+//! containers that do not span their children. A schedule straight from the
+//! solver should verify clean; this is a debugging and testing aid.
+
+use crate::graph::{flatten_tasks_with_context, resolve_dependency_path};
+use crate::{Calendar, Project, Schedule, TaskId, TaskStatus};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A schedule invariant
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Invariant {
+    /// Early start is on or before late start
+    EarlyStartBeforeLateStart,
+    /// Early finish is early start plus the duration in working days
+    FinishMatchesDuration,
+    /// Slack is the working days from early start to late start
+    SlackMatchesLateStart,
+    /// The successor's dates honour the dependency type and lag
+    DependencySatisfied,
+    /// Critical tasks have no slack
+    CriticalHasNoSlack,
+    /// A container starts no later and finishes no earlier than its children
+    ContainerSpansChildren,
+}
+
+impl fmt::Display for Invariant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            Invariant::EarlyStartBeforeLateStart => "ES <= LS",
+            Invariant::FinishMatchesDuration => "EF = ES + duration",
+            Invariant::SlackMatchesLateStart => "slack = LS - ES",
+            Invariant::DependencySatisfied => "dependency satisfied",
+            Invariant::CriticalHasNoSlack => "critical tasks have zero slack",
+            Invariant::ContainerSpansChildren => "container spans its children",
+        };
+        f.write_str(text)
+    }
+}
+
+/// A broken invariant found by [`Schedule::verify`]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InvariantViolation {
+    /// Offending task (the successor for dependencies, the container for
+    /// container bounds)
+    pub task_id: TaskId,
+    /// The invariant that failed
+    pub invariant: Invariant,
+    /// What was found, e.g. "slack is 2d but LS - ES is 3d"
+    pub detail: String,
+}
+
+impl fmt::Display for InvariantViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {} ({})", self.task_id, self.invariant, self.detail)
+    }
+}
+
+impl Schedule {
+    /// Check the schedule's internal invariants against `project`
+    ///
+    /// Working days are counted on the project calendar, except that a
+    /// task's duration is counted on its own calendar (task override, then
+    /// its first assigned resource or profile with one, restricted to
+    /// project working days). Date and dependency checks are skipped for
+    /// tasks that have started, since actuals legitimately override the
+    /// plan. Returns the violations sorted by task ID; an empty list means
+    /// the schedule is consistent.
+    pub fn verify(&self, project: &Project) -> Vec<InvariantViolation> {
+        let calendar = project.default_calendar();
+        let (task_map, context_map) = flatten_tasks_with_context(&project.tasks);
+        let mut violations = Vec::new();
+        let mut violation = |task_id: &str, invariant: Invariant, detail: String| {
+            violations.push(InvariantViolation {
+                task_id: task_id.to_string(),
+                invariant,
+                detail,
+            });
+        };
+
+        for (id, st) in &self.tasks {
+            if st.early_start > st.late_start {
+                violation(
+                    id,
+                    Invariant::EarlyStartBeforeLateStart,
+                    format!("ES {} is after LS {}", st.early_start, st.late_start),
+                );
+            }
+
+            let slack_days = st.slack.round_days();
+            if st.is_critical && slack_days > 0 {
+                violation(
+                    id,
+                    Invariant::CriticalHasNoSlack,
+                    format!("critical with {}d of slack", slack_days),
+                );
+            }
+
+            let is_container = self.has_children(id);
+            if is_container {
+                let prefix = format!("{}.", id);
+                for (child_id, child) in &self.tasks {
+                    if !child_id.starts_with(&prefix) {
+                        continue;
+                    }
+                    // A milestone on D marks the start of D, so it may sit
+                    // just past a container finishing on the working day before
+                    let overruns = if child.is_milestone {
+                        calendar.working_days_between(st.finish, child.start) > 1
+                    } else {
+                        child.finish > st.finish
+                    };
+                    if child.start < st.start || overruns {
+                        violation(
+                            id,
+                            Invariant::ContainerSpansChildren,
+                            format!(
+                                "{} to {} does not span '{}' ({} to {})",
+                                st.start, st.finish, child_id, child.start, child.finish
+                            ),
+                        );
+                    }
+                }
+            }
+
+            if st.status != TaskStatus::NotStarted {
+                continue;
+            }
+
+            if !is_container {
+                let ls_minus_es = calendar.working_days_between(st.early_start, st.late_start);
+                if slack_days != ls_minus_es {
+                    violation(
+                        id,
+                        Invariant::SlackMatchesLateStart,
+                        format!("slack is {}d but LS - ES is {}d", slack_days, ls_minus_es),
+                    );
+                }
+
+                let task_calendar = task_map
+                    .get(id)
+                    .and_then(|task| task_calendar(task, project, &calendar));
+                let task_calendar = task_calendar.as_ref().unwrap_or(&calendar);
+                let days = st.duration.as_days().ceil() as i64;
+                let expected = task_calendar.add_working_days(st.early_start, (days - 1).max(0));
+                if st.early_finish != expected {
+                    violation(
+                        id,
+                        Invariant::FinishMatchesDuration,
+                        format!(
+                            "EF is {} but ES {} + {}d ends {}",
+                            st.early_finish, st.early_start, days, expected
+                        ),
+                    );
+                }
+            }
+
+            let Some(task) = task_map.get(id) else {
+                continue;
+            };
+            for dep in &task.depends {
+                let Some(pred_id) =
+                    resolve_dependency_path(&dep.predecessor, id, &context_map, &task_map)
+                else {
+                    continue;
+                };
+                let Some(pred) = self.tasks.get(&pred_id) else {
+                    continue;
+                };
+                let Some((from, to)) = dep.dep_type.linked_dates(pred, st) else {
+                    continue;
+                };
+                let lag = dep.lag.map_or(0, |l| l.round_days());
+                let room = if dep.lag_calendar {
+                    (to - from).num_days()
+                } else {
+                    calendar.working_days_between(from, to)
+                };
+                if room < lag {
+                    violation(
+                        id,
+                        Invariant::DependencySatisfied,
+                        format!(
+                            "{:?} on '{}' needs {}d lag but has {}d",
+                            dep.dep_type, pred_id, lag, room
+                        ),
+                    );
+                }
+            }
+        }
+
+        violations.sort_by(|a, b| a.task_id.cmp(&b.task_id));
+        violations
+    }
+}
+
+/// The calendar a task's working days are counted on, when it is not the
+/// project calendar: the task's own calendar, else its first assigned
+/// resource or profile calendar restricted to project working days
+fn task_calendar(
+    task: &crate::Task,
+    project: &Project,
+    project_calendar: &Calendar,
+) -> Option<Calendar> {
+    let find = |id: &String| project.calendars.iter().find(|c| c.id == *id);
+    if let Some(id) = &task.calendar {
+        return find(id).cloned();
+    }
+    let id = task.assigned.iter().find_map(|r| {
+        project
+            .get_resource(&r.resource_id)
+            .and_then(|res| res.calendar.as_ref())
+            .or_else(|| {
+                project
+                    .get_profile(&r.resource_id)
+                    .and_then(|p| p.calendar.as_ref())
+            })
+    })?;
+    find(id).map(|c| c.intersect(project_calendar))
+}
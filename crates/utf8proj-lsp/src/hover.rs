@@ -10,8 +10,8 @@ use chrono::Datelike;
 use tower_lsp::lsp_types::{Hover, HoverContents, MarkupContent, MarkupKind, Position};
 
 use utf8proj_core::{
-    Calendar, Diagnostic, DiagnosticCode, Project, ResourceProfile, ResourceRate, Schedule, Task,
-    TaskConstraint,
+    Calendar, CurrencyFormat, Diagnostic, DiagnosticCode, Project, ResourceProfile, ResourceRate,
+    Schedule, Task, TaskConstraint,
 };
 
 /// Get hover information for a position in the document
@@ -100,14 +100,19 @@ fn hover_for_profile(profile: &ResourceProfile, project: &Project) -> Hover {
     if let Some(ref rate) = profile.rate {
         match rate {
             ResourceRate::Fixed(money) => {
-                lines.push(format!("Rate: ${}/day", money.amount));
+                lines.push(format!(
+                    "Rate: {}/day",
+                    money.format(&CurrencyFormat::default())
+                ));
             }
             ResourceRate::Range(range) => {
+                let currency = range.currency.as_deref().unwrap_or(&project.currency);
+                let format = CurrencyFormat::default();
                 lines.push(format!(
-                    "Rate: ${} - ${}/day (expected: ${})",
-                    range.min,
-                    range.max,
-                    range.expected()
+                    "Rate: {} - {}/day (expected: {})",
+                    format.render(range.min, currency),
+                    format.render(range.max, currency),
+                    format.render(range.expected(), currency)
                 ));
             }
         }
@@ -157,10 +162,19 @@ fn get_inherited_rate(profile_id: &str, project: &Project) -> Option<String> {
     let mut current = project.get_profile(profile_id);
     while let Some(p) = current {
         if let Some(ref rate) = p.rate {
+            let format = CurrencyFormat::default();
             return match rate {
-                ResourceRate::Fixed(money) => Some(format!("${}/day from {}", money.amount, p.id)),
+                ResourceRate::Fixed(money) => {
+                    Some(format!("{}/day from {}", money.format(&format), p.id))
+                }
                 ResourceRate::Range(range) => {
-                    Some(format!("${} - ${}/day from {}", range.min, range.max, p.id))
+                    let currency = range.currency.as_deref().unwrap_or(&project.currency);
+                    Some(format!(
+                        "{} - {}/day from {}",
+                        format.render(range.min, currency),
+                        format.render(range.max, currency),
+                        p.id
+                    ))
                 }
             };
         }
@@ -178,7 +192,10 @@ fn hover_for_resource(resource: &utf8proj_core::Resource) -> Hover {
     }
 
     if let Some(ref rate) = resource.rate {
-        lines.push(format!("Rate: ${}/day", rate.amount));
+        lines.push(format!(
+            "Rate: {}/day",
+            rate.format(&CurrencyFormat::default())
+        ));
     }
 
     if resource.capacity != 1.0 {
@@ -301,12 +318,7 @@ fn hover_for_task(
             }
 
             // Calendar Impact section
-            let project_calendar = project
-                .calendars
-                .iter()
-                .find(|c| c.id == project.calendar)
-                .cloned()
-                .unwrap_or_else(Calendar::default);
+            let project_calendar = project.default_calendar();
 
             let (working_days, weekend_days, holiday_days) =
                 calculate_calendar_impact(st.start, st.finish, &project_calendar);
@@ -330,7 +342,7 @@ fn hover_for_task(
 
             // Show constraint effects if any
             if !task.constraints.is_empty() {
-                let effects = analyze_constraint_effects(task, st);
+                let effects = analyze_constraint_effects(task, st, sched, &project_calendar);
                 if !effects.is_empty() {
                     lines.push("---".to_string());
                     lines.push("**Constraint Effects:**".to_string());
@@ -389,12 +401,15 @@ fn calculate_calendar_impact(
             .iter()
             .any(|h| current >= h.start && current <= h.end);
 
-        if is_holiday {
+        if calendar.is_working_day(current) {
+            working_days += 1;
+        } else if is_holiday {
             holiday_days += 1;
         } else if !calendar.working_days.contains(&weekday) {
             weekend_days += 1;
         } else {
-            working_days += 1;
+            // Blanked out by a calendar exception
+            holiday_days += 1;
         }
 
         current = current.succ_opt().unwrap_or(current);
@@ -422,7 +437,9 @@ fn is_diagnostic_for_task(diagnostic: &Diagnostic, task_id: &str) -> bool {
         DiagnosticCode::C010NonWorkingDay | DiagnosticCode::C011CalendarMismatch => {
             diagnostic.message.contains(&quoted_id)
         }
-        DiagnosticCode::H004TaskUnconstrained => diagnostic.message.contains(&quoted_id),
+        DiagnosticCode::H004TaskUnconstrained | DiagnosticCode::H005TaskDisconnected => {
+            diagnostic.message.contains(&quoted_id)
+        }
         DiagnosticCode::W001AbstractAssignment | DiagnosticCode::H001MixedAbstraction => {
             diagnostic.message.contains(&quoted_id)
         }
@@ -481,6 +498,16 @@ fn format_constraint(constraint: &TaskConstraint) -> String {
         TaskConstraint::StartNoLaterThan(d) => format!("start_no_later_than: {}", d),
         TaskConstraint::FinishNoEarlierThan(d) => format!("finish_no_earlier_than: {}", d),
         TaskConstraint::FinishNoLaterThan(d) => format!("finish_no_later_than: {}", d),
+        TaskConstraint::RelativeTo {
+            anchor,
+            offset,
+            from,
+        } => format!(
+            "relative_to: {} {} {:+}d",
+            anchor,
+            from,
+            offset.round_days()
+        ),
     }
 }
 
@@ -488,6 +515,8 @@ fn format_constraint(constraint: &TaskConstraint) -> String {
 fn analyze_constraint_effects(
     task: &Task,
     scheduled: &utf8proj_core::ScheduledTask,
+    schedule: &Schedule,
+    calendar: &Calendar,
 ) -> Vec<String> {
     use utf8proj_core::Duration;
 
@@ -584,6 +613,22 @@ fn analyze_constraint_effects(
                     format!("✓ `finish_no_later_than: {}` — Caps late finish", date)
                 }
             }
+            TaskConstraint::RelativeTo { .. } => {
+                let label = format_constraint(constraint);
+                match constraint.relative_start(schedule, calendar) {
+                    None => format!("○ `{}` — Anchor not scheduled", label),
+                    Some(date) if es == date && ls == date => {
+                        format!("📌 `{}` — Task pinned to {}", label, date)
+                    }
+                    Some(date) if es > date => {
+                        format!(
+                            "○ `{}` — Redundant (ES={}, anchor gives {})",
+                            label, es, date
+                        )
+                    }
+                    Some(date) => format!("✓ `{}` — Anchored start {}", label, date),
+                }
+            }
         };
         effects.push(effect);
     }
@@ -618,6 +663,7 @@ mod tests {
             duration,
             assignments: vec![],
             slack,
+            free_slack: slack,
             is_critical,
             early_start: start,
             early_finish: finish,
@@ -630,10 +676,15 @@ mod tests {
             status: TaskStatus::NotStarted,
             cost_range: None,
             has_abstract_assignments: false,
+            driving_predecessor: None,
+            is_milestone: false,
+            critical_reason: None,
+            is_pinned: false,
             baseline_start: start,
             baseline_finish: finish,
             start_variance_days: 0,
             finish_variance_days: 0,
+            tags: Vec::new(),
         }
     }
 
@@ -794,6 +845,7 @@ mod tests {
             predecessor: "task0".to_string(),
             dep_type: DependencyType::FinishToStart,
             lag: None,
+            lag_calendar: false,
         }];
         task1.complete = Some(0.5);
         project.tasks.push(task1);
@@ -827,7 +879,7 @@ mod tests {
         let content = extract_hover_content(&hover);
 
         assert!(content.contains("**Profile: developer**"));
-        assert!(content.contains("Rate: $100 - $200/day"));
+        assert!(content.contains("Rate: $100.00 - $200.00/day"));
         assert!(content.contains("expected:"));
     }
 
@@ -840,7 +892,7 @@ mod tests {
         let content = extract_hover_content(&hover);
 
         assert!(content.contains("**Profile: fixed_rate_dev**"));
-        assert!(content.contains("Rate: $150/day"));
+        assert!(content.contains("Rate: $150.00/day"));
     }
 
     #[test]
@@ -948,7 +1000,7 @@ mod tests {
 
         assert!(content.contains("**Resource: alice**"));
         assert!(content.contains("Name: Alice Smith"));
-        assert!(content.contains("Rate: $120/day"));
+        assert!(content.contains("Rate: $120.00/day"));
         assert!(content.contains("Capacity: 80%"));
         assert!(content.contains("Efficiency: 120%"));
     }
@@ -1082,6 +1134,8 @@ mod tests {
             planned_value: 0,
             earned_value: 0,
             spi: 1.0,
+            status_date: Some(finish),
+            unconstrained_finish: None,
         };
 
         let hover = hover_for_task(task, "task1", &project, Some(&schedule), &[]);
@@ -1128,6 +1182,8 @@ mod tests {
             planned_value: 0,
             earned_value: 0,
             spi: 1.0,
+            status_date: Some(project_end),
+            unconstrained_finish: None,
         };
 
         let hover = hover_for_task(task, "task1", &project, Some(&schedule), &[]);
@@ -1195,6 +1251,8 @@ mod tests {
             planned_value: 0,
             earned_value: 0,
             spi: 1.0,
+            status_date: Some(finish),
+            unconstrained_finish: None,
         };
 
         let hover = hover_for_task(&task, "pinned_task", &project, Some(&schedule), &[]);
@@ -1244,6 +1302,8 @@ mod tests {
             planned_value: 0,
             earned_value: 0,
             spi: 1.0,
+            status_date: Some(project_end),
+            unconstrained_finish: None,
         };
 
         let hover = hover_for_task(&task, "redundant_task", &project, Some(&schedule), &[]);
@@ -1324,7 +1384,9 @@ mod tests {
         let rate = get_inherited_rate("developer", &project);
 
         assert!(rate.is_some());
-        assert!(rate.unwrap().contains("$100 - $200/day from developer"));
+        assert!(rate
+            .unwrap()
+            .contains("$100.00 - $200.00/day from developer"));
     }
 
     #[test]
@@ -1341,7 +1403,7 @@ mod tests {
         let rate = get_inherited_rate("fixed_rate_dev", &project);
 
         assert!(rate.is_some());
-        assert!(rate.unwrap().contains("$150/day from fixed_rate_dev"));
+        assert!(rate.unwrap().contains("$150.00/day from fixed_rate_dev"));
     }
 
     // =========================================================================
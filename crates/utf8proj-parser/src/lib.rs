@@ -66,6 +66,13 @@ pub fn detect_format(path: &std::path::Path) -> FileFormat {
     }
 }
 
+/// Check an IANA time zone name (e.g. "Europe/Rome"), returning it as given
+pub(crate) fn parse_timezone(name: &str) -> Result<String, ParseError> {
+    name.parse::<chrono_tz::Tz>()
+        .map(|_| name.to_string())
+        .map_err(|_| ParseError::InvalidValue(format!("Unknown timezone: {}", name)))
+}
+
 /// Parse a project from the native DSL format
 pub fn parse_project(input: &str) -> Result<utf8proj_core::Project, ParseError> {
     native::parse(input)
@@ -13,7 +13,7 @@ use utf8proj_core::{
     TaskStatus, TemporalRegime, TimeRange, Trait,
 };
 
-use crate::ParseError;
+use crate::{parse_timezone, ParseError};
 
 #[derive(Parser)]
 #[grammar = "native/grammar.pest"]
@@ -79,6 +79,13 @@ pub fn parse(input: &str) -> Result<Project, ParseError> {
         project.calendars.push(Calendar::default());
     }
 
+    // Resource rates are quoted in the project currency
+    for rate in project.resources.iter_mut().filter_map(|r| r.rate.as_mut()) {
+        if rate.currency.is_empty() {
+            rate.currency = project.currency.clone();
+        }
+    }
+
     Ok(project)
 }
 
@@ -271,11 +278,8 @@ fn parse_project_attr(pair: Pair<Rule>, project: &mut Project) -> Result<(), Par
             project.calendar = parse_identifier(id_pair);
         }
         Rule::project_timezone => {
-            // Timezone stored in attributes for now
             let tz_pair = inner.into_inner().next().unwrap();
-            project
-                .attributes
-                .insert("timezone".to_string(), tz_pair.as_str().to_string());
+            project.timezone = Some(parse_timezone(tz_pair.as_str())?);
         }
         Rule::project_leveling => {
             // RFC-0014 Phase 3: leveling mode (optimal, heuristic, none)
@@ -516,6 +520,11 @@ fn parse_resource_attr(pair: Pair<Rule>, resource: &mut Resource) -> Result<(),
             let num_pair = inner.into_inner().next().unwrap();
             resource.efficiency = parse_number(num_pair)? as f32;
         }
+        Rule::resource_pool => {
+            // Consumable resource: total quantity for the whole project
+            let num_pair = inner.into_inner().next().unwrap();
+            resource.pool = Some(parse_number(num_pair)? as f32);
+        }
         Rule::resource_specializes => {
             // RFC-0001: Resource specializes a profile
             let id_pair = inner.into_inner().next().unwrap();
@@ -558,13 +567,13 @@ fn parse_money(pair: Pair<Rule>) -> Result<Money, ParseError> {
     let amount = Decimal::from_str(amount_str)
         .map_err(|_| ParseError::InvalidValue(format!("Invalid money amount: {}", amount_str)))?;
 
-    let time_unit = inner.next().unwrap().as_str();
-    // Currency is derived from time_unit context - we use the time unit as a suffix indicator
-    // The actual currency comes from the project
+    // Rates are per day; the currency is the project's, filled in by `parse`
+    // once the whole file has been read
+    let _time_unit = inner.next().unwrap().as_str();
 
     Ok(Money {
         amount,
-        currency: format!("/{}", time_unit), // Store rate type for now
+        currency: String::new(),
     })
 }
 
@@ -896,6 +905,10 @@ fn parse_task_attr(pair: Pair<Rule>, task: &mut Task) -> Result<(), ParseError>
             let pct_pair = inner.into_inner().next().unwrap();
             task.complete = Some(parse_percentage(pct_pair)? * 100.0); // Store as 0-100
         }
+        Rule::task_physical_complete => {
+            let pct_pair = inner.into_inner().next().unwrap();
+            task.physical_complete = Some(parse_percentage(pct_pair)? * 100.0);
+        }
         Rule::task_constraint => {
             let constraint = parse_task_constraint(inner)?;
             task.constraints.push(constraint);
@@ -946,6 +959,10 @@ fn parse_task_attr(pair: Pair<Rule>, task: &mut Task) -> Result<(), ParseError>
             let regime_pair = inner.into_inner().next().unwrap();
             task.regime = Some(parse_regime(regime_pair));
         }
+        Rule::task_calendar => {
+            let id_pair = inner.into_inner().next().unwrap();
+            task.calendar = Some(parse_identifier(id_pair));
+        }
         _ => {}
     }
     Ok(())
@@ -966,6 +983,7 @@ fn parse_dependency(pair: Pair<Rule>) -> Result<Dependency, ParseError> {
         predecessor,
         dep_type: DependencyType::FinishToStart,
         lag: None,
+        lag_calendar: false,
     };
 
     // Parse optional type and lag (grammar: task_ref ~ dep_type? ~ dep_lag?)
@@ -1269,6 +1287,22 @@ resource dev "Developer" {
         assert_eq!(res.efficiency, 1.2);
     }
 
+    #[test]
+    fn parse_resource_rate_uses_project_currency() {
+        let input = r#"
+project "Test" {
+    start: 2025-01-01
+    currency: EUR
+}
+
+resource dev "Developer" { rate: 600/day }
+"#;
+        let project = parse(input).expect("Failed to parse resource");
+        let rate = project.resources[0].rate.as_ref().unwrap();
+        assert_eq!(rate.amount, Decimal::from(600));
+        assert_eq!(rate.currency, "EUR");
+    }
+
     #[test]
     fn parse_task_with_priority_and_complete() {
         let input = r#"
@@ -1286,6 +1320,23 @@ task high_priority "High Priority Task" {
         assert_eq!(task.complete, Some(75.0));
     }
 
+    #[test]
+    fn parse_task_physical_complete() {
+        let input = r#"
+project "Test" { start: 2025-01-01 }
+
+task build "Build" {
+    duration: 10d
+    complete: 90%
+    physical_complete: 50%
+}
+"#;
+        let project = parse(input).expect("Failed to parse task");
+        let task = &project.tasks[0];
+        assert_eq!(task.complete, Some(90.0));
+        assert_eq!(task.physical_complete, Some(50.0));
+    }
+
     #[test]
     fn parse_task_constraints() {
         let input = r#"
@@ -1710,10 +1761,21 @@ project "Test" {
 }
 "#;
         let project = parse(input).expect("Failed to parse timezone");
-        assert_eq!(
-            project.attributes.get("timezone").map(|s| s.as_str()),
-            Some("Europe/Rome")
-        );
+        assert_eq!(project.timezone.as_deref(), Some("Europe/Rome"));
+    }
+
+    #[test]
+    fn parse_project_rejects_unknown_timezone() {
+        let input = r#"
+project "Test" {
+    start: 2025-01-01
+    timezone: Europe/Atlantis
+}
+"#;
+        let err = parse(input).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Unknown timezone: Europe/Atlantis"));
     }
 
     #[test]
@@ -2168,6 +2230,27 @@ resource alice "Alice" {
         assert_eq!(resource.availability, Some(0.8));
     }
 
+    #[test]
+    fn parse_consumable_resource_pool() {
+        let input = r#"
+project "Test" { start: 2025-01-01 }
+
+resource points "Story Points" {
+    pool: 100
+}
+
+task build "Build" {
+    effort: 5d
+    assign: points*40
+}
+"#;
+        let project = parse(input).expect("Failed to parse consumable resource");
+        let resource = &project.resources[0];
+        assert_eq!(resource.pool, Some(100.0));
+        assert!(resource.is_consumable());
+        assert_eq!(project.consumption("points"), 40.0);
+    }
+
     #[test]
     fn parse_full_rfc0001_example() {
         let input = r#"
@@ -2289,6 +2372,29 @@ resource_profile intern {
         }
     }
 
+    #[test]
+    fn parse_task_with_calendar() {
+        let input = r#"
+project "Test" { start: 2025-01-01 }
+
+calendar "Night Shift" {
+    working_days: tue, thu
+}
+
+task maintenance "Server Maintenance" {
+    duration: 3d
+    calendar: night_shift
+}
+
+task docs "Documentation" {
+    duration: 2d
+}
+"#;
+        let project = parse(input).expect("Failed to parse task with calendar");
+        assert_eq!(project.tasks[0].calendar, Some("night_shift".to_string()));
+        assert_eq!(project.tasks[1].calendar, None);
+    }
+
     #[test]
     fn parse_task_with_regime() {
         let input = r#"
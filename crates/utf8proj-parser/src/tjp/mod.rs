@@ -6,7 +6,7 @@ use chrono::NaiveDate;
 use pest::Parser;
 use pest_derive::Parser;
 
-use crate::ParseError;
+use crate::{parse_timezone, ParseError};
 use utf8proj_core::{
     Dependency, DependencyType, Duration, Project, Resource, ResourceRef, Task, TaskConstraint,
 };
@@ -102,7 +102,9 @@ fn parse_project_decl(
 
             match actual_attr.as_rule() {
                 Rule::timezone_attr => {
-                    // Could store timezone
+                    if let Some(timezone) = actual_attr.into_inner().next() {
+                        project.timezone = Some(parse_timezone(&parse_string(timezone.as_str()))?);
+                    }
                 }
                 Rule::currency_attr => {
                     let mut inner = actual_attr.into_inner();
@@ -289,6 +291,7 @@ fn parse_task_decl(pair: pest::iterators::Pair<Rule>) -> Result<Option<Task>, Pa
                                         predecessor: task_path,
                                         dep_type,
                                         lag,
+                                        lag_calendar: false,
                                     });
                                 }
                             }
@@ -418,6 +421,7 @@ mod tests {
 
         let project = parse(input).unwrap();
         assert_eq!(project.name, "Test Project");
+        assert_eq!(project.timezone.as_deref(), Some("UTC"));
         assert_eq!(project.tasks.len(), 2);
         assert_eq!(project.resources.len(), 1);
     }
@@ -1097,7 +1097,7 @@ impl ExcelRenderer {
                         DependencyType::StartToFinish => "SF",
                         DependencyType::FinishToStart => "FS",
                     };
-                    let lag_days = d.lag.map(|l| l.as_days() as i32).unwrap_or(0);
+                    let lag_days = d.lag.map(|l| l.round_days() as i32).unwrap_or(0);
                     // Resolve predecessor ID to full path for VLOOKUP compatibility
                     // Handle: simple IDs ("gnu_analysis"), partial paths ("gnu_val.gnu_analysis"),
                     // and full paths ("os_migration.gnu_val.gnu_analysis")
@@ -1391,7 +1391,7 @@ impl ExcelRenderer {
         // Get calendar for working day detection
         let calendar = self.calendar.clone().unwrap_or_else(|| {
             // Try to get from project, otherwise use default
-            project.calendars.first().cloned().unwrap_or_default()
+            project.default_calendar()
         });
 
         // Column layout (same as weekly with dependencies):
@@ -1479,7 +1479,7 @@ impl ExcelRenderer {
                         DependencyType::StartToFinish => "SF",
                         DependencyType::FinishToStart => "FS",
                     };
-                    let lag_days = d.lag.map(|l| l.as_days() as i32).unwrap_or(0);
+                    let lag_days = d.lag.map(|l| l.round_days() as i32).unwrap_or(0);
                     let full_predecessor = if all_full_ids.contains(&d.predecessor) {
                         d.predecessor.clone()
                     } else if let Some(full) = simple_to_full_id.get(&d.predecessor) {
@@ -3039,12 +3039,7 @@ impl ExcelRenderer {
         sheet.set_column_width(8, 30).ok(); // Diagnostics
 
         // Get project calendar for fallback
-        let project_calendar = project
-            .calendars
-            .iter()
-            .find(|c| c.id == project.calendar)
-            .cloned()
-            .unwrap_or_else(Calendar::default);
+        let project_calendar = project.default_calendar();
 
         // Collect tasks in WBS order
         let wbs_order = Self::collect_wbs_order(&project.tasks, 0);
@@ -3817,8 +3812,12 @@ mod tests {
                     cost_range: None,
                     is_abstract: false,
                     effort_days: None,
+                    cost_explanation: None,
+                    start_time: None,
+                    finish_time: None,
                 }],
                 slack: Duration::zero(),
+                free_slack: Duration::zero(),
                 is_critical: true,
                 early_start: start1,
                 early_finish: finish1,
@@ -3831,10 +3830,15 @@ mod tests {
                 status: TaskStatus::NotStarted,
                 cost_range: None,
                 has_abstract_assignments: false,
+                driving_predecessor: None,
+                is_milestone: false,
+                critical_reason: None,
+                is_pinned: false,
                 baseline_start: start1,
                 baseline_finish: finish1,
                 start_variance_days: 0,
                 finish_variance_days: 0,
+                tags: Vec::new(),
             },
         );
 
@@ -3856,8 +3860,12 @@ mod tests {
                     cost_range: None,
                     is_abstract: false,
                     effort_days: None,
+                    cost_explanation: None,
+                    start_time: None,
+                    finish_time: None,
                 }],
                 slack: Duration::zero(),
+                free_slack: Duration::zero(),
                 is_critical: true,
                 early_start: start2,
                 early_finish: finish2,
@@ -3870,10 +3878,15 @@ mod tests {
                 status: TaskStatus::NotStarted,
                 cost_range: None,
                 has_abstract_assignments: false,
+                driving_predecessor: None,
+                is_milestone: false,
+                critical_reason: None,
+                is_pinned: false,
                 baseline_start: start2,
                 baseline_finish: finish2,
                 start_variance_days: 0,
                 finish_variance_days: 0,
+                tags: Vec::new(),
             },
         );
 
@@ -3895,8 +3908,12 @@ mod tests {
                     cost_range: None,
                     is_abstract: false,
                     effort_days: None,
+                    cost_explanation: None,
+                    start_time: None,
+                    finish_time: None,
                 }],
                 slack: Duration::zero(),
+                free_slack: Duration::zero(),
                 is_critical: true,
                 early_start: start3,
                 early_finish: finish3,
@@ -3909,10 +3926,15 @@ mod tests {
                 status: TaskStatus::NotStarted,
                 cost_range: None,
                 has_abstract_assignments: false,
+                driving_predecessor: None,
+                is_milestone: false,
+                critical_reason: None,
+                is_pinned: false,
                 baseline_start: start3,
                 baseline_finish: finish3,
                 start_variance_days: 0,
                 finish_variance_days: 0,
+                tags: Vec::new(),
             },
         );
 
@@ -3935,6 +3957,8 @@ mod tests {
             planned_value: 0,
             earned_value: 0,
             spi: 1.0,
+            status_date: Some(project_end),
+            unconstrained_finish: None,
         }
     }
 
@@ -3996,6 +4020,8 @@ mod tests {
             planned_value: 0,
             earned_value: 0,
             spi: 1.0,
+            status_date: Some(project_end),
+            unconstrained_finish: None,
         };
 
         let result = renderer.render(&project, &schedule);
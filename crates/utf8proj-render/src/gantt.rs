@@ -1564,6 +1564,7 @@ mod tests {
                 duration: Duration::days(5),
                 assignments: vec![],
                 slack: Duration::zero(),
+                free_slack: Duration::zero(),
                 is_critical: true,
                 early_start: start1,
                 early_finish: finish1,
@@ -1576,10 +1577,15 @@ mod tests {
                 status: TaskStatus::NotStarted,
                 cost_range: None,
                 has_abstract_assignments: false,
+                driving_predecessor: None,
+                is_milestone: false,
+                critical_reason: None,
+                is_pinned: false,
                 baseline_start: start1,
                 baseline_finish: finish1,
                 start_variance_days: 0,
                 finish_variance_days: 0,
+                tags: Vec::new(),
             },
         );
 
@@ -1594,6 +1600,7 @@ mod tests {
                 duration: Duration::days(10),
                 assignments: vec![],
                 slack: Duration::zero(),
+                free_slack: Duration::zero(),
                 is_critical: true,
                 early_start: start2,
                 early_finish: finish2,
@@ -1606,10 +1613,15 @@ mod tests {
                 status: TaskStatus::NotStarted,
                 cost_range: None,
                 has_abstract_assignments: false,
+                driving_predecessor: None,
+                is_milestone: false,
+                critical_reason: None,
+                is_pinned: false,
                 baseline_start: start2,
                 baseline_finish: finish2,
                 start_variance_days: 0,
                 finish_variance_days: 0,
+                tags: Vec::new(),
             },
         );
 
@@ -1624,6 +1636,7 @@ mod tests {
                 duration: Duration::days(3),
                 assignments: vec![],
                 slack: Duration::zero(),
+                free_slack: Duration::zero(),
                 is_critical: true,
                 early_start: start3,
                 early_finish: finish3,
@@ -1636,10 +1649,15 @@ mod tests {
                 status: TaskStatus::NotStarted,
                 cost_range: None,
                 has_abstract_assignments: false,
+                driving_predecessor: None,
+                is_milestone: false,
+                critical_reason: None,
+                is_pinned: false,
                 baseline_start: start3,
                 baseline_finish: finish3,
                 start_variance_days: 0,
                 finish_variance_days: 0,
+                tags: Vec::new(),
             },
         );
 
@@ -1662,6 +1680,8 @@ mod tests {
             planned_value: 0,
             earned_value: 0,
             spi: 1.0,
+            status_date: Some(project_end),
+            unconstrained_finish: None,
         }
     }
 
@@ -1748,6 +1768,8 @@ mod tests {
             planned_value: 0,
             earned_value: 0,
             spi: 1.0,
+            status_date: Some(project_end),
+            unconstrained_finish: None,
         };
 
         let result = renderer.render(&project, &schedule);
@@ -1786,6 +1808,7 @@ mod tests {
             predecessor: "a".to_string(),
             dep_type: utf8proj_core::DependencyType::StartToStart,
             lag: None,
+            lag_calendar: false,
         });
         project.tasks.push(task_b);
 
@@ -1802,6 +1825,7 @@ mod tests {
                 duration: Duration::days(5),
                 assignments: vec![],
                 slack: Duration::zero(),
+                free_slack: Duration::zero(),
                 is_critical: true,
                 early_start: start1,
                 early_finish: finish1,
@@ -1814,10 +1838,15 @@ mod tests {
                 status: TaskStatus::NotStarted,
                 cost_range: None,
                 has_abstract_assignments: false,
+                driving_predecessor: None,
+                is_milestone: false,
+                critical_reason: None,
+                is_pinned: false,
                 baseline_start: start1,
                 baseline_finish: finish1,
                 start_variance_days: 0,
                 finish_variance_days: 0,
+                tags: Vec::new(),
             },
         );
         tasks.insert(
@@ -1829,6 +1858,7 @@ mod tests {
                 duration: Duration::days(3),
                 assignments: vec![],
                 slack: Duration::zero(),
+                free_slack: Duration::zero(),
                 is_critical: false,
                 early_start: start1,
                 early_finish: finish2,
@@ -1841,10 +1871,15 @@ mod tests {
                 status: TaskStatus::NotStarted,
                 cost_range: None,
                 has_abstract_assignments: false,
+                driving_predecessor: None,
+                is_milestone: false,
+                critical_reason: None,
+                is_pinned: false,
                 baseline_start: start1,
                 baseline_finish: finish2,
                 start_variance_days: 0,
                 finish_variance_days: 0,
+                tags: Vec::new(),
             },
         );
 
@@ -1862,6 +1897,8 @@ mod tests {
             planned_value: 0,
             earned_value: 0,
             spi: 1.0,
+            status_date: Some(finish1),
+            unconstrained_finish: None,
         };
 
         let renderer = HtmlGanttRenderer::new();
@@ -1885,6 +1922,7 @@ mod tests {
             predecessor: "a".to_string(),
             dep_type: utf8proj_core::DependencyType::FinishToFinish,
             lag: None,
+            lag_calendar: false,
         });
         project.tasks.push(task_b);
 
@@ -1901,6 +1939,7 @@ mod tests {
                 duration: Duration::days(5),
                 assignments: vec![],
                 slack: Duration::zero(),
+                free_slack: Duration::zero(),
                 is_critical: true,
                 early_start: start1,
                 early_finish: finish1,
@@ -1913,10 +1952,15 @@ mod tests {
                 status: TaskStatus::NotStarted,
                 cost_range: None,
                 has_abstract_assignments: false,
+                driving_predecessor: None,
+                is_milestone: false,
+                critical_reason: None,
+                is_pinned: false,
                 baseline_start: start1,
                 baseline_finish: finish1,
                 start_variance_days: 0,
                 finish_variance_days: 0,
+                tags: Vec::new(),
             },
         );
         tasks.insert(
@@ -1928,6 +1972,7 @@ mod tests {
                 duration: Duration::days(3),
                 assignments: vec![],
                 slack: Duration::zero(),
+                free_slack: Duration::zero(),
                 is_critical: false,
                 early_start: start2,
                 early_finish: finish1,
@@ -1940,10 +1985,15 @@ mod tests {
                 status: TaskStatus::NotStarted,
                 cost_range: None,
                 has_abstract_assignments: false,
+                driving_predecessor: None,
+                is_milestone: false,
+                critical_reason: None,
+                is_pinned: false,
                 baseline_start: start2,
                 baseline_finish: finish1,
                 start_variance_days: 0,
                 finish_variance_days: 0,
+                tags: Vec::new(),
             },
         );
 
@@ -1961,6 +2011,8 @@ mod tests {
             planned_value: 0,
             earned_value: 0,
             spi: 1.0,
+            status_date: Some(finish1),
+            unconstrained_finish: None,
         };
 
         let renderer = HtmlGanttRenderer::new();
@@ -1981,6 +2033,7 @@ mod tests {
             predecessor: "a".to_string(),
             dep_type: utf8proj_core::DependencyType::StartToFinish,
             lag: None,
+            lag_calendar: false,
         });
         project.tasks.push(task_b);
 
@@ -1997,6 +2050,7 @@ mod tests {
                 duration: Duration::days(5),
                 assignments: vec![],
                 slack: Duration::zero(),
+                free_slack: Duration::zero(),
                 is_critical: true,
                 early_start: start1,
                 early_finish: finish1,
@@ -2009,10 +2063,15 @@ mod tests {
                 status: TaskStatus::NotStarted,
                 cost_range: None,
                 has_abstract_assignments: false,
+                driving_predecessor: None,
+                is_milestone: false,
+                critical_reason: None,
+                is_pinned: false,
                 baseline_start: start1,
                 baseline_finish: finish1,
                 start_variance_days: 0,
                 finish_variance_days: 0,
+                tags: Vec::new(),
             },
         );
         tasks.insert(
@@ -2024,6 +2083,7 @@ mod tests {
                 duration: Duration::days(3),
                 assignments: vec![],
                 slack: Duration::zero(),
+                free_slack: Duration::zero(),
                 is_critical: false,
                 early_start: start2,
                 early_finish: start1,
@@ -2036,10 +2096,15 @@ mod tests {
                 status: TaskStatus::NotStarted,
                 cost_range: None,
                 has_abstract_assignments: false,
+                driving_predecessor: None,
+                is_milestone: false,
+                critical_reason: None,
+                is_pinned: false,
                 baseline_start: start2,
                 baseline_finish: start1,
                 start_variance_days: 0,
                 finish_variance_days: 0,
+                tags: Vec::new(),
             },
         );
 
@@ -2057,6 +2122,8 @@ mod tests {
             planned_value: 0,
             earned_value: 0,
             spi: 1.0,
+            status_date: Some(finish1),
+            unconstrained_finish: None,
         };
 
         let renderer = HtmlGanttRenderer::new();
@@ -0,0 +1,140 @@
+//! Task hierarchy helpers shared by the renderers
+//!
+//! Schedules are keyed by qualified task IDs ("phase1.design"), while the
+//! project tree stores plain IDs and relative dependency paths. These helpers
+//! walk the tree the same way the solver does so renderers agree with it.
+
+use std::collections::HashMap;
+use utf8proj_core::{Project, Task};
+
+/// A task in document order with its position in the WBS
+pub struct FlatTask<'a> {
+    /// Qualified ID ("parent.child"), matching `Schedule::tasks` keys
+    pub qualified_id: String,
+    /// The task definition
+    pub task: &'a Task,
+    /// Nesting depth (0 = top level)
+    pub depth: usize,
+    /// Outline number ("1", "1.2", "1.2.3")
+    pub wbs: String,
+}
+
+/// Flatten the project's task tree in document order
+pub fn flatten_project(project: &Project) -> Vec<FlatTask<'_>> {
+    fn walk<'a>(
+        tasks: &'a [Task],
+        prefix: &str,
+        wbs_prefix: &str,
+        depth: usize,
+        out: &mut Vec<FlatTask<'a>>,
+    ) {
+        for (i, task) in tasks.iter().enumerate() {
+            let qualified_id = if prefix.is_empty() {
+                task.id.clone()
+            } else {
+                format!("{}.{}", prefix, task.id)
+            };
+            let wbs = if wbs_prefix.is_empty() {
+                (i + 1).to_string()
+            } else {
+                format!("{}.{}", wbs_prefix, i + 1)
+            };
+            out.push(FlatTask {
+                qualified_id: qualified_id.clone(),
+                task,
+                depth,
+                wbs: wbs.clone(),
+            });
+            walk(&task.children, &qualified_id, &wbs, depth + 1, out);
+        }
+    }
+
+    let mut out = Vec::new();
+    walk(&project.tasks, "", "", 0, &mut out);
+    out
+}
+
+/// Index flattened tasks by qualified ID
+pub fn index_by_id<'a, 'b>(flat: &'b [FlatTask<'a>]) -> HashMap<&'b str, &'b FlatTask<'a>> {
+    flat.iter().map(|f| (f.qualified_id.as_str(), f)).collect()
+}
+
+/// Resolve a dependency path to a qualified task ID
+///
+/// Mirrors the solver: absolute paths are used as-is, dotted paths must be
+/// absolute, and bare IDs are looked up among the dependent task's siblings.
+pub fn resolve_dependency(
+    dep_path: &str,
+    from_qualified_id: &str,
+    exists: impl Fn(&str) -> bool,
+) -> Option<String> {
+    if exists(dep_path) {
+        return Some(dep_path.to_string());
+    }
+    if dep_path.contains('.') {
+        return None;
+    }
+
+    let (container, _) = from_qualified_id.rsplit_once('.')?;
+    let qualified = format!("{}.{}", container, dep_path);
+    exists(&qualified).then_some(qualified)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn nested_project() -> Project {
+        let mut project = Project::new("Nested");
+        project.tasks.push(
+            Task::new("phase1")
+                .child(Task::new("design"))
+                .child(Task::new("build").depends_on("design")),
+        );
+        project
+            .tasks
+            .push(Task::new("release").depends_on("phase1.build"));
+        project
+    }
+
+    #[test]
+    fn flatten_assigns_qualified_ids_depth_and_wbs() {
+        let project = nested_project();
+        let flat = flatten_project(&project);
+        let rows: Vec<_> = flat
+            .iter()
+            .map(|f| (f.qualified_id.as_str(), f.depth, f.wbs.as_str()))
+            .collect();
+        assert_eq!(
+            rows,
+            vec![
+                ("phase1", 0, "1"),
+                ("phase1.design", 1, "1.1"),
+                ("phase1.build", 1, "1.2"),
+                ("release", 0, "2"),
+            ]
+        );
+    }
+
+    #[test]
+    fn resolve_dependency_matches_solver_rules() {
+        let project = nested_project();
+        let flat = flatten_project(&project);
+        let index = index_by_id(&flat);
+        let exists = |id: &str| index.contains_key(id);
+
+        assert_eq!(
+            resolve_dependency("design", "phase1.build", exists),
+            Some("phase1.design".to_string())
+        );
+        assert_eq!(
+            resolve_dependency("phase1.build", "release", exists),
+            Some("phase1.build".to_string())
+        );
+        assert_eq!(resolve_dependency("design", "release", exists), None);
+        assert_eq!(
+            resolve_dependency("phase1.missing", "release", exists),
+            None
+        );
+    }
+}
@@ -7,6 +7,7 @@
 //! - SVG Gantt chart rendering
 //! - MermaidJS Gantt chart rendering (for Markdown/docs)
 //! - PlantUML Gantt chart rendering (for wikis and documentation)
+//! - Markdown tables (for issues and pull requests)
 //! - Excel costing reports (for corporate project quoting)
 //! - Text-based output
 //! - Custom renderer trait
@@ -15,7 +16,9 @@
 //!
 //! ```rust,ignore
 //! use utf8proj_core::{Project, Schedule, Renderer};
-//! use utf8proj_render::{HtmlGanttRenderer, SvgRenderer, MermaidRenderer, PlantUmlRenderer};
+//! use utf8proj_render::{
+//!     HtmlGanttRenderer, MarkdownRenderer, MermaidRenderer, PlantUmlRenderer, SvgRenderer,
+//! };
 //!
 //! // Interactive HTML Gantt chart
 //! let renderer = HtmlGanttRenderer::new();
@@ -33,6 +36,10 @@
 //! let plantuml_renderer = PlantUmlRenderer::new();
 //! let plantuml = plantuml_renderer.render(&project, &schedule)?;
 //!
+//! // Markdown table for issues and code review
+//! let markdown_renderer = MarkdownRenderer::new();
+//! let markdown = markdown_renderer.render(&project, &schedule)?;
+//!
 //! // Excel costing report
 //! let excel_renderer = ExcelRenderer::new().currency("€");
 //! let xlsx_bytes = excel_renderer.render(&project, &schedule)?;
@@ -41,6 +48,8 @@
 
 pub mod excel;
 pub mod gantt;
+mod hierarchy;
+pub mod markdown;
 pub mod mermaid;
 pub mod plantuml;
 
@@ -48,13 +57,16 @@ pub use excel::{
     ExcelConfig, ExcelRenderer, ProgressMode, ScheduleGranularity, TaskStatus as ExcelTaskStatus,
 };
 pub use gantt::{FocusConfig, GanttTheme, HtmlGanttRenderer, NowLineConfig, TaskVisibility};
+pub use markdown::{MarkdownColumn, MarkdownRenderer};
 pub use mermaid::MermaidRenderer;
 pub use plantuml::PlantUmlRenderer;
 
-use chrono::{Datelike, NaiveDate};
-use svg::node::element::{Group, Line, Rectangle, Text};
+use chrono::{Datelike, NaiveDate, Weekday};
+use std::collections::HashMap;
+use svg::node::element::{Group, Line, Path, Polygon, Rectangle, Text};
 use svg::Document;
-use utf8proj_core::{Project, RenderError, Renderer, Schedule, ScheduledTask};
+use utf8proj_core::gantt::{GanttBar, GanttBarKind};
+use utf8proj_core::{DependencyType, Project, RenderError, Renderer, Schedule};
 
 /// Format a date range as a human-readable header label.
 ///
@@ -127,6 +139,8 @@ impl DisplayMode {
 pub struct SvgRenderer {
     /// Width of the chart area (excluding labels) in pixels
     pub chart_width: u32,
+    /// Fixed horizontal scale; when set, the chart width follows the project span
+    pub pixels_per_day: Option<f64>,
     /// Height per task row in pixels
     pub row_height: u32,
     /// Width of the label column in pixels
@@ -141,10 +155,16 @@ pub struct SvgRenderer {
     pub normal_color: String,
     /// Color for milestones
     pub milestone_color: String,
+    /// Color for summary (container) bracket bars
+    pub summary_color: String,
+    /// Color for dependency arrows
+    pub dependency_color: String,
     /// Background color
     pub background_color: String,
     /// Grid line color
     pub grid_color: String,
+    /// Month boundary grid line color
+    pub month_grid_color: String,
     /// Text color
     pub text_color: String,
     /// Font family
@@ -155,12 +175,15 @@ pub struct SvgRenderer {
     pub display_mode: DisplayMode,
     /// Highlight critical path tasks in red (default: true)
     pub highlight_critical: bool,
+    /// Draw dependency arrows between tasks (default: true)
+    pub show_dependencies: bool,
 }
 
 impl Default for SvgRenderer {
     fn default() -> Self {
         Self {
             chart_width: 800,
+            pixels_per_day: None,
             row_height: 28,
             label_width: 180,
             header_height: 50,
@@ -168,13 +191,17 @@ impl Default for SvgRenderer {
             critical_color: "#e74c3c".into(),
             normal_color: "#3498db".into(),
             milestone_color: "#9b59b6".into(),
+            summary_color: "#34495e".into(),
+            dependency_color: "#7f8c8d".into(),
             background_color: "#ffffff".into(),
             grid_color: "#ecf0f1".into(),
+            month_grid_color: "#bdc3c7".into(),
             text_color: "#2c3e50".into(),
             font_family: "system-ui, -apple-system, sans-serif".into(),
             font_size: 12,
             display_mode: DisplayMode::Name,
             highlight_critical: true,
+            show_dependencies: true,
         }
     }
 }
@@ -202,6 +229,18 @@ impl SvgRenderer {
         self
     }
 
+    /// Use a fixed horizontal scale instead of fitting the chart width
+    pub fn pixels_per_day(mut self, px: f64) -> Self {
+        self.pixels_per_day = Some(px);
+        self
+    }
+
+    /// Disable dependency arrows
+    pub fn hide_dependencies(mut self) -> Self {
+        self.show_dependencies = false;
+        self
+    }
+
     /// Configure label column width
     pub fn label_width(mut self, width: u32) -> Self {
         self.label_width = width;
@@ -225,11 +264,26 @@ impl SvgRenderer {
     }
 
     /// Calculate pixels per day based on date range
-    fn pixels_per_day(&self, start: NaiveDate, end: NaiveDate) -> f64 {
+    fn scale(&self, start: NaiveDate, end: NaiveDate) -> f64 {
         let days = (end - start).num_days().max(1) as f64;
         self.chart_width as f64 / days
     }
 
+    /// Resolve the layout for a date range, applying a fixed `pixels_per_day`
+    fn layout_for(&self, start: NaiveDate, end: NaiveDate) -> Self {
+        let mut layout = self.clone();
+        if let Some(px) = self.pixels_per_day {
+            let days = (end - start).num_days().max(1) as f64;
+            layout.chart_width = (days * px).ceil().max(1.0) as u32;
+        }
+        layout
+    }
+
+    /// Y coordinate of the top of a row
+    fn row_top(&self, row: usize) -> u32 {
+        self.padding + self.header_height + (row as u32 * self.row_height)
+    }
+
     /// Convert a date to x position
     fn date_to_x(&self, date: NaiveDate, project_start: NaiveDate, px_per_day: f64) -> f64 {
         let days = (date - project_start).num_days() as f64;
@@ -343,22 +397,33 @@ impl SvgRenderer {
             group = group.add(line);
         }
 
-        // Vertical lines for days/weeks
+        // Vertical lines: month boundaries, week starts (Mondays), and days for short spans
         let total_days = (project_end - project_start).num_days();
-        let interval = if total_days <= 30 { 1 } else { 7 };
 
         let mut current = project_start;
         while current <= project_end {
+            let (class, color, width) = if current.day() == 1 {
+                ("month-line", self.month_grid_color.as_str(), 1.5)
+            } else if current.weekday() == Weekday::Mon {
+                ("week-line", self.grid_color.as_str(), 1.0)
+            } else if total_days <= 30 {
+                ("day-line", self.grid_color.as_str(), 0.5)
+            } else {
+                current += chrono::Duration::days(1);
+                continue;
+            };
+
             let x = self.date_to_x(current, project_start, px_per_day);
             let line = Line::new()
+                .set("class", class)
                 .set("x1", x)
                 .set("y1", chart_top)
                 .set("x2", x)
                 .set("y2", chart_bottom)
-                .set("stroke", self.grid_color.as_str())
-                .set("stroke-width", 1);
+                .set("stroke", color)
+                .set("stroke-width", width);
             group = group.add(line);
-            current += chrono::Duration::days(interval);
+            current += chrono::Duration::days(1);
         }
 
         group
@@ -367,15 +432,14 @@ impl SvgRenderer {
     /// Render a single task bar
     fn render_task(
         &self,
-        task: &ScheduledTask,
+        bar: &GanttBar,
         task_name: &str,
-        row: usize,
         project_start: NaiveDate,
         px_per_day: f64,
     ) -> Group {
         let mut group = Group::new().set("class", "task");
 
-        let y = self.padding + self.header_height + (row as u32 * self.row_height);
+        let y = self.row_top(bar.row);
         let bar_height = (self.row_height as f64 * 0.6) as u32;
         let bar_y = y + (self.row_height - bar_height) / 2;
 
@@ -389,20 +453,40 @@ impl SvgRenderer {
         group = group.add(label);
 
         // Calculate bar position and width
-        let x_start = self.date_to_x(task.start, project_start, px_per_day);
-        let x_end = self.date_to_x(task.finish, project_start, px_per_day);
+        let x_start = self.date_to_x(bar.start, project_start, px_per_day);
+        let x_end = self.date_to_x(bar.finish, project_start, px_per_day);
         let bar_width = (x_end - x_start).max(4.0); // Minimum width for visibility
 
-        // Determine if this is a milestone (zero duration)
-        let is_milestone = task.duration.minutes == 0;
-
-        if is_milestone {
+        if bar.kind == GanttBarKind::Summary {
+            // Draw bracket bar for summary tasks: thin bar with downward ticks at both ends
+            let top = bar_y as f64;
+            let thickness = (bar_height as f64 / 3.0).max(2.0);
+            let tick = bar_height as f64 * 0.8;
+            let x_end = x_start + bar_width;
+            let bracket = Path::new()
+                .set(
+                    "d",
+                    format!(
+                        "M {x0} {top} H {x1} V {tick_y} L {x1i} {bar_y} H {x0i} L {x0} {tick_y} Z",
+                        x0 = x_start,
+                        x1 = x_end,
+                        top = top,
+                        tick_y = top + tick,
+                        bar_y = top + thickness,
+                        x0i = x_start + thickness,
+                        x1i = x_end - thickness,
+                    ),
+                )
+                .set("class", "summary")
+                .set("fill", self.summary_color.as_str());
+            group = group.add(bracket);
+        } else if bar.kind == GanttBarKind::Milestone {
             // Draw diamond for milestone
             let cx = x_start;
             let cy = (bar_y + bar_height / 2) as f64;
             let size = (bar_height as f64) / 2.0;
 
-            let diamond = svg::node::element::Polygon::new()
+            let diamond = Polygon::new()
                 .set(
                     "points",
                     format!(
@@ -421,7 +505,7 @@ impl SvgRenderer {
             group = group.add(diamond);
         } else {
             // Draw bar for regular task
-            let color = if self.highlight_critical && task.is_critical {
+            let color = if self.highlight_critical && bar.is_critical {
                 self.critical_color.as_str()
             } else {
                 self.normal_color.as_str()
@@ -452,6 +536,76 @@ impl SvgRenderer {
         group
     }
 
+    /// Render dependency arrows between predecessor and successor bars
+    fn render_dependencies(
+        &self,
+        project: &Project,
+        rows: &HashMap<&str, &GanttBar>,
+        project_start: NaiveDate,
+        px_per_day: f64,
+    ) -> Group {
+        let mut group = Group::new().set("class", "dependencies");
+        let flat = hierarchy::flatten_project(project);
+
+        for entry in &flat {
+            let Some(&to_task) = rows.get(entry.qualified_id.as_str()) else {
+                continue;
+            };
+
+            for dep in &entry.task.depends {
+                let pred_id =
+                    hierarchy::resolve_dependency(&dep.predecessor, &entry.qualified_id, |id| {
+                        rows.contains_key(id)
+                    });
+                let Some(&from_task) = pred_id.as_deref().and_then(|id| rows.get(id)) else {
+                    continue;
+                };
+
+                // Anchor points depend on the dependency type (bars end at the finish date)
+                let (from_date, to_date) = match dep.dep_type {
+                    DependencyType::FinishToStart => (from_task.finish, to_task.start),
+                    DependencyType::StartToStart => (from_task.start, to_task.start),
+                    DependencyType::FinishToFinish => (from_task.finish, to_task.finish),
+                    DependencyType::StartToFinish => (from_task.start, to_task.finish),
+                };
+                let x1 = self.date_to_x(from_date, project_start, px_per_day);
+                let x2 = self.date_to_x(to_date, project_start, px_per_day);
+                let y1 = (self.row_top(from_task.row) + self.row_height / 2) as f64;
+                let y2 = (self.row_top(to_task.row) + self.row_height / 2) as f64;
+                let elbow = x1 + 6.0;
+                let head = 5.0;
+
+                let path = Path::new()
+                    .set(
+                        "d",
+                        format!("M {} {} H {} V {} H {}", x1, y1, elbow, y2, x2 - head),
+                    )
+                    .set("fill", "none")
+                    .set("stroke", self.dependency_color.as_str())
+                    .set("stroke-width", 1);
+                group = group.add(path);
+
+                let arrow = Polygon::new()
+                    .set(
+                        "points",
+                        format!(
+                            "{},{} {},{} {},{}",
+                            x2,
+                            y2,
+                            x2 - head,
+                            y2 - head / 2.0,
+                            x2 - head,
+                            y2 + head / 2.0
+                        ),
+                    )
+                    .set("fill", self.dependency_color.as_str());
+                group = group.add(arrow);
+            }
+        }
+
+        group
+    }
+
     /// Render the legend
     fn render_legend(&self, y_offset: u32) -> Group {
         let mut group = Group::new().set("class", "legend");
@@ -506,7 +660,7 @@ impl SvgRenderer {
         let my = y - box_size / 2.0 + 2.0;
         let msize = box_size / 2.0;
 
-        let milestone = svg::node::element::Polygon::new()
+        let milestone = Polygon::new()
             .set(
                 "points",
                 format!(
@@ -540,18 +694,34 @@ impl Renderer for SvgRenderer {
     type Output = String;
 
     fn render(&self, project: &Project, schedule: &Schedule) -> Result<String, RenderError> {
-        // Sort tasks by start date
-        let mut tasks: Vec<&ScheduledTask> = schedule.tasks.values().collect();
-        tasks.sort_by_key(|t| t.start);
+        // Rows in start order (ID breaks ties for stable output)
+        let bars = schedule.bars();
 
-        if tasks.is_empty() {
+        if bars.is_empty() {
             return Err(RenderError::InvalidData("No tasks to render".into()));
         }
 
-        let task_count = tasks.len();
         let project_start = project.start;
         let project_end = schedule.project_end;
-        let px_per_day = self.pixels_per_day(project_start, project_end);
+        let layout = self.layout_for(project_start, project_end);
+        let px_per_day = layout.scale(project_start, project_end);
+        layout.render_document(project, &bars, project_start, project_end, px_per_day)
+    }
+}
+
+impl SvgRenderer {
+    /// Build the SVG document once the layout is resolved
+    fn render_document(
+        &self,
+        project: &Project,
+        bars: &[GanttBar],
+        project_start: NaiveDate,
+        project_end: NaiveDate,
+        px_per_day: f64,
+    ) -> Result<String, RenderError> {
+        let task_count = bars.len();
+        let flat = hierarchy::flatten_project(project);
+        let by_id = hierarchy::index_by_id(&flat);
 
         // Calculate dimensions
         let width = self.total_width();
@@ -592,25 +762,28 @@ impl Renderer for SvgRenderer {
         // Calculate max characters based on label width (~8px per char)
         let max_chars = (self.label_width as usize / 8).max(10);
 
-        for (row, scheduled_task) in tasks.iter().enumerate() {
-            // Get the task name from the project
-            let task_name = project
-                .get_task(&scheduled_task.task_id)
-                .map(|t| t.name.as_str())
-                .unwrap_or(&scheduled_task.task_id);
+        for bar in bars {
+            // Get the task definition from the project (schedule keys are qualified IDs)
+            let task = by_id
+                .get(bar.task_id.as_str())
+                .map(|f| f.task)
+                .or_else(|| project.get_task(&bar.task_id));
+            let task_name = task.map(|t| t.name.as_str()).unwrap_or(&bar.task_id);
 
             // Format label according to display mode
-            let label =
-                self.display_mode
-                    .format_label(&scheduled_task.task_id, task_name, max_chars);
-
-            document = document.add(self.render_task(
-                scheduled_task,
-                &label,
-                row,
-                project_start,
-                px_per_day,
-            ));
+            let label = self
+                .display_mode
+                .format_label(&bar.task_id, task_name, max_chars);
+
+            document = document.add(self.render_task(bar, &label, project_start, px_per_day));
+        }
+
+        // Dependency arrows (drawn over the bars)
+        if self.show_dependencies {
+            let rows: HashMap<&str, &GanttBar> =
+                bars.iter().map(|b| (b.task_id.as_str(), b)).collect();
+            document =
+                document.add(self.render_dependencies(project, &rows, project_start, px_per_day));
         }
 
         // Legend
@@ -677,6 +850,7 @@ mod tests {
                 duration: Duration::days(3),
                 assignments: vec![],
                 slack: Duration::zero(),
+                free_slack: Duration::zero(),
                 is_critical: true,
                 early_start: start1,
                 early_finish: finish1,
@@ -689,10 +863,15 @@ mod tests {
                 status: TaskStatus::NotStarted,
                 cost_range: None,
                 has_abstract_assignments: false,
+                driving_predecessor: None,
+                is_milestone: false,
+                critical_reason: None,
+                is_pinned: false,
                 baseline_start: start1,
                 baseline_finish: finish1,
                 start_variance_days: 0,
                 finish_variance_days: 0,
+                tags: Vec::new(),
             },
         );
 
@@ -707,6 +886,7 @@ mod tests {
                 duration: Duration::days(5),
                 assignments: vec![],
                 slack: Duration::zero(),
+                free_slack: Duration::zero(),
                 is_critical: true,
                 early_start: start2,
                 early_finish: finish2,
@@ -719,10 +899,15 @@ mod tests {
                 status: TaskStatus::NotStarted,
                 cost_range: None,
                 has_abstract_assignments: false,
+                driving_predecessor: None,
+                is_milestone: false,
+                critical_reason: None,
+                is_pinned: false,
                 baseline_start: start2,
                 baseline_finish: finish2,
                 start_variance_days: 0,
                 finish_variance_days: 0,
+                tags: Vec::new(),
             },
         );
 
@@ -741,6 +926,8 @@ mod tests {
             planned_value: 0,
             earned_value: 0,
             spi: 1.0,
+            status_date: Some(project_end),
+            unconstrained_finish: None,
         }
     }
 
@@ -847,6 +1034,8 @@ mod tests {
             planned_value: 0,
             earned_value: 0,
             spi: 1.0,
+            status_date: Some(project_end),
+            unconstrained_finish: None,
         };
 
         let result = renderer.render(&project, &schedule);
@@ -893,6 +1082,7 @@ mod tests {
                 duration: Duration::days(5),
                 assignments: vec![],
                 slack: Duration::zero(),
+                free_slack: Duration::zero(),
                 is_critical: true,
                 early_start: start1,
                 early_finish: finish1,
@@ -905,10 +1095,15 @@ mod tests {
                 status: TaskStatus::NotStarted,
                 cost_range: None,
                 has_abstract_assignments: false,
+                driving_predecessor: None,
+                is_milestone: false,
+                critical_reason: None,
+                is_pinned: false,
                 baseline_start: start1,
                 baseline_finish: finish1,
                 start_variance_days: 0,
                 finish_variance_days: 0,
+                tags: Vec::new(),
             },
         );
         let ms_date = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
@@ -921,6 +1116,7 @@ mod tests {
                 duration: Duration::zero(),
                 assignments: vec![],
                 slack: Duration::zero(),
+                free_slack: Duration::zero(),
                 is_critical: true,
                 early_start: ms_date,
                 early_finish: ms_date,
@@ -933,10 +1129,15 @@ mod tests {
                 status: TaskStatus::NotStarted,
                 cost_range: None,
                 has_abstract_assignments: false,
+                driving_predecessor: None,
+                is_milestone: true,
+                critical_reason: None,
+                is_pinned: false,
                 baseline_start: ms_date,
                 baseline_finish: ms_date,
                 start_variance_days: 0,
                 finish_variance_days: 0,
+                tags: Vec::new(),
             },
         );
 
@@ -954,6 +1155,8 @@ mod tests {
             planned_value: 0,
             earned_value: 0,
             spi: 1.0,
+            status_date: Some(ms_date),
+            unconstrained_finish: None,
         };
 
         let renderer = SvgRenderer::new();
@@ -986,6 +1189,7 @@ mod tests {
                 duration: Duration::days(5),
                 assignments: vec![],
                 slack: Duration::days(5), // Has slack, so not critical
+                free_slack: Duration::days(5),
                 is_critical: false,
                 early_start: start1,
                 early_finish: finish1,
@@ -998,10 +1202,15 @@ mod tests {
                 status: TaskStatus::NotStarted,
                 cost_range: None,
                 has_abstract_assignments: false,
+                driving_predecessor: None,
+                is_milestone: false,
+                critical_reason: None,
+                is_pinned: false,
                 baseline_start: start1,
                 baseline_finish: finish1,
                 start_variance_days: 0,
                 finish_variance_days: 0,
+                tags: Vec::new(),
             },
         );
 
@@ -1019,6 +1228,8 @@ mod tests {
             planned_value: 0,
             earned_value: 0,
             spi: 1.0,
+            status_date: Some(finish1),
+            unconstrained_finish: None,
         };
 
         let renderer = SvgRenderer::new();
@@ -1057,6 +1268,7 @@ mod tests {
                 duration: Duration::days(50),
                 assignments: vec![],
                 slack: Duration::zero(),
+                free_slack: Duration::zero(),
                 is_critical: true,
                 early_start: start1,
                 early_finish: finish1,
@@ -1069,10 +1281,15 @@ mod tests {
                 status: TaskStatus::NotStarted,
                 cost_range: None,
                 has_abstract_assignments: false,
+                driving_predecessor: None,
+                is_milestone: false,
+                critical_reason: None,
+                is_pinned: false,
                 baseline_start: start1,
                 baseline_finish: finish1,
                 start_variance_days: 0,
                 finish_variance_days: 0,
+                tags: Vec::new(),
             },
         );
         let start2 = NaiveDate::from_ymd_opt(2025, 3, 17).unwrap();
@@ -1086,6 +1303,7 @@ mod tests {
                 duration: Duration::days(50),
                 assignments: vec![],
                 slack: Duration::zero(),
+                free_slack: Duration::zero(),
                 is_critical: true,
                 early_start: start2,
                 early_finish: finish2,
@@ -1098,10 +1316,15 @@ mod tests {
                 status: TaskStatus::NotStarted,
                 cost_range: None,
                 has_abstract_assignments: false,
+                driving_predecessor: None,
+                is_milestone: false,
+                critical_reason: None,
+                is_pinned: false,
                 baseline_start: start2,
                 baseline_finish: finish2,
                 start_variance_days: 0,
                 finish_variance_days: 0,
+                tags: Vec::new(),
             },
         );
 
@@ -1119,6 +1342,8 @@ mod tests {
             planned_value: 0,
             earned_value: 0,
             spi: 1.0,
+            status_date: Some(finish2),
+            unconstrained_finish: None,
         };
 
         let renderer = SvgRenderer::new();
@@ -1151,6 +1376,7 @@ mod tests {
                 duration: Duration::days(200),
                 assignments: vec![],
                 slack: Duration::zero(),
+                free_slack: Duration::zero(),
                 is_critical: true,
                 early_start: start1,
                 early_finish: finish1,
@@ -1163,10 +1389,15 @@ mod tests {
                 status: TaskStatus::NotStarted,
                 cost_range: None,
                 has_abstract_assignments: false,
+                driving_predecessor: None,
+                is_milestone: false,
+                critical_reason: None,
+                is_pinned: false,
                 baseline_start: start1,
                 baseline_finish: finish1,
                 start_variance_days: 0,
                 finish_variance_days: 0,
+                tags: Vec::new(),
             },
         );
 
@@ -1184,6 +1415,8 @@ mod tests {
             planned_value: 0,
             earned_value: 0,
             spi: 1.0,
+            status_date: Some(finish1),
+            unconstrained_finish: None,
         };
 
         let renderer = SvgRenderer::new();
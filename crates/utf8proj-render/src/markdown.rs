@@ -0,0 +1,261 @@
+//! Markdown table renderer
+//!
+//! Generates a pipe-delimited Markdown table of the schedule, suitable for
+//! pasting into GitHub issues, pull request descriptions, and code reviews.
+//!
+//! ## Example Output
+//!
+//! ```text
+//! **Website Launch** — finish 2025-01-29, 40% complete
+//!
+//! | WBS | Task | Start | Finish | Duration | Slack | Status | Progress |
+//! |---|---|---|---|---|---|---|---|
+//! | 1 | **Design** | 2025-01-06 | 2025-01-10 | 5d | 0d | Complete | 100% |
+//! | 1.1 | &nbsp;&nbsp;Wireframes | 2025-01-06 | 2025-01-08 | 3d | 2d | Complete | 100% |
+//! | 2 | **Build** | 2025-01-13 | 2025-01-24 | 10d | 0d | In Progress | 20% |
+//! ```
+
+use crate::hierarchy;
+use crate::DisplayMode;
+use utf8proj_core::{Duration, Project, RenderError, Renderer, Schedule, ScheduledTask};
+
+/// A column of the Markdown table
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MarkdownColumn {
+    /// Outline number ("1.2")
+    Wbs,
+    /// Task label, indented by depth and bolded when critical
+    Task,
+    /// Scheduled start date
+    Start,
+    /// Scheduled finish date
+    Finish,
+    /// Scheduled duration in days
+    Duration,
+    /// Total slack in days
+    Slack,
+    /// Task status
+    Status,
+    /// Percent complete
+    Progress,
+}
+
+impl MarkdownColumn {
+    /// All columns, in default order
+    pub const ALL: [MarkdownColumn; 8] = [
+        MarkdownColumn::Wbs,
+        MarkdownColumn::Task,
+        MarkdownColumn::Start,
+        MarkdownColumn::Finish,
+        MarkdownColumn::Duration,
+        MarkdownColumn::Slack,
+        MarkdownColumn::Status,
+        MarkdownColumn::Progress,
+    ];
+
+    /// Header text for this column
+    pub fn header(&self) -> &'static str {
+        match self {
+            MarkdownColumn::Wbs => "WBS",
+            MarkdownColumn::Task => "Task",
+            MarkdownColumn::Start => "Start",
+            MarkdownColumn::Finish => "Finish",
+            MarkdownColumn::Duration => "Duration",
+            MarkdownColumn::Slack => "Slack",
+            MarkdownColumn::Status => "Status",
+            MarkdownColumn::Progress => "Progress",
+        }
+    }
+}
+
+/// Markdown table renderer
+#[derive(Clone, Debug)]
+pub struct MarkdownRenderer {
+    /// Columns to emit, in order
+    pub columns: Vec<MarkdownColumn>,
+    /// Whether to bold critical path tasks
+    pub show_critical: bool,
+    /// Whether to emit the project summary line above the table
+    pub show_summary: bool,
+    /// Display mode for task labels
+    pub display_mode: DisplayMode,
+    /// Maximum label width in characters
+    pub label_width: usize,
+}
+
+impl Default for MarkdownRenderer {
+    fn default() -> Self {
+        Self {
+            columns: MarkdownColumn::ALL.to_vec(),
+            show_critical: true,
+            show_summary: true,
+            display_mode: DisplayMode::Name,
+            label_width: 60,
+        }
+    }
+}
+
+impl MarkdownRenderer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the columns to emit, in order
+    pub fn columns(mut self, columns: impl IntoIterator<Item = MarkdownColumn>) -> Self {
+        self.columns = columns.into_iter().collect();
+        self
+    }
+
+    /// Disable critical path bolding
+    pub fn no_critical(mut self) -> Self {
+        self.show_critical = false;
+        self
+    }
+
+    /// Omit the project summary line
+    pub fn no_summary(mut self) -> Self {
+        self.show_summary = false;
+        self
+    }
+
+    /// Configure display mode for task labels
+    pub fn display_mode(mut self, mode: DisplayMode) -> Self {
+        self.display_mode = mode;
+        self
+    }
+
+    /// Configure maximum label width in characters
+    pub fn label_width(mut self, width: usize) -> Self {
+        self.label_width = width;
+        self
+    }
+
+    /// Escape text for use inside a table cell
+    fn escape_cell(text: &str) -> String {
+        text.replace('\\', "\\\\")
+            .replace('|', "\\|")
+            .replace('\n', " ")
+            .replace('\r', "")
+    }
+
+    /// Format a duration as whole or fractional days ("5d", "2.5d")
+    fn format_days(duration: Duration) -> String {
+        let days = duration.as_days();
+        if days.fract() == 0.0 {
+            format!("{}d", days as i64)
+        } else {
+            format!("{:.1}d", days)
+        }
+    }
+
+    /// Render a single cell
+    fn cell(
+        &self,
+        column: MarkdownColumn,
+        wbs: &str,
+        label: &str,
+        depth: usize,
+        scheduled: &ScheduledTask,
+    ) -> String {
+        match column {
+            MarkdownColumn::Wbs => wbs.to_string(),
+            MarkdownColumn::Task => {
+                let label = Self::escape_cell(label);
+                let label = if self.show_critical && scheduled.is_critical {
+                    format!("**{}**", label)
+                } else {
+                    label
+                };
+                format!("{}{}", "&nbsp;&nbsp;".repeat(depth), label)
+            }
+            MarkdownColumn::Start => scheduled.start.format("%Y-%m-%d").to_string(),
+            MarkdownColumn::Finish => scheduled.finish.format("%Y-%m-%d").to_string(),
+            MarkdownColumn::Duration => Self::format_days(scheduled.duration),
+            MarkdownColumn::Slack => Self::format_days(scheduled.slack),
+            MarkdownColumn::Status => scheduled.status.to_string(),
+            MarkdownColumn::Progress => format!("{}%", scheduled.percent_complete),
+        }
+    }
+}
+
+impl Renderer for MarkdownRenderer {
+    type Output = String;
+
+    fn render(&self, project: &Project, schedule: &Schedule) -> Result<String, RenderError> {
+        if schedule.tasks.is_empty() {
+            return Err(RenderError::InvalidData("No tasks to render".into()));
+        }
+        if self.columns.is_empty() {
+            return Err(RenderError::InvalidData("No columns selected".into()));
+        }
+
+        let mut output = String::new();
+
+        // Summary line, with the zone the dates are in when one is set
+        if self.show_summary {
+            let zone = project
+                .timezone
+                .as_ref()
+                .map(|tz| format!(" ({})", tz))
+                .unwrap_or_default();
+            output.push_str(&format!(
+                "**{}** — finish {}{}, {}% complete\n\n",
+                Self::escape_cell(&project.name),
+                schedule.project_forecast_finish.format("%Y-%m-%d"),
+                zone,
+                schedule.project_progress
+            ));
+        }
+
+        // Header and separator
+        let headers: Vec<&str> = self.columns.iter().map(|c| c.header()).collect();
+        output.push_str(&format!("| {} |\n", headers.join(" | ")));
+        output.push_str(&format!("|{}\n", "---|".repeat(self.columns.len())));
+
+        // Rows in document order
+        for flat in hierarchy::flatten_project(project) {
+            let Some(scheduled) = schedule.tasks.get(&flat.qualified_id) else {
+                continue;
+            };
+            let label = self.display_mode.format_label(
+                &flat.qualified_id,
+                &flat.task.name,
+                self.label_width,
+            );
+            let cells: Vec<String> = self
+                .columns
+                .iter()
+                .map(|&column| self.cell(column, &flat.wbs, &label, flat.depth, scheduled))
+                .collect();
+            output.push_str(&format!("| {} |\n", cells.join(" | ")));
+        }
+
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn markdown_escapes_pipes() {
+        assert_eq!(MarkdownRenderer::escape_cell("A | B"), "A \\| B");
+        assert_eq!(MarkdownRenderer::escape_cell("Line\nBreak"), "Line Break");
+    }
+
+    #[test]
+    fn markdown_formats_days() {
+        assert_eq!(MarkdownRenderer::format_days(Duration::days(5)), "5d");
+        assert_eq!(MarkdownRenderer::format_days(Duration::zero()), "0d");
+        assert_eq!(MarkdownRenderer::format_days(Duration::hours(4)), "0.5d");
+    }
+
+    #[test]
+    fn markdown_default_columns() {
+        let renderer = MarkdownRenderer::new();
+        assert_eq!(renderer.columns, MarkdownColumn::ALL.to_vec());
+        assert!(renderer.show_critical);
+        assert!(renderer.show_summary);
+    }
+}
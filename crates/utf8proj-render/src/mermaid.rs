@@ -218,9 +218,12 @@ impl Renderer for MermaidRenderer {
 
         output.push('\n');
 
-        // Sort tasks by start date
-        let mut tasks: Vec<(&String, &ScheduledTask)> = schedule.tasks.iter().collect();
-        tasks.sort_by_key(|(_, t)| t.start);
+        // Tasks in Gantt row order
+        let bars = schedule.bars();
+        let tasks: Vec<(&String, &ScheduledTask)> = bars
+            .iter()
+            .map(|bar| (&bar.task_id, &schedule.tasks[&bar.task_id]))
+            .collect();
 
         // Build dependency map (task_id -> first predecessor)
         let mut first_predecessor: std::collections::HashMap<String, String> =
@@ -402,6 +405,7 @@ mod tests {
                 duration: Duration::days(5),
                 assignments: vec![],
                 slack: Duration::zero(),
+                free_slack: Duration::zero(),
                 is_critical: true,
                 early_start: start1,
                 early_finish: finish1,
@@ -414,10 +418,15 @@ mod tests {
                 status: TaskStatus::NotStarted,
                 cost_range: None,
                 has_abstract_assignments: false,
+                driving_predecessor: None,
+                is_milestone: false,
+                critical_reason: None,
+                is_pinned: false,
                 baseline_start: start1,
                 baseline_finish: finish1,
                 start_variance_days: 0,
                 finish_variance_days: 0,
+                tags: Vec::new(),
             },
         );
 
@@ -432,6 +441,7 @@ mod tests {
                 duration: Duration::days(10),
                 assignments: vec![],
                 slack: Duration::zero(),
+                free_slack: Duration::zero(),
                 is_critical: true,
                 early_start: start2,
                 early_finish: finish2,
@@ -444,10 +454,15 @@ mod tests {
                 status: TaskStatus::NotStarted,
                 cost_range: None,
                 has_abstract_assignments: false,
+                driving_predecessor: None,
+                is_milestone: false,
+                critical_reason: None,
+                is_pinned: false,
                 baseline_start: start2,
                 baseline_finish: finish2,
                 start_variance_days: 0,
                 finish_variance_days: 0,
+                tags: Vec::new(),
             },
         );
 
@@ -462,6 +477,7 @@ mod tests {
                 duration: Duration::days(3),
                 assignments: vec![],
                 slack: Duration::zero(),
+                free_slack: Duration::zero(),
                 is_critical: true,
                 early_start: start3,
                 early_finish: finish3,
@@ -474,10 +490,15 @@ mod tests {
                 status: TaskStatus::NotStarted,
                 cost_range: None,
                 has_abstract_assignments: false,
+                driving_predecessor: None,
+                is_milestone: false,
+                critical_reason: None,
+                is_pinned: false,
                 baseline_start: start3,
                 baseline_finish: finish3,
                 start_variance_days: 0,
                 finish_variance_days: 0,
+                tags: Vec::new(),
             },
         );
 
@@ -500,6 +521,8 @@ mod tests {
             planned_value: 0,
             earned_value: 0,
             spi: 1.0,
+            status_date: Some(project_end),
+            unconstrained_finish: None,
         }
     }
 
@@ -592,6 +615,8 @@ mod tests {
             planned_value: 0,
             earned_value: 0,
             spi: 1.0,
+            status_date: Some(project_end),
+            unconstrained_finish: None,
         };
 
         let result = renderer.render(&project, &schedule);
@@ -647,6 +672,7 @@ mod tests {
                 duration: Duration::zero(),
                 assignments: vec![],
                 slack: Duration::zero(),
+                free_slack: Duration::zero(),
                 is_critical: true,
                 early_start: ms_date,
                 early_finish: ms_date,
@@ -659,10 +685,15 @@ mod tests {
                 status: TaskStatus::NotStarted,
                 cost_range: None,
                 has_abstract_assignments: false,
+                driving_predecessor: None,
+                is_milestone: false,
+                critical_reason: None,
+                is_pinned: false,
                 baseline_start: ms_date,
                 baseline_finish: ms_date,
                 start_variance_days: 0,
                 finish_variance_days: 0,
+                tags: Vec::new(),
             },
         );
 
@@ -681,6 +712,8 @@ mod tests {
             planned_value: 0,
             earned_value: 0,
             spi: 1.0,
+            status_date: Some(project_end),
+            unconstrained_finish: None,
         };
 
         let renderer = MermaidRenderer::new();
@@ -739,6 +772,7 @@ mod tests {
                 duration: Duration::days(5),
                 assignments: vec![],
                 slack: Duration::zero(),
+                free_slack: Duration::zero(),
                 is_critical: true,
                 early_start: start,
                 early_finish: finish,
@@ -751,10 +785,15 @@ mod tests {
                 status: TaskStatus::Complete,
                 cost_range: None,
                 has_abstract_assignments: false,
+                driving_predecessor: None,
+                is_milestone: false,
+                critical_reason: None,
+                is_pinned: false,
                 baseline_start: start,
                 baseline_finish: finish,
                 start_variance_days: 0,
                 finish_variance_days: 0,
+                tags: Vec::new(),
             },
         );
 
@@ -772,6 +811,8 @@ mod tests {
             planned_value: 0,
             earned_value: 0,
             spi: 1.0,
+            status_date: Some(finish),
+            unconstrained_finish: None,
         };
 
         let renderer = MermaidRenderer::new();
@@ -802,6 +843,7 @@ mod tests {
                 duration: Duration::days(10),
                 assignments: vec![],
                 slack: Duration::zero(),
+                free_slack: Duration::zero(),
                 is_critical: true,
                 early_start: start,
                 early_finish: finish,
@@ -814,10 +856,15 @@ mod tests {
                 status: TaskStatus::InProgress,
                 cost_range: None,
                 has_abstract_assignments: false,
+                driving_predecessor: None,
+                is_milestone: false,
+                critical_reason: None,
+                is_pinned: false,
                 baseline_start: start,
                 baseline_finish: finish,
                 start_variance_days: 0,
                 finish_variance_days: 0,
+                tags: Vec::new(),
             },
         );
 
@@ -835,6 +882,8 @@ mod tests {
             planned_value: 0,
             earned_value: 0,
             spi: 1.0,
+            status_date: Some(finish),
+            unconstrained_finish: None,
         };
 
         let renderer = MermaidRenderer::new();
@@ -865,6 +914,7 @@ mod tests {
                 duration: Duration::days(5),
                 assignments: vec![],
                 slack: Duration::zero(),
+                free_slack: Duration::zero(),
                 is_critical: false,
                 early_start: start,
                 early_finish: finish,
@@ -877,10 +927,15 @@ mod tests {
                 status: TaskStatus::Complete,
                 cost_range: None,
                 has_abstract_assignments: false,
+                driving_predecessor: None,
+                is_milestone: false,
+                critical_reason: None,
+                is_pinned: false,
                 baseline_start: start,
                 baseline_finish: finish,
                 start_variance_days: 0,
                 finish_variance_days: 0,
+                tags: Vec::new(),
             },
         );
 
@@ -898,6 +953,8 @@ mod tests {
             planned_value: 0,
             earned_value: 0,
             spi: 1.0,
+            status_date: Some(finish),
+            unconstrained_finish: None,
         };
 
         let renderer = MermaidRenderer::new().no_completion().no_critical();
@@ -20,9 +20,12 @@
 //! @endgantt
 //! ```
 
+use crate::hierarchy::{self, FlatTask};
 use crate::{DisplayMode, NowLineConfig};
 use std::collections::HashMap;
-use utf8proj_core::{Project, RenderError, Renderer, Schedule, ScheduledTask};
+use utf8proj_core::{
+    DependencyType, Project, RenderError, Renderer, Schedule, ScheduledTask, Task,
+};
 
 /// PlantUML Gantt chart renderer
 #[derive(Clone, Debug)]
@@ -160,8 +163,10 @@ impl PlantUmlRenderer {
     }
 
     /// Create a valid PlantUML alias from task_id
+    ///
+    /// Aliases that would read as a PlantUML keyword get a `t_` prefix.
     fn make_alias(task_id: &str) -> String {
-        task_id
+        let alias: String = task_id
             .chars()
             .map(|c| {
                 if c.is_alphanumeric() || c == '_' {
@@ -170,7 +175,12 @@ impl PlantUmlRenderer {
                     '_'
                 }
             })
-            .collect()
+            .collect();
+        if PLANTUML_KEYWORDS.contains(&alias.to_ascii_lowercase().as_str()) {
+            format!("t_{}", alias)
+        } else {
+            alias
+        }
     }
 
     /// Format duration for PlantUML
@@ -226,23 +236,23 @@ impl Renderer for PlantUmlRenderer {
 
         output.push('\n');
 
-        // Sort tasks by start date
-        let mut tasks: Vec<(&String, &ScheduledTask)> = schedule.tasks.iter().collect();
-        tasks.sort_by_key(|(_, t)| t.start);
+        // Tasks in Gantt row order
+        let bars = schedule.bars();
+        let tasks: Vec<(&String, &ScheduledTask)> = bars
+            .iter()
+            .map(|bar| (&bar.task_id, &schedule.tasks[&bar.task_id]))
+            .collect();
 
-        // Build dependency map (task_id -> first predecessor)
-        let mut first_predecessor: HashMap<String, String> = HashMap::new();
-        for task in &project.tasks {
-            self.collect_predecessors(task, &mut first_predecessor);
-        }
+        // Schedule keys are qualified IDs; look task definitions up the same way
+        let flat = hierarchy::flatten_project(project);
+        let index = hierarchy::index_by_id(&flat);
 
-        // Track which tasks have been rendered (for dependency references)
-        let mut rendered_tasks: std::collections::HashSet<String> =
-            std::collections::HashSet::new();
+        // Reference (alias or name) of each rendered task, for dependency lines
+        let mut references: HashMap<&str, String> = HashMap::new();
 
         // Render each task
         for (task_id, scheduled) in &tasks {
-            let task = project.get_task(task_id);
+            let task = index.get(task_id.as_str()).map(|f| f.task);
             let name = task
                 .map(|t| t.name.clone())
                 .unwrap_or_else(|| (*task_id).clone());
@@ -252,41 +262,27 @@ impl Renderer for PlantUmlRenderer {
             let label = self
                 .display_mode
                 .format_label(task_id, &name, self.label_width);
-            let sanitized_name = Self::sanitize_name(&label);
+            let sanitized_name = Self::escape_keyword(&Self::sanitize_name(&label));
             let alias = Self::make_alias(task_id);
             let is_milestone = scheduled.duration.minutes == 0;
+            let start_date = scheduled.start.format("%Y-%m-%d").to_string();
+
+            // Anchor on an already-rendered predecessor when possible
+            let anchor = if self.use_dependencies {
+                task.and_then(|t| Self::driving_anchor(t, task_id, schedule, &index, &references))
+            } else {
+                None
+            };
 
             if is_milestone {
                 // Milestone syntax
-                if self.use_dependencies {
-                    if let Some(pred) = first_predecessor.get(*task_id) {
-                        let pred_alias = Self::make_alias(pred);
-                        if rendered_tasks.contains(pred) {
-                            output.push_str(&format!(
-                                "[{}] happens at [{}]'s end\n",
-                                sanitized_name, pred_alias
-                            ));
-                        } else {
-                            // Predecessor not rendered yet, use absolute date
-                            output.push_str(&format!(
-                                "[{}] happens {}\n",
-                                sanitized_name,
-                                scheduled.start.format("%Y-%m-%d")
-                            ));
-                        }
-                    } else {
-                        output.push_str(&format!(
-                            "[{}] happens {}\n",
-                            sanitized_name,
-                            scheduled.start.format("%Y-%m-%d")
-                        ));
+                match anchor {
+                    Some(anchor) => {
+                        output.push_str(&format!("[{}] happens at {}\n", sanitized_name, anchor))
+                    }
+                    None => {
+                        output.push_str(&format!("[{}] happens {}\n", sanitized_name, start_date))
                     }
-                } else {
-                    output.push_str(&format!(
-                        "[{}] happens {}\n",
-                        sanitized_name,
-                        scheduled.start.format("%Y-%m-%d")
-                    ));
                 }
             } else {
                 // Regular task
@@ -299,49 +295,24 @@ impl Renderer for PlantUmlRenderer {
                     format!("[{}]", sanitized_name)
                 };
 
-                // Start specification
-                if self.use_dependencies {
-                    if let Some(pred) = first_predecessor.get(*task_id) {
-                        let pred_alias = Self::make_alias(pred);
-                        if rendered_tasks.contains(pred) {
-                            output.push_str(&format!(
-                                "{} starts at [{}]'s end and lasts {}\n",
-                                task_def, pred_alias, duration
-                            ));
-                        } else {
-                            // Predecessor not rendered yet, use absolute date
-                            output.push_str(&format!(
-                                "{} starts {} and lasts {}\n",
-                                task_def,
-                                scheduled.start.format("%Y-%m-%d"),
-                                duration
-                            ));
-                        }
-                    } else {
-                        output.push_str(&format!(
-                            "{} starts {} and lasts {}\n",
-                            task_def,
-                            scheduled.start.format("%Y-%m-%d"),
-                            duration
-                        ));
-                    }
-                } else {
-                    output.push_str(&format!(
-                        "{} starts {} and lasts {}\n",
-                        task_def,
-                        scheduled.start.format("%Y-%m-%d"),
-                        duration
-                    ));
-                }
+                let start = match anchor {
+                    Some(anchor) => format!("at {}", anchor),
+                    None => start_date,
+                };
+                output.push_str(&format!(
+                    "{} starts {} and lasts {}\n",
+                    task_def, start, duration
+                ));
             }
 
+            let ref_name = if self.show_aliases && !is_milestone {
+                alias
+            } else {
+                sanitized_name
+            };
+
             // Color for critical path
             if self.show_critical && scheduled.is_critical && !is_milestone {
-                let ref_name = if self.show_aliases {
-                    alias.clone()
-                } else {
-                    sanitized_name.clone()
-                };
                 output.push_str(&format!(
                     "[{}] is colored in {}\n",
                     ref_name, self.critical_color
@@ -352,11 +323,6 @@ impl Renderer for PlantUmlRenderer {
             if self.show_completion && !is_milestone {
                 if let Some(pct) = complete {
                     if pct > 0.0 {
-                        let ref_name = if self.show_aliases {
-                            alias.clone()
-                        } else {
-                            sanitized_name.clone()
-                        };
                         output.push_str(&format!(
                             "[{}] is {}% complete\n",
                             ref_name,
@@ -366,7 +332,7 @@ impl Renderer for PlantUmlRenderer {
                 }
             }
 
-            rendered_tasks.insert((*task_id).clone());
+            references.insert(task_id.as_str(), ref_name);
         }
 
         // Footer
@@ -377,19 +343,80 @@ impl Renderer for PlantUmlRenderer {
 }
 
 impl PlantUmlRenderer {
-    /// Collect first predecessor for each task
-    #[allow(unknown_lints)]
-    #[allow(clippy::self_only_used_in_recursion)]
-    fn collect_predecessors(&self, task: &utf8proj_core::Task, map: &mut HashMap<String, String>) {
-        if let Some(first_dep) = task.depends.first() {
-            map.insert(task.id.clone(), first_dep.predecessor.clone());
-        }
-        for child in &task.children {
-            self.collect_predecessors(child, map);
+    /// Find the dependency anchor (`[X]'s end` / `[X]'s start`) for a task
+    ///
+    /// Dependency paths are resolved the way the solver resolves them. Only
+    /// lag-free finish-to-start and start-to-start links to tasks that were
+    /// already rendered can be expressed; among those the driving one (the
+    /// latest anchor date) wins.
+    fn driving_anchor(
+        task: &Task,
+        task_id: &str,
+        schedule: &Schedule,
+        index: &HashMap<&str, &FlatTask<'_>>,
+        references: &HashMap<&str, String>,
+    ) -> Option<String> {
+        task.depends
+            .iter()
+            .filter(|dep| dep.lag.map_or(true, |lag| lag.round_days() == 0))
+            .filter_map(|dep| {
+                let pred_id = hierarchy::resolve_dependency(&dep.predecessor, task_id, |id| {
+                    index.contains_key(id)
+                })?;
+                let reference = references.get(pred_id.as_str())?;
+                let pred = schedule.tasks.get(&pred_id)?;
+                let (edge, date) = match dep.dep_type {
+                    DependencyType::FinishToStart => ("end", pred.finish),
+                    DependencyType::StartToStart => ("start", pred.start),
+                    _ => return None,
+                };
+                Some((date, format!("[{}]'s {}", reference, edge)))
+            })
+            .max_by_key(|(date, _)| *date)
+            .map(|(_, anchor)| anchor)
+    }
+
+    /// Disambiguate names that PlantUML would read as a keyword
+    fn escape_keyword(name: &str) -> String {
+        let lower = name.trim().to_ascii_lowercase();
+        if PLANTUML_KEYWORDS.contains(&lower.as_str()) {
+            format!("{} (task)", name)
+        } else {
+            name.to_string()
         }
     }
 }
 
+/// Words with a meaning in PlantUML Gantt syntax
+const PLANTUML_KEYWORDS: &[&str] = &[
+    "and",
+    "as",
+    "at",
+    "closed",
+    "colored",
+    "complete",
+    "end",
+    "ends",
+    "happens",
+    "is",
+    "lasts",
+    "monday",
+    "tuesday",
+    "wednesday",
+    "thursday",
+    "friday",
+    "saturday",
+    "sunday",
+    "open",
+    "printscale",
+    "project",
+    "separator",
+    "start",
+    "starts",
+    "then",
+    "today",
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -433,6 +460,7 @@ mod tests {
                 duration: Duration::days(5),
                 assignments: vec![],
                 slack: Duration::zero(),
+                free_slack: Duration::zero(),
                 is_critical: true,
                 early_start: start1,
                 early_finish: finish1,
@@ -445,10 +473,15 @@ mod tests {
                 status: TaskStatus::NotStarted,
                 cost_range: None,
                 has_abstract_assignments: false,
+                driving_predecessor: None,
+                is_milestone: false,
+                critical_reason: None,
+                is_pinned: false,
                 baseline_start: start1,
                 baseline_finish: finish1,
                 start_variance_days: 0,
                 finish_variance_days: 0,
+                tags: Vec::new(),
             },
         );
 
@@ -463,6 +496,7 @@ mod tests {
                 duration: Duration::days(10),
                 assignments: vec![],
                 slack: Duration::zero(),
+                free_slack: Duration::zero(),
                 is_critical: true,
                 early_start: start2,
                 early_finish: finish2,
@@ -475,10 +509,15 @@ mod tests {
                 status: TaskStatus::NotStarted,
                 cost_range: None,
                 has_abstract_assignments: false,
+                driving_predecessor: None,
+                is_milestone: false,
+                critical_reason: None,
+                is_pinned: false,
                 baseline_start: start2,
                 baseline_finish: finish2,
                 start_variance_days: 0,
                 finish_variance_days: 0,
+                tags: Vec::new(),
             },
         );
 
@@ -493,6 +532,7 @@ mod tests {
                 duration: Duration::days(3),
                 assignments: vec![],
                 slack: Duration::zero(),
+                free_slack: Duration::zero(),
                 is_critical: true,
                 early_start: start3,
                 early_finish: finish3,
@@ -505,10 +545,15 @@ mod tests {
                 status: TaskStatus::NotStarted,
                 cost_range: None,
                 has_abstract_assignments: false,
+                driving_predecessor: None,
+                is_milestone: false,
+                critical_reason: None,
+                is_pinned: false,
                 baseline_start: start3,
                 baseline_finish: finish3,
                 start_variance_days: 0,
                 finish_variance_days: 0,
+                tags: Vec::new(),
             },
         );
 
@@ -531,6 +576,8 @@ mod tests {
             planned_value: 0,
             earned_value: 0,
             spi: 1.0,
+            status_date: Some(project_end),
+            unconstrained_finish: None,
         }
     }
 
@@ -655,6 +702,8 @@ mod tests {
             planned_value: 0,
             earned_value: 0,
             spi: 1.0,
+            status_date: Some(project_end),
+            unconstrained_finish: None,
         };
 
         let result = renderer.render(&project, &schedule);
@@ -707,6 +756,7 @@ mod tests {
                 duration: Duration::days(5),
                 assignments: vec![],
                 slack: Duration::zero(),
+                free_slack: Duration::zero(),
                 is_critical: true,
                 early_start: start1,
                 early_finish: finish1,
@@ -719,10 +769,15 @@ mod tests {
                 status: TaskStatus::NotStarted,
                 cost_range: None,
                 has_abstract_assignments: false,
+                driving_predecessor: None,
+                is_milestone: false,
+                critical_reason: None,
+                is_pinned: false,
                 baseline_start: start1,
                 baseline_finish: finish1,
                 start_variance_days: 0,
                 finish_variance_days: 0,
+                tags: Vec::new(),
             },
         );
         tasks.insert(
@@ -734,6 +789,7 @@ mod tests {
                 duration: Duration::zero(),
                 assignments: vec![],
                 slack: Duration::zero(),
+                free_slack: Duration::zero(),
                 is_critical: true,
                 early_start: ms_date,
                 early_finish: ms_date,
@@ -746,10 +802,15 @@ mod tests {
                 status: TaskStatus::NotStarted,
                 cost_range: None,
                 has_abstract_assignments: false,
+                driving_predecessor: None,
+                is_milestone: false,
+                critical_reason: None,
+                is_pinned: false,
                 baseline_start: ms_date,
                 baseline_finish: ms_date,
                 start_variance_days: 0,
                 finish_variance_days: 0,
+                tags: Vec::new(),
             },
         );
 
@@ -768,6 +829,8 @@ mod tests {
             planned_value: 0,
             earned_value: 0,
             spi: 1.0,
+            status_date: Some(project_end),
+            unconstrained_finish: None,
         };
 
         let renderer = PlantUmlRenderer::new();
@@ -855,6 +918,7 @@ mod tests {
             duration: Duration::zero(),
             assignments: vec![],
             slack: Duration::zero(),
+            free_slack: Duration::zero(),
             is_critical: false,
             early_start: date,
             early_finish: date,
@@ -867,10 +931,15 @@ mod tests {
             status: TaskStatus::NotStarted,
             cost_range: None,
             has_abstract_assignments: false,
+            driving_predecessor: None,
+            is_milestone: false,
+            critical_reason: None,
+            is_pinned: false,
             baseline_start: date,
             baseline_finish: date,
             start_variance_days: 0,
             finish_variance_days: 0,
+            tags: Vec::new(),
         };
         assert_eq!(PlantUmlRenderer::format_duration(&task), "0 days");
     }
@@ -885,6 +954,7 @@ mod tests {
             duration: Duration::days(1),
             assignments: vec![],
             slack: Duration::zero(),
+            free_slack: Duration::zero(),
             is_critical: false,
             early_start: date,
             early_finish: date,
@@ -897,10 +967,15 @@ mod tests {
             status: TaskStatus::NotStarted,
             cost_range: None,
             has_abstract_assignments: false,
+            driving_predecessor: None,
+            is_milestone: false,
+            critical_reason: None,
+            is_pinned: false,
             baseline_start: date,
             baseline_finish: date,
             start_variance_days: 0,
             finish_variance_days: 0,
+            tags: Vec::new(),
         };
         assert_eq!(PlantUmlRenderer::format_duration(&task), "1 day");
     }
@@ -926,6 +1001,7 @@ mod tests {
                 duration: Duration::days(5),
                 assignments: vec![],
                 slack: Duration::zero(),
+                free_slack: Duration::zero(),
                 is_critical: false,
                 early_start: start1,
                 early_finish: finish1,
@@ -938,10 +1014,15 @@ mod tests {
                 status: TaskStatus::InProgress,
                 cost_range: None,
                 has_abstract_assignments: false,
+                driving_predecessor: None,
+                is_milestone: false,
+                critical_reason: None,
+                is_pinned: false,
                 baseline_start: start1,
                 baseline_finish: finish1,
                 start_variance_days: 0,
                 finish_variance_days: 0,
+                tags: Vec::new(),
             },
         );
 
@@ -960,6 +1041,8 @@ mod tests {
             planned_value: 0,
             earned_value: 0,
             spi: 1.0,
+            status_date: Some(project_end),
+            unconstrained_finish: None,
         };
 
         let renderer = PlantUmlRenderer::new();
@@ -988,6 +1071,7 @@ mod tests {
                 duration: Duration::days(5),
                 assignments: vec![],
                 slack: Duration::zero(),
+                free_slack: Duration::zero(),
                 is_critical: false,
                 early_start: start1,
                 early_finish: finish1,
@@ -1000,10 +1084,15 @@ mod tests {
                 status: TaskStatus::InProgress,
                 cost_range: None,
                 has_abstract_assignments: false,
+                driving_predecessor: None,
+                is_milestone: false,
+                critical_reason: None,
+                is_pinned: false,
                 baseline_start: start1,
                 baseline_finish: finish1,
                 start_variance_days: 0,
                 finish_variance_days: 0,
+                tags: Vec::new(),
             },
         );
 
@@ -1022,6 +1111,8 @@ mod tests {
             planned_value: 0,
             earned_value: 0,
             spi: 1.0,
+            status_date: Some(project_end),
+            unconstrained_finish: None,
         };
 
         let renderer = PlantUmlRenderer::new().no_completion();
@@ -1050,6 +1141,7 @@ mod tests {
                 duration: Duration::zero(),
                 assignments: vec![],
                 slack: Duration::zero(),
+                free_slack: Duration::zero(),
                 is_critical: true,
                 early_start: ms_date,
                 early_finish: ms_date,
@@ -1062,10 +1154,15 @@ mod tests {
                 status: TaskStatus::NotStarted,
                 cost_range: None,
                 has_abstract_assignments: false,
+                driving_predecessor: None,
+                is_milestone: false,
+                critical_reason: None,
+                is_pinned: false,
                 baseline_start: ms_date,
                 baseline_finish: ms_date,
                 start_variance_days: 0,
                 finish_variance_days: 0,
+                tags: Vec::new(),
             },
         );
 
@@ -1083,6 +1180,8 @@ mod tests {
             planned_value: 0,
             earned_value: 0,
             spi: 1.0,
+            status_date: Some(ms_date),
+            unconstrained_finish: None,
         };
 
         let renderer = PlantUmlRenderer::new();
@@ -1119,6 +1218,7 @@ mod tests {
                 duration: Duration::days(5),
                 assignments: vec![],
                 slack: Duration::zero(),
+                free_slack: Duration::zero(),
                 is_critical: true,
                 early_start: start1,
                 early_finish: finish1,
@@ -1131,10 +1231,15 @@ mod tests {
                 status: TaskStatus::NotStarted,
                 cost_range: None,
                 has_abstract_assignments: false,
+                driving_predecessor: None,
+                is_milestone: false,
+                critical_reason: None,
+                is_pinned: false,
                 baseline_start: start1,
                 baseline_finish: finish1,
                 start_variance_days: 0,
                 finish_variance_days: 0,
+                tags: Vec::new(),
             },
         );
         tasks.insert(
@@ -1146,6 +1251,7 @@ mod tests {
                 duration: Duration::zero(),
                 assignments: vec![],
                 slack: Duration::zero(),
+                free_slack: Duration::zero(),
                 is_critical: true,
                 early_start: ms_date,
                 early_finish: ms_date,
@@ -1158,10 +1264,15 @@ mod tests {
                 status: TaskStatus::NotStarted,
                 cost_range: None,
                 has_abstract_assignments: false,
+                driving_predecessor: None,
+                is_milestone: false,
+                critical_reason: None,
+                is_pinned: false,
                 baseline_start: ms_date,
                 baseline_finish: ms_date,
                 start_variance_days: 0,
                 finish_variance_days: 0,
+                tags: Vec::new(),
             },
         );
 
@@ -1179,6 +1290,8 @@ mod tests {
             planned_value: 0,
             earned_value: 0,
             spi: 1.0,
+            status_date: Some(ms_date),
+            unconstrained_finish: None,
         };
 
         // Use absolute dates mode
@@ -1213,6 +1326,7 @@ mod tests {
                 duration: Duration::days(5),
                 assignments: vec![],
                 slack: Duration::zero(),
+                free_slack: Duration::zero(),
                 is_critical: false,
                 early_start: start1,
                 early_finish: finish1,
@@ -1225,10 +1339,15 @@ mod tests {
                 status: TaskStatus::InProgress,
                 cost_range: None,
                 has_abstract_assignments: false,
+                driving_predecessor: None,
+                is_milestone: false,
+                critical_reason: None,
+                is_pinned: false,
                 baseline_start: start1,
                 baseline_finish: finish1,
                 start_variance_days: 0,
                 finish_variance_days: 0,
+                tags: Vec::new(),
             },
         );
 
@@ -1246,6 +1365,8 @@ mod tests {
             planned_value: 0,
             earned_value: 0,
             spi: 1.0,
+            status_date: Some(finish1),
+            unconstrained_finish: None,
         };
 
         // Use no_aliases mode
@@ -0,0 +1,125 @@
+//! Tests for the standalone SVG Gantt renderer
+
+use chrono::NaiveDate;
+use utf8proj_core::{Duration, Project, Renderer, Scheduler, Task};
+use utf8proj_render::SvgRenderer;
+use utf8proj_solver::CpmSolver;
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+fn render(project: &Project, renderer: &SvgRenderer) -> String {
+    let schedule = CpmSolver::new().schedule(project).unwrap();
+    renderer.render(project, &schedule).unwrap()
+}
+
+fn chain_project() -> Project {
+    let mut project = Project::new("Chain");
+    project.start = date(2025, 1, 6);
+    project.tasks = vec![
+        Task::new("design").duration(Duration::days(5)),
+        Task::new("build")
+            .duration(Duration::days(5))
+            .depends_on("design"),
+        Task::new("ship").milestone().depends_on("build"),
+    ];
+    project
+}
+
+fn count_arrows(svg: &str) -> usize {
+    svg.matches(r##"stroke="#7f8c8d""##).count()
+}
+
+#[test]
+fn svg_draws_one_arrow_per_dependency() {
+    let svg = render(&chain_project(), &SvgRenderer::new());
+
+    assert!(svg.contains(r#"class="dependencies""#));
+    assert_eq!(count_arrows(&svg), 2);
+}
+
+#[test]
+fn svg_hide_dependencies_omits_arrows() {
+    let svg = render(&chain_project(), &SvgRenderer::new().hide_dependencies());
+
+    assert!(!svg.contains(r#"class="dependencies""#));
+    assert_eq!(count_arrows(&svg), 0);
+}
+
+#[test]
+fn svg_resolves_relative_dependencies_in_containers() {
+    let mut project = Project::new("Nested");
+    project.start = date(2025, 1, 6);
+    project.tasks = vec![Task::new("phase")
+        .name("Phase")
+        .child(Task::new("a").duration(Duration::days(2)))
+        .child(Task::new("b").duration(Duration::days(2)).depends_on("a"))];
+
+    let svg = render(&project, &SvgRenderer::new());
+
+    assert_eq!(count_arrows(&svg), 1);
+    // Container renders as a bracket bar
+    assert!(svg.contains(r#"class="summary""#));
+}
+
+#[test]
+fn svg_pixels_per_day_controls_chart_width() {
+    let renderer = SvgRenderer::new().pixels_per_day(20.0);
+    let svg = render(&chain_project(), &renderer);
+
+    // Jan 6 (start) to Jan 20 (ship milestone) is 14 calendar days
+    let expected_width = renderer.padding * 2 + renderer.label_width + 14 * 20;
+    assert!(
+        svg.contains(&format!(r#"width="{}""#, expected_width)),
+        "expected width {} in {}",
+        expected_width,
+        &svg[..200.min(svg.len())]
+    );
+}
+
+#[test]
+fn svg_row_height_controls_document_height() {
+    let compact = render(&chain_project(), &SvgRenderer::new().row_height(20));
+    let roomy = render(&chain_project(), &SvgRenderer::new().row_height(40));
+
+    let renderer = SvgRenderer::new();
+    let height = |row: u32| renderer.padding * 2 + renderer.header_height + 3 * row + 30;
+    assert!(compact.contains(&format!(r#"height="{}""#, height(20))));
+    assert!(roomy.contains(&format!(r#"height="{}""#, height(40))));
+}
+
+#[test]
+fn svg_draws_month_and_week_gridlines() {
+    let mut project = Project::new("Across Months");
+    project.start = date(2025, 1, 20);
+    project.tasks = vec![Task::new("long").duration(Duration::days(20))];
+
+    let svg = render(&project, &SvgRenderer::new());
+
+    assert!(svg.contains(r#"class="month-line""#));
+    assert!(svg.contains(r#"class="week-line""#));
+}
+
+#[test]
+fn svg_escapes_task_labels() {
+    let mut project = Project::new("Escaping <Test> & Co");
+    project.start = date(2025, 1, 6);
+    project.tasks = vec![Task::new("rd")
+        .name("R&D <core>")
+        .duration(Duration::days(3))];
+
+    let svg = render(&project, &SvgRenderer::new());
+
+    assert!(svg.contains("R&amp;D &lt;core&gt;"));
+    assert!(svg.contains("Escaping &lt;Test&gt; &amp; Co"));
+    assert!(!svg.contains("<core>"));
+}
+
+#[test]
+fn svg_milestones_render_as_diamonds() {
+    let svg = render(&chain_project(), &SvgRenderer::new());
+    let renderer = SvgRenderer::new();
+
+    assert!(svg.contains(&format!(r#"fill="{}""#, renderer.milestone_color)));
+}
@@ -1,8 +1,8 @@
-//! Tests for Mermaid and PlantUML text renderers
+//! Tests for Mermaid, PlantUML and Markdown text renderers
 
 use chrono::NaiveDate;
 use utf8proj_core::{Duration, Project, Renderer, Resource, Scheduler, Task};
-use utf8proj_render::{MermaidRenderer, PlantUmlRenderer};
+use utf8proj_render::{MarkdownColumn, MarkdownRenderer, MermaidRenderer, PlantUmlRenderer};
 use utf8proj_solver::CpmSolver;
 
 fn date(year: i32, month: u32, day: u32) -> NaiveDate {
@@ -326,3 +326,176 @@ fn plantuml_long_project() {
     // Should have project start date
     assert!(plantuml.contains("2025"));
 }
+
+#[test]
+fn plantuml_resolves_relative_dependencies() {
+    let mut project = Project::new("Nested");
+    project.start = date(2025, 1, 6);
+    project.tasks = vec![
+        Task::new("phase1")
+            .name("Phase 1")
+            .child(Task::new("a").name("Task A").duration(Duration::days(5)))
+            .child(
+                Task::new("b")
+                    .name("Task B")
+                    .duration(Duration::days(3))
+                    .depends_on("a"),
+            ),
+        Task::new("c")
+            .name("Task C")
+            .duration(Duration::days(4))
+            .depends_on("phase1.b"),
+    ];
+
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+    let plantuml = PlantUmlRenderer::new().render(&project, &schedule).unwrap();
+
+    assert!(plantuml.contains("[Task B] as [phase1_b] starts at [phase1_a]'s end"));
+    assert!(plantuml.contains("[Task C] as [c] starts at [phase1_b]'s end"));
+}
+
+#[test]
+fn plantuml_anchors_on_driving_predecessor() {
+    let mut project = Project::new("Merge");
+    project.start = date(2025, 1, 6);
+    project.tasks = vec![
+        Task::new("short").duration(Duration::days(2)),
+        Task::new("long").duration(Duration::days(6)),
+        Task::new("merge")
+            .duration(Duration::days(1))
+            .depends_on("short")
+            .depends_on("long"),
+        Task::new("done").milestone().depends_on("merge"),
+    ];
+
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+    let plantuml = PlantUmlRenderer::new().render(&project, &schedule).unwrap();
+
+    assert!(plantuml.contains("[merge] as [merge] starts at [long]'s end"));
+    assert!(plantuml.contains("[done] happens at [merge]'s end"));
+}
+
+#[test]
+fn plantuml_dependencies_reference_names_without_aliases() {
+    let mut project = Project::new("No Aliases");
+    project.start = date(2025, 1, 6);
+    project.tasks = vec![
+        Task::new("a").name("Alpha").duration(Duration::days(2)),
+        Task::new("b")
+            .name("Beta")
+            .duration(Duration::days(2))
+            .depends_on("a"),
+    ];
+
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+    let plantuml = PlantUmlRenderer::new()
+        .no_aliases()
+        .render(&project, &schedule)
+        .unwrap();
+
+    assert!(plantuml.contains("[Beta] starts at [Alpha]'s end"));
+}
+
+#[test]
+fn plantuml_escapes_keyword_names() {
+    let mut project = Project::new("Keywords");
+    project.start = date(2025, 1, 6);
+    project.tasks = vec![
+        Task::new("start")
+            .name("Project")
+            .duration(Duration::days(2)),
+        Task::new("end")
+            .name("Today")
+            .milestone()
+            .depends_on("start"),
+    ];
+
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+    let plantuml = PlantUmlRenderer::new().render(&project, &schedule).unwrap();
+
+    assert!(plantuml.contains("[Project (task)] as [t_start]"));
+    assert!(plantuml.contains("[Today (task)] happens at [t_start]'s end"));
+}
+
+// ============================================================================
+// Markdown Renderer Tests
+// ============================================================================
+
+fn markdown_project() -> Project {
+    let mut project = Project::new("Website | Launch");
+    project.start = date(2025, 1, 6);
+    project.tasks = vec![
+        Task::new("design")
+            .name("Design")
+            .child(
+                Task::new("wireframes")
+                    .name("Wireframes")
+                    .duration(Duration::days(3))
+                    .complete(100.0),
+            )
+            .child(
+                Task::new("mockups")
+                    .name("Mockups | v2")
+                    .duration(Duration::days(2))
+                    .depends_on("wireframes"),
+            ),
+        Task::new("polish")
+            .name("Polish")
+            .duration(Duration::days(1)),
+    ];
+    project
+}
+
+#[test]
+fn markdown_renders_table_in_wbs_order() {
+    let project = markdown_project();
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+    let markdown = MarkdownRenderer::new().render(&project, &schedule).unwrap();
+
+    let lines: Vec<&str> = markdown.lines().collect();
+    assert!(lines[0].starts_with("**Website \\| Launch** — finish 2025-01-10"));
+    assert_eq!(
+        lines[2],
+        "| WBS | Task | Start | Finish | Duration | Slack | Status | Progress |"
+    );
+    assert_eq!(lines[3], "|---|---|---|---|---|---|---|---|");
+    assert!(lines[4].starts_with("| 1 | **Design** |"));
+    assert!(lines[5]
+        .starts_with("| 1.1 | &nbsp;&nbsp;**Wireframes** | 2025-01-06 | 2025-01-08 | 3d | 0d |"));
+    assert!(lines[5].ends_with("| 100% |"));
+    assert!(lines[6].contains("&nbsp;&nbsp;**Mockups \\| v2**"));
+    assert!(lines[7].starts_with("| 2 | Polish |"));
+    assert!(lines[7].contains("| 4d |"));
+}
+
+#[test]
+fn markdown_custom_columns() {
+    let project = markdown_project();
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+    let markdown = MarkdownRenderer::new()
+        .columns([MarkdownColumn::Task, MarkdownColumn::Finish])
+        .no_summary()
+        .no_critical()
+        .render(&project, &schedule)
+        .unwrap();
+
+    let lines: Vec<&str> = markdown.lines().collect();
+    assert_eq!(lines[0], "| Task | Finish |");
+    assert_eq!(lines[1], "|---|---|");
+    assert_eq!(lines[2], "| Design | 2025-01-10 |");
+    assert_eq!(lines.len(), 6);
+}
+
+#[test]
+fn markdown_summary_names_the_timezone() {
+    let mut project = markdown_project();
+    project.timezone = Some("Europe/Rome".into());
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+    let markdown = MarkdownRenderer::new().render(&project, &schedule).unwrap();
+
+    assert!(markdown
+        .lines()
+        .next()
+        .unwrap()
+        .starts_with("**Website \\| Launch** — finish 2025-01-10 (Europe/Rome), "));
+}
@@ -586,6 +586,8 @@ mod tests {
             planned_value: 0,
             earned_value: 0,
             spi: 1.0,
+            status_date: Some(project_end),
+            unconstrained_finish: None,
         };
 
         // Both tasks scheduled at the same time - conflict!
@@ -608,8 +610,12 @@ mod tests {
                     cost_range: None,
                     is_abstract: false,
                     effort_days: None,
+                    cost_explanation: None,
+                    start_time: None,
+                    finish_time: None,
                 }],
                 slack: Duration::zero(),
+                free_slack: Duration::zero(),
                 is_critical: true,
                 early_start: start,
                 early_finish: finish,
@@ -626,6 +632,11 @@ mod tests {
                 finish_variance_days: 0,
                 cost_range: None,
                 has_abstract_assignments: false,
+                driving_predecessor: None,
+                is_milestone: false,
+                critical_reason: None,
+                is_pinned: false,
+                tags: Vec::new(),
             },
         );
 
@@ -645,8 +656,12 @@ mod tests {
                     cost_range: None,
                     is_abstract: false,
                     effort_days: None,
+                    cost_explanation: None,
+                    start_time: None,
+                    finish_time: None,
                 }],
                 slack: Duration::days(5),
+                free_slack: Duration::zero(),
                 is_critical: false,
                 early_start: start,
                 early_finish: finish,
@@ -663,6 +678,11 @@ mod tests {
                 finish_variance_days: 0,
                 cost_range: None,
                 has_abstract_assignments: false,
+                driving_predecessor: None,
+                is_milestone: false,
+                critical_reason: None,
+                is_pinned: false,
+                tags: Vec::new(),
             },
         );
 
@@ -703,6 +723,8 @@ mod tests {
             planned_value: 0,
             earned_value: 0,
             spi: 1.0,
+            status_date: Some(project_end),
+            unconstrained_finish: None,
         };
 
         let start1 = project.start;
@@ -726,8 +748,12 @@ mod tests {
                     cost_range: None,
                     is_abstract: false,
                     effort_days: None,
+                    cost_explanation: None,
+                    start_time: None,
+                    finish_time: None,
                 }],
                 slack: Duration::zero(),
+                free_slack: Duration::zero(),
                 is_critical: true,
                 early_start: start1,
                 early_finish: finish1,
@@ -744,6 +770,11 @@ mod tests {
                 finish_variance_days: 0,
                 cost_range: None,
                 has_abstract_assignments: false,
+                driving_predecessor: None,
+                is_milestone: false,
+                critical_reason: None,
+                is_pinned: false,
+                tags: Vec::new(),
             },
         );
 
@@ -763,8 +794,12 @@ mod tests {
                     cost_range: None,
                     is_abstract: false,
                     effort_days: None,
+                    cost_explanation: None,
+                    start_time: None,
+                    finish_time: None,
                 }],
                 slack: Duration::zero(),
+                free_slack: Duration::zero(),
                 is_critical: true,
                 early_start: start2,
                 early_finish: finish2,
@@ -777,10 +812,15 @@ mod tests {
                 status: TaskStatus::NotStarted,
                 cost_range: None,
                 has_abstract_assignments: false,
+                driving_predecessor: None,
+                is_milestone: false,
+                critical_reason: None,
+                is_pinned: false,
                 baseline_start: start2,
                 baseline_finish: finish2,
                 start_variance_days: 0,
                 finish_variance_days: 0,
+                tags: Vec::new(),
             },
         );
 
@@ -881,6 +921,8 @@ mod tests {
             planned_value: 0,
             earned_value: 0,
             spi: 1.0,
+            status_date: Some(project_end),
+            unconstrained_finish: None,
         };
 
         let analyzer = BddConflictAnalyzer::new();
@@ -0,0 +1,65 @@
+//! Side-by-side comparison of candidate plans
+//!
+//! Schedules each project variant and tabulates the metrics used to pick
+//! between them. Infeasible variants stay in the table with the reason
+//! they could not be scheduled.
+
+use crate::detect_overallocations;
+use crate::what_if::feasible_schedule;
+use utf8proj_core::{Duration, Money, Project, Scheduler};
+
+/// Key metrics of one scheduled plan
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlanSummary {
+    /// Project name
+    pub name: String,
+    /// Whether the plan could be scheduled within its end date
+    pub feasible: bool,
+    /// Why the plan is infeasible
+    pub conflict: Option<String>,
+    /// Project duration in working days
+    pub makespan: Option<Duration>,
+    /// Expected total cost
+    pub expected_cost: Option<Money>,
+    /// Number of tasks on the critical path
+    pub critical_tasks: usize,
+    /// Largest daily demand above any resource's capacity (0.0 when no
+    /// resource is over-allocated)
+    pub peak_overallocation: f32,
+}
+
+/// Schedule each plan and summarize it, in input order
+///
+/// A plan is infeasible when the solver fails or when it finishes after
+/// the project end date.
+pub fn compare_plans(projects: &[&Project], solver: &impl Scheduler) -> Vec<PlanSummary> {
+    projects
+        .iter()
+        .map(|project| match feasible_schedule(solver, project) {
+            Ok(schedule) => PlanSummary {
+                name: project.name.clone(),
+                feasible: true,
+                conflict: None,
+                makespan: Some(schedule.project_duration),
+                expected_cost: schedule
+                    .total_cost_range
+                    .as_ref()
+                    .map(|cost| Money::new(cost.expected, cost.currency.clone())),
+                critical_tasks: schedule.critical_path.len(),
+                peak_overallocation: detect_overallocations(project, &schedule)
+                    .iter()
+                    .map(|(_, period)| period.peak_excess)
+                    .fold(0.0, f32::max),
+            },
+            Err(e) => PlanSummary {
+                name: project.name.clone(),
+                feasible: false,
+                conflict: Some(e.to_string()),
+                makespan: None,
+                expected_cost: None,
+                critical_tasks: 0,
+                peak_overallocation: 0.0,
+            },
+        })
+        .collect()
+}
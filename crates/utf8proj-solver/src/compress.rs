@@ -0,0 +1,117 @@
+//! Suggestions for pulling a plan in to a deadline
+//!
+//! Walks the critical path and, for each effort-driven task on it, works
+//! out how many resource units would shorten the task enough to close the
+//! gap to the deadline. Each change is re-scheduled before the next task
+//! is considered, so a suggestion only claims the days it actually saves
+//! on the project finish.
+
+use std::collections::HashSet;
+
+use chrono::NaiveDate;
+use utf8proj_core::{Calendar, Duration, Project, Schedule, Scheduler, TaskId};
+
+use crate::{date_to_working_days, effective_units, get_task_duration_days};
+
+/// Added capacity on one critical task
+#[derive(Clone, Debug, PartialEq)]
+pub struct CompressionSuggestion {
+    /// Qualified ID of the task to staff up
+    pub task_id: TaskId,
+    /// Resource units to add, rounded up to a quarter unit
+    pub added_units: f32,
+    /// Working days the change removes from the project finish
+    pub days_saved: i64,
+}
+
+/// Suggest added capacity on critical tasks until the plan finishes by
+/// `deadline`
+///
+/// Tasks are taken in critical-path order; only effort-driven leaves
+/// without a fixed duration can be compressed, and each is compressed at
+/// most once. Stops as soon as the deadline is met, or when no candidate
+/// is left, in which case the suggestions close only part of the gap.
+/// Returns nothing when the plan already meets the deadline or cannot be
+/// scheduled.
+pub fn compress_to(
+    project: &Project,
+    solver: &impl Scheduler,
+    deadline: NaiveDate,
+) -> Vec<CompressionSuggestion> {
+    let calendar = project.default_calendar();
+    let target = date_to_working_days(project.start, deadline, &calendar);
+
+    let mut plan = project.clone();
+    let mut compressed = HashSet::new();
+    let mut suggestions = Vec::new();
+    let Ok(mut schedule) = solver.schedule(&plan) else {
+        return suggestions;
+    };
+
+    while schedule.project_end > deadline {
+        let overrun = date_to_working_days(project.start, schedule.project_end, &calendar) - target;
+        let Some((task_id, effort_days, units, days)) =
+            next_candidate(&plan, &schedule, &compressed, &calendar)
+        else {
+            break;
+        };
+        compressed.insert(task_id.clone());
+
+        let target_days = (days - overrun).max(1);
+        let needed = effort_days / target_days as f64;
+        let added_units = (((needed - units) * 4.0).ceil() / 4.0) as f32;
+        let new_days = (effort_days / (units + f64::from(added_units))).ceil() as i64;
+
+        let mut candidate = plan.clone();
+        if let Some(task) = candidate.get_task_by_path_mut(&task_id) {
+            task.duration = Some(Duration::days(new_days));
+        }
+        let Ok(rescheduled) = solver.schedule(&candidate) else {
+            continue;
+        };
+        let days_saved = schedule.project_duration.as_days() as i64
+            - rescheduled.project_duration.as_days() as i64;
+        if days_saved <= 0 {
+            continue;
+        }
+
+        suggestions.push(CompressionSuggestion {
+            task_id,
+            added_units,
+            days_saved,
+        });
+        plan = candidate;
+        schedule = rescheduled;
+    }
+
+    suggestions
+}
+
+/// First critical task that more capacity would shorten, with its effort
+/// in days, current effective units and current duration in days
+fn next_candidate(
+    plan: &Project,
+    schedule: &Schedule,
+    compressed: &HashSet<TaskId>,
+    calendar: &Calendar,
+) -> Option<(TaskId, f64, f64, i64)> {
+    schedule.critical_path.iter().find_map(|id| {
+        if compressed.contains(id) {
+            return None;
+        }
+        let task = plan.get_task_by_path(id)?;
+        let effort = task.effort?;
+        if task.duration.is_some() || task.is_container() {
+            return None;
+        }
+        let days = get_task_duration_days(task, plan, calendar);
+        (days > 1).then(|| {
+            (
+                id.clone(),
+                calendar.effort_days(effort),
+                effective_units(task, plan),
+                days,
+            )
+        })
+    })
+}
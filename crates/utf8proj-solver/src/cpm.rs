@@ -531,6 +531,7 @@ mod tests {
                     predecessor: "b".to_string(),
                     dep_type: DependencyType::StartToStart,
                     lag: None,
+                    lag_calendar: false,
                 }),
         ];
         let graph = SchedulingGraph::from_wbs(&tasks_ss).unwrap();
@@ -548,6 +549,7 @@ mod tests {
                     predecessor: "b".to_string(),
                     dep_type: DependencyType::FinishToFinish,
                     lag: None,
+                    lag_calendar: false,
                 }),
         ];
         let graph = SchedulingGraph::from_wbs(&tasks_ff).unwrap();
@@ -565,6 +567,7 @@ mod tests {
                     predecessor: "b".to_string(),
                     dep_type: DependencyType::StartToFinish,
                     lag: None,
+                    lag_calendar: false,
                 }),
         ];
         let graph = SchedulingGraph::from_wbs(&tasks_sf).unwrap();
@@ -587,6 +590,7 @@ mod tests {
                     predecessor: "b".to_string(),
                     dep_type: DependencyType::StartToStart,
                     lag: Some(Duration::days(2)),
+                    lag_calendar: false,
                 }),
         ];
 
@@ -200,7 +200,7 @@ fn collect_leaves(
                 .map(|dep| LeafDependency {
                     predecessor: dep.predecessor.clone(),
                     dep_type: dep.dep_type,
-                    lag_days: dep.lag.map(|d| d.as_days() as i64).unwrap_or(0),
+                    lag_days: dep.lag.map(|d| d.round_days()).unwrap_or(0),
                 })
                 .collect();
 
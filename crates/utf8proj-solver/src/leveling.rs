@@ -11,9 +11,10 @@
 //! - L001-L004 diagnostics emitted for transparency
 
 use crate::bdd::BddConflictAnalyzer;
-use chrono::NaiveDate;
+use chrono::{NaiveDate, NaiveTime};
 use rayon::prelude::*;
 use std::collections::{BTreeMap, BinaryHeap, HashMap, HashSet, VecDeque};
+use utf8proj_core::gantt::ResourceConflictPeriod;
 use utf8proj_core::{
     Calendar, DependencyType, Diagnostic, DiagnosticCode, Duration, Project, ResourceId, Schedule,
     ScheduledTask, Severity, Task, TaskId,
@@ -133,6 +134,58 @@ pub struct DayUsage {
     pub total_units: f32,
     /// Tasks contributing to this usage
     pub tasks: Vec<(TaskId, f32)>,
+    /// Time-of-day windows of sub-day assignments (task, start, finish,
+    /// units), each finishing after it starts; these tasks are also listed
+    /// in `tasks`
+    pub windows: Vec<(TaskId, NaiveTime, NaiveTime, f32)>,
+}
+
+impl DayUsage {
+    fn empty() -> Self {
+        DayUsage {
+            total_units: 0.0,
+            tasks: Vec::new(),
+            windows: Vec::new(),
+        }
+    }
+
+    /// Recompute `total_units` from the contributing tasks
+    ///
+    /// Whole-day usage adds up as before and timed windows add their share
+    /// of the working day, so short assignments fit side by side while
+    /// windows that together outlast the day over-allocate. Windows that
+    /// overlap beyond `capacity` count at their peak concurrent units.
+    fn recompute(&mut self, minutes_per_day: i64, capacity: f32) {
+        let whole_day: f32 = self
+            .tasks
+            .iter()
+            .filter(|(id, _)| !self.windows.iter().any(|(w, ..)| w == id))
+            .map(|(_, units)| units)
+            .sum();
+        let share: f32 = self
+            .windows
+            .iter()
+            .map(|(_, start, finish, units)| {
+                units * (*finish - *start).num_minutes() as f32 / minutes_per_day.max(1) as f32
+            })
+            .sum();
+        let peak = self
+            .windows
+            .iter()
+            .map(|(_, at, _, _)| {
+                self.windows
+                    .iter()
+                    .filter(|(_, start, finish, _)| start <= at && at < finish)
+                    .map(|(.., units)| units)
+                    .sum::<f32>()
+            })
+            .fold(0.0f32, f32::max);
+        self.total_units = if whole_day + peak > capacity {
+            whole_day + share.max(peak)
+        } else {
+            whole_day + share
+        };
+    }
 }
 
 /// Timeline of resource usage
@@ -142,6 +195,12 @@ pub struct ResourceTimeline {
     pub capacity: f32,
     /// Usage by date (BTreeMap for sorted iteration - enables O(log n) gap finding)
     pub usage: BTreeMap<NaiveDate, DayUsage>,
+    /// Capacity on calendar exception days whose working minutes differ
+    /// from a regular day (e.g. half-days); other days use `capacity`
+    pub day_capacity: BTreeMap<NaiveDate, f32>,
+    /// Working minutes in a regular day, against which timed windows are
+    /// measured
+    pub minutes_per_day: i64,
 }
 
 impl ResourceTimeline {
@@ -150,34 +209,111 @@ impl ResourceTimeline {
             resource_id,
             capacity,
             usage: BTreeMap::new(),
+            day_capacity: BTreeMap::new(),
+            minutes_per_day: 480,
         }
     }
 
+    /// Scale capacity on the calendar's partial working days
+    ///
+    /// Usage is in units of a regular day, so on a day with half the usual
+    /// working minutes a full-time assignment is over-allocated.
+    pub fn with_calendar(mut self, calendar: &Calendar) -> Self {
+        let regular = calendar.minutes_per_day();
+        if regular > 0 {
+            self.minutes_per_day = regular;
+            for exception in &calendar.exceptions {
+                if exception.working_hours.is_some() {
+                    let minutes = calendar.minutes_available(exception.date);
+                    self.day_capacity.insert(
+                        exception.date,
+                        self.capacity * minutes as f32 / regular as f32,
+                    );
+                }
+            }
+            let dates: Vec<_> = self.usage.keys().copied().collect();
+            for date in dates {
+                let capacity = self.capacity_on(date);
+                if let Some(day) = self.usage.get_mut(&date) {
+                    if !day.windows.is_empty() {
+                        day.recompute(regular, capacity);
+                    }
+                }
+            }
+        }
+        self
+    }
+
+    /// Capacity available on a date
+    pub fn capacity_on(&self, date: NaiveDate) -> f32 {
+        self.day_capacity
+            .get(&date)
+            .copied()
+            .unwrap_or(self.capacity)
+    }
+
     /// Add usage for a task over a date range
     pub fn add_usage(&mut self, task_id: &TaskId, start: NaiveDate, finish: NaiveDate, units: f32) {
         let mut date = start;
         while date <= finish {
-            let day = self.usage.entry(date).or_insert(DayUsage {
-                total_units: 0.0,
-                tasks: Vec::new(),
-            });
-            day.total_units += units;
+            let capacity = self.capacity_on(date);
+            let day = self.usage.entry(date).or_insert_with(DayUsage::empty);
             day.tasks.push((task_id.clone(), units));
+            if day.windows.is_empty() {
+                day.total_units += units;
+            } else {
+                day.recompute(self.minutes_per_day, capacity);
+            }
             date = date.succ_opt().unwrap_or(date);
         }
     }
 
+    /// Add usage for a task between two times of one day
+    ///
+    /// A zero-length or inverted window has no share of the day to measure,
+    /// so it books the whole day instead of lowering the day's usage.
+    pub fn add_timed_usage(
+        &mut self,
+        task_id: &TaskId,
+        date: NaiveDate,
+        start_time: NaiveTime,
+        finish_time: NaiveTime,
+        units: f32,
+    ) {
+        if finish_time <= start_time {
+            self.add_usage(task_id, date, date, units);
+            return;
+        }
+        let capacity = self.capacity_on(date);
+        let day = self.usage.entry(date).or_insert_with(DayUsage::empty);
+        day.tasks.push((task_id.clone(), units));
+        day.windows
+            .push((task_id.clone(), start_time, finish_time, units));
+        day.recompute(self.minutes_per_day, capacity);
+    }
+
     /// Remove usage for a task
     pub fn remove_usage(&mut self, task_id: &TaskId) {
-        for day in self.usage.values_mut() {
-            day.tasks.retain(|(id, units)| {
-                if id == task_id {
-                    day.total_units -= units;
-                    false
-                } else {
-                    true
-                }
-            });
+        for (date, day) in &mut self.usage {
+            if day.windows.is_empty() {
+                day.tasks.retain(|(id, units)| {
+                    if id == task_id {
+                        day.total_units -= units;
+                        false
+                    } else {
+                        true
+                    }
+                });
+            } else {
+                day.tasks.retain(|(id, _)| id != task_id);
+                day.windows.retain(|(id, ..)| id != task_id);
+                let capacity = self
+                    .day_capacity
+                    .get(date)
+                    .copied()
+                    .unwrap_or(self.capacity);
+                day.recompute(self.minutes_per_day, capacity);
+            }
         }
         // Clean up empty days
         self.usage.retain(|_, day| day.total_units > 0.0);
@@ -187,7 +323,7 @@ impl ResourceTimeline {
     pub fn is_overallocated(&self, date: NaiveDate) -> bool {
         self.usage
             .get(&date)
-            .map(|day| day.total_units > self.capacity)
+            .map(|day| day.total_units > self.capacity_on(date))
             .unwrap_or(false)
     }
 
@@ -201,11 +337,13 @@ impl ResourceTimeline {
 
         for date in dates {
             if let Some(day) = self.usage.get(&date) {
-                if day.total_units > self.capacity {
+                let excess = day.total_units - self.capacity_on(date);
+                if excess > 0.0 {
                     match &mut current_period {
                         Some(period) if period.end.succ_opt() == Some(date) => {
                             period.end = date;
                             period.peak_usage = period.peak_usage.max(day.total_units);
+                            period.peak_excess = period.peak_excess.max(excess);
                             for (task_id, _) in &day.tasks {
                                 if !period.involved_tasks.contains(task_id) {
                                     period.involved_tasks.push(task_id.clone());
@@ -220,6 +358,7 @@ impl ResourceTimeline {
                                 start: date,
                                 end: date,
                                 peak_usage: day.total_units,
+                                peak_excess: excess,
                                 involved_tasks: day
                                     .tasks
                                     .iter()
@@ -388,6 +527,8 @@ pub struct OverallocationPeriod {
     pub start: NaiveDate,
     pub end: NaiveDate,
     pub peak_usage: f32,
+    /// Largest amount by which a day's demand exceeds that day's capacity
+    pub peak_excess: f32,
     pub involved_tasks: Vec<TaskId>,
 }
 
@@ -412,6 +553,86 @@ pub struct LevelingResult {
     pub diagnostics: Vec<Diagnostic>,
 }
 
+impl LevelingResult {
+    /// Summarize the run: resolution counts, added days and biggest moves
+    pub fn summary(&self) -> LevelingSummary {
+        let resolved_conflicts = self
+            .shifted_tasks
+            .iter()
+            .filter(|s| matches!(s.reason, LevelingReason::ResourceOverallocated { .. }))
+            .count();
+
+        let mut unresolved_resources: Vec<ResourceId> = self
+            .unresolved_conflicts
+            .iter()
+            .map(|c| c.resource_id.clone())
+            .collect();
+        unresolved_resources.sort();
+        unresolved_resources.dedup();
+
+        let mut top_shifted: Vec<(TaskId, i64)> = self
+            .shifted_tasks
+            .iter()
+            .map(|s| (s.task_id.clone(), s.days_shifted))
+            .collect();
+        top_shifted.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        top_shifted.truncate(LevelingSummary::TOP_SHIFTED);
+
+        LevelingSummary {
+            fully_resolved: self.unresolved_conflicts.is_empty(),
+            days_added: self.metrics.project_duration_increase,
+            resolved_conflicts,
+            unresolved_conflicts: self.unresolved_conflicts.len(),
+            unresolved_resources,
+            top_shifted,
+        }
+    }
+
+    /// Append W004 when conflicts remain after leveling
+    fn emit_approximate_leveling(&mut self) {
+        let summary = self.summary();
+        if summary.fully_resolved {
+            return;
+        }
+
+        let mut notes = vec![format!(
+            "{} conflict(s) resolved, {} unresolved on {} resource(s)",
+            summary.resolved_conflicts,
+            summary.unresolved_conflicts,
+            summary.unresolved_resources.len()
+        )];
+        notes.extend(self.unresolved_conflicts.iter().map(|c| {
+            format!(
+                "{} over-allocated on {}..{} ({:.0}% peak)",
+                c.resource_id,
+                c.period.start,
+                c.period.end,
+                c.period.peak_usage * 100.0
+            )
+        }));
+        if self.project_extended {
+            notes.push(format!(
+                "project extended by {} day(s) to {}",
+                summary.days_added, self.new_project_end
+            ));
+        }
+
+        self.diagnostics.push(Diagnostic {
+            code: DiagnosticCode::W004ApproximateLeveling,
+            severity: Severity::Warning,
+            message: format!(
+                "resource leveling incomplete - {} conflict(s) unresolved",
+                summary.unresolved_conflicts
+            ),
+            file: None,
+            span: None,
+            secondary_spans: vec![],
+            notes,
+            hints: vec!["add resources, extend deadlines, or reduce scope".to_string()],
+        });
+    }
+}
+
 // Backwards compatibility alias
 impl LevelingResult {
     /// Get the leveled schedule (alias for backwards compatibility)
@@ -433,6 +654,28 @@ pub struct ShiftedTask {
     pub resources_involved: Vec<ResourceId>,
 }
 
+/// Executive view of a leveling run, aggregated from a [`LevelingResult`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct LevelingSummary {
+    /// Whether every overallocation was resolved
+    pub fully_resolved: bool,
+    /// Working days added to the project duration
+    pub days_added: i64,
+    /// Conflicts resolved by shifting a task
+    pub resolved_conflicts: usize,
+    /// Conflicts left in the leveled schedule
+    pub unresolved_conflicts: usize,
+    /// Resources that are still overallocated (sorted)
+    pub unresolved_resources: Vec<ResourceId>,
+    /// Tasks that moved the most, as (task, days shifted), largest first
+    pub top_shifted: Vec<(TaskId, i64)>,
+}
+
+impl LevelingSummary {
+    /// Number of shifted tasks listed in `top_shifted`
+    pub const TOP_SHIFTED: usize = 5;
+}
+
 /// A conflict that could not be resolved
 #[derive(Debug, Clone)]
 pub struct UnresolvedConflict {
@@ -487,6 +730,21 @@ pub fn level_resources(
     level_resources_with_options(project, schedule, calendar, &LevelingOptions::default())
 }
 
+/// Preview what leveling would do to a schedule, for approval
+///
+/// Runs the same leveling as [`CpmSolver::with_leveling`](crate::CpmSolver::with_leveling)
+/// against an already computed schedule, which is left untouched. The
+/// result lists the tasks that would move (`shifted_tasks`) and the
+/// dates they would get (`leveled_schedule`); apply it by using the
+/// leveled schedule or rescheduling with leveling on.
+pub fn preview_leveling(
+    project: &Project,
+    schedule: &Schedule,
+    calendar: &Calendar,
+) -> LevelingResult {
+    level_resources(project, schedule, calendar)
+}
+
 /// Perform resource leveling with explicit options
 pub fn level_resources_with_options(
     project: &Project,
@@ -494,6 +752,28 @@ pub fn level_resources_with_options(
     calendar: &Calendar,
     options: &LevelingOptions,
 ) -> LevelingResult {
+    // A calendar with no working days has no slot to shift a task into;
+    // searching for one would never end
+    if !calendar.has_working_days() {
+        return unleveled_result(
+            project,
+            schedule,
+            vec![Diagnostic {
+                code: DiagnosticCode::C002NoWorkingDays,
+                severity: Severity::Error,
+                message: format!(
+                    "calendar '{}' has no working days; leveling skipped",
+                    calendar.id
+                ),
+                file: None,
+                span: None,
+                secondary_spans: vec![],
+                notes: vec![],
+                hints: vec![],
+            }],
+        );
+    }
+
     // Use hybrid leveling if strategy is Hybrid (RFC-0014)
     if options.strategy == LevelingStrategy::Hybrid {
         return hybrid_level_resources(project, schedule, calendar, options);
@@ -845,7 +1125,7 @@ pub fn level_resources_with_options(
         total_delay_days: shifted_tasks.iter().map(|s| s.days_shifted).sum(),
     };
 
-    LevelingResult {
+    let mut result = LevelingResult {
         original_schedule,
         leveled_schedule: Schedule {
             tasks: leveled_tasks,
@@ -861,6 +1141,8 @@ pub fn level_resources_with_options(
             planned_value: schedule.planned_value,
             earned_value: schedule.earned_value,
             spi: schedule.spi,
+            status_date: schedule.status_date,
+            unconstrained_finish: Some(schedule.project_end),
         },
         shifted_tasks,
         unresolved_conflicts,
@@ -868,7 +1150,9 @@ pub fn level_resources_with_options(
         new_project_end,
         metrics,
         diagnostics,
-    }
+    };
+    result.emit_approximate_leveling();
+    result
 }
 
 /// Result from processing a single conflict cluster (for parallel processing)
@@ -917,7 +1201,7 @@ fn process_cluster(
         if cluster_resources.contains(resource.id.as_str()) {
             timelines.insert(
                 resource.id.clone(),
-                ResourceTimeline::new(resource.id.clone(), resource.capacity),
+                ResourceTimeline::new(resource.id.clone(), resource.effective_capacity()),
             );
         }
     }
@@ -1171,6 +1455,34 @@ fn process_cluster(
     }
 }
 
+/// A leveling result that leaves the schedule as it is
+fn unleveled_result(
+    project: &Project,
+    schedule: &Schedule,
+    diagnostics: Vec<Diagnostic>,
+) -> LevelingResult {
+    let peak_utilization = calculate_peak_utilization(project, &schedule.tasks);
+    LevelingResult {
+        original_schedule: schedule.clone(),
+        leveled_schedule: Schedule {
+            unconstrained_finish: Some(schedule.project_end),
+            ..schedule.clone()
+        },
+        shifted_tasks: vec![],
+        unresolved_conflicts: vec![],
+        project_extended: false,
+        new_project_end: schedule.project_end,
+        metrics: LevelingMetrics {
+            project_duration_increase: 0,
+            peak_utilization_before: peak_utilization,
+            peak_utilization_after: peak_utilization,
+            tasks_delayed: 0,
+            total_delay_days: 0,
+        },
+        diagnostics,
+    }
+}
+
 /// Hybrid BDD + heuristic resource leveling (RFC-0014 Phase 1+2)
 ///
 /// Uses BDD to identify conflict clusters, then applies heuristic leveling
@@ -1219,22 +1531,7 @@ fn hybrid_level_resources(
 
     // If no clusters, no leveling needed
     if cluster_analysis.clusters.is_empty() {
-        return LevelingResult {
-            original_schedule,
-            leveled_schedule: schedule.clone(),
-            shifted_tasks: vec![],
-            unresolved_conflicts: vec![],
-            project_extended: false,
-            new_project_end: schedule.project_end,
-            metrics: LevelingMetrics {
-                project_duration_increase: 0,
-                peak_utilization_before,
-                peak_utilization_after: peak_utilization_before,
-                tasks_delayed: 0,
-                total_delay_days: 0,
-            },
-            diagnostics: vec![],
-        };
+        return unleveled_result(project, schedule, vec![]);
     }
 
     // Build task priorities (shared, read-only)
@@ -1458,7 +1755,7 @@ fn hybrid_level_resources(
         total_delay_days: shifted_tasks.iter().map(|s| s.days_shifted).sum(),
     };
 
-    LevelingResult {
+    let mut result = LevelingResult {
         original_schedule,
         leveled_schedule: Schedule {
             tasks: leveled_tasks,
@@ -1474,6 +1771,8 @@ fn hybrid_level_resources(
             planned_value: schedule.planned_value,
             earned_value: schedule.earned_value,
             spi: schedule.spi,
+            status_date: schedule.status_date,
+            unconstrained_finish: Some(schedule.project_end),
         },
         shifted_tasks,
         unresolved_conflicts,
@@ -1481,7 +1780,9 @@ fn hybrid_level_resources(
         new_project_end,
         metrics,
         diagnostics,
-    }
+    };
+    result.emit_approximate_leveling();
+    result
 }
 
 /// Type alias for the successor map: predecessor_id -> Vec<(successor_id, dep_type, lag)>
@@ -1540,7 +1841,7 @@ fn flatten_project_tasks<'a>(
     }
 }
 
-/// Resolve a dependency path (mirrors lib.rs resolve_dependency_path)
+/// Resolve a dependency path (mirrors utf8proj_core::graph::resolve_dependency_path)
 fn resolve_dep_path(
     dep_path: &str,
     from_qualified_id: &str,
@@ -1585,7 +1886,7 @@ fn compute_earliest_start(
                 continue;
             }
             let pred = leveled_tasks.get(pred_id)?;
-            let lag_days = lag.as_ref().map(|l| l.as_days() as i64).unwrap_or(0);
+            let lag_days = lag.as_ref().map(|l| l.round_days()).unwrap_or(0);
 
             let constraint_date = match dep_type {
                 DependencyType::FinishToStart => {
@@ -1764,11 +2065,16 @@ fn build_resource_timelines(
 ) -> HashMap<ResourceId, ResourceTimeline> {
     let mut timelines: HashMap<ResourceId, ResourceTimeline> = HashMap::new();
 
-    // Initialize timelines for all resources
-    for resource in &project.resources {
+    // Initialize timelines for all renewable resources; consumables are
+    // limited by their pool, not by daily capacity, and materials by neither
+    for resource in project
+        .resources
+        .iter()
+        .filter(|r| !r.is_consumable() && !r.is_material())
+    {
         timelines.insert(
             resource.id.clone(),
-            ResourceTimeline::new(resource.id.clone(), resource.capacity),
+            ResourceTimeline::new(resource.id.clone(), resource.effective_capacity()),
         );
     }
 
@@ -1776,12 +2082,25 @@ fn build_resource_timelines(
     for task in tasks.values() {
         for assignment in &task.assignments {
             if let Some(timeline) = timelines.get_mut(&assignment.resource_id) {
-                timeline.add_usage(
-                    &task.task_id,
-                    assignment.start,
-                    assignment.finish,
-                    assignment.units,
-                );
+                match (assignment.start_time, assignment.finish_time) {
+                    (Some(start_time), Some(finish_time))
+                        if assignment.start == assignment.finish =>
+                    {
+                        timeline.add_timed_usage(
+                            &task.task_id,
+                            assignment.start,
+                            start_time,
+                            finish_time,
+                            assignment.units,
+                        );
+                    }
+                    _ => timeline.add_usage(
+                        &task.task_id,
+                        assignment.start,
+                        assignment.finish,
+                        assignment.units,
+                    ),
+                }
             }
         }
     }
@@ -1789,6 +2108,26 @@ fn build_resource_timelines(
     timelines
 }
 
+/// Build timelines whose capacity follows each resource's calendar (or the
+/// given project calendar) on partial working days
+fn build_calendar_timelines(
+    project: &Project,
+    tasks: &HashMap<TaskId, ScheduledTask>,
+    calendar: &Calendar,
+) -> HashMap<ResourceId, ResourceTimeline> {
+    build_resource_timelines(project, tasks)
+        .into_iter()
+        .map(|(id, timeline)| {
+            let resource_calendar = project
+                .get_resource(&id)
+                .and_then(|r| r.calendar.as_ref())
+                .and_then(|cal_id| project.calendars.iter().find(|c| c.id == *cal_id))
+                .unwrap_or(calendar);
+            (id, timeline.with_calendar(resource_calendar))
+        })
+        .collect()
+}
+
 /// Build a map of task ID to (priority, task reference)
 fn build_task_priority_map(
     tasks: &HashMap<TaskId, ScheduledTask>,
@@ -1824,7 +2163,7 @@ fn build_task_priority_map(
 }
 
 /// Add working days to a date
-fn add_working_days(start: NaiveDate, days: i64, calendar: &Calendar) -> NaiveDate {
+pub(crate) fn add_working_days(start: NaiveDate, days: i64, calendar: &Calendar) -> NaiveDate {
     if days <= 0 {
         return start;
     }
@@ -1897,11 +2236,15 @@ fn recalculate_critical_path(
 }
 
 /// Detect resource over-allocations without resolving them
+///
+/// Daily demand is compared with the working minutes available on each
+/// day, so partial-day calendar exceptions lower that day's capacity.
 pub fn detect_overallocations(
     project: &Project,
     schedule: &Schedule,
 ) -> Vec<(ResourceId, OverallocationPeriod)> {
-    let timelines = build_resource_timelines(project, &schedule.tasks);
+    let calendar = project.default_calendar();
+    let timelines = build_calendar_timelines(project, &schedule.tasks, &calendar);
 
     timelines
         .into_iter()
@@ -1915,6 +2258,47 @@ pub fn detect_overallocations(
         .collect()
 }
 
+/// Resource conflict windows for shading a Gantt chart
+///
+/// The same periods as [`detect_overallocations`], available without
+/// leveling, with each contending task's row in [`Schedule::bars`] so an
+/// overlay lines up with the bars it covers. Sorted by resource, then
+/// start date.
+pub fn resource_conflicts(project: &Project, schedule: &Schedule) -> Vec<ResourceConflictPeriod> {
+    let rows: HashMap<TaskId, usize> = schedule
+        .bars()
+        .into_iter()
+        .map(|bar| (bar.task_id, bar.row))
+        .collect();
+
+    let mut conflicts: Vec<ResourceConflictPeriod> = detect_overallocations(project, schedule)
+        .into_iter()
+        .map(|(resource_id, period)| {
+            let mut task_ids = period.involved_tasks;
+            task_ids.sort();
+            let task_rows = task_ids
+                .iter()
+                .filter_map(|id| rows.get(id).copied())
+                .collect();
+            ResourceConflictPeriod {
+                resource_id,
+                start: period.start,
+                finish: period.end,
+                peak_units: period.peak_usage,
+                excess_units: period.peak_excess,
+                task_ids,
+                task_rows,
+            }
+        })
+        .collect();
+    conflicts.sort_by(|a, b| {
+        a.resource_id
+            .cmp(&b.resource_id)
+            .then_with(|| a.start.cmp(&b.start))
+    });
+    conflicts
+}
+
 /// Utilization statistics for a single resource
 #[derive(Debug, Clone)]
 pub struct ResourceUtilization {
@@ -1950,12 +2334,15 @@ pub struct UtilizationSummary {
 }
 
 /// Calculate resource utilization for a schedule
+///
+/// Capacity is measured in working minutes, so partial-day calendar
+/// exceptions count as the matching fraction of a day.
 pub fn calculate_utilization(
     project: &Project,
     schedule: &Schedule,
     calendar: &Calendar,
 ) -> UtilizationSummary {
-    let timelines = build_resource_timelines(project, &schedule.tasks);
+    let timelines = build_calendar_timelines(project, &schedule.tasks, calendar);
 
     // Determine schedule date range
     let schedule_start = schedule
@@ -1973,10 +2360,15 @@ pub fn calculate_utilization(
 
     // Count working days in schedule period
     let total_working_days = count_schedule_working_days(schedule_start, schedule_end, calendar);
+    let available_days = available_schedule_days(schedule_start, schedule_end, calendar);
 
     let mut resources = Vec::new();
 
-    for resource in &project.resources {
+    for resource in project
+        .resources
+        .iter()
+        .filter(|r| !r.is_consumable() && !r.is_material())
+    {
         let timeline = timelines.get(&resource.id);
 
         let (used_days, peak_usage, assigned_days) = if let Some(timeline) = timeline {
@@ -1997,8 +2389,8 @@ pub fn calculate_utilization(
             (0.0, 0.0, 0)
         };
 
-        // Calculate utilization: used_days / (total_working_days * capacity) * 100
-        let capacity_days = total_working_days as f32 * resource.capacity;
+        // Calculate utilization: used_days / (available_days * capacity) * 100
+        let capacity_days = available_days * resource.capacity;
         let utilization_percent = if capacity_days > 0.0 {
             (used_days / capacity_days) * 100.0
         } else {
@@ -2054,6 +2446,24 @@ fn count_schedule_working_days(start: NaiveDate, end: NaiveDate, calendar: &Cale
     count
 }
 
+/// Working time in the schedule period, in regular days (both inclusive)
+///
+/// Matches [`count_schedule_working_days`] on calendars without partial-day
+/// exceptions.
+fn available_schedule_days(start: NaiveDate, end: NaiveDate, calendar: &Calendar) -> f32 {
+    let regular = calendar.minutes_per_day();
+    if end <= start || regular == 0 {
+        return count_schedule_working_days(start, end, calendar) as f32;
+    }
+
+    let minutes: i64 = start
+        .iter_days()
+        .take_while(|d| *d <= end)
+        .map(|d| calendar.minutes_available(d))
+        .sum();
+    minutes as f32 / regular as f32
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2107,6 +2517,35 @@ mod tests {
         assert!(!timeline.is_overallocated(start));
     }
 
+    #[test]
+    fn timeline_adds_whole_days_over_timed_usage() {
+        let mut timeline = ResourceTimeline::new("dev".into(), 1.0);
+        let day = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+        let at = |h| NaiveTime::from_hms_opt(h, 0, 0).unwrap();
+
+        // One hour at full units leaves no room beside half-day work
+        timeline.add_timed_usage(&"call".into(), day, at(9), at(10), 1.0);
+        timeline.add_usage(&"task1".into(), day, day, 0.5);
+
+        assert!(timeline.is_overallocated(day));
+    }
+
+    #[test]
+    fn timeline_books_invalid_windows_as_whole_days() {
+        let mut timeline = ResourceTimeline::new("dev".into(), 1.0);
+        let day = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+        let at = |h| NaiveTime::from_hms_opt(h, 0, 0).unwrap();
+
+        timeline.add_usage(&"task1".into(), day, day, 1.0);
+        // Neither window may offset the full day already booked
+        timeline.add_timed_usage(&"inverted".into(), day, at(14), at(9), 0.5);
+        timeline.add_timed_usage(&"empty".into(), day, at(9), at(9), 0.5);
+
+        assert!(timeline.is_overallocated(day));
+        assert!((timeline.usage[&day].total_units - 2.0).abs() < 0.001);
+        assert!(timeline.usage[&day].windows.is_empty());
+    }
+
     #[test]
     fn find_available_slot_basic() {
         let mut timeline = ResourceTimeline::new("dev".into(), 1.0);
@@ -2185,6 +2624,80 @@ mod tests {
         );
     }
 
+    #[test]
+    fn detect_overallocations_uses_availability() {
+        use utf8proj_core::Scheduler;
+
+        // 60% is within full capacity but exceeds a 0.5-available resource
+        let mut project = Project::new("Test");
+        project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+        project.resources = vec![Resource::new("dev").availability(0.5)];
+        project.tasks = vec![Task::new("task1")
+            .duration(Duration::days(5))
+            .assign_with_units("dev", 0.6)];
+
+        let solver = crate::CpmSolver::new();
+        let schedule = solver.schedule(&project).unwrap();
+
+        assert!(!detect_overallocations(&project, &schedule).is_empty());
+
+        project.resources = vec![Resource::new("dev")];
+        assert!(detect_overallocations(&project, &schedule).is_empty());
+    }
+
+    /// Standard calendar with Wednesday 2025-01-08 cut to a 4-hour half-day
+    fn half_day_calendar() -> Calendar {
+        let mut calendar = Calendar::default();
+        calendar.exceptions.push(utf8proj_core::CalendarException {
+            date: NaiveDate::from_ymd_opt(2025, 1, 8).unwrap(),
+            working_hours: Some(vec![utf8proj_core::TimeRange {
+                start: 9 * 60,
+                end: 13 * 60,
+            }]),
+        });
+        calendar
+    }
+
+    #[test]
+    fn detect_overallocations_on_half_day() {
+        use utf8proj_core::Scheduler;
+
+        let mut project = Project::new("Test");
+        project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+        project.resources = vec![Resource::new("dev")];
+        project.tasks = vec![Task::new("task1").duration(Duration::days(5)).assign("dev")];
+        let schedule = crate::CpmSolver::new().schedule(&project).unwrap();
+        assert!(detect_overallocations(&project, &schedule).is_empty());
+
+        // Full-time over a half-day needs twice the available minutes
+        project.calendars = vec![half_day_calendar()];
+        let conflicts = detect_overallocations(&project, &schedule);
+        assert_eq!(conflicts.len(), 1);
+        let (resource_id, period) = &conflicts[0];
+        assert_eq!(resource_id, "dev");
+        assert_eq!(period.start, NaiveDate::from_ymd_opt(2025, 1, 8).unwrap());
+        assert_eq!(period.end, period.start);
+    }
+
+    #[test]
+    fn calculate_utilization_counts_half_days_by_minutes() {
+        use utf8proj_core::Scheduler;
+
+        let mut project = Project::new("Test");
+        project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+        project.resources = vec![Resource::new("dev")];
+        project.tasks = vec![Task::new("task1").duration(Duration::days(5)).assign("dev")];
+        let schedule = crate::CpmSolver::new().schedule(&project).unwrap();
+
+        let utilization = calculate_utilization(&project, &schedule, &half_day_calendar());
+
+        // 5 days of demand over 4 full days plus 4/7 of a day
+        let dev = &utilization.resources[0];
+        assert_eq!(dev.total_days, 5);
+        let expected = 5.0 / (4.0 + 4.0 / 7.0) * 100.0;
+        assert!((dev.utilization_percent - expected).abs() < 0.01);
+    }
+
     #[test]
     fn level_resources_resolves_simple_conflict() {
         use utf8proj_core::Scheduler;
@@ -2469,6 +2982,7 @@ mod tests {
             predecessor: "critical1".into(),
             dep_type: DependencyType::FinishToStart,
             lag: None,
+            lag_calendar: false,
         }];
 
         project.tasks.push(task1);
@@ -2507,6 +3021,7 @@ mod tests {
             predecessor: "first".into(),
             dep_type: DependencyType::FinishToStart,
             lag: None,
+            lag_calendar: false,
         }];
 
         project.tasks.push(task1);
@@ -2759,11 +3274,13 @@ mod tests {
                 predecessor: "a".into(),
                 dep_type: DependencyType::FinishToStart,
                 lag: None,
+                lag_calendar: false,
             },
             Dependency {
                 predecessor: "b".into(),
                 dep_type: DependencyType::FinishToStart,
                 lag: None,
+                lag_calendar: false,
             },
         ];
 
@@ -2816,6 +3333,7 @@ mod tests {
             predecessor: "a".into(),
             dep_type: DependencyType::FinishToStart,
             lag: Some(Duration::days(2)),
+            lag_calendar: false,
         }];
 
         project.tasks = vec![
@@ -2865,6 +3383,7 @@ mod tests {
             predecessor: "a".into(),
             dep_type: DependencyType::StartToStart,
             lag: None,
+            lag_calendar: false,
         }];
 
         project.tasks = vec![
@@ -2914,7 +3433,7 @@ mod tests {
                 let Some(succ) = schedule.tasks.get(succ_id) else {
                     continue;
                 };
-                let lag_days = lag.as_ref().map(|l| l.as_days() as i64).unwrap_or(0);
+                let lag_days = lag.as_ref().map(|l| l.round_days()).unwrap_or(0);
 
                 let violated = match dep_type {
                     DependencyType::FinishToStart => {
@@ -3033,11 +3552,13 @@ mod tests {
                 predecessor: "a".into(),
                 dep_type: DependencyType::FinishToStart,
                 lag: Some(Duration::days(1)),
+                lag_calendar: false,
             },
             Dependency {
                 predecessor: "b".into(),
                 dep_type: DependencyType::FinishToStart,
                 lag: None,
+                lag_calendar: false,
             },
         ];
 
@@ -3241,6 +3762,85 @@ mod tests {
         );
     }
 
+    #[test]
+    fn summary_reports_fully_resolved_run() {
+        use utf8proj_core::Scheduler;
+
+        let mut project = Project::new("Summary");
+        project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+        project.resources = vec![Resource::new("dev")];
+        project.tasks = vec![
+            Task::new("long").effort(Duration::days(5)).assign("dev"),
+            Task::new("mid").effort(Duration::days(3)).assign("dev"),
+            Task::new("short").effort(Duration::days(1)).assign("dev"),
+        ];
+
+        let schedule = crate::CpmSolver::new().schedule(&project).unwrap();
+        let result = level_resources(&project, &schedule, &Calendar::default());
+        let summary = result.summary();
+
+        assert!(summary.fully_resolved);
+        assert_eq!(summary.unresolved_conflicts, 0);
+        assert!(summary.unresolved_resources.is_empty());
+        assert_eq!(summary.resolved_conflicts, 2);
+        assert_eq!(summary.top_shifted.len(), 2);
+        assert_eq!(summary.days_added, result.metrics.project_duration_increase);
+        // Largest move first
+        let shifts: Vec<i64> = summary.top_shifted.iter().map(|(_, d)| *d).collect();
+        assert!(shifts.windows(2).all(|w| w[0] >= w[1]));
+        assert!(!result
+            .diagnostics
+            .iter()
+            .any(|d| d.code == DiagnosticCode::W004ApproximateLeveling));
+    }
+
+    #[test]
+    fn unresolved_conflicts_emit_w004_with_summary_counts() {
+        use utf8proj_core::Scheduler;
+
+        let mut project = Project::new("W004");
+        project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+        project.resources = vec![Resource::new("dev").capacity(0.5)];
+        project.tasks = vec![
+            Task::new("blocker")
+                .effort(Duration::days(5))
+                .assign_with_units("dev", 0.5),
+            Task::new("exceeds")
+                .effort(Duration::days(3))
+                .assign_with_units("dev", 0.6),
+        ];
+
+        let schedule = crate::CpmSolver::new().schedule(&project).unwrap();
+        let result = level_resources(&project, &schedule, &Calendar::default());
+        let summary = result.summary();
+
+        assert!(!summary.fully_resolved);
+        assert_eq!(
+            summary.unresolved_conflicts,
+            result.unresolved_conflicts.len()
+        );
+        assert_eq!(summary.unresolved_resources, vec!["dev".to_string()]);
+
+        let w004: Vec<_> = result
+            .diagnostics
+            .iter()
+            .filter(|d| d.code == DiagnosticCode::W004ApproximateLeveling)
+            .collect();
+        assert_eq!(w004.len(), 1);
+        assert_eq!(w004[0].severity, Severity::Warning);
+        assert_eq!(
+            w004[0].message,
+            format!(
+                "resource leveling incomplete - {} conflict(s) unresolved",
+                summary.unresolved_conflicts
+            )
+        );
+        assert!(w004[0].notes[0].contains(&format!(
+            "{} conflict(s) resolved, {} unresolved on 1 resource(s)",
+            summary.resolved_conflicts, summary.unresolved_conflicts
+        )));
+    }
+
     // --- G2: Max Delay Factor ---
 
     #[test]
@@ -3652,6 +4252,7 @@ mod tests {
             predecessor: "a".into(),
             dep_type: DependencyType::FinishToFinish,
             lag: None,
+            lag_calendar: false,
         }];
 
         project.tasks = vec![
@@ -3705,6 +4306,7 @@ mod tests {
             predecessor: "a".into(),
             dep_type: DependencyType::StartToFinish,
             lag: None,
+            lag_calendar: false,
         }];
 
         project.tasks = vec![
@@ -3756,6 +4358,7 @@ mod tests {
             predecessor: "a".into(),
             dep_type: DependencyType::FinishToFinish,
             lag: Some(Duration::days(2)),
+            lag_calendar: false,
         }];
 
         project.tasks = vec![
@@ -3810,6 +4413,7 @@ mod tests {
             predecessor: "a".into(),
             dep_type: DependencyType::FinishToStart,
             lag: None,
+            lag_calendar: false,
         }];
 
         // SS: a -> b_ss
@@ -3823,6 +4427,7 @@ mod tests {
             predecessor: "a".into(),
             dep_type: DependencyType::StartToStart,
             lag: None,
+            lag_calendar: false,
         }];
 
         // FF: a -> b_ff
@@ -3836,6 +4441,7 @@ mod tests {
             predecessor: "a".into(),
             dep_type: DependencyType::FinishToFinish,
             lag: None,
+            lag_calendar: false,
         }];
 
         // SF: a -> b_sf
@@ -3849,6 +4455,7 @@ mod tests {
             predecessor: "a".into(),
             dep_type: DependencyType::StartToFinish,
             lag: None,
+            lag_calendar: false,
         }];
 
         project.tasks = vec![
@@ -3890,6 +4497,7 @@ mod tests {
             predecessor: "task_a".into(), // relative sibling reference
             dep_type: DependencyType::FinishToStart,
             lag: None,
+            lag_calendar: false,
         }];
 
         let phase = Task::new("phase")
@@ -3938,6 +4546,7 @@ mod tests {
             predecessor: "child_a".into(),
             dep_type: DependencyType::FinishToStart,
             lag: None,
+            lag_calendar: false,
         }];
 
         let mut child_d = Task::new("child_d");
@@ -3950,6 +4559,7 @@ mod tests {
             predecessor: "child_c".into(),
             dep_type: DependencyType::FinishToStart,
             lag: None,
+            lag_calendar: false,
         }];
 
         let phase1 = Task::new("phase1")
@@ -3989,6 +4599,7 @@ mod tests {
             predecessor: "phase1.task_a".into(), // absolute cross-hierarchy ref
             dep_type: DependencyType::FinishToStart,
             lag: None,
+            lag_calendar: false,
         }];
 
         let phase1 =
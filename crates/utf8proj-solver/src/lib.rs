@@ -25,17 +25,26 @@
 //! ```
 
 use chrono::{Datelike, Local, NaiveDate, TimeDelta};
-use std::collections::{HashMap, VecDeque};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
 
-use rust_decimal::prelude::FromPrimitive;
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
 use rust_decimal::Decimal;
 use std::path::PathBuf;
 use utf8proj_core::{
+    baseline::Baseline,
+    graph::{
+        build_children_map, flatten_tasks_with_context, resolve_dependency_path, topological_sort,
+    },
     Assignment,
     Calendar,
+    // Diagnostics
+    CollectingEmitter,
+    CostExplanation,
     CostRange,
+    CriticalReason,
+    CurrencyFormat,
+    Dependency,
     DependencyType,
-    // Diagnostics
     Diagnostic,
     DiagnosticCode,
     DiagnosticEmitter,
@@ -43,12 +52,15 @@ use utf8proj_core::{
     Explanation,
     FeasibilityResult,
     Money,
+    ProgressWeighting,
     Project,
     RateRange,
     ResourceProfile,
     ResourceRate,
+    ResourceRef,
     Schedule,
     ScheduleError,
+    ScheduleReport,
     ScheduledTask,
     Scheduler,
     SchedulingMode,
@@ -60,9 +72,14 @@ use utf8proj_core::{
 };
 
 pub mod bdd;
+pub mod compare;
+pub mod compress;
 pub mod cpm;
 pub mod dag;
 pub mod leveling;
+pub mod staffing;
+pub mod stress;
+pub mod what_if;
 
 #[cfg(feature = "optimal-leveling")]
 pub mod optimal;
@@ -71,12 +88,16 @@ pub use bdd::{
     BddConflictAnalyzer, BddStats, ClusterAnalysis, ConflictAnalysis, ConflictCluster,
     ConflictResolution, ResourceConflict, ShiftDirection,
 };
+pub use compare::{compare_plans, PlanSummary};
+pub use compress::{compress_to, CompressionSuggestion};
 pub use leveling::{
     calculate_utilization, detect_overallocations, level_resources, level_resources_with_options,
-    LevelingMetrics, LevelingOptions, LevelingReason, LevelingResult, LevelingStrategy,
-    OverallocationPeriod, ResourceTimeline, ResourceUtilization, ShiftedTask, UnresolvedConflict,
-    UtilizationSummary,
+    preview_leveling, resource_conflicts, LevelingMetrics, LevelingOptions, LevelingReason,
+    LevelingResult, LevelingStrategy, LevelingSummary, OverallocationPeriod, ResourceTimeline,
+    ResourceUtilization, ShiftedTask, UnresolvedConflict, UtilizationSummary,
 };
+pub use staffing::minimum_staffing;
+pub use stress::{stress_test_start_date, BreakKind, BrokenConstraint};
 
 /// CPM-based scheduler
 pub struct CpmSolver {
@@ -85,6 +106,12 @@ pub struct CpmSolver {
     /// CLI-specified status date override (RFC-0004)
     /// Takes precedence over project.status_date per C-01
     pub status_date_override: Option<NaiveDate>,
+    /// Start not-started tasks no earlier than the status date
+    /// (see [`CpmSolver::replan_from`])
+    pub replan: bool,
+    /// Place tasks at their actual dates regardless of the network
+    /// (see [`CpmSolver::schedule_as_built`])
+    pub as_built: bool,
 }
 
 impl CpmSolver {
@@ -92,6 +119,8 @@ impl CpmSolver {
         Self {
             resource_leveling: false,
             status_date_override: None,
+            replan: false,
+            as_built: false,
         }
     }
 
@@ -100,6 +129,8 @@ impl CpmSolver {
         Self {
             resource_leveling: true,
             status_date_override: None,
+            replan: false,
+            as_built: false,
         }
     }
 
@@ -109,6 +140,8 @@ impl CpmSolver {
         Self {
             resource_leveling: false,
             status_date_override: Some(date),
+            replan: false,
+            as_built: false,
         }
     }
 
@@ -122,13 +155,123 @@ impl CpmSolver {
             .unwrap_or_else(|| Local::now().date_naive())
     }
 
+    /// Reschedule the remaining work of a tracked project from `as_of`
+    ///
+    /// Complete tasks stay at their actual dates, in-progress tasks keep
+    /// their actual start and finish `as_of` plus their remaining duration
+    /// (even when that runs past the original plan), and not-started tasks
+    /// are pushed forward so none starts before `as_of`. Baseline dates
+    /// ignore progress and the replan floor, so each task's variance fields
+    /// measure the slip against the original plan.
+    pub fn replan_from(
+        &self,
+        project: &Project,
+        as_of: NaiveDate,
+    ) -> Result<Schedule, ScheduleError> {
+        let solver = Self {
+            resource_leveling: self.resource_leveling,
+            status_date_override: Some(as_of),
+            replan: true,
+            as_built: false,
+        };
+        solver.schedule(project)
+    }
+
+    /// Schedule a tracked project from its actuals, for post-mortems
+    ///
+    /// Tasks with an actual start and finish sit at exactly those dates,
+    /// even where they overlap a predecessor. A task with an actual start
+    /// but no finish runs its planned duration from the actual start, and
+    /// tasks without actuals fall back to the plan, chained from their
+    /// predecessors' as-built finish. The status date plays no part, and
+    /// variance fields compare the as-built dates with the original plan.
+    pub fn schedule_as_built(&self, project: &Project) -> Result<Schedule, ScheduleError> {
+        let solver = Self {
+            resource_leveling: self.resource_leveling,
+            status_date_override: self.status_date_override,
+            replan: false,
+            as_built: true,
+        };
+        solver.schedule(project)
+    }
+
+    /// Reschedule a changed project, holding frozen tasks at their prior
+    /// dates
+    ///
+    /// Each `frozen` leaf task (or leaf under a frozen container) found in
+    /// `prior` is pinned to the start it had there, its finish following
+    /// from its duration, and everything else flows around it. A frozen
+    /// task whose predecessors now finish after its prior start cannot stay
+    /// put: it is released to start as early as they allow, and W016 is
+    /// emitted for it, located in `config.file`. Frozen tasks missing from
+    /// `prior` schedule normally.
+    pub fn reschedule_frozen(
+        &self,
+        project: &Project,
+        prior: &Schedule,
+        config: &AnalysisConfig,
+        emitter: &mut dyn DiagnosticEmitter,
+    ) -> Result<Schedule, ScheduleError> {
+        let mut pins: HashMap<TaskId, NaiveDate> = HashMap::new();
+        frozen_task_starts(&project.tasks, "", false, prior, &mut pins);
+        if pins.is_empty() {
+            return self.schedule(project);
+        }
+
+        // Floor each frozen task at its prior start to find those the
+        // network now pushes later
+        let mut floored = project.clone();
+        pin_frozen_tasks(
+            &mut floored.tasks,
+            "",
+            &pins,
+            TaskConstraint::StartNoEarlierThan,
+        );
+        let trial = self.schedule(&floored)?;
+
+        let mut frozen_ids: Vec<TaskId> = pins.keys().cloned().collect();
+        frozen_ids.sort();
+        for id in frozen_ids {
+            let prior_start = pins[&id];
+            let Some(start) = trial.tasks.get(&id).map(|t| t.start) else {
+                continue;
+            };
+            if start <= prior_start {
+                continue;
+            }
+            pins.remove(&id);
+            emitter.emit(
+                Diagnostic::new(
+                    DiagnosticCode::W016FrozenTaskMoved,
+                    format!(
+                        "frozen task '{}' cannot keep its start on {}",
+                        id, prior_start
+                    ),
+                )
+                .with_file(config.file.clone().unwrap_or_default())
+                .with_note(format!(
+                    "its predecessors now finish later, so it starts on {}",
+                    start
+                ))
+                .with_hint(format!(
+                    "unfreeze '{}', or recover the time in its predecessors",
+                    id
+                )),
+            );
+        }
+
+        let mut pinned = project.clone();
+        pin_frozen_tasks(&mut pinned.tasks, "", &pins, TaskConstraint::MustStartOn);
+        self.schedule(&pinned)
+    }
+
     /// Analyze the effects of temporal constraints on a task
     fn analyze_constraint_effects(
         &self,
         project: &Project,
         task: &Task,
     ) -> Vec<utf8proj_core::ConstraintEffect> {
-        use utf8proj_core::{ConstraintEffect, ConstraintEffectType, TaskConstraint};
+        use utf8proj_core::{ConstraintEffect, ConstraintEffectType};
 
         if task.constraints.is_empty() {
             return vec![];
@@ -155,202 +298,19 @@ impl CpmSolver {
             }
         };
 
-        // Get the scheduled task data
-        let scheduled_task = match schedule.tasks.get(&task.id) {
-            Some(t) => t,
-            None => {
-                return task
-                    .constraints
-                    .iter()
-                    .map(|c| ConstraintEffect {
-                        constraint: c.clone(),
-                        effect: ConstraintEffectType::Redundant,
-                        description: format!(
-                            "{} (task not in schedule)",
-                            Self::format_constraint(c)
-                        ),
-                    })
-                    .collect();
-            }
-        };
-
-        let es = scheduled_task.start;
-        let ef = scheduled_task.finish;
-        let ls = scheduled_task.late_start;
-        let lf = scheduled_task.late_finish;
-        let slack = scheduled_task.slack;
-        let zero_slack = Duration::zero();
-
-        task.constraints
-            .iter()
-            .map(|c| {
-                let (effect, description) = match c {
-                    TaskConstraint::MustStartOn(date) => {
-                        if es == *date && ls == *date {
-                            (
-                                ConstraintEffectType::Pinned,
-                                format!("Task pinned to start on {}", date),
-                            )
-                        } else if es == *date {
-                            (
-                                ConstraintEffectType::PushedStart,
-                                format!("Constraint pushed early start to {}", date),
-                            )
-                        } else if es > *date {
-                            (
-                                ConstraintEffectType::Redundant,
-                                format!(
-                                    "Constraint date {} superseded by dependencies (ES={})",
-                                    date, es
-                                ),
-                            )
-                        } else {
-                            (
-                                ConstraintEffectType::CappedLate,
-                                format!("Constraint capped late start at {}", date),
-                            )
-                        }
-                    }
-                    TaskConstraint::MustFinishOn(date) => {
-                        if ef == *date && lf == *date {
-                            (
-                                ConstraintEffectType::Pinned,
-                                format!("Task pinned to finish on {}", date),
-                            )
-                        } else if ef == *date {
-                            (
-                                ConstraintEffectType::PushedStart,
-                                format!("Constraint pushed early finish to {}", date),
-                            )
-                        } else if ef > *date {
-                            (
-                                ConstraintEffectType::Redundant,
-                                format!(
-                                    "Constraint date {} superseded by dependencies (EF={})",
-                                    date, ef
-                                ),
-                            )
-                        } else {
-                            (
-                                ConstraintEffectType::CappedLate,
-                                format!("Constraint capped late finish at {}", date),
-                            )
-                        }
-                    }
-                    TaskConstraint::StartNoEarlierThan(date) => {
-                        if es == *date {
-                            (
-                                ConstraintEffectType::PushedStart,
-                                format!("Task starts exactly on constraint boundary {}", date),
-                            )
-                        } else if es > *date {
-                            (
-                                ConstraintEffectType::Redundant,
-                                format!(
-                                    "Constraint {} redundant (dependencies already push ES to {})",
-                                    date, es
-                                ),
-                            )
-                        } else {
-                            // es < date shouldn't happen if scheduling is correct
-                            (
-                                ConstraintEffectType::PushedStart,
-                                format!("Constraint pushed early start to {}", date),
-                            )
-                        }
-                    }
-                    TaskConstraint::StartNoLaterThan(date) => {
-                        if ls == *date {
-                            if slack == zero_slack {
-                                (
-                                    ConstraintEffectType::CappedLate,
-                                    format!(
-                                        "Constraint made task critical (LS capped at {})",
-                                        date
-                                    ),
-                                )
-                            } else {
-                                (
-                                    ConstraintEffectType::CappedLate,
-                                    format!("Constraint capped late start at {}", date),
-                                )
-                            }
-                        } else if ls < *date {
-                            (
-                                ConstraintEffectType::Redundant,
-                                format!(
-                                    "Constraint {} redundant (successors already require LS={})",
-                                    date, ls
-                                ),
-                            )
-                        } else {
-                            (
-                                ConstraintEffectType::CappedLate,
-                                format!("Constraint caps late start at {}", date),
-                            )
-                        }
-                    }
-                    TaskConstraint::FinishNoEarlierThan(date) => {
-                        if ef == *date {
-                            (
-                                ConstraintEffectType::PushedStart,
-                                format!("Task finishes exactly on constraint boundary {}", date),
-                            )
-                        } else if ef > *date {
-                            (
-                                ConstraintEffectType::Redundant,
-                                format!(
-                                    "Constraint {} redundant (dependencies already push EF to {})",
-                                    date, ef
-                                ),
-                            )
-                        } else {
-                            (
-                                ConstraintEffectType::PushedStart,
-                                format!("Constraint pushed early finish to {}", date),
-                            )
-                        }
-                    }
-                    TaskConstraint::FinishNoLaterThan(date) => {
-                        if lf == *date {
-                            if slack == zero_slack {
-                                (
-                                    ConstraintEffectType::CappedLate,
-                                    format!(
-                                        "Constraint made task critical (LF capped at {})",
-                                        date
-                                    ),
-                                )
-                            } else {
-                                (
-                                    ConstraintEffectType::CappedLate,
-                                    format!("Constraint capped late finish at {}", date),
-                                )
-                            }
-                        } else if lf < *date {
-                            (
-                                ConstraintEffectType::Redundant,
-                                format!(
-                                    "Constraint {} redundant (successors already require LF={})",
-                                    date, lf
-                                ),
-                            )
-                        } else {
-                            (
-                                ConstraintEffectType::CappedLate,
-                                format!("Constraint caps late finish at {}", date),
-                            )
-                        }
-                    }
-                };
-
-                ConstraintEffect {
+        if !schedule.tasks.contains_key(&task.id) {
+            return task
+                .constraints
+                .iter()
+                .map(|c| ConstraintEffect {
                     constraint: c.clone(),
-                    effect,
-                    description,
-                }
-            })
-            .collect()
+                    effect: ConstraintEffectType::Redundant,
+                    description: format!("{} (task not in schedule)", Self::format_constraint(c)),
+                })
+                .collect();
+        }
+
+        schedule.constraint_effects(project, &task.id)
     }
 
     /// Format a constraint for display
@@ -363,8 +323,164 @@ impl CpmSolver {
             TaskConstraint::StartNoLaterThan(d) => format!("StartNoLaterThan({})", d),
             TaskConstraint::FinishNoEarlierThan(d) => format!("FinishNoEarlierThan({})", d),
             TaskConstraint::FinishNoLaterThan(d) => format!("FinishNoLaterThan({})", d),
+            TaskConstraint::RelativeTo {
+                anchor,
+                offset,
+                from,
+            } => format!("RelativeTo({} {} {:+}d)", anchor, from, offset.round_days()),
         }
     }
+
+    /// Schedule a project whose tasks carry `RelativeTo` constraints
+    ///
+    /// Relative anchors resolve in a second pass: schedule with them left
+    /// out, pin each task's start at the date its anchor then implies, and
+    /// reschedule until no pin moves so chains of anchors settle. Returns
+    /// `None` when the project has no relative constraints.
+    fn schedule_relative_constraints(
+        &self,
+        project: &Project,
+    ) -> Result<Option<Schedule>, ScheduleError> {
+        let relative_count = count_relative_constraints(&project.tasks);
+        if relative_count == 0 {
+            return Ok(None);
+        }
+        let calendar = project.default_calendar();
+
+        let mut pins: HashMap<TaskId, Vec<NaiveDate>> = HashMap::new();
+        let mut pinned = project.clone();
+        pin_relative_constraints(&mut pinned.tasks, "", &pins);
+        let mut schedule = self.schedule(&pinned)?;
+
+        // Each pass settles at least one more link of a chain of anchors
+        for _ in 0..relative_count {
+            let mut next = HashMap::new();
+            relative_constraint_pins(&project.tasks, "", &schedule, &calendar, &mut next)?;
+            if next == pins {
+                break;
+            }
+            pins = next;
+            let mut pinned = project.clone();
+            pin_relative_constraints(&mut pinned.tasks, "", &pins);
+            schedule = self.schedule(&pinned)?;
+        }
+        Ok(Some(schedule))
+    }
+}
+
+/// Number of `RelativeTo` constraints anywhere in the task tree
+fn count_relative_constraints(tasks: &[Task]) -> usize {
+    tasks
+        .iter()
+        .map(|task| {
+            task.constraints
+                .iter()
+                .filter(|c| matches!(c, TaskConstraint::RelativeTo { .. }))
+                .count()
+                + count_relative_constraints(&task.children)
+        })
+        .sum()
+}
+
+/// Start dates each task's `RelativeTo` constraints pin, in order, keyed by
+/// qualified task ID
+fn relative_constraint_pins(
+    tasks: &[Task],
+    parent_path: &str,
+    schedule: &Schedule,
+    calendar: &Calendar,
+    pins: &mut HashMap<TaskId, Vec<NaiveDate>>,
+) -> Result<(), ScheduleError> {
+    for task in tasks {
+        let qualified_id = if parent_path.is_empty() {
+            task.id.clone()
+        } else {
+            format!("{}.{}", parent_path, task.id)
+        };
+        for constraint in &task.constraints {
+            if let TaskConstraint::RelativeTo { anchor, .. } = constraint {
+                let date = constraint
+                    .relative_start(schedule, calendar)
+                    .ok_or_else(|| ScheduleError::TaskNotFound(anchor.clone()))?;
+                pins.entry(qualified_id.clone()).or_default().push(date);
+            }
+        }
+        relative_constraint_pins(&task.children, &qualified_id, schedule, calendar, pins)?;
+    }
+    Ok(())
+}
+
+/// Replace `RelativeTo` constraints with `MustStartOn` at their pinned
+/// dates, dropping those not yet pinned
+fn pin_relative_constraints(
+    tasks: &mut [Task],
+    parent_path: &str,
+    pins: &HashMap<TaskId, Vec<NaiveDate>>,
+) {
+    for task in tasks {
+        let qualified_id = if parent_path.is_empty() {
+            task.id.clone()
+        } else {
+            format!("{}.{}", parent_path, task.id)
+        };
+        let mut dates = pins.get(&qualified_id).into_iter().flatten();
+        task.constraints = std::mem::take(&mut task.constraints)
+            .into_iter()
+            .filter_map(|constraint| match constraint {
+                TaskConstraint::RelativeTo { .. } => {
+                    dates.next().map(|date| TaskConstraint::MustStartOn(*date))
+                }
+                other => Some(other),
+            })
+            .collect();
+        pin_relative_constraints(&mut task.children, &qualified_id, pins);
+    }
+}
+
+/// Prior start of every frozen leaf task found in `prior`, keyed by
+/// qualified task ID; children of a frozen container are frozen too
+fn frozen_task_starts(
+    tasks: &[Task],
+    parent_path: &str,
+    parent_frozen: bool,
+    prior: &Schedule,
+    pins: &mut HashMap<TaskId, NaiveDate>,
+) {
+    for task in tasks {
+        let qualified_id = if parent_path.is_empty() {
+            task.id.clone()
+        } else {
+            format!("{}.{}", parent_path, task.id)
+        };
+        let frozen = parent_frozen || task.frozen;
+        if !task.children.is_empty() {
+            frozen_task_starts(&task.children, &qualified_id, frozen, prior, pins);
+        } else if frozen {
+            if let Some(scheduled) = prior.tasks.get(&qualified_id) {
+                pins.insert(qualified_id, scheduled.start);
+            }
+        }
+    }
+}
+
+/// Add a `pin(date)` constraint to each task in `pins`
+fn pin_frozen_tasks(
+    tasks: &mut [Task],
+    parent_path: &str,
+    pins: &HashMap<TaskId, NaiveDate>,
+    pin: fn(NaiveDate) -> TaskConstraint,
+) {
+    for task in tasks {
+        let qualified_id = if parent_path.is_empty() {
+            task.id.clone()
+        } else {
+            format!("{}.{}", parent_path, task.id)
+        };
+        if let Some(date) = pins.get(&qualified_id) {
+            task.constraints.push(pin(*date));
+        }
+        pin_frozen_tasks(&mut task.children, &qualified_id, pins, pin);
+    }
 }
 
 impl Default for CpmSolver {
@@ -409,6 +525,20 @@ struct TaskNode<'a> {
     /// When a milestone has a constraint on a non-working day (e.g., Sunday release),
     /// this stores the exact date rather than advancing to the next working day.
     pinned_date: Option<NaiveDate>,
+    /// Task's own calendar when it differs from the project calendar
+    /// (task override, or the assigned resource's calendar restricted to
+    /// project working days)
+    task_calendar: Option<Calendar>,
+    /// Forecast start/finish placed on `task_calendar`
+    calendar_dates: Option<(NaiveDate, NaiveDate)>,
+    /// Baseline start/finish placed on `task_calendar`
+    baseline_calendar_dates: Option<(NaiveDate, NaiveDate)>,
+    /// Late start/finish placed on `task_calendar`
+    late_calendar_dates: Option<(NaiveDate, NaiveDate)>,
+    /// Predecessor whose dependency binds the forward-pass early start
+    driving_predecessor: Option<String>,
+    /// Ceiling that bound the backward-pass late dates (`None` for network)
+    late_driver: Option<CriticalReason>,
 }
 
 // =============================================================================
@@ -454,12 +584,9 @@ enum ProgressState {
 /// - complete = 100% → Complete (remaining = 0)
 /// - 0 < complete < 100 with actual_start → InProgress
 /// - else → NotStarted
-fn classify_progress_state(
-    task: &Task,
-    project_start: NaiveDate,
-    calendar: &Calendar,
-) -> ProgressState {
-    let duration_days = get_task_duration_days(task);
+fn classify_progress_state(task: &Task, project: &Project, calendar: &Calendar) -> ProgressState {
+    let project_start = project.start;
+    let duration_days = get_task_duration_days(task, project, calendar);
     let complete_pct = task.complete.unwrap_or(0.0);
 
     if complete_pct >= 100.0 || task.actual_finish.is_some() {
@@ -502,54 +629,9 @@ fn classify_progress_state(
 // Helper Functions
 // =============================================================================
 
-/// Flatten the hierarchical task tree into a HashMap with qualified IDs
-///
-/// For nested tasks like:
-///   phase1 { act1 { sub1 } }
-///
-/// This produces:
-///   "phase1" -> phase1
-///   "phase1.act1" -> act1
-///   "phase1.act1.sub1" -> sub1
-///
-/// Also builds a context map for resolving relative dependencies:
-///   "phase1.act1" -> "phase1" (parent context for resolving siblings)
-fn flatten_tasks_with_prefix<'a>(
-    tasks: &'a [Task],
-    prefix: &str,
-    map: &mut HashMap<String, &'a Task>,
-    context_map: &mut HashMap<String, String>,
-) {
-    for task in tasks {
-        let qualified_id = if prefix.is_empty() {
-            task.id.clone()
-        } else {
-            format!("{}.{}", prefix, task.id)
-        };
-
-        map.insert(qualified_id.clone(), task);
-        context_map.insert(qualified_id.clone(), prefix.to_string());
-
-        if !task.children.is_empty() {
-            flatten_tasks_with_prefix(&task.children, &qualified_id, map, context_map);
-        }
-    }
-}
-
 /// Flatten the hierarchical task tree into a HashMap (convenience wrapper)
 fn flatten_tasks<'a>(tasks: &'a [Task], map: &mut HashMap<String, &'a Task>) {
-    let mut context_map = HashMap::new();
-    flatten_tasks_with_prefix(tasks, "", map, &mut context_map);
-}
-
-/// Flatten tasks and return both the task map and context map for dependency resolution
-fn flatten_tasks_with_context<'a>(
-    tasks: &'a [Task],
-) -> (HashMap<String, &'a Task>, HashMap<String, String>) {
-    let mut task_map = HashMap::new();
-    let mut context_map = HashMap::new();
-    flatten_tasks_with_prefix(tasks, "", &mut task_map, &mut context_map);
-    (task_map, context_map)
+    map.extend(flatten_tasks_with_context(tasks).0);
 }
 
 /// Extract task ID from an infeasible constraint error message
@@ -565,96 +647,367 @@ fn extract_task_from_infeasible_message(msg: &str) -> Option<String> {
     None
 }
 
-/// Build a map from parent qualified ID to list of direct children qualified IDs
-fn build_children_map(task_map: &HashMap<String, &Task>) -> HashMap<String, Vec<String>> {
-    let mut children_map: HashMap<String, Vec<String>> = HashMap::new();
+/// Weight of a leaf task in the project progress roll-up
+///
+/// Effort is counted in working days of `calendar`, like durations.
+fn progress_weight(
+    weighting: ProgressWeighting,
+    scheduled: &ScheduledTask,
+    task: Option<&Task>,
+    calendar: &Calendar,
+) -> f64 {
+    match weighting {
+        ProgressWeighting::ByDuration => scheduled.duration.as_days().trunc(),
+        ProgressWeighting::ByEffort => task
+            .and_then(|t| t.effort)
+            .map_or(scheduled.duration.as_days(), |effort| {
+                calendar.effort_days(effort)
+            }),
+        ProgressWeighting::ByCost => scheduled
+            .cost_range
+            .as_ref()
+            .and_then(|range| range.expected.to_f64())
+            .unwrap_or(0.0),
+    }
+}
+
+/// Whether a dependency type is anchored on the predecessor's start
+///
+/// SS and SF read the predecessor's start; FS and FF read its finish.
+fn is_start_anchored(dep_type: DependencyType) -> bool {
+    matches!(
+        dep_type,
+        DependencyType::StartToStart | DependencyType::StartToFinish
+    )
+}
 
-    for qualified_id in task_map.keys() {
-        // Find the parent by removing the last component
-        if let Some(dot_pos) = qualified_id.rfind('.') {
-            let parent_id = &qualified_id[..dot_pos];
-            children_map
-                .entry(parent_id.to_string())
-                .or_default()
-                .push(qualified_id.clone());
+/// Collect the leaf tasks nested (at any depth) under a container
+fn descendant_leaves(
+    container_id: &str,
+    children_map: &HashMap<String, Vec<String>>,
+) -> Vec<String> {
+    let mut leaves = Vec::new();
+    let mut stack = vec![container_id.to_string()];
+    while let Some(id) = stack.pop() {
+        match children_map.get(&id) {
+            Some(children) => stack.extend(children.iter().cloned()),
+            None if id != container_id => leaves.push(id),
+            None => {}
         }
     }
-
-    children_map
+    leaves
 }
 
-/// Resolve a dependency path to a qualified task ID
+/// The leaf that gates a dependency on a container
 ///
-/// Handles:
-/// - Absolute paths: "phase1.act1" -> "phase1.act1"
-/// - Relative paths: "act1" (from phase1.act2) -> "phase1.act1"
-fn resolve_dependency_path(
-    dep_path: &str,
-    from_qualified_id: &str,
-    context_map: &HashMap<String, String>,
-    task_map: &HashMap<String, &Task>,
+/// A container stands for the span of its descendant leaves: a
+/// finish-anchored link (FS, FF) waits for the latest-finishing leaf and
+/// a start-anchored link (SS, SF) follows the earliest-starting leaf.
+/// Ties go to the lowest qualified ID so the choice is deterministic.
+fn container_gate_leaf(
+    container_id: &str,
+    dep_type: DependencyType,
+    children_map: &HashMap<String, Vec<String>>,
+    nodes: &HashMap<String, TaskNode>,
 ) -> Option<String> {
-    // First, try as absolute path
-    if task_map.contains_key(dep_path) {
-        return Some(dep_path.to_string());
+    let mut leaves: Vec<(&String, &TaskNode)> = Vec::new();
+    let ids = descendant_leaves(container_id, children_map);
+    for id in &ids {
+        if let Some(node) = nodes.get(id) {
+            leaves.push((id, node));
+        }
     }
+    leaves.sort_by(|a, b| a.0.cmp(b.0));
+    let gate = if is_start_anchored(dep_type) {
+        leaves.into_iter().min_by_key(|(_, node)| node.early_start)
+    } else {
+        // max_by_key keeps the last maximum, so compare on reversed IDs
+        leaves
+            .into_iter()
+            .rev()
+            .max_by_key(|(_, node)| node.early_finish)
+    };
+    gate.map(|(id, _)| id.clone())
+}
 
-    // If path contains a dot, it's meant to be absolute - don't try relative resolution
-    if dep_path.contains('.') {
-        return None;
+/// Replace computed baseline dates with those of an attached baseline (RFC-0013)
+///
+/// Leaf tasks recorded in the baseline take its start and finish; tasks
+/// added since keep their computed plan. Containers then span their
+/// children's baseline dates, and variance is re-measured against them.
+fn apply_attached_baseline(
+    baseline: &Baseline,
+    scheduled_tasks: &mut HashMap<String, ScheduledTask>,
+    children_map: &HashMap<String, Vec<String>>,
+) {
+    for (id, task) in scheduled_tasks.iter_mut() {
+        if children_map.contains_key(id) {
+            continue;
+        }
+        if let Some(snapshot) = baseline.tasks.get(id) {
+            task.baseline_start = snapshot.start;
+            task.baseline_finish = snapshot.finish;
+        }
     }
 
-    // Try relative resolution: look in the same container
-    if let Some(container) = context_map.get(from_qualified_id) {
-        let qualified = if container.is_empty() {
-            dep_path.to_string()
-        } else {
-            format!("{}.{}", container, dep_path)
-        };
+    // Process deepest containers first so parents see updated children
+    let mut container_ids: Vec<&String> = children_map.keys().collect();
+    container_ids.sort_by_key(|id| std::cmp::Reverse(id.matches('.').count()));
+    for container_id in container_ids {
+        let span = children_map[container_id]
+            .iter()
+            .filter_map(|child_id| scheduled_tasks.get(child_id))
+            .map(|child| (child.baseline_start, child.baseline_finish))
+            .reduce(|(start, finish), (s, f)| (start.min(s), finish.max(f)));
+        if let (Some((start, finish)), Some(task)) = (span, scheduled_tasks.get_mut(container_id)) {
+            task.baseline_start = start;
+            task.baseline_finish = finish;
+        }
+    }
 
-        if task_map.contains_key(&qualified) {
-            return Some(qualified);
+    for task in scheduled_tasks.values_mut() {
+        task.start_variance_days = (task.forecast_start - task.baseline_start).num_days();
+        task.finish_variance_days = (task.forecast_finish - task.baseline_finish).num_days();
+    }
+}
+
+/// Add each container's descendant cost ranges to its own
+///
+/// Processes the deepest containers first, so a phase's range is the sum
+/// of its leaves' ranges (plus any cost assigned to containers on the way).
+/// A container with costs in more than one currency below it gets no range.
+fn roll_up_container_costs(
+    scheduled_tasks: &mut HashMap<String, ScheduledTask>,
+    children_map: &HashMap<String, Vec<String>>,
+) {
+    // Tasks whose own assignments could not be summed
+    let mut mixed: HashSet<String> = scheduled_tasks
+        .iter()
+        .filter(|(_, st)| {
+            st.cost_range.is_none() && st.assignments.iter().any(|a| a.cost_range.is_some())
+        })
+        .map(|(id, _)| id.clone())
+        .collect();
+
+    let mut container_ids: Vec<&String> = children_map.keys().collect();
+    container_ids.sort_by_key(|id| std::cmp::Reverse(id.matches('.').count()));
+    for container_id in container_ids {
+        let children = &children_map[container_id];
+        if children.iter().any(|child_id| mixed.contains(child_id)) {
+            if let Some(task) = scheduled_tasks.get_mut(container_id) {
+                task.cost_range = None;
+            }
+            mixed.insert(container_id.clone());
+            continue;
+        }
+        let mut ranges: Vec<CostRange> = children
+            .iter()
+            .filter_map(|child_id| scheduled_tasks.get(child_id)?.cost_range.clone())
+            .collect();
+        if ranges.is_empty() {
+            continue;
+        }
+        if let Some(task) = scheduled_tasks.get_mut(container_id) {
+            ranges.extend(task.cost_range.take());
+            task.cost_range = aggregate_cost_ranges(&ranges).unwrap_or_else(|_| {
+                mixed.insert(container_id.clone());
+                None
+            });
         }
     }
+}
 
-    None
+/// Set each task's free slack from its direct successor links
+///
+/// Same rule as [`Schedule::free_slack`], in one pass over the
+/// dependencies: each link allows the working days between the linked
+/// dates (per dependency type, less the lag), and a task's free slack is
+/// the smallest of these, capped at its total slack and never negative.
+fn assign_free_slack(
+    scheduled_tasks: &mut HashMap<String, ScheduledTask>,
+    task_map: &HashMap<String, &Task>,
+    context_map: &HashMap<String, String>,
+    calendar: &Calendar,
+) {
+    let mut free: HashMap<String, i64> = scheduled_tasks
+        .iter()
+        .map(|(id, st)| (id.clone(), st.slack.round_days()))
+        .collect();
+
+    for (succ_id, task) in task_map {
+        let Some(succ) = scheduled_tasks.get(succ_id) else {
+            continue;
+        };
+        for dep in &task.depends {
+            let Some(pred_id) =
+                resolve_dependency_path(&dep.predecessor, succ_id, context_map, task_map)
+            else {
+                continue;
+            };
+            let Some(pred) = scheduled_tasks.get(&pred_id) else {
+                continue;
+            };
+            let Some((from, to)) = dep.dep_type.linked_dates(pred, succ) else {
+                continue;
+            };
+            let lag = dep.lag.map_or(0, |l| l.round_days());
+            let room = calendar.working_days_between(from, to) - lag;
+            if let Some(slack) = free.get_mut(&pred_id) {
+                *slack = (*slack).min(room);
+            }
+        }
+    }
+
+    for (id, st) in scheduled_tasks.iter_mut() {
+        st.free_slack = Duration::days(free[id].max(0));
+    }
 }
 
 /// Get the duration of a task in working days
 ///
 /// For effort-driven tasks (PMI "Fixed Work"):
-///   Duration = Effort / Total_Resource_Units
+///   Duration = Effort_Hours / Hours_Per_Day / Total_Resource_Units
 ///
-/// Where Total_Resource_Units is the sum of all assigned resource allocation
-/// percentages (e.g., 1.0 = 100%, 0.5 = 50%).
+/// Where Hours_Per_Day comes from the calendar's working hours (7 for the
+/// default 9:00-12:00, 13:00-17:00 calendar; 8 when it defines none), and
+/// Total_Resource_Units is the sum of all assigned resource allocation
+/// percentages (e.g., 1.0 = 100%, 0.5 = 50%), each scaled by the resource's
+/// availability (RFC-0001). Profiles and unknown resources count as fully
+/// available. Fixed durations are already in working days and milestones
+/// take none, so neither depends on the calendar.
 ///
-/// Examples:
-/// - 40h effort with 1 resource @ 100% = 5 days
-/// - 40h effort with 1 resource @ 50% = 10 days
-/// - 40h effort with 2 resources @ 100% each = 2.5 days
-fn get_task_duration_days(task: &Task) -> i64 {
+/// Examples, on a 7-hour calendar:
+/// - 40h effort with 1 resource @ 100% = ceil(5.7) = 6 days
+/// - 35h effort with 1 resource @ 50% = 10 days
+/// - 35h effort with 1 resource @ 100%, availability 0.5 = 10 days
+/// - 35h effort with 2 resources @ 100% each = ceil(2.5) = 3 days
+fn get_task_duration_days(task: &Task, project: &Project, calendar: &Calendar) -> i64 {
     // If explicit duration is set, use that (Fixed Duration task type)
     if let Some(dur) = task.duration {
         return dur.as_days().ceil() as i64;
     }
 
-    // Effort-driven: Duration = Effort / Total_Resource_Units
+    // Effort-driven: Duration = Effort_Hours / Hours_Per_Day / Total_Resource_Units
     if let Some(effort) = task.effort {
-        let total_units: f64 = if task.assigned.is_empty() {
-            1.0 // Default: assume 1 resource at 100%
-        } else {
-            task.assigned.iter().map(|r| r.units as f64).sum()
-        };
-
-        // Prevent division by zero
-        let effective_units = if total_units > 0.0 { total_units } else { 1.0 };
-        return (effort.as_days() / effective_units).ceil() as i64;
+        return (calendar.effort_days(effort) / effective_units(task, project)).ceil() as i64;
     }
 
     // Milestone or summary task
     0
 }
 
+/// Resource units working an effort-driven task, scaled by availability
+///
+/// Unassigned tasks (and tasks whose units sum to zero) count as one
+/// resource at 100% so the division in the effort math stays defined.
+fn effective_units(task: &Task, project: &Project) -> f64 {
+    let total_units: f64 = if task.assigned.is_empty() {
+        1.0 // Default: assume 1 resource at 100%
+    } else {
+        task.assigned
+            .iter()
+            .map(|r| assignment_work_units(r, project))
+            .sum()
+    };
+
+    // Prevent division by zero
+    if total_units > 0.0 {
+        total_units
+    } else {
+        1.0
+    }
+}
+
+/// Units one assignment works, scaled by availability
+///
+/// Consumable and material resources are used up or paid for, not worked,
+/// so they count zero and do not shorten the task.
+fn assignment_work_units(assignment: &ResourceRef, project: &Project) -> f64 {
+    match project.get_resource(&assignment.resource_id) {
+        Some(res) if res.is_consumable() || res.is_material() => 0.0,
+        Some(res) => assignment.units as f64 * res.effective_availability() as f64,
+        None => assignment.units as f64,
+    }
+}
+
+/// Whether any task in the tree is inactive
+fn has_inactive_tasks(tasks: &[Task]) -> bool {
+    tasks
+        .iter()
+        .any(|task| !task.active || has_inactive_tasks(&task.children))
+}
+
+/// Drop inactive tasks, with everything under them, from the tree
+fn prune_inactive_tasks(tasks: &mut Vec<Task>) {
+    tasks.retain(|task| task.active);
+    for task in tasks {
+        prune_inactive_tasks(&mut task.children);
+    }
+}
+
+/// Reject calendars on which no day can ever be a working day
+///
+/// Checks the project calendar and every calendar a task is scheduled on.
+/// Advancing to the next working day on such a calendar would loop
+/// forever, so this guards the solver even when C002 was not consulted.
+fn check_working_calendars(project: &Project) -> Result<(), ScheduleError> {
+    let calendar = project.default_calendar();
+    if !calendar.has_working_days() {
+        return Err(ScheduleError::InvalidCalendar(calendar.id));
+    }
+
+    let mut task_map = HashMap::new();
+    flatten_tasks(&project.tasks, &mut task_map);
+    let mut ids: Vec<&String> = task_map.keys().collect();
+    ids.sort();
+    for id in ids {
+        if let Some(task_calendar) = resolve_task_calendar(task_map[id], project, &calendar) {
+            if !task_calendar.has_working_days() {
+                return Err(ScheduleError::InvalidCalendar(task_calendar.id.clone()));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Reject tasks whose assigned resources can never do any work
+///
+/// A renewable resource with zero capacity or zero availability has no
+/// effective capacity. A task staffed only by such resources could never
+/// progress; one that also has a working assignee just runs without the
+/// idle one (W018 reports it). Consumables and materials are used up or
+/// paid for rather than worked and are not checked.
+fn check_assigned_capacity(
+    task_map: &HashMap<String, &Task>,
+    project: &Project,
+) -> Result<(), ScheduleError> {
+    let mut ids: Vec<&String> = task_map.keys().collect();
+    ids.sort();
+    for id in ids {
+        let mut idle = None;
+        let mut working = false;
+        for assignment in &task_map[id].assigned {
+            match project.get_resource(&assignment.resource_id) {
+                Some(resource) if resource.is_consumable() || resource.is_material() => {}
+                Some(resource) if resource.effective_capacity() <= 0.0 => {
+                    idle.get_or_insert(resource);
+                }
+                _ => working = true,
+            }
+        }
+        if let (Some(resource), false) = (idle, working) {
+            return Err(ScheduleError::Infeasible(format!(
+                "task '{}' can never progress: assigned resource '{}' has zero effective capacity (capacity {}, availability {})",
+                id,
+                resource.id,
+                resource.capacity,
+                resource.effective_availability()
+            )));
+        }
+    }
+    Ok(())
+}
+
 /// Pre-computed mapping from working day index to calendar date
 /// This provides O(1) lookup instead of O(days) recalculation
 struct WorkingDayCache {
@@ -664,9 +1017,15 @@ struct WorkingDayCache {
 
 impl WorkingDayCache {
     /// Build a cache for the given project duration
+    ///
+    /// A calendar with no working days caches only day 0, so every offset
+    /// maps to the project start instead of searching forever.
     fn new(project_start: NaiveDate, max_days: i64, calendar: &Calendar) -> Self {
         let mut dates = Vec::with_capacity((max_days + 1) as usize);
         dates.push(project_start); // Day 0 = project start
+        if !calendar.has_working_days() {
+            return Self { dates };
+        }
 
         let mut current = project_start;
         for _ in 0..max_days {
@@ -714,6 +1073,67 @@ fn date_to_working_days(project_start: NaiveDate, target: NaiveDate, calendar: &
     working_days
 }
 
+/// Earliest start (working day index) a single dependency allows
+///
+/// `pred_es`/`pred_ef` are the predecessor's start and exclusive finish,
+/// `lag` is in whole working days and `duration` is the successor's.
+fn dependency_earliest_start(
+    dep_type: DependencyType,
+    lag: i64,
+    pred_es: i64,
+    pred_ef: i64,
+    duration: i64,
+) -> i64 {
+    match dep_type {
+        DependencyType::FinishToStart => {
+            if lag >= 0 {
+                pred_ef + lag
+            } else {
+                (pred_ef - 1 + lag).max(0)
+            }
+        }
+        DependencyType::StartToStart => pred_es + lag,
+        DependencyType::FinishToFinish => (pred_ef + lag - duration).max(0),
+        DependencyType::StartToFinish => (pred_es + lag - duration).max(0),
+    }
+}
+
+/// A dependency's lag in whole working days
+///
+/// Elapsed (calendar-day) lags are converted by counting the working days
+/// they cover, measured from the predecessor's start (SS, SF) or from the
+/// day after it finishes (FS, FF). The successor then lands on the first
+/// working day once the elapsed time has passed.
+fn dependency_lag_days(
+    dep: &Dependency,
+    pred_es: i64,
+    pred_ef: i64,
+    project_start: NaiveDate,
+    calendar: &Calendar,
+) -> i64 {
+    let lag = dep.lag.map(|d| d.round_days()).unwrap_or(0);
+    if !dep.lag_calendar || lag == 0 {
+        return lag;
+    }
+
+    let anchor = if is_start_anchored(dep.dep_type) {
+        leveling::add_working_days(project_start, pred_es, calendar)
+    } else {
+        leveling::add_working_days(project_start, pred_ef - 1, calendar) + TimeDelta::days(1)
+    };
+    let (from, to) = if lag > 0 {
+        (anchor, anchor + TimeDelta::days(lag))
+    } else {
+        (anchor + TimeDelta::days(lag), anchor)
+    };
+    let working_days = from
+        .iter_days()
+        .take_while(|date| *date < to)
+        .filter(|date| calendar.is_working_day(*date))
+        .count() as i64;
+    working_days * lag.signum()
+}
+
 /// Advance a date to the next working day if it falls on a non-working day.
 /// Used for "no earlier than" constraints where we need to round forward.
 fn advance_to_working_day(date: NaiveDate, calendar: &Calendar) -> NaiveDate {
@@ -724,120 +1144,94 @@ fn advance_to_working_day(date: NaiveDate, calendar: &Calendar) -> NaiveDate {
     current
 }
 
-/// Result of topological sort including precomputed successor map
-struct TopoSortResult {
-    /// Tasks in topological order
-    sorted_ids: Vec<String>,
-    /// Map from task ID to its successors (tasks that depend on it)
-    successors: HashMap<String, Vec<String>>,
-}
-
-/// Perform topological sort using Kahn's algorithm
-/// Returns sorted task IDs and a precomputed successors map
+/// Resolve the calendar a task runs on when it differs from the project calendar.
 ///
-/// This ensures:
-/// 1. Tasks come after their dependencies (explicit edges)
-/// 2. Container tasks come after their children (implicit edges)
-fn topological_sort(
-    tasks: &HashMap<String, &Task>,
-    context_map: &HashMap<String, String>,
-) -> Result<TopoSortResult, ScheduleError> {
-    // Build children map for container handling
-    let children_map = build_children_map(tasks);
-
-    // Build adjacency list, in-degree count, and successors map
-    let mut in_degree: HashMap<String, usize> = HashMap::new();
-    let mut adjacency: HashMap<String, Vec<String>> = HashMap::new();
-    let mut successors: HashMap<String, Vec<String>> = HashMap::new();
-
-    // Initialize all tasks with 0 in-degree and empty successors
-    for id in tasks.keys() {
-        in_degree.insert(id.clone(), 0);
-        adjacency.insert(id.clone(), Vec::new());
-        successors.insert(id.clone(), Vec::new());
-    }
-
-    // Add implicit edges: children -> container (container comes after children)
-    for (container_id, children) in &children_map {
-        for child_id in children {
-            // child -> container edge
-            if let Some(adj) = adjacency.get_mut(child_id) {
-                adj.push(container_id.clone());
-            }
-            if let Some(deg) = in_degree.get_mut(container_id) {
-                *deg += 1;
-            }
-            // Note: container is not a "real" successor for backward pass
-        }
-    }
-
-    // Build the graph with resolved dependency paths
-    for (qualified_id, task) in tasks {
-        for dep in &task.depends {
-            // Resolve the dependency path (handles both absolute and relative)
-            let resolved =
-                resolve_dependency_path(&dep.predecessor, qualified_id, context_map, tasks);
-
-            if let Some(pred_id) = resolved {
-                // pred_id -> qualified_id (predecessor must come before this task)
-                if let Some(adj) = adjacency.get_mut(&pred_id) {
-                    adj.push(qualified_id.clone());
-                }
-                if let Some(deg) = in_degree.get_mut(qualified_id) {
-                    *deg += 1;
-                }
-                // Build successors map: pred_id has qualified_id as successor
-                if let Some(succ) = successors.get_mut(&pred_id) {
-                    succ.push(qualified_id.clone());
-                }
-            }
-            // If dependency can't be resolved, we skip it (might be external or error)
-        }
+/// Precedence: task override > assigned resource (or profile) calendar > project.
+/// A task override is used as is; a resource calendar is intersected with the
+/// project calendar, so the task only works days both calendars work.
+/// Returns `None` when the task follows the project calendar, or when the
+/// referenced calendar is not defined in the project.
+fn resolve_task_calendar(
+    task: &Task,
+    project: &Project,
+    project_calendar: &Calendar,
+) -> Option<Calendar> {
+    let find = |id: &String| {
+        (*id != project_calendar.id)
+            .then(|| project.calendars.iter().find(|c| c.id == *id))
+            .flatten()
+    };
+    if let Some(id) = &task.calendar {
+        return find(id).cloned();
     }
+    let calendar_id = task.assigned.iter().find_map(|r| {
+        project
+            .get_resource(&r.resource_id)
+            .and_then(|res| res.calendar.as_ref())
+            .or_else(|| {
+                project
+                    .get_profile(&r.resource_id)
+                    .and_then(|p| p.calendar.as_ref())
+            })
+    })?;
+    find(calendar_id).map(|c| c.intersect(project_calendar))
+}
 
-    // Kahn's algorithm
-    let mut queue: VecDeque<String> = VecDeque::new();
-    let mut result: Vec<String> = Vec::new();
-
-    // Start with tasks that have no dependencies
-    for (id, &degree) in &in_degree {
-        if degree == 0 {
-            queue.push_back(id.clone());
-        }
-    }
+/// Place a task on its own calendar, starting from a project working-day offset.
+///
+/// The task starts on the first day at or after `es` that is working in
+/// `task_calendar` and runs `duration_days` working days of that calendar.
+/// Returns the start/finish dates and the exclusive early finish mapped back
+/// onto the project working-day axis, so successors align on the next working
+/// day after the task's actual finish.
+fn place_on_task_calendar(
+    project_start: NaiveDate,
+    es: i64,
+    duration_days: i64,
+    project_calendar: &Calendar,
+    task_calendar: &Calendar,
+) -> (NaiveDate, NaiveDate, i64) {
+    let axis_date = leveling::add_working_days(project_start, es, project_calendar);
+    let start = advance_to_working_day(axis_date, task_calendar);
+    if duration_days <= 0 {
+        return (start, start, es);
+    }
+
+    let finish = leveling::add_working_days(start, duration_days - 1, task_calendar);
+    let ef = date_to_working_days(project_start, finish, project_calendar) + 1;
+    (start, finish, ef.max(es + 1))
+}
 
-    while let Some(id) = queue.pop_front() {
-        result.push(id.clone());
-
-        if let Some(successors) = adjacency.get(&id) {
-            for successor in successors {
-                if let Some(deg) = in_degree.get_mut(successor) {
-                    *deg -= 1;
-                    if *deg == 0 {
-                        queue.push_back(successor.clone());
-                    }
-                }
-            }
+/// Place a task on its own calendar, finishing before a project working-day offset.
+///
+/// The backward-pass mirror of [`place_on_task_calendar`]: the task finishes
+/// on the last day working in `task_calendar` before the project working day
+/// `lf` (exclusive) and runs `duration_days` working days of that calendar.
+/// Returns the start/finish dates and the late start on the project
+/// working-day axis: the latest offset from which the forward placement
+/// still starts on or before the returned start.
+fn place_late_on_task_calendar(
+    project_start: NaiveDate,
+    lf: i64,
+    duration_days: i64,
+    project_calendar: &Calendar,
+    task_calendar: &Calendar,
+) -> (NaiveDate, NaiveDate, i64) {
+    let mut finish =
+        leveling::add_working_days(project_start, lf, project_calendar) - TimeDelta::days(1);
+    while !task_calendar.is_working_day(finish) {
+        finish = finish - TimeDelta::days(1);
+    }
+    let mut start = finish;
+    let mut remaining = duration_days - 1;
+    while remaining > 0 {
+        start = start - TimeDelta::days(1);
+        if task_calendar.is_working_day(start) {
+            remaining -= 1;
         }
     }
-
-    // Check for cycles
-    if result.len() != tasks.len() {
-        let remaining: Vec<_> = tasks
-            .keys()
-            .filter(|id| !result.contains(id))
-            .cloned()
-            .collect();
-        return Err(ScheduleError::CircularDependency(format!(
-            "Cycle detected involving tasks: {:?}",
-            remaining
-        )));
-    }
-
-    Ok(TopoSortResult {
-        sorted_ids: result,
-        successors,
-    })
+    let ls = date_to_working_days(project_start, start, project_calendar);
+    (start, finish, ls)
 }
 
 // =============================================================================
@@ -852,6 +1246,8 @@ enum ResolvedAssignment<'a> {
         #[allow(dead_code)]
         resource_id: &'a str,
     },
+    /// Material resource with a one-time cost
+    Material { cost: Option<&'a Money> },
     /// Abstract profile with rate range
     Abstract {
         rate_range: Option<RateRange>,
@@ -864,6 +1260,11 @@ enum ResolvedAssignment<'a> {
 fn resolve_assignment<'a>(resource_id: &'a str, project: &'a Project) -> ResolvedAssignment<'a> {
     // First, check if it's a concrete resource
     if let Some(resource) = project.get_resource(resource_id) {
+        if resource.is_material() {
+            return ResolvedAssignment::Material {
+                cost: resource.cost.as_ref(),
+            };
+        }
         return ResolvedAssignment::Concrete {
             rate: resource.rate.as_ref(),
             resource_id,
@@ -898,6 +1299,25 @@ fn resolve_profile_rate(profile: &ResourceProfile, project: &Project) -> Option<
     Some(base_rate.apply_multiplier(trait_multiplier))
 }
 
+/// Break a profile's rate down into its base range and trait multipliers
+///
+/// `rate` is the same range [`resolve_profile_rate`] uses for costing.
+fn explain_profile_cost(profile: &ResourceProfile, project: &Project) -> Option<CostExplanation> {
+    let base_rate = get_profile_rate_range(profile, project)?;
+    let trait_multipliers = profile
+        .traits
+        .iter()
+        .filter_map(|id| project.get_trait(id))
+        .map(|t| (t.id.clone(), t.rate_multiplier))
+        .collect();
+    let rate = resolve_profile_rate(profile, project)?;
+    Some(CostExplanation {
+        base_rate,
+        trait_multipliers,
+        rate,
+    })
+}
+
 /// Get the rate range for a profile, walking up the specialization chain if needed
 fn get_profile_rate_range(profile: &ResourceProfile, project: &Project) -> Option<RateRange> {
     // If this profile has a rate, use it
@@ -933,10 +1353,17 @@ fn calculate_trait_multiplier(trait_ids: &[String], project: &Project) -> f64 {
 }
 
 /// Calculate cost range for a single assignment
+///
+/// Work resources cost `rate × person_days` (units times working days, or
+/// the assignment's share of the effort for effort-driven tasks), material
+/// resources their one-time `cost × units`. The expected cost of a profile
+/// assignment follows `project.cost_policy`; `task_id` keys the draw for
+/// `CostPolicy::Triangular`.
 fn calculate_assignment_cost(
+    task_id: &str,
     resource_id: &str,
     units: f32,
-    duration_days: i64,
+    person_days: Decimal,
     project: &Project,
 ) -> (Option<CostRange>, bool) {
     let resolved = resolve_assignment(resource_id, project);
@@ -944,31 +1371,43 @@ fn calculate_assignment_cost(
     match resolved {
         ResolvedAssignment::Concrete { rate, .. } => {
             if let Some(money) = rate {
-                // Fixed cost: rate × units × days
-                let units_dec = Decimal::from_f32(units).unwrap_or(Decimal::ONE);
-                let days_dec = Decimal::from(duration_days);
-                let cost = money.amount * units_dec * days_dec;
-                let cost_range = CostRange::fixed(cost, &money.currency);
+                // Fixed cost: rate × person-days
+                let cost = money.amount * person_days;
+                let cost_range =
+                    CostRange::fixed(cost, &money.currency).with_policy(project.cost_policy);
                 (Some(cost_range), false)
             } else {
                 (None, false)
             }
         }
+        ResolvedAssignment::Material { cost } => {
+            // One-time cost: cost × units, whatever the duration
+            let cost_range = cost.map(|money| {
+                let units_dec = Decimal::from_f32(units).unwrap_or(Decimal::ONE);
+                CostRange::fixed(money.amount * units_dec, &money.currency)
+                    .with_policy(project.cost_policy)
+            });
+            (cost_range, false)
+        }
         ResolvedAssignment::Abstract { rate_range, .. } => {
             if let Some(range) = rate_range {
-                // Cost range: (min, expected, max) × units × days
-                let units_dec = Decimal::from_f32(units).unwrap_or(Decimal::ONE);
-                let days_dec = Decimal::from(duration_days);
-                let factor = units_dec * days_dec;
+                // Cost range: (min, expected, max) × person-days
+                let factor = person_days;
                 let min_cost = range.min * factor;
                 let max_cost = range.max * factor;
-                let expected_cost = range.expected() * factor;
+                let expected_rate = project.cost_policy.expected_for(
+                    range.min,
+                    range.max,
+                    &format!("{}:{}", task_id, resource_id),
+                );
+                let expected_cost = expected_rate * factor;
                 let currency = range
                     .currency
                     .clone()
                     .unwrap_or_else(|| project.currency.clone());
 
-                let cost_range = CostRange::new(min_cost, expected_cost, max_cost, currency);
+                let cost_range = CostRange::new(min_cost, expected_cost, max_cost, currency)
+                    .with_policy(project.cost_policy);
                 (Some(cost_range), true)
             } else {
                 (None, true)
@@ -978,16 +1417,26 @@ fn calculate_assignment_cost(
 }
 
 /// Aggregate cost ranges from multiple assignments
-fn aggregate_cost_ranges(ranges: &[CostRange]) -> Option<CostRange> {
+///
+/// Fails with the sorted, distinct currencies when the ranges do not all
+/// share one, since their amounts cannot be added up.
+fn aggregate_cost_ranges(ranges: &[CostRange]) -> Result<Option<CostRange>, Vec<String>> {
     if ranges.is_empty() {
-        return None;
+        return Ok(None);
+    }
+
+    let mut currencies: Vec<String> = ranges.iter().map(|r| r.currency.clone()).collect();
+    currencies.sort();
+    currencies.dedup();
+    if currencies.len() > 1 {
+        return Err(currencies);
     }
 
     let mut total = ranges[0].clone();
     for range in &ranges[1..] {
         total = total.add(range);
     }
-    Some(total)
+    Ok(Some(total))
 }
 
 // =============================================================================
@@ -1001,6 +1450,14 @@ pub struct AnalysisConfig {
     pub file: Option<PathBuf>,
     /// Cost spread threshold for W002 (percentage, default 50)
     pub cost_spread_threshold: f64,
+    /// Demand/capacity ratio above which W008 fires (default 1.5)
+    pub capacity_shortfall_ratio: f64,
+    /// Manual vs derived container progress gap above which P006 fires
+    /// (percentage points, default 10)
+    pub progress_mismatch_threshold: u8,
+    /// Finish slip in days above which W006 fires and I004 turns red
+    /// (default 5)
+    pub variance_threshold_days: i64,
 }
 
 impl Default for AnalysisConfig {
@@ -1008,6 +1465,9 @@ impl Default for AnalysisConfig {
         Self {
             file: None,
             cost_spread_threshold: 50.0,
+            capacity_shortfall_ratio: 1.5,
+            progress_mismatch_threshold: 10,
+            variance_threshold_days: 5,
         }
     }
 }
@@ -1026,6 +1486,21 @@ impl AnalysisConfig {
         self.cost_spread_threshold = threshold;
         self
     }
+
+    pub fn with_capacity_shortfall_ratio(mut self, ratio: f64) -> Self {
+        self.capacity_shortfall_ratio = ratio;
+        self
+    }
+
+    pub fn with_progress_mismatch_threshold(mut self, threshold: u8) -> Self {
+        self.progress_mismatch_threshold = threshold;
+        self
+    }
+
+    pub fn with_variance_threshold(mut self, days: i64) -> Self {
+        self.variance_threshold_days = days;
+        self
+    }
 }
 
 /// Analyze a project and emit diagnostics
@@ -1033,12 +1508,26 @@ impl AnalysisConfig {
 /// This function performs semantic analysis on a project and emits
 /// diagnostics for issues like abstract assignments, unused profiles, etc.
 ///
-/// Call this after parsing but before or during scheduling.
+/// Call this after parsing but before or during scheduling. It runs
+/// [`lint_structure`] followed by [`analyze_with_schedule`].
 pub fn analyze_project(
     project: &Project,
     schedule: Option<&Schedule>,
     config: &AnalysisConfig,
     emitter: &mut dyn DiagnosticEmitter,
+) {
+    lint_structure(project, config, emitter);
+    analyze_with_schedule(project, schedule, config, emitter);
+}
+
+/// Emit the diagnostics that never read a schedule
+///
+/// Cheap enough to run on every edit: these checks look only at the
+/// parsed project, so no scheduling is needed.
+pub fn lint_structure(
+    project: &Project,
+    config: &AnalysisConfig,
+    emitter: &mut dyn DiagnosticEmitter,
 ) {
     // E001: Circular specialization
     check_circular_specialization(project, config, emitter);
@@ -1055,12 +1544,21 @@ pub fn analyze_project(
     // R012: Trait multiplier stack > 2.0
     check_trait_multiplier_stack(project, config, emitter);
 
-    // Calendar diagnostics (C001, C002, C010, C011, C020-C023)
-    check_calendars(project, schedule, config, emitter);
+    // Calendar structure (C001, C002, C011, C012, C020-C024)
+    check_calendars(project, config, emitter);
 
     // W003: Unknown traits (check before E002 since it affects rate resolution)
     check_unknown_traits(project, config, emitter);
 
+    // W009: Profiles shadowed by a resource with the same ID
+    check_shadowed_profiles(project, config, emitter);
+
+    // W010: Consumable resources used beyond their pool
+    check_consumable_pools(project, config, emitter);
+
+    // W018: Single assignments above the resource's capacity
+    check_assignment_capacity(project, config, emitter);
+
     // E002: Profile without rate (cost-bearing)
     check_profiles_without_rate(project, config, emitter);
 
@@ -1073,11 +1571,6 @@ pub fn analyze_project(
     // H001: Mixed abstraction level
     check_mixed_abstraction(project, &assignments_info, config, emitter);
 
-    // W002: Wide cost range (requires schedule)
-    if let Some(sched) = schedule {
-        check_wide_cost_ranges(project, sched, config, emitter);
-    }
-
     // H002: Unused profiles
     check_unused_profiles(project, &assignments_info, config, emitter);
 
@@ -1093,6 +1586,67 @@ pub fn analyze_project(
     // W007: Unresolved dependencies (references to non-existent tasks)
     check_unresolved_dependencies(project, config, emitter);
 
+    // W012: Relative dependencies matching several tasks
+    check_ambiguous_dependencies(project, config, emitter);
+
+    // W011: Leaf tasks with no effort or duration
+    check_unsized_tasks(project, config, emitter);
+
+    // W013: Containers carrying their own effort or duration
+    check_container_effort(project, config, emitter);
+
+    // W015: Active tasks depending on inactive ones
+    check_inactive_dependencies(project, config, emitter);
+
+    // P005-P007: Progress conflicts
+    check_progress_conflicts(project, config, emitter);
+
+    // R001-R004: Temporal regime diagnostics
+    check_temporal_regimes(project, config, emitter);
+}
+
+/// Emit the diagnostics that read the schedule
+///
+/// Checks that need a schedule are skipped without one; W008 and I002
+/// still run, falling back to the project end date and leaving out the
+/// cost notes respectively. Pair with [`lint_structure`] for a full
+/// analysis.
+pub fn analyze_with_schedule(
+    project: &Project,
+    schedule: Option<&Schedule>,
+    config: &AnalysisConfig,
+    emitter: &mut dyn DiagnosticEmitter,
+) {
+    let assignments_info = collect_assignment_info(project);
+
+    // C010: Tasks scheduled on non-working days (requires schedule)
+    if let Some(sched) = schedule {
+        check_non_working_day_starts(project, sched, config, emitter);
+    }
+
+    // E005: Costs in more than one currency (requires schedule)
+    if let Some(sched) = schedule {
+        check_mixed_currency(sched, config, emitter);
+    }
+
+    // W002: Wide cost range (requires schedule)
+    if let Some(sched) = schedule {
+        check_wide_cost_ranges(project, sched, config, emitter);
+    }
+
+    // H005: Tasks disconnected from the project end (requires schedule)
+    if let Some(sched) = schedule {
+        check_disconnected_tasks(project, sched, config, emitter);
+    }
+
+    // W017: Dependency leads reaching back before the project start
+    if let Some(sched) = schedule {
+        check_degenerate_dependencies(project, sched, config, emitter);
+    }
+
+    // W008: Remaining effort vs resource capacity
+    check_capacity_shortfall(project, schedule, config, emitter);
+
     // W005: Constraint zero slack (requires schedule)
     if let Some(sched) = schedule {
         check_constraint_zero_slack(project, sched, config, emitter);
@@ -1103,17 +1657,19 @@ pub fn analyze_project(
         check_schedule_variance(sched, config, emitter);
     }
 
-    // P005, P006: Progress conflicts
-    check_progress_conflicts(project, config, emitter);
-
-    // R001-R004: Temporal regime diagnostics
-    check_temporal_regimes(project, schedule, config, emitter);
+    // B005: Attached baseline tasks missing from the schedule
+    if let Some(sched) = schedule {
+        check_attached_baseline(project, sched, config, emitter);
+    }
 
     // I001: Project cost summary (requires schedule)
     if let Some(sched) = schedule {
         emit_project_summary(project, sched, &assignments_info, config, emitter);
     }
 
+    // I002: Refinement progress
+    check_refinement_progress(project, schedule, &assignments_info, config, emitter);
+
     // I004: Project status (requires schedule)
     if let Some(sched) = schedule {
         check_project_status(sched, config, emitter);
@@ -1123,6 +1679,29 @@ pub fn analyze_project(
     if let Some(sched) = schedule {
         check_earned_value(sched, config, emitter);
     }
+
+    // I006: Schedule fragility (requires schedule)
+    if let Some(sched) = schedule {
+        check_schedule_fragility(project, sched, config, emitter);
+    }
+}
+
+/// Schedule a project and analyze the result, bundling both
+///
+/// The returned report serializes the schedule together with every
+/// diagnostic `analyze_project` raised for it, in emission order.
+pub fn schedule_with_diagnostics(
+    project: &Project,
+    solver: &impl Scheduler,
+    config: &AnalysisConfig,
+) -> Result<ScheduleReport, ScheduleError> {
+    let schedule = solver.schedule(project)?;
+    let mut emitter = CollectingEmitter::new();
+    analyze_project(project, Some(&schedule), config, &mut emitter);
+    Ok(ScheduleReport {
+        schedule,
+        diagnostics: emitter.diagnostics,
+    })
 }
 
 /// Filter diagnostics relevant to a specific task
@@ -1164,13 +1743,15 @@ fn is_diagnostic_for_task(diagnostic: &Diagnostic, task_id: &str) -> bool {
         }
 
         // Scheduling hints about tasks
-        DiagnosticCode::H004TaskUnconstrained => diagnostic.message.contains(&quoted_id),
-
-        // Assignment warnings
-        DiagnosticCode::W001AbstractAssignment | DiagnosticCode::H001MixedAbstraction => {
+        DiagnosticCode::H004TaskUnconstrained | DiagnosticCode::H005TaskDisconnected => {
             diagnostic.message.contains(&quoted_id)
         }
 
+        // Assignment warnings
+        DiagnosticCode::W001AbstractAssignment
+        | DiagnosticCode::H001MixedAbstraction
+        | DiagnosticCode::W018AssignmentExceedsCapacity => diagnostic.message.contains(&quoted_id),
+
         // Container dependency warnings
         DiagnosticCode::W014ContainerDependency => {
             // W014 contains both container and child task names
@@ -1178,10 +1759,10 @@ fn is_diagnostic_for_task(diagnostic: &Diagnostic, task_id: &str) -> bool {
         }
 
         // Project-level or calendar-level diagnostics are not task-specific
-        // C001, C002, C020, C022, C023 - these affect the calendar, not specific tasks
+        // C001, C002, C012, C020, C022-C024 - these affect the calendar, not specific tasks
         // E001, E002, W002, W003, W004 - these are about profiles/traits/resources
         // H002, H003 - unused profiles/traits
-        // I001, I002, I003, I004, I005 - info summaries
+        // I001-I006 - info summaries
         _ => false,
     }
 }
@@ -1267,10 +1848,9 @@ fn collect_assignment_info(project: &Project) -> AssignmentInfo {
     info
 }
 
-/// Calendar diagnostics (C001, C002, C010, C011, C020-C023)
+/// Calendar structure diagnostics (C001, C002, C011, C012, C020-C024)
 fn check_calendars(
     project: &Project,
-    schedule: Option<&Schedule>,
     config: &AnalysisConfig,
     emitter: &mut dyn DiagnosticEmitter,
 ) {
@@ -1289,7 +1869,7 @@ fn check_calendars(
         }
 
         // C002: No working days
-        if calendar.working_days.is_empty() {
+        if !calendar.has_working_days() {
             emitter.emit(
                 Diagnostic::error(
                     DiagnosticCode::C002NoWorkingDays,
@@ -1377,10 +1957,15 @@ fn check_calendars(
                 );
             }
         }
+
+        // C012, C024: Holidays contradicting exceptions or each other
+        for diagnostic in calendar.consistency_diagnostics() {
+            emitter.emit(diagnostic.with_file(config.file.clone().unwrap_or_default()));
+        }
     }
 
-    // C011: Calendar mismatch between project and assigned resource
-    // Tasks inherit the project's calendar, but resources may have their own
+    // C011: Calendar mismatch between task and assigned resource
+    // Tasks use their own calendar override if set, otherwise the project's calendar
     fn collect_leaf_tasks(tasks: &[Task]) -> Vec<&Task> {
         let mut leaves = Vec::new();
         for task in tasks {
@@ -1394,57 +1979,147 @@ fn check_calendars(
     }
 
     for task in collect_leaf_tasks(&project.tasks) {
-        let project_calendar = &project.calendar;
+        let task_calendar = task.calendar.as_ref().unwrap_or(&project.calendar);
+        let source = if task.calendar.is_some() {
+            "calendar"
+        } else {
+            "project calendar"
+        };
 
         for assignment in &task.assigned {
             if let Some(resource) = project.get_resource(&assignment.resource_id) {
                 if let Some(rc) = resource.calendar.as_ref() {
-                    if project_calendar != rc {
+                    if task_calendar != rc {
                         emitter.emit(
                             Diagnostic::warning(
                                 DiagnosticCode::C011CalendarMismatch,
                                 format!(
-                                    "task '{}' uses project calendar '{}' but assigned resource '{}' uses calendar '{}'",
-                                    task.id, project_calendar, resource.id, rc
+                                    "task '{}' uses {} '{}' but assigned resource '{}' uses calendar '{}'",
+                                    task.id, source, task_calendar, resource.id, rc
                                 ),
                             )
                             .with_file(config.file.clone().unwrap_or_default())
                             .with_note("different calendars may cause scheduling conflicts")
-                            .with_hint("ensure project and resource calendars are compatible"),
+                            .with_hint("ensure task and resource calendars are compatible"),
                         );
                     }
                 }
             }
         }
     }
+}
 
-    // C010: Task scheduled on non-working day (requires schedule)
-    if let Some(sched) = schedule {
-        // Tasks use the project's calendar
-        let project_calendar = project
-            .calendars
-            .iter()
-            .find(|c| c.id == project.calendar)
-            .cloned()
-            .unwrap_or_default();
-
-        for (task_id, task_schedule) in &sched.tasks {
-            // Check start date against project calendar
-            let start_weekday = task_schedule.start.weekday().num_days_from_sunday() as u8;
-            if !project_calendar.working_days.contains(&start_weekday) {
-                let day_name = task_schedule.start.format("%A").to_string();
-                emitter.emit(
-                    Diagnostic::warning(
-                        DiagnosticCode::C010NonWorkingDay,
-                        format!(
-                            "task '{}' scheduled to start on {} ({}), which is a non-working day",
-                            task_id, task_schedule.start, day_name
-                        ),
-                    )
-                    .with_file(config.file.clone().unwrap_or_default())
-                    .with_hint("adjust task constraints or calendar"),
-                );
+/// C010: Tasks scheduled to start on a non-working day
+fn check_non_working_day_starts(
+    project: &Project,
+    schedule: &Schedule,
+    config: &AnalysisConfig,
+    emitter: &mut dyn DiagnosticEmitter,
+) {
+    // Tasks use the project's calendar unless they resolve to their own
+    let project_calendar = project.default_calendar();
+    let mut task_map: HashMap<String, &Task> = HashMap::new();
+    flatten_tasks(&project.tasks, &mut task_map);
+
+    for (task_id, task_schedule) in &schedule.tasks {
+        let task_calendar = task_map
+            .get(task_id)
+            .and_then(|t| resolve_task_calendar(t, project, &project_calendar));
+        let calendar = task_calendar.as_ref().unwrap_or(&project_calendar);
+
+        // Check start date against the task's effective calendar, where
+        // exceptions can turn weekends into working days and back
+        if !calendar.is_working_day(task_schedule.start) {
+            let day_name = task_schedule.start.format("%A").to_string();
+            emitter.emit(
+                Diagnostic::warning(
+                    DiagnosticCode::C010NonWorkingDay,
+                    format!(
+                        "task '{}' scheduled to start on {} ({}), which is a non-working day",
+                        task_id, task_schedule.start, day_name
+                    ),
+                )
+                .with_file(config.file.clone().unwrap_or_default())
+                .with_hint("adjust task constraints or calendar"),
+            );
+        }
+    }
+}
+
+/// W017: Dependencies whose lead puts the successor before the project start
+///
+/// Recomputes each dependency's date formula from the scheduled
+/// predecessor dates without the clamp to the project start. An FS or SS
+/// link degenerates when the successor would have to start before the
+/// project does; an FF or SF link when the successor would have to finish
+/// by the project start, i.e. before it can even start. Only negative lags
+/// can do this, and the clamp silently turns such links into no-ops.
+fn check_degenerate_dependencies(
+    project: &Project,
+    schedule: &Schedule,
+    config: &AnalysisConfig,
+    emitter: &mut dyn DiagnosticEmitter,
+) {
+    let calendar = project.default_calendar();
+    let (task_map, context_map) = flatten_tasks_with_context(&project.tasks);
+    let mut task_ids: Vec<&String> = task_map.keys().collect();
+    task_ids.sort();
+
+    for id in task_ids {
+        if !schedule.tasks.contains_key(id) {
+            continue;
+        }
+        for dep in &task_map[id].depends {
+            let Some(pred_id) =
+                resolve_dependency_path(&dep.predecessor, id, &context_map, &task_map)
+            else {
+                continue;
+            };
+            let Some(pred) = schedule.tasks.get(&pred_id) else {
+                continue;
+            };
+            let pred_es = date_to_working_days(project.start, pred.start, &calendar);
+            let pred_ef = if pred.duration.minutes == 0 {
+                pred_es
+            } else {
+                date_to_working_days(project.start, pred.finish, &calendar) + 1
+            };
+            let lag = dependency_lag_days(dep, pred_es, pred_ef, project.start, &calendar);
+            if lag >= 0 {
+                continue;
+            }
+
+            let (implied, relationship) = match dep.dep_type {
+                DependencyType::FinishToStart => {
+                    (pred_ef - 1 + lag, "start before the project start")
+                }
+                DependencyType::StartToStart => (pred_es + lag, "start before the project start"),
+                // Exclusive finish at or before day 0
+                DependencyType::FinishToFinish => (pred_ef + lag - 1, "finish before it can start"),
+                DependencyType::StartToFinish => (pred_es + lag - 1, "finish before it can start"),
+            };
+            if implied >= 0 {
+                continue;
             }
+            emitter.emit(
+                Diagnostic::new(
+                    DiagnosticCode::W017DegenerateDependency,
+                    format!(
+                        "{} dependency '{}' -> '{}' with lag {}d requires '{}' to {}",
+                        dep.dep_type.short_code(),
+                        pred_id,
+                        id,
+                        lag,
+                        id,
+                        relationship
+                    ),
+                )
+                .with_file(config.file.clone().unwrap_or_default())
+                .with_note(
+                    "the date is clamped to the project start, so the dependency has no effect",
+                )
+                .with_hint("shorten the lead, or check the dependency type"),
+            );
         }
     }
 }
@@ -1661,6 +2336,156 @@ fn check_unknown_traits(
     }
 }
 
+/// W009: Check for IDs defined as both a resource and a profile
+///
+/// `resolve_assignment` looks up resources before profiles, so the profile
+/// can never be assigned.
+fn check_shadowed_profiles(
+    project: &Project,
+    config: &AnalysisConfig,
+    emitter: &mut dyn DiagnosticEmitter,
+) {
+    let resource_ids: std::collections::HashSet<_> =
+        project.resources.iter().map(|r| r.id.as_str()).collect();
+
+    for profile in &project.profiles {
+        if resource_ids.contains(profile.id.as_str()) {
+            emitter.emit(
+                Diagnostic::new(
+                    DiagnosticCode::W009ProfileShadowed,
+                    format!(
+                        "'{}' is defined as both a resource and a profile",
+                        profile.id
+                    ),
+                )
+                .with_file(config.file.clone().unwrap_or_default())
+                .with_note(format!(
+                    "assignments to '{}' resolve to the resource; the profile is shadowed",
+                    profile.id
+                ))
+                .with_hint("rename the resource or the profile"),
+            );
+        }
+    }
+}
+
+/// W010: Check that consumable resources are not used beyond their pool
+fn check_consumable_pools(
+    project: &Project,
+    config: &AnalysisConfig,
+    emitter: &mut dyn DiagnosticEmitter,
+) {
+    for resource in &project.resources {
+        let Some(pool) = resource.pool else {
+            continue;
+        };
+        let consumed = project.consumption(&resource.id);
+        if consumed <= pool {
+            continue;
+        }
+
+        let consumers: Vec<String> = project
+            .leaf_tasks()
+            .iter()
+            .flat_map(|task| {
+                task.assigned
+                    .iter()
+                    .filter(|r| r.resource_id == resource.id)
+                    .map(move |r| format!("{} ({})", task.id, r.units))
+            })
+            .collect();
+
+        emitter.emit(
+            Diagnostic::new(
+                DiagnosticCode::W010ConsumablePoolExceeded,
+                format!(
+                    "consumable resource '{}' is over-committed: {} used of a pool of {}",
+                    resource.id, consumed, pool
+                ),
+            )
+            .with_file(config.file.clone().unwrap_or_default())
+            .with_note(format!("consumed by: {}", consumers.join(", ")))
+            .with_hint("increase the pool or reduce what the tasks consume"),
+        );
+    }
+}
+
+/// W018: Check that no single assignment asks for more than its resource has
+///
+/// Renewable resources are checked against their effective capacity
+/// (capacity × availability, as in leveling), consumables against their
+/// whole pool; materials have no limit. Unlike overallocation this needs
+/// no schedule: the assignment is impossible however the work is placed.
+/// A resource with no effective capacity (zero capacity or
+/// availability) is reported as idle on every task it is assigned to.
+fn check_assignment_capacity(
+    project: &Project,
+    config: &AnalysisConfig,
+    emitter: &mut dyn DiagnosticEmitter,
+) {
+    let mut task_map: HashMap<String, &Task> = HashMap::new();
+    flatten_tasks(&project.tasks, &mut task_map);
+    let mut ids: Vec<&String> = task_map.keys().collect();
+    ids.sort();
+
+    for id in ids {
+        for assignment in &task_map[id].assigned {
+            let Some(resource) = project
+                .get_resource(&assignment.resource_id)
+                .filter(|r| !r.is_material())
+            else {
+                continue;
+            };
+            if resource.pool.is_none() && resource.effective_capacity() <= 0.0 {
+                emitter.emit(
+                    Diagnostic::new(
+                        DiagnosticCode::W018AssignmentExceedsCapacity,
+                        format!(
+                            "task '{}' assigns '{}', which has no effective capacity (capacity {}, availability {})",
+                            id,
+                            resource.id,
+                            resource.capacity,
+                            resource.effective_availability()
+                        ),
+                    )
+                    .with_file(config.file.clone().unwrap_or_default())
+                    .with_note(format!("'{}' does no work on this task", resource.id))
+                    .with_hint(format!(
+                        "remove '{}' from the task, or give it capacity and availability",
+                        resource.id
+                    )),
+                );
+                continue;
+            }
+            let (limit, what) = match resource.pool {
+                Some(pool) => (pool, "pool"),
+                None if resource.availability.is_some() => {
+                    (resource.effective_capacity(), "effective capacity")
+                }
+                None => (resource.capacity, "capacity"),
+            };
+            if assignment.units <= limit + f32::EPSILON {
+                continue;
+            }
+            emitter.emit(
+                Diagnostic::new(
+                    DiagnosticCode::W018AssignmentExceedsCapacity,
+                    format!(
+                        "task '{}' assigns '{}' at {} units, above its {} of {}",
+                        id, resource.id, assignment.units, what, limit
+                    ),
+                )
+                .with_file(config.file.clone().unwrap_or_default())
+                .with_note("a single assignment cannot use more than the resource has")
+                .with_hint(format!(
+                    "assign '{}' at {} units or less, or raise its {}",
+                    resource.id, limit, what
+                )),
+            );
+        }
+    }
+}
+
 /// E002: Check for profiles without rate that are used in assignments
 fn check_profiles_without_rate(
     project: &Project,
@@ -1750,9 +2575,13 @@ fn check_abstract_assignments(
                     let rate_range = resolve_profile_rate(profile, project);
                     let cost_note = if let Some(range) = rate_range {
                         let spread = range.spread_percent();
+                        let currency = range.currency.as_deref().unwrap_or(&project.currency);
+                        let format = CurrencyFormat::default();
                         format!(
-                            "cost range is ${} - ${} ({:.0}% spread)",
-                            range.min, range.max, spread
+                            "cost range is {} - {} ({:.0}% spread)",
+                            format.render(range.min, currency),
+                            format.render(range.max, currency),
+                            spread
                         )
                     } else {
                         "cost range is unknown (no rate defined)".to_string()
@@ -1819,6 +2648,47 @@ fn check_mixed_abstraction(
     }
 }
 
+/// E005: Check that every assignment cost shares one currency
+///
+/// Mixed currencies leave task and project totals out of the schedule, so
+/// the diagnostic names the tasks costed in each currency.
+fn check_mixed_currency(
+    schedule: &Schedule,
+    config: &AnalysisConfig,
+    emitter: &mut dyn DiagnosticEmitter,
+) {
+    let mut tasks_by_currency: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+    for (task_id, scheduled_task) in &schedule.tasks {
+        for assignment in &scheduled_task.assignments {
+            if let Some(ref range) = assignment.cost_range {
+                tasks_by_currency
+                    .entry(range.currency.as_str())
+                    .or_default()
+                    .insert(task_id.as_str());
+            }
+        }
+    }
+    if tasks_by_currency.len() < 2 {
+        return;
+    }
+
+    let currencies: Vec<&str> = tasks_by_currency.keys().copied().collect();
+    let mut diag = Diagnostic::new(
+        DiagnosticCode::E005MixedCurrency,
+        format!(
+            "project costs mix currencies ({}); cost totals are left out",
+            currencies.join(", ")
+        ),
+    )
+    .with_file(config.file.clone().unwrap_or_default());
+    for (currency, task_ids) in &tasks_by_currency {
+        let task_ids: Vec<&str> = task_ids.iter().copied().collect();
+        diag = diag.with_note(format!("{}: {}", currency, task_ids.join(", ")));
+    }
+    emitter
+        .emit(diag.with_hint("express every resource rate and profile rate range in one currency"));
+}
+
 /// W002: Check for wide cost ranges
 fn check_wide_cost_ranges(
     project: &Project,
@@ -1827,35 +2697,38 @@ fn check_wide_cost_ranges(
     emitter: &mut dyn DiagnosticEmitter,
 ) {
     for (task_id, scheduled_task) in &schedule.tasks {
+        // Containers only repeat their children's ranges
+        if project
+            .get_task_by_path(task_id)
+            .is_some_and(|task| !task.children.is_empty())
+        {
+            continue;
+        }
         if let Some(ref cost_range) = scheduled_task.cost_range {
             let spread = cost_range.spread_percent();
             if spread > config.cost_spread_threshold {
-                // Find contributing factors
+                // Find contributing factors from each profile's rate breakdown
                 let mut contributors: Vec<String> = Vec::new();
-
-                // Get task details
-                let mut task_map: HashMap<String, &Task> = HashMap::new();
-                flatten_tasks(&project.tasks, &mut task_map);
-
-                if let Some(task) = task_map.get(task_id) {
-                    for res_ref in &task.assigned {
-                        if let Some(profile) = project.get_profile(&res_ref.resource_id) {
-                            if let Some(rate) = resolve_profile_rate(profile, project) {
-                                contributors.push(format!(
-                                    "{}: ${} - ${}/day",
-                                    res_ref.resource_id, rate.min, rate.max
-                                ));
-                            }
-                            for trait_id in &profile.traits {
-                                if let Some(t) = project.get_trait(trait_id) {
-                                    if (t.rate_multiplier - 1.0).abs() > 0.01 {
-                                        contributors.push(format!(
-                                            "{} trait: {}x multiplier",
-                                            trait_id, t.rate_multiplier
-                                        ));
-                                    }
-                                }
-                            }
+                for assignment in &scheduled_task.assignments {
+                    let Some(ref explanation) = assignment.cost_explanation else {
+                        continue;
+                    };
+                    let currency = explanation
+                        .rate
+                        .currency
+                        .as_deref()
+                        .unwrap_or(&project.currency);
+                    let format = CurrencyFormat::default();
+                    contributors.push(format!(
+                        "{}: {} - {}/day",
+                        assignment.resource_id,
+                        format.render(explanation.rate.min, currency),
+                        format.render(explanation.rate.max, currency)
+                    ));
+                    for (trait_id, multiplier) in &explanation.trait_multipliers {
+                        if (multiplier - 1.0).abs() > 0.01 {
+                            contributors
+                                .push(format!("{} trait: {}x multiplier", trait_id, multiplier));
                         }
                     }
                 }
@@ -1869,8 +2742,8 @@ fn check_wide_cost_ranges(
                 )
                 .with_file(config.file.clone().unwrap_or_default())
                 .with_note(format!(
-                    "cost range: ${} - ${} (expected: ${})",
-                    cost_range.min, cost_range.max, cost_range.expected
+                    "cost range: {}",
+                    cost_range.format(&CurrencyFormat::default())
                 ));
 
                 if !contributors.is_empty() {
@@ -1957,40 +2830,350 @@ fn check_unconstrained_recursive(
     config: &AnalysisConfig,
     emitter: &mut dyn DiagnosticEmitter,
 ) {
-    for task in tasks {
-        let task_path = if parent_path.is_empty() {
-            task.id.clone()
-        } else {
-            format!("{}.{}", parent_path, task.id)
-        };
+    for task in tasks {
+        let task_path = if parent_path.is_empty() {
+            task.id.clone()
+        } else {
+            format!("{}.{}", parent_path, task.id)
+        };
+
+        if task.children.is_empty() {
+            // Leaf task - check if unconstrained
+            let has_predecessors = !task.depends.is_empty();
+            let has_date_constraint = !task.constraints.is_empty();
+
+            if !has_predecessors && !has_date_constraint {
+                emitter.emit(
+                    Diagnostic::new(
+                        DiagnosticCode::H004TaskUnconstrained,
+                        format!(
+                            "task '{}' has no predecessors or date constraints",
+                            task.name
+                        ),
+                    )
+                    .with_file(config.file.clone().unwrap_or_default())
+                    .with_note(format!(
+                        "'{}' will start on project start date (ASAP scheduling)",
+                        task.name
+                    ))
+                    .with_hint(
+                        "add 'depends:' or 'start_no_earlier_than:' to anchor scheduling logic",
+                    ),
+                );
+            }
+        } else {
+            // Container task - recurse into children
+            check_unconstrained_recursive(&task.children, &task_path, config, emitter);
+        }
+    }
+}
+
+/// H005: Check for tasks with no path of successors to the project end
+///
+/// Walks predecessor links back from the tasks that finish the project
+/// (`Schedule::finish_drivers`), reusing the successors map built by the
+/// topological sort. A link to or from a container counts for every leaf
+/// inside it. Leaves never reached are grouped into islands of tasks
+/// linked to each other, with one hint per island.
+fn check_disconnected_tasks(
+    project: &Project,
+    schedule: &Schedule,
+    config: &AnalysisConfig,
+    emitter: &mut dyn DiagnosticEmitter,
+) {
+    let (task_map, context_map) = flatten_tasks_with_context(&project.tasks);
+    let Ok(topo_result) = topological_sort(&task_map, &context_map) else {
+        return; // Cycles are reported by scheduling
+    };
+    let end_tasks = schedule.finish_drivers();
+    if end_tasks.is_empty() {
+        return;
+    }
+
+    let children_map = build_children_map(&task_map);
+    let leaves_of = |id: &String| -> Vec<String> {
+        if children_map.contains_key(id) {
+            descendant_leaves(id, &children_map)
+        } else {
+            vec![id.clone()]
+        }
+    };
+
+    // Leaf-to-leaf links, with container links applied to their leaves
+    let mut predecessors: HashMap<String, Vec<String>> = HashMap::new();
+    let mut neighbours: HashMap<String, Vec<String>> = HashMap::new();
+    for (pred_id, succ_ids) in &topo_result.successors {
+        let pred_leaves = leaves_of(pred_id);
+        for succ_id in succ_ids {
+            for succ_leaf in leaves_of(succ_id) {
+                for pred_leaf in &pred_leaves {
+                    predecessors
+                        .entry(succ_leaf.clone())
+                        .or_default()
+                        .push(pred_leaf.clone());
+                    neighbours
+                        .entry(succ_leaf.clone())
+                        .or_default()
+                        .push(pred_leaf.clone());
+                    neighbours
+                        .entry(pred_leaf.clone())
+                        .or_default()
+                        .push(succ_leaf.clone());
+                }
+            }
+        }
+    }
+
+    let mut reached: std::collections::HashSet<String> = end_tasks.iter().cloned().collect();
+    let mut queue: VecDeque<String> = end_tasks.iter().cloned().collect();
+    while let Some(id) = queue.pop_front() {
+        for pred_id in predecessors.get(&id).into_iter().flatten() {
+            if reached.insert(pred_id.clone()) {
+                queue.push_back(pred_id.clone());
+            }
+        }
+    }
+
+    let mut unreached: Vec<&String> = task_map
+        .keys()
+        .filter(|id| !children_map.contains_key(*id) && !reached.contains(*id))
+        .collect();
+    unreached.sort();
+
+    // Group unreached leaves into islands of linked tasks
+    let mut assigned: std::collections::HashSet<&String> = std::collections::HashSet::new();
+    for start in unreached {
+        if !assigned.insert(start) {
+            continue;
+        }
+        let mut island = vec![start];
+        let mut stack = vec![start];
+        while let Some(id) = stack.pop() {
+            for next in neighbours.get(id).into_iter().flatten() {
+                if !reached.contains(next) && assigned.insert(next) {
+                    island.push(next);
+                    stack.push(next);
+                }
+            }
+        }
+        island.sort();
+
+        let quoted: Vec<String> = island.iter().map(|id| format!("'{}'", id)).collect();
+        let message = if island.len() == 1 {
+            format!("task {} is disconnected from the project end", quoted[0])
+        } else {
+            format!(
+                "tasks {} form an island disconnected from the project end",
+                quoted.join(", ")
+            )
+        };
+        emitter.emit(
+            Diagnostic::new(DiagnosticCode::H005TaskDisconnected, message)
+                .with_file(config.file.clone().unwrap_or_default())
+                .with_note(format!(
+                    "no chain of successors reaches the tasks finishing the project: {}",
+                    end_tasks
+                        .iter()
+                        .map(|id| format!("'{}'", id))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ))
+                .with_hint("link to the work it feeds, or remove it if no longer needed"),
+        );
+    }
+}
+
+/// W011: Check for leaf tasks that have neither effort nor duration
+///
+/// Such a task schedules as zero length like a milestone, which is almost
+/// always a forgotten estimate. Declared milestones are exempt.
+fn check_unsized_tasks(
+    project: &Project,
+    config: &AnalysisConfig,
+    emitter: &mut dyn DiagnosticEmitter,
+) {
+    let mut task_map = HashMap::new();
+    flatten_tasks(&project.tasks, &mut task_map);
+
+    let mut unsized_ids: Vec<&String> = task_map
+        .iter()
+        .filter(|(_, task)| {
+            task.children.is_empty()
+                && !task.milestone
+                && task.effort.is_none()
+                && task.duration.is_none()
+        })
+        .map(|(id, _)| id)
+        .collect();
+    unsized_ids.sort();
+
+    for id in unsized_ids {
+        emitter.emit(
+            Diagnostic::new(
+                DiagnosticCode::W011UnsizedTask,
+                format!("task '{}' has no effort or duration", id),
+            )
+            .with_file(config.file.clone().unwrap_or_default())
+            .with_note("it will be scheduled with zero length, like a milestone")
+            .with_hint("add 'effort:' or 'duration:', or declare it as a milestone"),
+        );
+    }
+}
+
+/// W013: Check for containers that set effort or duration
+///
+/// A container's dates are rolled up from its children, so an estimate on
+/// the container itself is ignored. This usually means the task was a
+/// leaf that later grew children.
+fn check_container_effort(
+    project: &Project,
+    config: &AnalysisConfig,
+    emitter: &mut dyn DiagnosticEmitter,
+) {
+    let mut task_map = HashMap::new();
+    flatten_tasks(&project.tasks, &mut task_map);
+
+    let mut container_ids: Vec<&String> = task_map
+        .iter()
+        .filter(|(_, task)| {
+            task.is_container() && (task.effort.is_some() || task.duration.is_some())
+        })
+        .map(|(id, _)| id)
+        .collect();
+    container_ids.sort();
+
+    for id in container_ids {
+        let task = task_map[id];
+        let attribute = match (task.effort, task.duration) {
+            (Some(_), Some(_)) => "effort and duration",
+            (Some(_), None) => "effort",
+            _ => "duration",
+        };
+        emitter.emit(
+            Diagnostic::new(
+                DiagnosticCode::W013ContainerEffort,
+                format!("container '{}' sets {} directly", id, attribute),
+            )
+            .with_file(config.file.clone().unwrap_or_default())
+            .with_note(format!(
+                "its dates are rolled up from its {} children, so the {} is ignored",
+                task.children.len(),
+                attribute
+            ))
+            .with_hint("move the estimate to a child task, or remove it"),
+        );
+    }
+}
+
+/// W015: Active tasks whose predecessor is inactive
+///
+/// A task is inactive when it or any of its containers is; the solver
+/// skips dependencies on such tasks, which is rarely what was meant.
+fn check_inactive_dependencies(
+    project: &Project,
+    config: &AnalysisConfig,
+    emitter: &mut dyn DiagnosticEmitter,
+) {
+    let (task_map, context_map) = flatten_tasks_with_context(&project.tasks);
+    let is_inactive = |id: &str| {
+        let mut path = String::new();
+        id.split('.').any(|segment| {
+            if !path.is_empty() {
+                path.push('.');
+            }
+            path.push_str(segment);
+            task_map.get(path.as_str()).is_some_and(|task| !task.active)
+        })
+    };
+
+    let mut task_ids: Vec<&String> = task_map.keys().collect();
+    task_ids.sort();
+
+    for id in task_ids {
+        if is_inactive(id) {
+            continue;
+        }
+        for dep in &task_map[id].depends {
+            let Some(resolved) =
+                resolve_dependency_path(&dep.predecessor, id, &context_map, &task_map)
+            else {
+                continue;
+            };
+            if !is_inactive(&resolved) {
+                continue;
+            }
+            emitter.emit(
+                Diagnostic::new(
+                    DiagnosticCode::W015InactiveDependency,
+                    format!("task '{}' depends on inactive task '{}'", id, resolved),
+                )
+                .with_file(config.file.clone().unwrap_or_default())
+                .with_note("the dependency is skipped when scheduling")
+                .with_hint(format!(
+                    "remove the dependency, or reactivate '{}'",
+                    resolved
+                )),
+            );
+        }
+    }
+}
+
+/// W012: Check for relative dependencies whose name matches several tasks
+///
+/// A dependency without a dot is resolved first as a top-level ID, then
+/// within the depending task's container. When the same short name also
+/// exists elsewhere in the tree, which task is meant depends on where the
+/// reference sits, so a qualified path is recommended.
+fn check_ambiguous_dependencies(
+    project: &Project,
+    config: &AnalysisConfig,
+    emitter: &mut dyn DiagnosticEmitter,
+) {
+    let (task_map, context_map) = flatten_tasks_with_context(&project.tasks);
 
-        if task.children.is_empty() {
-            // Leaf task - check if unconstrained
-            let has_predecessors = !task.depends.is_empty();
-            let has_date_constraint = !task.constraints.is_empty();
+    let mut task_ids: Vec<&String> = task_map.keys().collect();
+    task_ids.sort();
 
-            if !has_predecessors && !has_date_constraint {
-                emitter.emit(
-                    Diagnostic::new(
-                        DiagnosticCode::H004TaskUnconstrained,
-                        format!(
-                            "task '{}' has no predecessors or date constraints",
-                            task.name
-                        ),
-                    )
-                    .with_file(config.file.clone().unwrap_or_default())
-                    .with_note(format!(
-                        "'{}' will start on project start date (ASAP scheduling)",
-                        task.name
-                    ))
-                    .with_hint(
-                        "add 'depends:' or 'start_no_earlier_than:' to anchor scheduling logic",
-                    ),
-                );
+    for id in task_ids {
+        for dep in &task_map[id].depends {
+            if dep.predecessor.contains('.') {
+                continue;
             }
-        } else {
-            // Container task - recurse into children
-            check_unconstrained_recursive(&task.children, &task_path, config, emitter);
+            let mut candidates: Vec<&String> = task_map
+                .keys()
+                .filter(|candidate| candidate.rsplit('.').next() == Some(dep.predecessor.as_str()))
+                .collect();
+            if candidates.len() < 2 {
+                continue;
+            }
+            candidates.sort();
+
+            let resolved = resolve_dependency_path(&dep.predecessor, id, &context_map, &task_map);
+            let mut diagnostic = Diagnostic::new(
+                DiagnosticCode::W012AmbiguousDependency,
+                format!(
+                    "task '{}' depends on '{}', which matches {} tasks",
+                    id,
+                    dep.predecessor,
+                    candidates.len()
+                ),
+            )
+            .with_file(config.file.clone().unwrap_or_default())
+            .with_note(format!(
+                "candidates: {}",
+                candidates
+                    .iter()
+                    .map(|c| c.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+            if let Some(resolved) = &resolved {
+                diagnostic = diagnostic.with_note(format!("currently resolves to '{}'", resolved));
+            }
+            emitter.emit(diagnostic.with_hint(format!(
+                "use a qualified path, e.g. 'depends: {}'",
+                resolved.as_deref().unwrap_or(candidates[0])
+            )));
         }
     }
 }
@@ -2201,6 +3384,72 @@ fn resolve_dependency_reference(
 // Container Dependency Fix
 // =============================================================================
 
+/// W008: Check remaining effort against resource capacity
+///
+/// A coarse feasibility check: the planning window runs from the status date
+/// (or project start) to the project end date, or the scheduled finish when
+/// no end date is set. Skipped while work is assigned to profiles, since
+/// their capacity is unknown until they are refined to resources.
+fn check_capacity_shortfall(
+    project: &Project,
+    schedule: Option<&Schedule>,
+    config: &AnalysisConfig,
+    emitter: &mut dyn DiagnosticEmitter,
+) {
+    if project.resources.is_empty() {
+        return;
+    }
+    let has_profile_assignments = project.leaf_tasks().iter().any(|task| {
+        task.assigned
+            .iter()
+            .any(|r| project.get_profile(&r.resource_id).is_some())
+    });
+    if has_profile_assignments {
+        return;
+    }
+    let Some(window_end) = project.end.or_else(|| schedule.map(|s| s.project_end)) else {
+        return;
+    };
+    let window_start = project
+        .status_date
+        .map_or(project.start, |d| d.max(project.start));
+    if window_end < window_start {
+        return;
+    }
+
+    let demand = project.remaining_effort();
+    let supply = project.available_capacity(window_start, window_end);
+    if demand.minutes as f64 <= supply.minutes as f64 * config.capacity_shortfall_ratio {
+        return;
+    }
+
+    let ratio = if supply.minutes > 0 {
+        format!(" ({:.1}x)", demand.minutes as f64 / supply.minutes as f64)
+    } else {
+        String::new()
+    };
+    emitter.emit(
+        Diagnostic::new(
+            DiagnosticCode::W008CapacityShortfall,
+            format!(
+                "remaining effort of {:.1} person-days exceeds resource capacity of {:.1} person-days{}",
+                demand.as_days(),
+                supply.as_days(),
+                ratio
+            ),
+        )
+        .with_file(config.file.clone().unwrap_or_default())
+        .with_note(format!(
+            "planning window: {} to {} ({} resource{})",
+            window_start,
+            window_end,
+            project.resources.len(),
+            if project.resources.len() == 1 { "" } else { "s" }
+        ))
+        .with_hint("add resources, extend the end date, or reduce scope"),
+    );
+}
+
 /// Fix container dependency issues (W014) by propagating container dependencies to children.
 ///
 /// This function modifies the project in-place, adding missing dependencies to child tasks
@@ -2222,6 +3471,7 @@ fn resolve_dependency_reference(
 ///     predecessor: "predecessor".to_string(),
 ///     dep_type: utf8proj_core::DependencyType::FinishToStart,
 ///     lag: None,
+///     lag_calendar: false,
 /// });
 ///
 /// // Child without the dependency
@@ -2269,6 +3519,7 @@ fn fix_container_deps_recursive(tasks: &mut [Task]) -> usize {
                                 predecessor: dep.predecessor.clone(),
                                 dep_type: dep.dep_type,
                                 lag: dep.lag,
+                                lag_calendar: dep.lag_calendar,
                             });
                             fixed_count += 1;
                         }
@@ -2352,9 +3603,6 @@ fn check_constraint_zero_slack(
     }
 }
 
-/// Default threshold for variance warnings (days)
-const VARIANCE_THRESHOLD_DAYS: i64 = 5;
-
 /// W006: Check for tasks with significant schedule variance
 fn check_schedule_variance(
     schedule: &Schedule,
@@ -2363,7 +3611,7 @@ fn check_schedule_variance(
 ) {
     for (task_id, scheduled) in &schedule.tasks {
         // Only warn if finish variance exceeds threshold
-        if scheduled.finish_variance_days > VARIANCE_THRESHOLD_DAYS {
+        if scheduled.finish_variance_days > config.variance_threshold_days {
             let variance_str = format!("+{}d", scheduled.finish_variance_days);
             emitter.emit(
                 Diagnostic::new(
@@ -2381,17 +3629,56 @@ fn check_schedule_variance(
     }
 }
 
-/// P005, P006: Check for progress-related conflicts
+/// B005: Check for attached baseline tasks missing from the schedule
+///
+/// The solver ignores such tasks when measuring variance, so surface them
+/// as warnings rather than failing the schedule.
+fn check_attached_baseline(
+    project: &Project,
+    schedule: &Schedule,
+    config: &AnalysisConfig,
+    emitter: &mut dyn DiagnosticEmitter,
+) {
+    let Some(baseline) = &project.baseline else {
+        return;
+    };
+    // Baseline tasks are kept sorted by ID, so output is deterministic
+    for (task_id, snapshot) in &baseline.tasks {
+        if schedule.tasks.contains_key(task_id) {
+            continue;
+        }
+        emitter.emit(
+            Diagnostic::warning(
+                DiagnosticCode::B005TaskRemoved,
+                format!(
+                    "baseline '{}' task '{}' is not in the current schedule",
+                    baseline.name, task_id
+                ),
+            )
+            .with_file(config.file.clone().unwrap_or_default())
+            .with_note(format!(
+                "baselined from {} to {}",
+                snapshot.start, snapshot.finish
+            ))
+            .with_hint("variance ignores this task; capture a new baseline if it was dropped"),
+        );
+    }
+}
+
+/// P005-P007: Check for progress-related conflicts
 fn check_progress_conflicts(
     project: &Project,
     config: &AnalysisConfig,
     emitter: &mut dyn DiagnosticEmitter,
 ) {
-    check_progress_conflicts_recursive(&project.tasks, config, emitter);
+    let calendar = project.default_calendar();
+    check_progress_conflicts_recursive(project, &project.tasks, &calendar, config, emitter);
 }
 
 fn check_progress_conflicts_recursive(
+    project: &Project,
     tasks: &[Task],
+    calendar: &Calendar,
     config: &AnalysisConfig,
     emitter: &mut dyn DiagnosticEmitter,
 ) {
@@ -2400,7 +3687,7 @@ fn check_progress_conflicts_recursive(
         if let (Some(explicit_remaining), Some(complete_pct)) =
             (&task.explicit_remaining, task.complete)
         {
-            let duration_days = get_task_duration_days(task);
+            let duration_days = get_task_duration_days(task, project, calendar);
             let linear_remaining =
                 ((duration_days as f64) * (1.0 - complete_pct as f64 / 100.0)).ceil() as i64;
             let explicit_days = explicit_remaining.as_days() as i64;
@@ -2422,68 +3709,50 @@ fn check_progress_conflicts_recursive(
             }
         }
 
-        // P006: Container's explicit progress conflicts with weighted children average
-        if !task.children.is_empty() {
-            if let Some(explicit_complete) = task.complete {
-                let (children_weighted_sum, children_total_duration) =
-                    compute_children_progress_weighted(&task.children);
-
-                if children_total_duration > 0 {
-                    let derived_complete =
-                        (children_weighted_sum as f64 / children_total_duration as f64) * 100.0;
-                    let diff = (explicit_complete as f64 - derived_complete).abs();
+        // P006: Container's explicit progress conflicts with its derived rollup
+        if let Some((manual, derived)) = task.progress_mismatch(config.progress_mismatch_threshold)
+        {
+            emitter.emit(
+                Diagnostic::new(
+                    DiagnosticCode::P006ContainerProgressMismatch,
+                    format!(
+                        "container '{}' is set to {}% complete but its children derive to {}%",
+                        task.id, manual, derived
+                    ),
+                )
+                .with_file(config.file.clone().unwrap_or_default())
+                .with_note(format!(
+                    "manual: {}%, derived from children: {}% (threshold: {}%)",
+                    manual, derived, config.progress_mismatch_threshold
+                ))
+                .with_hint("update child progress or remove the manual complete% override"),
+            );
+        }
 
-                    // P006 threshold: conflict if > 10% difference
-                    if diff > 10.0 {
-                        emitter.emit(
-                            Diagnostic::new(
-                                DiagnosticCode::P006ContainerProgressMismatch,
-                                format!(
-                                    "container '{}' has explicit complete ({}%) inconsistent with children average ({:.0}%)",
-                                    task.id, explicit_complete as i32, derived_complete
-                                ),
-                            )
-                            .with_file(config.file.clone().unwrap_or_default())
-                            .with_note(format!(
-                                "children weighted average: {:.1}%, explicit: {}%",
-                                derived_complete, explicit_complete as i32
-                            ))
-                            .with_hint("remove explicit complete% or update to match children"),
-                        );
-                    }
-                }
-            }
+        // P007: Explicit status, complete%, and actual dates contradict each other
+        for (field, other) in task.progress_contradictions() {
+            emitter.emit(
+                Diagnostic::new(
+                    DiagnosticCode::P007ProgressFieldConflict,
+                    format!(
+                        "task '{}' has '{}' conflicting with '{}'",
+                        task.id, field, other
+                    ),
+                )
+                .with_file(config.file.clone().unwrap_or_default())
+                .with_note("an explicit status overrides the status derived from progress")
+                .with_hint("correct whichever field is out of date"),
+            );
         }
 
         // Recurse into children
-        check_progress_conflicts_recursive(&task.children, config, emitter);
-    }
-}
-
-/// Compute weighted progress sum and total duration for children
-fn compute_children_progress_weighted(children: &[Task]) -> (i64, i64) {
-    let mut weighted_sum: i64 = 0;
-    let mut total_duration: i64 = 0;
-
-    for child in children {
-        let duration = get_task_duration_days(child);
-        let complete = child.complete.unwrap_or(0.0);
-        weighted_sum += (duration as f64 * complete as f64 / 100.0) as i64;
-        total_duration += duration;
-
-        // Include nested children
-        let (nested_sum, nested_duration) = compute_children_progress_weighted(&child.children);
-        weighted_sum += nested_sum;
-        total_duration += nested_duration;
+        check_progress_conflicts_recursive(project, &task.children, calendar, config, emitter);
     }
-
-    (weighted_sum, total_duration)
 }
 
 /// R001-R004: Check temporal regime diagnostics (RFC-0012)
 fn check_temporal_regimes(
     project: &Project,
-    _schedule: Option<&Schedule>,
     config: &AnalysisConfig,
     emitter: &mut dyn DiagnosticEmitter,
 ) {
@@ -2578,7 +3847,7 @@ fn check_project_status(
         "on schedule".to_string()
     };
 
-    let status_emoji = if schedule.project_variance_days > VARIANCE_THRESHOLD_DAYS {
+    let status_emoji = if schedule.project_variance_days > config.variance_threshold_days {
         "🔴"
     } else if schedule.project_variance_days > 0 {
         "🟡"
@@ -2617,19 +3886,124 @@ fn check_earned_value(
         ("behind schedule", "🔴")
     };
 
+    let mut diagnostic = Diagnostic::new(
+        DiagnosticCode::I005EarnedValueSummary,
+        format!("SPI {:.2}: {} {}", schedule.spi, spi_status, spi_emoji),
+    )
+    .with_file(config.file.clone().unwrap_or_default())
+    .with_note(format!(
+        "EV {}%, PV {}% (earned vs planned progress)",
+        schedule.earned_value, schedule.planned_value
+    ));
+
+    // Without earned progress, a status date or remaining work the SPI
+    // forecast is just the plan date, so only report it when it says more
+    if schedule.spi > 0.0 && schedule.project_progress < 100 && schedule.status_date.is_some() {
+        diagnostic = diagnostic.with_note(format!(
+            "SPI forecast finish: {} (plan-based forecast: {})",
+            schedule.spi_forecast_finish(),
+            schedule.project_forecast_finish
+        ));
+    }
+
+    emitter.emit(diagnostic);
+}
+
+/// I006: Emit schedule fragility when constraints alone make tasks critical
+fn check_schedule_fragility(
+    project: &Project,
+    schedule: &Schedule,
+    config: &AnalysisConfig,
+    emitter: &mut dyn DiagnosticEmitter,
+) {
+    let fragility = schedule.fragility(project);
+    if fragility.score() == 0 {
+        return;
+    }
+
+    let buffer_note = match fragility.deadline_buffer_days {
+        Some(days) if days < 0 => format!("project end date missed by {} days", -days),
+        Some(days) => format!("{} days of buffer before the project end date", days),
+        None => "no project end date set".to_string(),
+    };
+
     emitter.emit(
         Diagnostic::new(
-            DiagnosticCode::I005EarnedValueSummary,
-            format!("SPI {:.2}: {} {}", schedule.spi, spi_status, spi_emoji),
+            DiagnosticCode::I006ScheduleFragility,
+            format!(
+                "schedule fragility {}: tasks critical only because of date constraints",
+                fragility.score()
+            ),
         )
         .with_file(config.file.clone().unwrap_or_default())
         .with_note(format!(
-            "EV {}%, PV {}% (earned vs planned progress)",
-            schedule.earned_value, schedule.planned_value
-        )),
+            "constraint-critical: {}",
+            fragility.constraint_critical.join(", ")
+        ))
+        .with_note(buffer_note),
     );
 }
 
+/// I002: Share of assignments still staffed by abstract profiles
+///
+/// Only emitted while at least one assignment is abstract. Cost notes need
+/// a schedule with a cost range.
+fn check_refinement_progress(
+    project: &Project,
+    schedule: Option<&Schedule>,
+    info: &AssignmentInfo,
+    config: &AnalysisConfig,
+    emitter: &mut dyn DiagnosticEmitter,
+) {
+    let (abstract_count, total) = info
+        .assignments
+        .values()
+        .flatten()
+        .fold((0usize, 0usize), |(abs, total), (_, is_abstract)| {
+            (abs + usize::from(*is_abstract), total + 1)
+        });
+    if abstract_count == 0 {
+        return;
+    }
+
+    let concrete_count = total - abstract_count;
+    let concrete_pct = (concrete_count as f64 / total as f64 * 100.0).round() as u32;
+
+    let mut diagnostic = Diagnostic::new(
+        DiagnosticCode::I002RefinementProgress,
+        format!(
+            "refinement status: {}% of assignments concrete",
+            concrete_pct
+        ),
+    )
+    .with_file(config.file.clone().unwrap_or_default())
+    .with_note(format!("profiles defined: {}", project.profiles.len()))
+    .with_note(format!("profiles assigned: {}", info.used_profiles.len()))
+    .with_note(format!(
+        "concrete assignments: {} ({}%)",
+        concrete_count, concrete_pct
+    ))
+    .with_note(format!(
+        "abstract assignments: {} ({}%)",
+        abstract_count,
+        100 - concrete_pct
+    ));
+
+    if let Some(cost) = schedule.and_then(|s| s.total_cost_range.as_ref()) {
+        // certainty = 100 - total_spread / expected * 100
+        let certainty = (100.0 - 2.0 * cost.spread_percent()).max(0.0);
+        diagnostic = diagnostic
+            .with_note(format!("cost certainty: {:.0}%", certainty))
+            .with_note(format!(
+                "remaining uncertainty: ±{}",
+                CurrencyFormat::default()
+                    .render((cost.max - cost.min) / Decimal::from(2), &cost.currency)
+            ));
+    }
+
+    emitter.emit(diagnostic);
+}
+
 /// Classify the scheduling mode of a project based on its characteristics
 ///
 /// This is capability awareness, not validation. All modes are valid.
@@ -2655,7 +4029,10 @@ pub fn classify_scheduling_mode(project: &Project) -> SchedulingMode {
         .any(|t| t.effort.is_some() && !t.assigned.is_empty());
 
     // Check if any resource has a rate
-    let has_rates = project.resources.iter().any(|r| r.rate.is_some())
+    let has_rates = project
+        .resources
+        .iter()
+        .any(|r| r.rate.is_some() || r.cost.is_some())
         || project.profiles.iter().any(|p| p.rate.is_some());
 
     // Classify based on what's present
@@ -2688,14 +4065,7 @@ fn emit_project_summary(
         .count();
 
     let cost_str = if let Some(ref cost) = schedule.total_cost_range {
-        if cost.is_fixed() {
-            format!("${}", cost.expected)
-        } else {
-            format!(
-                "${} - ${} (expected: ${})",
-                cost.min, cost.max, cost.expected
-            )
-        }
+        cost.format(&CurrencyFormat::default())
     } else {
         "unknown (no cost data)".to_string()
     };
@@ -2735,6 +4105,22 @@ fn emit_project_summary(
 
 impl Scheduler for CpmSolver {
     fn schedule(&self, project: &Project) -> Result<Schedule, ScheduleError> {
+        // Step 0: Every calendar dates are counted on must have working days
+        check_working_calendars(project)?;
+
+        // Step 0a: Inactive tasks take no part; dependencies on them no
+        // longer resolve and are skipped like any unresolved dependency
+        if has_inactive_tasks(&project.tasks) {
+            let mut active = project.clone();
+            prune_inactive_tasks(&mut active.tasks);
+            return self.schedule(&active);
+        }
+
+        // Step 0b: Resolve relative constraints once their anchors are scheduled
+        if let Some(schedule) = self.schedule_relative_constraints(project)? {
+            return Ok(schedule);
+        }
+
         // Step 1: Flatten tasks with context for dependency resolution
         let (task_map, context_map) = flatten_tasks_with_context(&project.tasks);
 
@@ -2755,22 +4141,21 @@ impl Scheduler for CpmSolver {
                 planned_value: 0,
                 earned_value: 0,
                 spi: 1.0,
+                status_date: Some(self.effective_status_date(project)),
+                unconstrained_finish: None,
             });
         }
 
+        // Step 1b: Every assigned resource must be able to do some work
+        check_assigned_capacity(&task_map, project)?;
+
         // Step 2: Topological sort (with dependency path resolution)
         let topo_result = topological_sort(&task_map, &context_map)?;
         let sorted_ids = topo_result.sorted_ids;
         let successors_map = topo_result.successors;
 
         // Step 3: Get calendar (use first calendar or default)
-        let calendar = project
-            .calendars
-            .iter()
-            .find(|c| c.id == project.calendar)
-            .or_else(|| project.calendars.first())
-            .cloned()
-            .unwrap_or_default();
+        let calendar = project.default_calendar();
 
         // Step 3b: Resolve effective status_date (RFC-0004, C-01)
         // Priority: CLI override > project.status_date > today()
@@ -2781,7 +4166,8 @@ impl Scheduler for CpmSolver {
         let mut nodes: HashMap<String, TaskNode> = HashMap::new();
         for id in &sorted_ids {
             let task = task_map[id];
-            let duration_days = get_task_duration_days(task);
+            let duration_days = get_task_duration_days(task, project, &calendar);
+            let task_calendar = resolve_task_calendar(task, project, &calendar);
             nodes.insert(
                 id.clone(),
                 TaskNode {
@@ -2797,6 +4183,12 @@ impl Scheduler for CpmSolver {
                     baseline_start_days: 0,        // Computed in forward pass
                     baseline_finish_days: 0,       // Computed in forward pass
                     pinned_date: None,             // Set for milestones on non-working days
+                    task_calendar,
+                    calendar_dates: None,
+                    baseline_calendar_dates: None,
+                    late_calendar_dates: None,
+                    driving_predecessor: None,
+                    late_driver: None,
                 },
             );
         }
@@ -2850,26 +4242,23 @@ impl Scheduler for CpmSolver {
                         resolve_dependency_path(&dep.predecessor, id, &context_map, &task_map);
                     if let Some(pred_id) = resolved {
                         if let Some(pred_node) = nodes.get(&pred_id) {
-                            let lag = dep.lag.map(|d| d.as_days() as i64).unwrap_or(0);
                             // Use predecessor's BASELINE finish for baseline calculation
                             let pred_baseline_ef = pred_node.baseline_finish_days;
+                            let lag = dependency_lag_days(
+                                dep,
+                                pred_node.baseline_start_days,
+                                pred_baseline_ef,
+                                project.start,
+                                &calendar,
+                            );
 
-                            let constraint_es = match dep.dep_type {
-                                DependencyType::FinishToStart => {
-                                    if lag >= 0 {
-                                        pred_baseline_ef + lag
-                                    } else {
-                                        (pred_baseline_ef - 1 + lag).max(0)
-                                    }
-                                }
-                                DependencyType::StartToStart => pred_node.baseline_start_days + lag,
-                                DependencyType::FinishToFinish => {
-                                    (pred_baseline_ef + lag - original_duration).max(0)
-                                }
-                                DependencyType::StartToFinish => {
-                                    (pred_node.baseline_start_days + lag - original_duration).max(0)
-                                }
-                            };
+                            let constraint_es = dependency_earliest_start(
+                                dep.dep_type,
+                                lag,
+                                pred_node.baseline_start_days,
+                                pred_baseline_ef,
+                                original_duration,
+                            );
                             baseline_es = baseline_es.max(constraint_es);
                         }
                     }
@@ -2909,41 +4298,78 @@ impl Scheduler for CpmSolver {
                     }
                 }
 
+                // Tasks on their own calendar count duration in that calendar's working days
+                let task_calendar = nodes[id].task_calendar.clone();
+                let baseline_calendar_dates = task_calendar.as_ref().map(|task_cal| {
+                    let (start, finish, ef) = place_on_task_calendar(
+                        project.start,
+                        baseline_es,
+                        original_duration,
+                        &calendar,
+                        task_cal,
+                    );
+                    baseline_ef = ef;
+                    (start, finish)
+                });
+
                 // Step 5b: Classify progress state
-                let progress_state = classify_progress_state(task, project.start, &calendar);
+                let mut progress_state = classify_progress_state(task, project, &calendar);
+                // As-built: a recorded start counts even without reported progress
+                if self.as_built {
+                    if let (ProgressState::NotStarted { duration_days }, Some(actual_start)) =
+                        (&progress_state, task.actual_start)
+                    {
+                        progress_state = ProgressState::InProgress {
+                            actual_start_days: date_to_working_days(
+                                project.start,
+                                actual_start,
+                                &calendar,
+                            ),
+                            remaining_days: *duration_days,
+                        };
+                    }
+                }
 
                 // Step 5b2: Compute forecast ES from predecessors' progress-aware EF
                 // For NotStarted tasks, we need to chain from predecessors' FORECAST finish,
                 // not their baseline finish. This ensures correct cascade of progress.
                 let mut forecast_es = 0i64;
+                let mut driving: Option<(i64, String)> = None;
                 for dep in &task.depends {
                     let resolved =
                         resolve_dependency_path(&dep.predecessor, id, &context_map, &task_map);
+                    // A dependency on a container expands to the leaf that gates it
+                    let resolved = resolved.map(|pred_id| {
+                        if children_map.contains_key(&pred_id) {
+                            container_gate_leaf(&pred_id, dep.dep_type, &children_map, &nodes)
+                                .unwrap_or(pred_id)
+                        } else {
+                            pred_id
+                        }
+                    });
                     if let Some(pred_id) = resolved {
                         if let Some(pred_node) = nodes.get(&pred_id) {
-                            let lag = dep.lag.map(|d| d.as_days() as i64).unwrap_or(0);
                             // Use predecessor's PROGRESS-AWARE EF (early_finish) for forecast
                             let pred_ef = pred_node.early_finish;
+                            let lag = dependency_lag_days(
+                                dep,
+                                pred_node.early_start,
+                                pred_ef,
+                                project.start,
+                                &calendar,
+                            );
 
-                            let constraint_es = match dep.dep_type {
-                                DependencyType::FinishToStart => {
-                                    if lag >= 0 {
-                                        pred_ef + lag
-                                    } else {
-                                        (pred_ef - 1 + lag).max(0)
-                                    }
-                                }
-                                DependencyType::StartToStart => pred_node.early_start + lag,
-                                DependencyType::FinishToFinish => {
-                                    let original_dur = nodes[id].original_duration_days;
-                                    (pred_ef + lag - original_dur).max(0)
-                                }
-                                DependencyType::StartToFinish => {
-                                    let original_dur = nodes[id].original_duration_days;
-                                    (pred_node.early_start + lag - original_dur).max(0)
-                                }
-                            };
+                            let constraint_es = dependency_earliest_start(
+                                dep.dep_type,
+                                lag,
+                                pred_node.early_start,
+                                pred_ef,
+                                nodes[id].original_duration_days,
+                            );
                             forecast_es = forecast_es.max(constraint_es);
+                            if driving.as_ref().map_or(true, |(d, _)| constraint_es > *d) {
+                                driving = Some((constraint_es, pred_id.clone()));
+                            }
                         }
                     }
                 }
@@ -2969,14 +4395,24 @@ impl Scheduler for CpmSolver {
                         // InProgress: schedule remaining work from status_date (RFC-0004 Rule 2)
                         // ES = actual_start (task already started)
                         // EF = status_date + remaining (forecast based on current position)
+                        // As-built: no status date, so the planned duration runs
+                        // from the actual start
                         let es = actual_start_days;
-                        let ef = status_date_days + remaining_days;
+                        let ef = if self.as_built {
+                            es + nodes[id].original_duration_days
+                        } else {
+                            status_date_days + remaining_days
+                        };
                         (es, ef, remaining_days)
                     }
                     ProgressState::NotStarted { duration_days } => {
                         // NotStarted: use FORECAST ES from predecessors (RFC-0004 Rule 3)
                         // This chains correctly from in-progress/complete predecessors
                         let mut es = forecast_es;
+                        // Replanning: remaining work cannot start in the past
+                        if self.replan {
+                            es = es.max(status_date_days);
+                        }
 
                         // Track pinned date for Event/Deadline regime tasks on non-working days
                         // RFC-0012: Event and Deadline regimes use exact dates, Work regime uses working days
@@ -3094,9 +4530,27 @@ impl Scheduler for CpmSolver {
                             }
                         }
 
+                        // Place on the task's own calendar; dependencies arriving from
+                        // other calendars align on this calendar's next working day
+                        let calendar_dates = task_calendar
+                            .as_ref()
+                            .filter(|_| milestone_pinned_date.is_none())
+                            .map(|task_cal| {
+                                let (start, finish, task_ef) = place_on_task_calendar(
+                                    project.start,
+                                    es,
+                                    duration_days,
+                                    &calendar,
+                                    task_cal,
+                                );
+                                ef = task_ef;
+                                (start, finish)
+                            });
+
                         // Store pinned date for milestones on non-working days
                         if let Some(node) = nodes.get_mut(id) {
                             node.pinned_date = milestone_pinned_date;
+                            node.calendar_dates = calendar_dates;
                         }
 
                         // Not started: remaining = full duration
@@ -3107,6 +4561,10 @@ impl Scheduler for CpmSolver {
                 if let Some(node) = nodes.get_mut(id) {
                     node.early_start = es;
                     node.early_finish = ef;
+                    // A predecessor drives the start only if its constraint binds
+                    node.driving_predecessor = driving
+                        .filter(|(constraint_es, _)| *constraint_es == es)
+                        .map(|(_, pred_id)| pred_id);
                     // Update duration_days for backward pass calculations
                     node.duration_days = ef - es;
                     // Store progress-aware remaining duration (RFC-0004)
@@ -3114,6 +4572,7 @@ impl Scheduler for CpmSolver {
                     // Store baseline (original plan)
                     node.baseline_start_days = baseline_es;
                     node.baseline_finish_days = baseline_ef;
+                    node.baseline_calendar_dates = baseline_calendar_dates;
                 }
             }
         }
@@ -3135,8 +4594,20 @@ impl Scheduler for CpmSolver {
 
             let duration = nodes[id].duration_days;
 
-            // Get successors from precomputed map (O(1) lookup instead of O(n) scan)
-            let successors = successors_map.get(id);
+            // Get successors from precomputed map (O(1) lookup instead of O(n) scan).
+            // Successors of enclosing containers also bind this leaf: a
+            // finish-anchored link on a container holds every descendant
+            // leaf, a start-anchored one only the leaf that starts it.
+            let mut links: Vec<(&str, &String)> = Vec::new();
+            if let Some(succs) = successors_map.get(id) {
+                links.extend(succs.iter().map(|succ_id| (id.as_str(), succ_id)));
+            }
+            for (dot_pos, _) in id.rmatch_indices('.') {
+                let ancestor = &id[..dot_pos];
+                if let Some(succs) = successors_map.get(ancestor) {
+                    links.extend(succs.iter().map(|succ_id| (ancestor, succ_id)));
+                }
+            }
 
             // LF = min(constraint from each successor), or project_end if no successors
             // The constraint depends on the dependency type:
@@ -3144,64 +4615,92 @@ impl Scheduler for CpmSolver {
             //   SS: LF(pred) <= LS(succ) - lag + duration(pred)
             //   FF: LF(pred) <= LF(succ) - lag
             //   SF: LF(pred) <= LF(succ) - lag + duration(pred)
-            let lf = match successors {
-                Some(succs) if !succs.is_empty() => {
-                    let mut min_lf = project_end_days;
-                    for succ_id in succs {
-                        if let Some(succ_node) = nodes.get(succ_id) {
-                            // Find the dependency type from successor's depends list
-                            let succ_task = task_map.get(succ_id);
-                            let dep_info = succ_task.and_then(|t| {
-                                t.depends.iter().find(|d| {
-                                    // Check if this dependency refers to current task
-                                    let resolved = resolve_dependency_path(
-                                        &d.predecessor,
-                                        succ_id,
-                                        &context_map,
-                                        &task_map,
-                                    );
-                                    resolved.as_ref() == Some(id)
-                                })
-                            });
+            // A constraint-driven successor passes its reason back to
+            // predecessors whose late finish it strictly determines
+            let mut late_driver: Option<CriticalReason> = None;
+            let lf = {
+                let mut min_lf = project_end_days;
+                for (pred_id, succ_id) in links {
+                    // Container successors only get late dates in Step 7b
+                    if children_map.contains_key(succ_id) {
+                        continue;
+                    }
+                    if let Some(succ_node) = nodes.get(succ_id) {
+                        // Find the dependency type from successor's depends list
+                        let succ_task = task_map.get(succ_id);
+                        let dep_info = succ_task.and_then(|t| {
+                            t.depends.iter().find(|d| {
+                                // Check if this dependency refers to the linked task
+                                let resolved = resolve_dependency_path(
+                                    &d.predecessor,
+                                    succ_id,
+                                    &context_map,
+                                    &task_map,
+                                );
+                                resolved.as_deref() == Some(pred_id)
+                            })
+                        });
+
+                        // A container's start is held only by its gate leaf
+                        if let Some(dep) = dep_info {
+                            if pred_id != id.as_str()
+                                && is_start_anchored(dep.dep_type)
+                                && container_gate_leaf(pred_id, dep.dep_type, &children_map, &nodes)
+                                    .as_ref()
+                                    != Some(id)
+                            {
+                                continue;
+                            }
+                        }
 
-                            let constraint_lf = if let Some(dep) = dep_info {
-                                let lag = dep.lag.map(|d| d.as_days() as i64).unwrap_or(0);
-                                match dep.dep_type {
-                                    DependencyType::FinishToStart => {
-                                        // LF(pred) <= LS(succ) - lag
-                                        // For negative lag, mirror the forward pass formula:
-                                        // Forward uses pred.EF - 1 + lag, so backward uses LS + 1 - lag
-                                        if lag >= 0 {
-                                            succ_node.late_start - lag
-                                        } else {
-                                            succ_node.late_start + 1 - lag
-                                        }
-                                    }
-                                    DependencyType::StartToStart => {
-                                        // LS(pred) <= LS(succ) - lag
-                                        // LF(pred) = LS(pred) + duration = LS(succ) - lag + duration
-                                        succ_node.late_start - lag + duration
-                                    }
-                                    DependencyType::FinishToFinish => {
-                                        // LF(pred) <= LF(succ) - lag
-                                        succ_node.late_finish - lag
-                                    }
-                                    DependencyType::StartToFinish => {
-                                        // LS(pred) <= LF(succ) - lag
-                                        // LF(pred) = LF(succ) - lag + duration
-                                        succ_node.late_finish - lag + duration
+                        let constraint_lf = if let Some(dep) = dep_info {
+                            // Elapsed lags are converted from this task's early dates
+                            let lag = dependency_lag_days(
+                                dep,
+                                nodes[id].early_start,
+                                nodes[id].early_finish,
+                                project.start,
+                                &calendar,
+                            );
+                            match dep.dep_type {
+                                DependencyType::FinishToStart => {
+                                    // LF(pred) <= LS(succ) - lag
+                                    // For negative lag, mirror the forward pass formula:
+                                    // Forward uses pred.EF - 1 + lag, so backward uses LS + 1 - lag
+                                    if lag >= 0 {
+                                        succ_node.late_start - lag
+                                    } else {
+                                        succ_node.late_start + 1 - lag
                                     }
                                 }
-                            } else {
-                                // Default to FS behavior
-                                succ_node.late_start
-                            };
-                            min_lf = min_lf.min(constraint_lf);
+                                DependencyType::StartToStart => {
+                                    // LS(pred) <= LS(succ) - lag
+                                    // LF(pred) = LS(pred) + duration = LS(succ) - lag + duration
+                                    succ_node.late_start - lag + duration
+                                }
+                                DependencyType::FinishToFinish => {
+                                    // LF(pred) <= LF(succ) - lag
+                                    succ_node.late_finish - lag
+                                }
+                                DependencyType::StartToFinish => {
+                                    // LS(pred) <= LF(succ) - lag
+                                    // LF(pred) = LF(succ) - lag + duration
+                                    succ_node.late_finish - lag + duration
+                                }
+                            }
+                        } else {
+                            // Default to FS behavior
+                            succ_node.late_start
+                        };
+                        if constraint_lf < min_lf
+                            || (constraint_lf == min_lf && succ_node.late_driver.is_none())
+                        {
+                            late_driver = succ_node.late_driver;
                         }
+                        min_lf = min_lf.min(constraint_lf);
                     }
-                    min_lf
                 }
-                _ => project_end_days,
+                min_lf
             };
 
             // Apply ceiling constraints to LF/LS (backward pass)
@@ -3236,28 +4735,75 @@ impl Scheduler for CpmSolver {
                 }
             }
 
+            let own_reason = match task.map(|t| t.effective_regime()) {
+                Some(TemporalRegime::Deadline) => CriticalReason::DeadlineDriven,
+                _ => CriticalReason::ConstraintDriven,
+            };
+
             // Apply finish ceiling if specified
-            let lf = if let Some(mf) = max_finish {
-                lf.min(mf)
-            } else {
-                lf
+            let lf = match max_finish {
+                Some(mf) if mf < lf => {
+                    late_driver = Some(own_reason);
+                    mf
+                }
+                _ => lf,
             };
 
-            // LS = LF - duration (initial calculation)
-            let mut ls = lf - duration;
+            // LS = LF - duration (initial calculation); tasks placed on their
+            // own calendar in the forward pass count back on it too
+            let task_calendar = nodes[id]
+                .task_calendar
+                .as_ref()
+                .filter(|_| nodes[id].calendar_dates.is_some() && duration > 0);
+            let original_duration = nodes[id].original_duration_days;
+            let (mut ls, mut late_calendar_dates) = match task_calendar {
+                Some(task_cal) => {
+                    let (start, finish, ls) = place_late_on_task_calendar(
+                        project.start,
+                        lf,
+                        original_duration,
+                        &calendar,
+                        task_cal,
+                    );
+                    (ls, Some((start, finish)))
+                }
+                None => (lf - duration, None),
+            };
 
             // Apply start ceiling if specified
             if let Some(ms) = max_start {
-                ls = ls.min(ms);
+                if ms < ls {
+                    late_driver = Some(own_reason);
+                    ls = ms;
+                    late_calendar_dates = task_calendar.map(|task_cal| {
+                        let (start, finish, _) = place_on_task_calendar(
+                            project.start,
+                            ms,
+                            original_duration,
+                            &calendar,
+                            task_cal,
+                        );
+                        (start, finish)
+                    });
+                }
             }
 
-            // Slack = LS - ES (or LF - EF, they should be equal)
-            let slack = ls - nodes[id].early_start;
+            // Slack = LS - ES (or LF - EF, they should be equal), from the
+            // day the task actually starts when placed on its own calendar
+            let es = match (late_calendar_dates, nodes[id].calendar_dates) {
+                (Some(_), Some((start, _))) => {
+                    date_to_working_days(project.start, start, &calendar)
+                }
+                _ => nodes[id].early_start,
+            };
+            let slack = ls - es;
 
             if let Some(node) = nodes.get_mut(id) {
                 node.late_start = ls;
                 node.late_finish = lf;
                 node.slack = slack;
+                node.late_driver = late_driver;
+                node.late_calendar_dates = late_calendar_dates;
             }
         }
 
@@ -3273,9 +4819,14 @@ impl Scheduler for CpmSolver {
             if let Some(children) = children_map.get(container_id) {
                 let mut min_ls = i64::MAX;
                 let mut max_lf = i64::MIN;
+                let mut late_driver = None;
 
                 for child_id in children {
                     if let Some(child_node) = nodes.get(child_id) {
+                        // The earliest-starting child drives the container
+                        if child_node.late_start < min_ls {
+                            late_driver = child_node.late_driver;
+                        }
                         min_ls = min_ls.min(child_node.late_start);
                         max_lf = max_lf.max(child_node.late_finish);
                     }
@@ -3286,6 +4837,7 @@ impl Scheduler for CpmSolver {
                         container_node.late_start = min_ls;
                         container_node.late_finish = max_lf;
                         container_node.slack = min_ls - container_node.early_start;
+                        container_node.late_driver = late_driver;
                     }
                 }
             }
@@ -3298,7 +4850,8 @@ impl Scheduler for CpmSolver {
         infeasibility_check_ids.sort();
         for id in infeasibility_check_ids {
             let node = &nodes[id];
-            if node.slack < 0 {
+            // As-built dates are history, so overlaps with the network are not errors
+            if node.slack < 0 && !self.as_built {
                 return Err(ScheduleError::Infeasible(format!(
                     "task '{}' has infeasible constraints: ES ({}) > LS ({}), slack = {} days",
                     id, node.early_start, node.late_start, node.slack
@@ -3333,6 +4886,9 @@ impl Scheduler for CpmSolver {
             let (start_date, finish_date) = if let Some(pinned) = node.pinned_date {
                 // Milestone pinned to a specific non-working day
                 (pinned, pinned)
+            } else if let Some(dates) = node.calendar_dates {
+                // Task placed on its own calendar
+                dates
             } else {
                 let start = working_day_cache.get(node.early_start);
                 // Finish date is the last day of work, not the day after
@@ -3351,11 +4907,41 @@ impl Scheduler for CpmSolver {
             let mut task_cost_ranges: Vec<CostRange> = Vec::new();
             let mut has_abstract = false;
 
+            // Explicit effort is shared among assignments by the units they
+            // work; effort-driven work is costed from that share rather than
+            // from the duration, which is rounded up to whole days
+            let total_work_units: f64 = node
+                .task
+                .assigned
+                .iter()
+                .map(|r| assignment_work_units(r, project))
+                .sum();
+            let num_assignments = node.task.assigned.len().max(1) as f64;
+
             for res_ref in &node.task.assigned {
+                let work_units = assignment_work_units(res_ref, project);
+                let effort_days = node.task.effort.map(|effort| {
+                    let share = if total_work_units > 0.0 {
+                        work_units / total_work_units
+                    } else {
+                        1.0 / num_assignments
+                    };
+                    calendar.effort_days(effort) * share
+                });
+                let person_days = match effort_days
+                    .filter(|_| node.task.duration.is_none() && work_units > 0.0)
+                {
+                    Some(days) => Decimal::from_f64(days).unwrap_or_default(),
+                    None => {
+                        Decimal::from_f32(res_ref.units).unwrap_or(Decimal::ONE)
+                            * Decimal::from(node.duration_days)
+                    }
+                };
                 let (cost_range, is_abstract) = calculate_assignment_cost(
+                    id,
                     &res_ref.resource_id,
                     res_ref.units,
-                    node.duration_days,
+                    person_days,
                     project,
                 );
 
@@ -3377,14 +4963,6 @@ impl Scheduler for CpmSolver {
                     None
                 };
 
-                // Calculate effort_days for this assignment
-                // If task has explicit effort, distribute it among assignments
-                // Otherwise, leave as None to calculate from duration × units
-                let effort_days = node.task.effort.map(|effort| {
-                    let num_assignments = node.task.assigned.len().max(1) as f64;
-                    effort.as_days() / num_assignments
-                });
-
                 assignments.push(Assignment {
                     resource_id: res_ref.resource_id.clone(),
                     start: start_date,
@@ -3394,11 +4972,17 @@ impl Scheduler for CpmSolver {
                     cost_range: cost_range.clone(),
                     is_abstract,
                     effort_days,
+                    cost_explanation: project
+                        .get_profile(&res_ref.resource_id)
+                        .filter(|_| is_abstract)
+                        .and_then(|profile| explain_profile_cost(profile, project)),
+                    start_time: None,
+                    finish_time: None,
                 });
             }
 
-            // Aggregate task-level cost range
-            let task_cost_range = aggregate_cost_ranges(&task_cost_ranges);
+            // Aggregate task-level cost range (none when currencies mix, E005)
+            let task_cost_range = aggregate_cost_ranges(&task_cost_ranges).ok().flatten();
 
             // Progress tracking calculations
             let task = node.task;
@@ -3419,6 +5003,8 @@ impl Scheduler for CpmSolver {
             // For not-started tasks, EF = ES + duration
             let forecast_finish = if status == TaskStatus::Complete {
                 task.actual_finish.unwrap_or(finish_date)
+            } else if let Some((_, finish)) = node.calendar_dates {
+                finish
             } else if node.remaining_days > 0 {
                 // Use the early_finish calculated in forward pass
                 // This is already progress-aware (status_date + remaining for in-progress)
@@ -3433,12 +5019,20 @@ impl Scheduler for CpmSolver {
 
             // Variance calculation (calendar days, positive = late)
             // Compare forecast to BASELINE (original plan), not to progress-aware dates
-            let baseline_start_date = working_day_cache.get(node.baseline_start_days);
-            let baseline_finish_date = if node.original_duration_days > 0 {
-                working_day_cache.get(node.baseline_finish_days - 1)
-            } else {
-                working_day_cache.get(node.baseline_finish_days)
-            };
+            let (baseline_start_date, baseline_finish_date) =
+                if let Some(dates) = node.baseline_calendar_dates {
+                    dates
+                } else if node.original_duration_days > 0 {
+                    (
+                        working_day_cache.get(node.baseline_start_days),
+                        working_day_cache.get(node.baseline_finish_days - 1),
+                    )
+                } else {
+                    (
+                        working_day_cache.get(node.baseline_start_days),
+                        working_day_cache.get(node.baseline_finish_days),
+                    )
+                };
             let start_variance_days = (forecast_start - baseline_start_date).num_days();
             let finish_variance_days = (forecast_finish - baseline_finish_date).num_days();
 
@@ -3447,6 +5041,16 @@ impl Scheduler for CpmSolver {
                 if let Some(pinned) = node.pinned_date {
                     // All dates collapse to the pinned date for milestones on non-working days
                     (pinned, pinned, pinned, pinned, pinned, pinned)
+                } else if let Some((start, finish)) = node.calendar_dates {
+                    // Early, late and baseline dates follow the task's own calendar
+                    let (bs, bf) = node.baseline_calendar_dates.unwrap_or((start, finish));
+                    let (ls, lf) = node.late_calendar_dates.unwrap_or_else(|| {
+                        (
+                            working_day_cache.get(node.late_start),
+                            working_day_cache.get(node.late_finish - node.duration_days.min(1)),
+                        )
+                    });
+                    (start, finish, ls, lf, bs, bf)
                 } else {
                     (
                         working_day_cache.get(node.early_start),
@@ -3479,6 +5083,7 @@ impl Scheduler for CpmSolver {
                     duration: Duration::days(node.original_duration_days),
                     assignments,
                     slack: Duration::days(node.slack),
+                    free_slack: Duration::zero(),
                     is_critical: node.slack == 0, // Milestones can be critical too
                     early_start: es_date,
                     early_finish: ef_date,
@@ -3499,16 +5104,41 @@ impl Scheduler for CpmSolver {
                     // RFC-0001: Cost range fields
                     cost_range: task_cost_range,
                     has_abstract_assignments: has_abstract,
+                    driving_predecessor: node.driving_predecessor.clone(),
+                    is_milestone: node.task.milestone,
+                    critical_reason: (node.slack == 0)
+                        .then(|| node.late_driver.unwrap_or(CriticalReason::NetworkDriven)),
+                    is_pinned: node
+                        .task
+                        .constraints
+                        .iter()
+                        .any(|c| c.pins(es_date, ef_date, ls_date, lf_date)),
+                    tags: node.task.tags().map(String::from).collect(),
                 },
             );
         }
 
-        // Aggregate project-level cost ranges from all tasks
-        let all_task_cost_ranges: Vec<CostRange> = scheduled_tasks
+        // Step 9b: Measure variance against an attached baseline (RFC-0013)
+        if let Some(baseline) = &project.baseline {
+            apply_attached_baseline(baseline, &mut scheduled_tasks, &children_map);
+        }
+
+        // Aggregate project-level cost ranges from all assignments
+        let all_assignment_cost_ranges: Vec<CostRange> = scheduled_tasks
             .values()
-            .filter_map(|st| st.cost_range.clone())
+            .flat_map(|st| &st.assignments)
+            .filter_map(|a| a.cost_range.clone())
             .collect();
-        let total_cost_range = aggregate_cost_ranges(&all_task_cost_ranges);
+        let total_cost_range = aggregate_cost_ranges(&all_assignment_cost_ranges)
+            .ok()
+            .flatten();
+
+        // Step 9c: Roll container cost ranges up from their children (after
+        // the project total, so the subtree is not counted twice)
+        roll_up_container_costs(&mut scheduled_tasks, &children_map);
+
+        // Step 9d: Free slack from the finished dates of each successor link
+        assign_free_slack(&mut scheduled_tasks, &task_map, &context_map, &calendar);
 
         // Step 10: Build final schedule
         // project_end is the maximum finish date of all scheduled tasks
@@ -3520,21 +5150,15 @@ impl Scheduler for CpmSolver {
             .unwrap_or(project.start);
 
         // Step 10b: Compute project-level progress and variance (I004)
-        // Progress: weighted average of leaf task progress, weighted by duration
+        // Progress: weighted average of leaf task progress, weighted per
+        //           project.progress_weighting (duration by default)
         // Variance: max(forecast_finish) - max(baseline_finish)
         let (project_progress, project_baseline_finish, project_forecast_finish) = {
-            let mut total_weight: i64 = 0;
-            let mut weighted_progress: i64 = 0;
+            let mut total_weight: f64 = 0.0;
+            let mut weighted_progress: f64 = 0.0;
             let mut max_baseline = project.start;
             let mut max_forecast = project.start;
 
-            // Build set of container task IDs (tasks with children)
-            let container_ids: std::collections::HashSet<&str> = task_map
-                .values()
-                .filter(|t| !t.children.is_empty())
-                .map(|t| t.id.as_str())
-                .collect();
-
             for st in scheduled_tasks.values() {
                 // Update baseline/forecast max for ALL tasks
                 if st.baseline_finish > max_baseline {
@@ -3544,18 +5168,20 @@ impl Scheduler for CpmSolver {
                     max_forecast = st.forecast_finish;
                 }
 
-                // Only aggregate progress from leaf tasks (non-containers)
-                if !container_ids.contains(st.task_id.as_str()) {
-                    let duration_days = st.duration.as_days() as i64;
-                    if duration_days > 0 {
-                        total_weight += duration_days;
-                        weighted_progress += (st.percent_complete as i64) * duration_days;
+                // Only aggregate progress from leaf tasks (containers carry
+                // their subtree's cost)
+                if !children_map.contains_key(&st.task_id) {
+                    let task = task_map.get(&st.task_id).copied();
+                    let weight = progress_weight(project.progress_weighting, st, task, &calendar);
+                    if weight > 0.0 {
+                        total_weight += weight;
+                        weighted_progress += f64::from(st.percent_complete) * weight;
                     }
                 }
             }
 
-            let progress = if total_weight > 0 {
-                (weighted_progress / total_weight) as u8
+            let progress = if total_weight > 0.0 {
+                (weighted_progress / total_weight).floor() as u8
             } else {
                 0
             };
@@ -3567,13 +5193,14 @@ impl Scheduler for CpmSolver {
 
         // Step 10c: Compute Earned Value metrics (I005)
         // PV = weighted % of baseline work that should be complete by status date
-        // EV = actual project progress (already computed)
-        // SPI = EV / PV
-        let status_date = chrono::Local::now().date_naive();
+        // EV = project progress weighted the same way, but using physical
+        //      completion where set (falls back to schedule completion)
+        // SPI = EV / PV, measured at the effective status date (Step 3b)
 
         let (planned_value, earned_value, spi) = {
             let mut total_weight: i64 = 0;
             let mut weighted_pv: f64 = 0.0;
+            let mut weighted_ev: f64 = 0.0;
 
             // Build set of container task IDs (tasks with children)
             let container_ids: std::collections::HashSet<&str> = task_map
@@ -3609,18 +5236,26 @@ impl Scheduler for CpmSolver {
                         };
 
                         weighted_pv += task_pv * (duration_days as f64);
+
+                        let physical = task_map
+                            .get(&st.task_id)
+                            .and_then(|t| t.physical_complete)
+                            .map(|c| c.clamp(0.0, 100.0) as u8)
+                            .unwrap_or(st.percent_complete);
+                        weighted_ev += f64::from(physical) * (duration_days as f64);
                     }
                 }
             }
 
-            let pv = if total_weight > 0 {
-                (weighted_pv / total_weight as f64).round() as u8
+            let (pv, ev) = if total_weight > 0 {
+                (
+                    (weighted_pv / total_weight as f64).round() as u8,
+                    (weighted_ev / total_weight as f64).round() as u8,
+                )
             } else {
-                0
+                (0, 0)
             };
 
-            let ev = project_progress;
-
             // SPI = EV / PV with edge case handling
             let spi_value = if pv == 0 {
                 if ev == 0 {
@@ -3651,6 +5286,8 @@ impl Scheduler for CpmSolver {
             planned_value,
             earned_value,
             spi,
+            status_date: Some(status_date),
+            unconstrained_finish: None,
         };
 
         // Step 11: Apply resource leveling if enabled
@@ -3739,11 +5376,9 @@ impl Scheduler for CpmSolver {
         flatten_tasks(&project.tasks, &mut task_map);
 
         if let Some(task) = task_map.get(task_id) {
-            let dependency_constraints: Vec<String> = task
-                .depends
-                .iter()
-                .map(|d| format!("Depends on: {}", d.predecessor))
-                .collect();
+            let dependencies = self.explain_dependencies(project, task_id, task);
+            let dependency_constraints: Vec<String> =
+                dependencies.iter().map(|d| d.description.clone()).collect();
 
             // Build constraint effects from temporal constraints
             let constraint_effects = self.analyze_constraint_effects(project, task);
@@ -3772,6 +5407,7 @@ impl Scheduler for CpmSolver {
                 constraint_effects,
                 calendar_impact,
                 related_diagnostics: vec![], // Will be populated by analyze_project
+                dependencies,
             }
         } else {
             Explanation {
@@ -3782,6 +5418,7 @@ impl Scheduler for CpmSolver {
                 constraint_effects: vec![],
                 calendar_impact: None,
                 related_diagnostics: vec![],
+                dependencies: vec![],
             }
         }
     }
@@ -3789,6 +5426,74 @@ impl Scheduler for CpmSolver {
 
 // Helper methods for CpmSolver (not part of the Scheduler trait)
 impl CpmSolver {
+    /// Describe each dependency of a task with the earliest start it implies
+    ///
+    /// Mirrors the forward pass: the predecessor's scheduled dates are
+    /// converted to working days on the project calendar, the dependency
+    /// type and rounded lag are applied, and the result is mapped back to a
+    /// date.
+    fn explain_dependencies(
+        &self,
+        project: &Project,
+        task_id: &str,
+        task: &Task,
+    ) -> Vec<utf8proj_core::DependencyExplanation> {
+        use utf8proj_core::DependencyExplanation;
+
+        let (task_map, context_map) = flatten_tasks_with_context(&project.tasks);
+        let schedule = Scheduler::schedule(self, project).ok();
+        let calendar = project.default_calendar();
+        let to_days = |date: NaiveDate| date_to_working_days(project.start, date, &calendar);
+
+        task.depends
+            .iter()
+            .map(|dep| {
+                let earliest_start = schedule.as_ref().and_then(|schedule| {
+                    let pred_id = resolve_dependency_path(
+                        &dep.predecessor,
+                        task_id,
+                        &context_map,
+                        &task_map,
+                    )?;
+                    let pred = schedule.tasks.get(&pred_id)?;
+                    let duration = schedule.tasks.get(task_id)?.duration.round_days();
+                    let pred_ef =
+                        to_days(pred.early_finish) + i64::from(pred.duration.round_days() > 0);
+                    let pred_es = to_days(pred.early_start);
+                    let days = dependency_earliest_start(
+                        dep.dep_type,
+                        dependency_lag_days(dep, pred_es, pred_ef, project.start, &calendar),
+                        pred_es,
+                        pred_ef,
+                        duration,
+                    );
+                    Some(WorkingDayCache::new(project.start, days, &calendar).get(days))
+                });
+
+                let mut description =
+                    format!("{} from {}", dep.dep_type.short_code(), dep.predecessor);
+                if let Some(lag) = dep.lag.filter(|lag| lag.minutes != 0) {
+                    let sign = if lag.minutes < 0 { '-' } else { '+' };
+                    description.push_str(&format!(" {} {}d", sign, lag.as_days().abs()));
+                    if dep.lag_calendar {
+                        description.push_str(" elapsed");
+                    }
+                }
+                if let Some(date) = earliest_start {
+                    description.push_str(&format!(" → earliest {}", date));
+                }
+
+                DependencyExplanation {
+                    predecessor: dep.predecessor.clone(),
+                    dep_type: dep.dep_type,
+                    lag: dep.lag,
+                    earliest_start,
+                    description,
+                }
+            })
+            .collect()
+    }
+
     /// Calculate the impact of the calendar on task scheduling
     fn calculate_calendar_impact(
         &self,
@@ -3803,12 +5508,7 @@ impl CpmSolver {
         let scheduled_task = schedule.tasks.get(&task.id)?;
 
         // Get the calendar for this task (use project calendar)
-        let calendar = project
-            .calendars
-            .iter()
-            .find(|c| c.id == project.calendar)
-            .cloned()
-            .unwrap_or_default();
+        let calendar = project.default_calendar();
 
         let start = scheduled_task.start;
         let finish = scheduled_task.finish;
@@ -3891,7 +5591,7 @@ impl CpmSolver {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use utf8proj_core::{Resource, Task};
+    use utf8proj_core::{Resource, ResourceKind, Task};
 
     fn make_test_project() -> Project {
         let mut project = Project::new("Test Project");
@@ -3948,7 +5648,8 @@ mod tests {
 
         let task = &schedule.tasks["task1"];
         assert_eq!(task.start, project.start);
-        assert_eq!(task.duration, Duration::days(5));
+        // 40h of effort on the default 7-hour day
+        assert_eq!(task.duration, Duration::days(6));
         assert!(task.is_critical);
     }
 
@@ -3961,8 +5662,8 @@ mod tests {
         // All tasks should be scheduled
         assert_eq!(schedule.tasks.len(), 3);
 
-        // Project duration: 5 + 10 + 3 = 18 days
-        assert_eq!(schedule.project_duration, Duration::days(18));
+        // Project duration on 7-hour days: 6 + 12 + 4 = 22 days
+        assert_eq!(schedule.project_duration, Duration::days(22));
 
         // All tasks in a linear chain are critical
         assert!(schedule.tasks["design"].is_critical);
@@ -4005,9 +5706,10 @@ mod tests {
         let solver = CpmSolver::new();
         let schedule = solver.schedule(&project).unwrap();
 
-        // Critical path: design -> implement -> deploy (5 + 10 + 1 = 16 days)
-        // Non-critical: docs -> review (3 + 2 = 5 days)
-        assert_eq!(schedule.project_duration, Duration::days(16));
+        // On 7-hour days:
+        // Critical path: design -> implement -> deploy (6 + 12 + 2 = 20 days)
+        // Non-critical: docs -> review (4 + 3 = 7 days)
+        assert_eq!(schedule.project_duration, Duration::days(20));
 
         // design, implement, deploy should be critical
         assert!(schedule.tasks["design"].is_critical);
@@ -4020,6 +5722,44 @@ mod tests {
         assert!(schedule.tasks["docs"].slack.minutes > 0);
     }
 
+    #[test]
+    fn free_slack_on_parallel_paths() {
+        let mut project = Project::new("Parallel");
+        project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+        project.tasks = vec![
+            Task::new("design").effort(Duration::days(5)),
+            Task::new("implement")
+                .effort(Duration::days(10))
+                .depends_on("design"),
+            Task::new("docs").effort(Duration::days(3)),
+            Task::new("review")
+                .effort(Duration::days(2))
+                .depends_on("docs"),
+            Task::new("deploy")
+                .effort(Duration::days(1))
+                .depends_on("implement")
+                .depends_on("review"),
+        ];
+
+        let schedule = CpmSolver::new().schedule(&project).unwrap();
+
+        // docs -> review (4 + 3 days) runs alongside 18 days of design and
+        // implement: the whole path can slip 11 days, but docs cannot slip
+        // at all without pushing review
+        let docs = &schedule.tasks["docs"];
+        assert_eq!(docs.slack, Duration::days(11));
+        assert_eq!(docs.free_slack, Duration::zero());
+        assert_eq!(schedule.tasks["review"].free_slack, Duration::days(11));
+        assert_eq!(schedule.tasks["design"].free_slack, Duration::zero());
+        // The last task has no successors, so free slack is total slack
+        assert_eq!(schedule.tasks["deploy"].free_slack, Duration::zero());
+
+        for (id, task) in &schedule.tasks {
+            assert_eq!(Some(task.free_slack), schedule.free_slack(&project, id));
+            assert!(task.free_slack <= task.slack);
+        }
+    }
+
     #[test]
     fn detect_circular_dependency() {
         let mut project = Project::new("Circular");
@@ -4153,6 +5893,7 @@ mod tests {
 
     // =========================================================================
     // Effort-Driven Duration Tests (PMI Compliance)
+    // Effort is spread over the default calendar's 7-hour day
     // =========================================================================
 
     #[test]
@@ -4160,12 +5901,12 @@ mod tests {
         // No resources assigned = assume 1 resource at 100%
         let mut project = Project::new("Test");
         project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
-        project.tasks = vec![Task::new("work").effort(Duration::days(5))];
+        project.tasks = vec![Task::new("work").effort(Duration::hours(35))];
 
         let solver = CpmSolver::new();
         let schedule = solver.schedule(&project).unwrap();
 
-        // 5 days effort / 1.0 units = 5 days
+        // 35h effort / 1.0 units = 5 days
         assert_eq!(schedule.tasks["work"].duration.as_days(), 5.0);
     }
 
@@ -4175,12 +5916,12 @@ mod tests {
         let mut project = Project::new("Test");
         project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
         project.resources = vec![Resource::new("dev")];
-        project.tasks = vec![Task::new("work").effort(Duration::days(5)).assign("dev")]; // 100% by default
+        project.tasks = vec![Task::new("work").effort(Duration::hours(35)).assign("dev")]; // 100% by default
 
         let solver = CpmSolver::new();
         let schedule = solver.schedule(&project).unwrap();
 
-        // 5 days effort / 1.0 units = 5 days
+        // 35h effort / 1.0 units = 5 days
         assert_eq!(schedule.tasks["work"].duration.as_days(), 5.0);
     }
 
@@ -4191,16 +5932,88 @@ mod tests {
         project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
         project.resources = vec![Resource::new("dev")];
         project.tasks = vec![Task::new("work")
-            .effort(Duration::days(5))
+            .effort(Duration::hours(35))
             .assign_with_units("dev", 0.5)]; // 50%
 
         let solver = CpmSolver::new();
         let schedule = solver.schedule(&project).unwrap();
 
-        // 5 days effort / 0.5 units = 10 days
+        // 35h effort / 0.5 units = 10 days
+        assert_eq!(schedule.tasks["work"].duration.as_days(), 10.0);
+    }
+
+    #[test]
+    fn effort_with_partial_availability() {
+        // Full assignment to a half-available resource = duration doubles
+        let mut project = Project::new("Test");
+        project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+        project.resources = vec![Resource::new("dev").availability(0.5)];
+        project.tasks = vec![Task::new("work").effort(Duration::hours(35)).assign("dev")];
+
+        let solver = CpmSolver::new();
+        let schedule = solver.schedule(&project).unwrap();
+
+        // 35h effort / (1.0 units × 0.5 availability) = 10 days
         assert_eq!(schedule.tasks["work"].duration.as_days(), 10.0);
     }
 
+    #[test]
+    fn zero_capacity_resource_is_rejected() {
+        let mut project = Project::new("Test");
+        project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+        project.resources = vec![Resource::new("dev").capacity(0.0)];
+        project.tasks = vec![Task::new("work").effort(Duration::days(5)).assign("dev")];
+
+        let err = CpmSolver::new().schedule(&project).unwrap_err();
+        let msg = err.to_string();
+        assert!(matches!(err, ScheduleError::Infeasible(_)));
+        assert!(msg.contains("task 'work'"), "{}", msg);
+        assert!(msg.contains("resource 'dev'"), "{}", msg);
+    }
+
+    #[test]
+    fn zero_availability_resource_is_rejected() {
+        let mut project = Project::new("Test");
+        project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+        project.resources = vec![Resource::new("away").availability(0.0)];
+        project.tasks = vec![Task::new("work").effort(Duration::days(5)).assign("away")];
+
+        let solver = CpmSolver::new();
+        let err = solver.schedule(&project).unwrap_err();
+        assert!(err.to_string().contains("resource 'away'"));
+        assert!(!solver.is_feasible(&project).feasible);
+    }
+
+    #[test]
+    fn idle_assignee_beside_a_working_one_is_a_warning() {
+        let mut project = Project::new("Test");
+        project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+        project.resources = vec![
+            Resource::new("dev"),
+            Resource::new("away").availability(0.0),
+        ];
+        project.tasks = vec![Task::new("work")
+            .effort(Duration::days(5))
+            .assign("dev")
+            .assign("away")];
+
+        let schedule = CpmSolver::new().schedule(&project).unwrap();
+        let mut emitter = CollectingEmitter::new();
+        analyze_project(
+            &project,
+            Some(&schedule),
+            &AnalysisConfig::default(),
+            &mut emitter,
+        );
+
+        let warning = emitter
+            .diagnostics
+            .iter()
+            .find(|d| d.code == DiagnosticCode::W018AssignmentExceedsCapacity)
+            .unwrap();
+        assert!(warning.message.contains("'away'"), "{}", warning.message);
+    }
+
     #[test]
     fn effort_with_multiple_resources() {
         // 2 resources at 100% each = duration halves
@@ -4208,14 +6021,14 @@ mod tests {
         project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
         project.resources = vec![Resource::new("dev1"), Resource::new("dev2")];
         project.tasks = vec![Task::new("work")
-            .effort(Duration::days(10))
+            .effort(Duration::hours(70))
             .assign("dev1")
             .assign("dev2")]; // 100% + 100% = 200%
 
         let solver = CpmSolver::new();
         let schedule = solver.schedule(&project).unwrap();
 
-        // 10 days effort / 2.0 units = 5 days
+        // 70h effort / 2.0 units = 5 days
         assert_eq!(schedule.tasks["work"].duration.as_days(), 5.0);
     }
 
@@ -4226,14 +6039,14 @@ mod tests {
         project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
         project.resources = vec![Resource::new("dev1"), Resource::new("dev2")];
         project.tasks = vec![Task::new("work")
-            .effort(Duration::days(15))
+            .effort(Duration::hours(105))
             .assign("dev1") // 100%
             .assign_with_units("dev2", 0.5)]; // 50%
 
         let solver = CpmSolver::new();
         let schedule = solver.schedule(&project).unwrap();
 
-        // 15 days effort / 1.5 units = 10 days
+        // 105h effort / 1.5 units = 10 days
         assert_eq!(schedule.tasks["work"].duration.as_days(), 10.0);
     }
 
@@ -4261,9 +6074,11 @@ mod tests {
         project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
         project.resources = vec![Resource::new("dev")];
         project.tasks = vec![
-            Task::new("phase1").effort(Duration::days(5)).assign("dev"), // 100% -> 5 days
+            Task::new("phase1")
+                .effort(Duration::hours(35))
+                .assign("dev"), // 100% -> 5 days
             Task::new("phase2")
-                .effort(Duration::days(5))
+                .effort(Duration::hours(35))
                 .assign_with_units("dev", 0.5) // 50% -> 10 days
                 .depends_on("phase1"),
         ];
@@ -4277,6 +6092,60 @@ mod tests {
         assert_eq!(schedule.tasks["phase2"].duration.as_days(), 10.0);
     }
 
+    /// A one-task project on a calendar with the given working hours
+    fn project_with_working_hours(hours: &[(u32, u32)], task: Task) -> Project {
+        let mut project = Project::new("Test");
+        project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+        project.calendars[0].working_hours = hours
+            .iter()
+            .map(|&(start, end)| utf8proj_core::TimeRange {
+                start: (start * 60) as u16,
+                end: (end * 60) as u16,
+            })
+            .collect();
+        project.tasks = vec![task];
+        project
+    }
+
+    #[test]
+    fn effort_on_six_hour_days() {
+        let project = project_with_working_hours(
+            &[(9, 12), (13, 16)],
+            Task::new("work").effort(Duration::hours(40)),
+        );
+        let schedule = CpmSolver::new().schedule(&project).unwrap();
+
+        // ceil(40h / 6h) = 7 days
+        assert_eq!(schedule.tasks["work"].duration.as_days(), 7.0);
+    }
+
+    #[test]
+    fn effort_on_ten_hour_days() {
+        let project = project_with_working_hours(
+            &[(7, 12), (13, 18)],
+            Task::new("work")
+                .effort(Duration::hours(40))
+                .assign_with_units("dev", 0.5),
+        );
+        let schedule = CpmSolver::new().schedule(&project).unwrap();
+
+        // 40h / 10h = 4 days, doubled at 50%
+        assert_eq!(schedule.tasks["work"].duration.as_days(), 8.0);
+    }
+
+    #[test]
+    fn working_hours_leave_fixed_durations_and_milestones_alone() {
+        let mut project = project_with_working_hours(
+            &[(7, 12), (13, 18)],
+            Task::new("meeting").duration(Duration::days(3)),
+        );
+        project.tasks.push(Task::new("launch").milestone());
+        let schedule = CpmSolver::new().schedule(&project).unwrap();
+
+        assert_eq!(schedule.tasks["meeting"].duration.as_days(), 3.0);
+        assert_eq!(schedule.tasks["launch"].duration, Duration::zero());
+    }
+
     #[test]
     fn solver_default() {
         let solver = CpmSolver::default();
@@ -4385,7 +6254,7 @@ mod tests {
         // Task with no dependencies and nothing depends on it
         let mut project = Project::new("Isolated");
         project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
-        project.tasks = vec![Task::new("alone").effort(Duration::days(3))];
+        project.tasks = vec![Task::new("alone").effort(Duration::hours(21))];
 
         let solver = CpmSolver::new();
         let schedule = solver.schedule(&project).unwrap();
@@ -4446,6 +6315,61 @@ mod tests {
             .any(|c| c.contains("design")));
     }
 
+    #[test]
+    fn explain_renders_dependency_type_lag_and_earliest_start() {
+        use utf8proj_core::Dependency;
+
+        let dep = |dep_type, lag: Option<Duration>| Dependency {
+            predecessor: "design".to_string(),
+            dep_type,
+            lag,
+            lag_calendar: false,
+        };
+        let mut project = Project::new("Dependency Types");
+        project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+        project.tasks = vec![
+            // Mon 2025-01-06 .. Fri 2025-01-10
+            Task::new("design").duration(Duration::days(5)),
+            Task::new("fs")
+                .duration(Duration::days(2))
+                .with_dependency(dep(DependencyType::FinishToStart, None)),
+            Task::new("ss")
+                .duration(Duration::days(2))
+                .with_dependency(dep(DependencyType::StartToStart, Some(Duration::days(2)))),
+            Task::new("ff")
+                .duration(Duration::days(2))
+                .with_dependency(dep(DependencyType::FinishToFinish, None)),
+            Task::new("sf")
+                .duration(Duration::days(2))
+                .with_dependency(dep(DependencyType::StartToFinish, Some(Duration::days(3)))),
+        ];
+
+        let solver = CpmSolver::new();
+        let explain = |id: &str| solver.explain(&project, &id.to_string());
+        let date = |day| NaiveDate::from_ymd_opt(2025, 1, day).unwrap();
+
+        let cases = [
+            ("fs", "FS from design → earliest 2025-01-13", date(13)),
+            ("ss", "SS from design + 2d → earliest 2025-01-08", date(8)),
+            ("ff", "FF from design → earliest 2025-01-09", date(9)),
+            ("sf", "SF from design + 3d → earliest 2025-01-07", date(7)),
+        ];
+        for (id, text, earliest) in cases {
+            let explanation = explain(id);
+            assert_eq!(explanation.constraints_applied, vec![text.to_string()]);
+            let structured = &explanation.dependencies[0];
+            assert_eq!(structured.predecessor, "design");
+            assert_eq!(structured.earliest_start, Some(earliest));
+            // The implied date matches the forward pass when it is the only input
+            let schedule = solver.schedule(&project).unwrap();
+            assert_eq!(schedule.tasks[id].early_start, earliest, "{}", id);
+        }
+
+        let ss = &explain("ss").dependencies[0];
+        assert_eq!(ss.dep_type, DependencyType::StartToStart);
+        assert_eq!(ss.lag, Some(Duration::days(2)));
+    }
+
     #[test]
     fn explain_task_with_temporal_constraint_shows_effects() {
         use utf8proj_core::{ConstraintEffectType, TaskConstraint};
@@ -4691,6 +6615,17 @@ mod tests {
         assert_eq!(schedule.tasks["long_task"].duration, Duration::days(500));
     }
 
+    #[test]
+    fn working_day_cache_without_working_days() {
+        let start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+        let mut calendar = Calendar::default();
+        calendar.working_days = vec![];
+
+        let cache = WorkingDayCache::new(start, 10, &calendar);
+
+        assert_eq!(cache.get(10), start);
+    }
+
     // =============================================================================
     // RFC-0001: Progressive Resource Refinement Tests
     // =============================================================================
@@ -4847,7 +6782,8 @@ mod tests {
             .resources
             .push(Resource::new("alice").rate(Money::new(Decimal::from(100), "USD")));
 
-        let (cost, is_abstract) = calculate_assignment_cost("alice", 1.0, 5, &project);
+        let (cost, is_abstract) =
+            calculate_assignment_cost("task", "alice", 1.0, Decimal::from(5), &project);
 
         assert!(!is_abstract);
         let cost = cost.unwrap();
@@ -4865,7 +6801,8 @@ mod tests {
                 .rate_range(RateRange::new(Decimal::from(50), Decimal::from(100))),
         );
 
-        let (cost, is_abstract) = calculate_assignment_cost("developer", 1.0, 10, &project);
+        let (cost, is_abstract) =
+            calculate_assignment_cost("task", "developer", 1.0, Decimal::from(10), &project);
 
         assert!(is_abstract);
         let cost = cost.unwrap();
@@ -4876,6 +6813,48 @@ mod tests {
         assert_eq!(cost.expected, Decimal::from(750));
     }
 
+    #[test]
+    fn calculate_cost_follows_project_cost_policy() {
+        use utf8proj_core::CostPolicy;
+
+        let mut project = Project::new("Test");
+        project.profiles.push(
+            ResourceProfile::new("developer")
+                .rate_range(RateRange::new(Decimal::from(50), Decimal::from(100))),
+        );
+
+        // Only `expected` moves; min and max stay the true bounds
+        for (policy, expected) in [
+            (CostPolicy::Midpoint, 750),
+            (CostPolicy::Optimistic, 500),
+            (CostPolicy::Pessimistic, 1000),
+        ] {
+            project.cost_policy = policy;
+            let cost =
+                calculate_assignment_cost("task", "developer", 1.0, Decimal::from(10), &project)
+                    .0
+                    .unwrap();
+            assert_eq!(cost.expected, Decimal::from(expected), "{:?}", policy);
+            assert_eq!(cost.min, Decimal::from(500));
+            assert_eq!(cost.max, Decimal::from(1000));
+            assert_eq!(cost.policy, policy);
+        }
+
+        // Triangular: a stable draw inside the range, varying by task
+        project.cost_policy = CostPolicy::Triangular { seed: 7 };
+        let draw = |task: &str| {
+            calculate_assignment_cost(task, "developer", 1.0, Decimal::from(10), &project)
+                .0
+                .unwrap()
+                .expected
+        };
+        assert_eq!(draw("design"), draw("design"));
+        assert!(draw("design") >= Decimal::from(500) && draw("design") <= Decimal::from(1000));
+        let distinct: std::collections::HashSet<Decimal> =
+            ["a", "b", "c", "d", "e"].iter().map(|t| draw(t)).collect();
+        assert!(distinct.len() > 1);
+    }
+
     #[test]
     fn calculate_cost_with_partial_allocation() {
         use utf8proj_core::Resource;
@@ -4885,7 +6864,8 @@ mod tests {
             .resources
             .push(Resource::new("bob").rate(Money::new(Decimal::from(200), "EUR")));
 
-        let (cost, is_abstract) = calculate_assignment_cost("bob", 0.5, 4, &project);
+        let (cost, is_abstract) =
+            calculate_assignment_cost("task", "bob", 0.5, Decimal::from(2), &project);
 
         assert!(!is_abstract);
         let cost = cost.unwrap();
@@ -4897,7 +6877,7 @@ mod tests {
     #[test]
     fn aggregate_cost_ranges_single() {
         let ranges = vec![CostRange::fixed(Decimal::from(100), "USD")];
-        let total = aggregate_cost_ranges(&ranges).unwrap();
+        let total = aggregate_cost_ranges(&ranges).unwrap().unwrap();
 
         assert_eq!(total.min, Decimal::from(100));
         assert_eq!(total.max, Decimal::from(100));
@@ -4919,13 +6899,28 @@ mod tests {
                 "USD".to_string(),
             ),
         ];
-        let total = aggregate_cost_ranges(&ranges).unwrap();
+        let total = aggregate_cost_ranges(&ranges).unwrap().unwrap();
 
         assert_eq!(total.min, Decimal::from(150));
         assert_eq!(total.expected, Decimal::from(225));
         assert_eq!(total.max, Decimal::from(300));
     }
 
+    #[test]
+    fn aggregate_cost_ranges_rejects_mixed_currencies() {
+        let ranges = vec![
+            CostRange::fixed(Decimal::from(100), "USD"),
+            CostRange::fixed(Decimal::from(50), "EUR"),
+            CostRange::fixed(Decimal::from(25), "USD"),
+        ];
+
+        assert_eq!(
+            aggregate_cost_ranges(&ranges).unwrap_err(),
+            vec!["EUR".to_string(), "USD".to_string()]
+        );
+        assert_eq!(aggregate_cost_ranges(&[]), Ok(None));
+    }
+
     #[test]
     fn schedule_with_profile_assignment() {
         let mut project = Project::new("RFC-0001 Test");
@@ -5060,6 +7055,136 @@ mod tests {
             .any(|d| d.code == DiagnosticCode::W003UnknownTrait));
     }
 
+    #[test]
+    fn analyze_detects_profile_shadowed_by_resource() {
+        use utf8proj_core::{CollectingEmitter, Resource};
+
+        let mut project = Project::new("Shadowed Profile Test");
+        project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+        project.resources.push(Resource::new("developer"));
+        project.resources.push(Resource::new("alice"));
+        project.profiles.push(
+            ResourceProfile::new("developer")
+                .rate_range(RateRange::new(Decimal::from(100), Decimal::from(200))),
+        );
+
+        let mut emitter = CollectingEmitter::new();
+        let config = AnalysisConfig::default();
+        analyze_project(&project, None, &config, &mut emitter);
+
+        let shadowed: Vec<_> = emitter
+            .diagnostics
+            .iter()
+            .filter(|d| d.code == DiagnosticCode::W009ProfileShadowed)
+            .collect();
+        assert_eq!(shadowed.len(), 1);
+        assert!(shadowed[0].message.contains("'developer'"));
+        assert!(shadowed[0].notes[0].contains("resolve to the resource"));
+    }
+
+    #[test]
+    fn analyze_detects_consumable_pool_exceeded() {
+        use utf8proj_core::{CollectingEmitter, Resource};
+
+        let mut project = Project::new("Consumable Pool Test");
+        project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+        project.resources = vec![
+            Resource::new("dev"),
+            Resource::new("points").consumable(100.0),
+        ];
+        project.tasks = vec![
+            Task::new("a")
+                .effort(Duration::days(2))
+                .assign("dev")
+                .assign_with_units("points", 60.0),
+            Task::new("b")
+                .effort(Duration::days(2))
+                .assign("dev")
+                .assign_with_units("points", 30.0),
+        ];
+
+        let w010 = |project: &Project| {
+            let mut emitter = CollectingEmitter::new();
+            analyze_project(project, None, &AnalysisConfig::default(), &mut emitter);
+            emitter
+                .diagnostics
+                .into_iter()
+                .filter(|d| d.code == DiagnosticCode::W010ConsumablePoolExceeded)
+                .collect::<Vec<_>>()
+        };
+
+        // 90 of 100 used: within the pool
+        assert!(w010(&project).is_empty());
+
+        project.tasks[1] = Task::new("b")
+            .effort(Duration::days(2))
+            .assign("dev")
+            .assign_with_units("points", 50.0);
+        let diagnostics = w010(&project);
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0]
+            .message
+            .contains("'points' is over-committed: 110 used of a pool of 100"));
+        assert_eq!(diagnostics[0].notes[0], "consumed by: a (60), b (50)");
+    }
+
+    #[test]
+    fn analyze_detects_unsized_leaf_task() {
+        use utf8proj_core::CollectingEmitter;
+
+        let mut project = Project::new("Unsized Task Test");
+        project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+        project.tasks = vec![
+            Task::new("x"),
+            Task::new("gate").milestone(),
+            Task::new("phase").child(Task::new("work").effort(Duration::days(2))),
+        ];
+
+        let mut emitter = CollectingEmitter::new();
+        analyze_project(&project, None, &AnalysisConfig::default(), &mut emitter);
+
+        let unsized_tasks: Vec<_> = emitter
+            .diagnostics
+            .iter()
+            .filter(|d| d.code == DiagnosticCode::W011UnsizedTask)
+            .collect();
+        assert_eq!(unsized_tasks.len(), 1);
+        assert_eq!(
+            unsized_tasks[0].message,
+            "task 'x' has no effort or duration"
+        );
+        assert!(unsized_tasks[0].hints[0].contains("milestone"));
+    }
+
+    #[test]
+    fn consumable_assignments_do_not_affect_duration_or_leveling() {
+        use utf8proj_core::Resource;
+
+        let mut project = Project::new("Consumable Schedule Test");
+        project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+        project.resources = vec![
+            Resource::new("dev"),
+            Resource::new("material").consumable(500.0),
+        ];
+        project.tasks = vec![
+            Task::new("a")
+                .effort(Duration::hours(21))
+                .assign("dev")
+                .assign_with_units("material", 200.0),
+            Task::new("b")
+                .effort(Duration::hours(21))
+                .assign_with_units("material", 200.0),
+        ];
+
+        let schedule = CpmSolver::new().schedule(&project).unwrap();
+        // Three 7-hour days of effort are worked by dev alone, not divided
+        // by 200 units of material
+        assert_eq!(schedule.tasks["a"].duration, Duration::days(3));
+
+        // Both tasks draw on material the same days without a conflict
+        assert!(detect_overallocations(&project, &schedule).is_empty());
+    }
+
     #[test]
     fn analyze_detects_profile_without_rate() {
         use utf8proj_core::CollectingEmitter;
@@ -5266,6 +7391,35 @@ mod tests {
         assert_eq!(config.cost_spread_threshold, 75.0);
     }
 
+    #[test]
+    fn variance_threshold_is_configurable() {
+        use utf8proj_core::CollectingEmitter;
+
+        // 5-day task started a day late: forecast Mon Jan 13 vs baseline Fri Jan 10
+        let mut project = Project::new("Test");
+        project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+        project.status_date = Some(NaiveDate::from_ymd_opt(2025, 1, 8).unwrap());
+        let mut task = Task::new("work").duration(Duration::days(5));
+        task.actual_start = Some(NaiveDate::from_ymd_opt(2025, 1, 7).unwrap());
+        task.complete = Some(20.0);
+        project.tasks = vec![task];
+
+        let schedule = CpmSolver::new().schedule(&project).unwrap();
+        assert_eq!(schedule.tasks["work"].finish_variance_days, 3);
+
+        let warns_at = |days: i64| {
+            let config = AnalysisConfig::default().with_variance_threshold(days);
+            let mut emitter = CollectingEmitter::new();
+            analyze_project(&project, Some(&schedule), &config, &mut emitter);
+            emitter
+                .diagnostics
+                .iter()
+                .any(|d| d.code == DiagnosticCode::W006ScheduleVariance)
+        };
+        assert!(warns_at(2));
+        assert!(!warns_at(5));
+    }
+
     // =========================================================================
     // Coverage: Semantic Gap Tests
     // =========================================================================
@@ -5769,6 +7923,7 @@ mod tests {
             predecessor: "pred".to_string(),
             dep_type: DependencyType::FinishToStart,
             lag: None,
+            lag_calendar: false,
         });
         // SNET is before when task would start due to dependency
         successor
@@ -5852,6 +8007,9 @@ mod tests {
             attributes: HashMap::new(),
             specializes: None,
             availability: None,
+            pool: None,
+            kind: ResourceKind::Work,
+            cost: None,
         };
         project.resources = vec![resource];
 
@@ -5887,6 +8045,9 @@ mod tests {
             attributes: HashMap::new(),
             specializes: None,
             availability: None,
+            pool: None,
+            kind: ResourceKind::Work,
+            cost: None,
         };
         project.resources = vec![resource];
 
@@ -0,0 +1,76 @@
+//! Team sizing for abstract profiles
+//!
+//! Answers "how many people do we need to hit the date": for each profile
+//! the plan is staffed with, finds the smallest pool of interchangeable
+//! people that resource leveling can work with without moving the finish.
+
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use utf8proj_core::{Calendar, ProfileId, Project, Resource, Scheduler};
+
+use crate::level_resources;
+
+/// Smallest pool size per profile that keeps the project finish
+///
+/// Each profile assigned on a leaf task is sized on its own: its
+/// assignments are moved to a pool resource of that capacity (on the
+/// profile's calendar) and the plan is leveled, while other profiles stay
+/// unconstrained. A pool size works when leveling resolves every conflict
+/// on the pool without finishing later than the plan does with the
+/// profile unconstrained. Sizes are binary searched between one and the
+/// profile's peak concurrent demand, which always works. Empty when the
+/// plan cannot be scheduled.
+pub fn minimum_staffing(project: &Project, solver: &impl Scheduler) -> HashMap<ProfileId, u32> {
+    let Ok(schedule) = solver.schedule(project) else {
+        return HashMap::new();
+    };
+    let calendar = project.default_calendar();
+    let target = level_resources(project, &schedule, &calendar).new_project_end;
+
+    schedule
+        .profile_demand()
+        .into_iter()
+        .map(|(profile, demand)| {
+            let (mut low, mut high) = (1, demand.peak_units.ceil().max(1.0) as u32);
+            while low < high {
+                let size = (low + high) / 2;
+                if keeps_finish(project, solver, &calendar, &profile, size, target) {
+                    high = size;
+                } else {
+                    low = size + 1;
+                }
+            }
+            (profile, low)
+        })
+        .collect()
+}
+
+/// Whether a pool of `size` people for `profile` levels within `target`
+fn keeps_finish(
+    project: &Project,
+    solver: &impl Scheduler,
+    calendar: &Calendar,
+    profile: &str,
+    size: u32,
+    target: NaiveDate,
+) -> bool {
+    let pool_id = format!("{}#pool", profile);
+    let mut sized = project.clone();
+    let mut pool = Resource::new(pool_id.clone()).capacity(size as f32);
+    pool.calendar = project
+        .get_profile(profile)
+        .and_then(|p| p.calendar.clone());
+    sized.resources.push(pool);
+    sized.reassign_resource(profile, &pool_id);
+
+    let Ok(schedule) = solver.schedule(&sized) else {
+        return false;
+    };
+    let result = level_resources(&sized, &schedule, calendar);
+    result.new_project_end <= target
+        && !result
+            .unresolved_conflicts
+            .iter()
+            .any(|c| c.resource_id == pool_id)
+}
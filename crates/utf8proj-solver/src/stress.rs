@@ -0,0 +1,158 @@
+//! Start-date stress testing
+//!
+//! Reschedules a copy of the project from a different start date and
+//! reports which fixed-date task constraints stop being satisfiable or
+//! start driving the schedule. Answers questions like "can we start two
+//! weeks later without blowing any deadlines".
+
+use crate::what_if::feasible_schedule;
+use chrono::NaiveDate;
+use utf8proj_core::{
+    ConstraintEffectType, Project, Schedule, Scheduler, Task, TaskConstraint, TaskId,
+};
+
+/// How a date shift breaks a constraint
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BreakKind {
+    /// The constraint can no longer be met
+    Infeasible,
+    /// The constraint now drives the task's dates, with no float left
+    NewlyBinding,
+}
+
+/// A fixed-date constraint affected by a start-date shift
+#[derive(Clone, Debug)]
+pub struct BrokenConstraint {
+    /// Qualified ID of the constrained task
+    pub task_id: TaskId,
+    /// The affected constraint
+    pub constraint: TaskConstraint,
+    /// Whether it became infeasible or newly binding
+    pub kind: BreakKind,
+    /// What happens under the shifted start, e.g. "finishes 2025-02-03,
+    /// after 2025-01-31"
+    pub detail: String,
+}
+
+/// Report the constraints that break when the project starts on `new_start`
+///
+/// A ceiling (`StartNoLaterThan`, `FinishNoLaterThan`, or the latest side of
+/// `MustStartOn`/`MustFinishOn`) is infeasible when the shifted project,
+/// scheduled with its ceilings lifted, cannot reach the task by the
+/// constraint date. When the shifted project is feasible, constraints that
+/// bind under it but did not under the current start are reported as newly
+/// binding. Anchored (`RelativeTo`) constraints move with their anchor and
+/// are not checked. Sorted by task ID; empty when the shifted project
+/// cannot be scheduled even without its ceilings.
+pub fn stress_test_start_date(
+    project: &Project,
+    new_start: NaiveDate,
+    solver: &impl Scheduler,
+) -> Vec<BrokenConstraint> {
+    let mut shifted = project.clone();
+    shifted.start = new_start;
+
+    let mut relaxed = shifted.clone();
+    lift_ceilings(&mut relaxed.tasks);
+    let Ok(relaxed_schedule) = solver.schedule(&relaxed) else {
+        return Vec::new();
+    };
+
+    let mut ids: Vec<&TaskId> = relaxed_schedule.tasks.keys().collect();
+    ids.sort();
+
+    let mut broken = Vec::new();
+    for id in &ids {
+        let (Some(task), Some(scheduled)) = (
+            shifted.get_task_by_path(id),
+            relaxed_schedule.tasks.get(*id),
+        ) else {
+            continue;
+        };
+        for constraint in &task.constraints {
+            let detail = match constraint {
+                TaskConstraint::MustStartOn(date) | TaskConstraint::StartNoLaterThan(date)
+                    if scheduled.start > *date =>
+                {
+                    format!("starts {}, after {}", scheduled.start, date)
+                }
+                TaskConstraint::MustFinishOn(date) | TaskConstraint::FinishNoLaterThan(date)
+                    if scheduled.finish > *date =>
+                {
+                    format!("finishes {}, after {}", scheduled.finish, date)
+                }
+                _ => continue,
+            };
+            broken.push(BrokenConstraint {
+                task_id: (*id).clone(),
+                constraint: constraint.clone(),
+                kind: BreakKind::Infeasible,
+                detail,
+            });
+        }
+    }
+    if !broken.is_empty() {
+        return broken;
+    }
+
+    let Ok(after) = feasible_schedule(solver, &shifted) else {
+        return broken;
+    };
+    let before = solver.schedule(project).ok();
+    for id in ids {
+        for (index, effect) in after
+            .constraint_effects(&shifted, id)
+            .into_iter()
+            .enumerate()
+        {
+            if matches!(effect.constraint, TaskConstraint::RelativeTo { .. })
+                || !binds(&after, id, &effect.effect)
+            {
+                continue;
+            }
+            let was_binding = before.as_ref().is_some_and(|before| {
+                before
+                    .constraint_effects(project, id)
+                    .get(index)
+                    .is_some_and(|e| binds(before, id, &e.effect))
+            });
+            if !was_binding {
+                broken.push(BrokenConstraint {
+                    task_id: id.clone(),
+                    constraint: effect.constraint,
+                    kind: BreakKind::NewlyBinding,
+                    detail: effect.description,
+                });
+            }
+        }
+    }
+    broken
+}
+
+/// Whether a constraint effect leaves the task no room to move
+fn binds(schedule: &Schedule, task_id: &str, effect: &ConstraintEffectType) -> bool {
+    match effect {
+        ConstraintEffectType::Pinned | ConstraintEffectType::PushedStart => true,
+        ConstraintEffectType::CappedLate => schedule.tasks[task_id].slack.round_days() == 0,
+        ConstraintEffectType::Redundant => false,
+    }
+}
+
+/// Drop start and finish ceilings, keeping the floor side of pinned dates
+fn lift_ceilings(tasks: &mut [Task]) {
+    for task in tasks {
+        task.constraints = task
+            .constraints
+            .drain(..)
+            .filter_map(|c| match c {
+                TaskConstraint::MustStartOn(date) => Some(TaskConstraint::StartNoEarlierThan(date)),
+                TaskConstraint::MustFinishOn(date) => {
+                    Some(TaskConstraint::FinishNoEarlierThan(date))
+                }
+                TaskConstraint::StartNoLaterThan(_) | TaskConstraint::FinishNoLaterThan(_) => None,
+                c => Some(c),
+            })
+            .collect();
+        lift_ceilings(&mut task.children);
+    }
+}
@@ -0,0 +1,187 @@
+//! What-if analysis by rescheduling
+//!
+//! Applies [`Constraint`] changes, inserts new tasks, or substitutes one
+//! resource for another on a copy of the project, reschedules it with CPM,
+//! and reports the effect against the unchanged plan. A CPM run yields a
+//! single schedule, so solution counts are 1 (feasible) or 0.
+
+use crate::CpmSolver;
+use std::collections::HashSet;
+use utf8proj_core::{
+    Constraint, Money, Project, Schedule, ScheduleError, Scheduler, Task, TaskId, WhatIfReport,
+};
+
+impl CpmSolver {
+    /// Analyze the combined impact of several changes
+    ///
+    /// All changes are applied to one clone of the project, which is
+    /// rescheduled once, so interaction effects are captured. The report
+    /// compares against the unchanged project: newly critical tasks, the
+    /// change in project duration, and the change in expected cost. If the
+    /// combination is infeasible, each change is checked on its own and
+    /// `infeasible_in_combination` is set when every one of them was fine.
+    pub fn what_if_batch(&self, project: &Project, changes: &[Constraint]) -> WhatIfReport {
+        let before = feasible_schedule(self, project).ok();
+        let after = apply_all(project, changes).and_then(|p| feasible_schedule(self, &p));
+
+        let infeasible_in_combination = after.is_err()
+            && changes.len() > 1
+            && changes.iter().all(|change| {
+                apply_all(project, std::slice::from_ref(change))
+                    .and_then(|p| feasible_schedule(self, &p))
+                    .is_ok()
+            });
+
+        compare(before, after.ok(), infeasible_in_combination)
+    }
+
+    /// Analyze the impact of inserting a new task
+    ///
+    /// The task is added to a clone of the project, depending (finish to
+    /// start) on each of `after`, as a child of the `parent` container
+    /// (by qualified ID) or at the top level. The report compares the rescheduled clone against
+    /// the unchanged project like [`CpmSolver::what_if_batch`]; an unknown
+    /// parent makes the change infeasible.
+    pub fn what_if_add_task(
+        &self,
+        project: &Project,
+        task: Task,
+        after: &[TaskId],
+        parent: Option<&str>,
+    ) -> WhatIfReport {
+        let before = feasible_schedule(self, project).ok();
+        let after = insert_task(project, task, after, parent)
+            .and_then(|p| feasible_schedule(self, &p))
+            .ok();
+        compare(before, after, false)
+    }
+
+    /// Analyze the impact of substituting one resource or profile for another
+    ///
+    /// Every assignment of `from_id` is moved to `to_id` on a clone of the
+    /// project (like [`Project::reassign_resource`], keeping units), which
+    /// is rescheduled and costed at the substitute's rate. The report
+    /// compares against the unchanged project like
+    /// [`CpmSolver::what_if_batch`]; `critical_path_changed` shows whether
+    /// the substitute's calendar or availability reshaped the critical path.
+    /// Substituting an unknown resource or profile is infeasible.
+    pub fn what_if_substitute(
+        &self,
+        project: &Project,
+        from_id: &str,
+        to_id: &str,
+    ) -> WhatIfReport {
+        let before = feasible_schedule(self, project).ok();
+        let after = substitute(project, from_id, to_id)
+            .and_then(|p| feasible_schedule(self, &p))
+            .ok();
+        compare(before, after, false)
+    }
+}
+
+/// Report the change from `before` to `after`
+fn compare(
+    before: Option<Schedule>,
+    after: Option<Schedule>,
+    infeasible_in_combination: bool,
+) -> WhatIfReport {
+    let (newly_critical, critical_path_changed, schedule_delta, cost_delta) =
+        match (&before, &after) {
+            (Some(before), Some(after)) => {
+                let was_critical: HashSet<&str> =
+                    before.critical_path.iter().map(String::as_str).collect();
+                let mut newly_critical: Vec<_> = after
+                    .critical_path
+                    .iter()
+                    .filter(|id| !was_critical.contains(id.as_str()))
+                    .cloned()
+                    .collect();
+                newly_critical.sort();
+                let is_critical: HashSet<&str> =
+                    after.critical_path.iter().map(String::as_str).collect();
+                (
+                    newly_critical,
+                    is_critical != was_critical,
+                    Some(after.project_duration - before.project_duration),
+                    cost_delta(before, after),
+                )
+            }
+            _ => (Vec::new(), false, None, None),
+        };
+
+    WhatIfReport {
+        still_feasible: after.is_some(),
+        solutions_before: u32::from(before.is_some()).into(),
+        solutions_after: u32::from(after.is_some()).into(),
+        newly_critical,
+        critical_path_changed,
+        schedule_delta,
+        cost_delta,
+        infeasible_in_combination,
+    }
+}
+
+/// Schedule a project, treating a missed project end date as infeasible
+pub(crate) fn feasible_schedule(
+    solver: &impl Scheduler,
+    project: &Project,
+) -> Result<Schedule, ScheduleError> {
+    let schedule = solver.schedule(project)?;
+    match project.end {
+        Some(end) if schedule.project_end > end => Err(ScheduleError::Infeasible(format!(
+            "project finishes {} after its end date {}",
+            schedule.project_end, end
+        ))),
+        _ => Ok(schedule),
+    }
+}
+
+/// Apply changes in order to a clone of the project
+fn apply_all(project: &Project, changes: &[Constraint]) -> Result<Project, ScheduleError> {
+    let mut changed = project.clone();
+    for change in changes {
+        change.apply_to(&mut changed)?;
+    }
+    Ok(changed)
+}
+
+/// Insert `task` into a clone of the project after the given predecessors
+fn insert_task(
+    project: &Project,
+    mut task: Task,
+    after: &[TaskId],
+    parent: Option<&str>,
+) -> Result<Project, ScheduleError> {
+    for predecessor in after {
+        task = task.depends_on(predecessor.as_str());
+    }
+    let mut changed = project.clone();
+    match parent {
+        Some(parent) => changed
+            .get_task_by_path_mut(parent)
+            .ok_or_else(|| ScheduleError::TaskNotFound(parent.to_string()))?
+            .children
+            .push(task),
+        None => changed.tasks.push(task),
+    }
+    Ok(changed)
+}
+
+/// Reassign `from_id` to `to_id` on a clone of the project
+fn substitute(project: &Project, from_id: &str, to_id: &str) -> Result<Project, ScheduleError> {
+    if project.get_resource(to_id).is_none() && project.get_profile(to_id).is_none() {
+        return Err(ScheduleError::ResourceNotFound(to_id.to_string()));
+    }
+    let mut changed = project.clone();
+    changed.reassign_resource(from_id, to_id);
+    Ok(changed)
+}
+
+/// Change in expected total cost, when both schedules are costed in the
+/// same currency
+fn cost_delta(before: &Schedule, after: &Schedule) -> Option<Money> {
+    let before = before.total_cost_range.as_ref()?;
+    let after = after.total_cost_range.as_ref()?;
+    (before.currency == after.currency)
+        .then(|| Money::new(after.expected - before.expected, after.currency.clone()))
+}
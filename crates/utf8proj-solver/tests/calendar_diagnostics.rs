@@ -1,13 +1,19 @@
-//! Integration tests for Calendar Diagnostics (C001, C002, C010, C011, C020-C023)
+//! Integration tests for Calendar Diagnostics (C001, C002, C010-C012, C020-C024)
 //!
-//! These tests verify calendar-related diagnostic checks.
+//! These tests verify calendar-related diagnostic checks, and that the
+//! solver rejects calendars with no working days instead of hanging.
+//! Per-task calendar overrides are covered at the end.
 
-use chrono::{Datelike, Local};
+use chrono::{Datelike, Local, NaiveDate};
 use utf8proj_core::{
-    Calendar, CollectingEmitter, DiagnosticCode, Duration, Holiday, Project, Resource, Scheduler,
-    Task, TimeRange,
+    Calendar, CalendarException, CollectingEmitter, DiagnosticCode, Duration, Holiday, Project,
+    Resource, Schedule, ScheduleError, Scheduler, Task, TimeRange,
 };
-use utf8proj_solver::{analyze_project, AnalysisConfig, CpmSolver};
+use utf8proj_solver::{analyze_project, level_resources, AnalysisConfig, CpmSolver};
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
 
 /// Helper to get current date
 fn today() -> chrono::NaiveDate {
@@ -81,6 +87,132 @@ fn c002_no_working_days() {
     );
 }
 
+/// Test: Scheduling on a calendar with no working days fails instead of hanging
+#[test]
+fn no_working_days_calendar_is_rejected_by_solver() {
+    let mut project = Project::new("Invalid Calendar");
+    project.start = today();
+    let mut no_days_cal = Calendar::default();
+    no_days_cal.id = "no_days".to_string();
+    no_days_cal.working_days = vec![];
+    project.calendar = "no_days".to_string();
+    project.calendars.push(no_days_cal);
+    project
+        .tasks
+        .push(Task::new("task1").duration(Duration::days(5)));
+
+    let result = CpmSolver::new().schedule(&project);
+
+    assert!(matches!(
+        result,
+        Err(ScheduleError::InvalidCalendar(ref id)) if id == "no_days"
+    ));
+}
+
+/// Test: A task calendar with only out-of-range weekdays is rejected too
+#[test]
+fn no_working_days_task_calendar_is_rejected_by_solver() {
+    let mut project = Project::new("Invalid Task Calendar");
+    project.start = today();
+    let mut bad_cal = Calendar::default();
+    bad_cal.id = "bad".to_string();
+    bad_cal.working_days = vec![7];
+    project.calendars.push(bad_cal);
+    let mut task = Task::new("task1").duration(Duration::days(5));
+    task.calendar = Some("bad".to_string());
+    project.tasks.push(task);
+
+    let result = CpmSolver::new().schedule(&project);
+
+    assert!(matches!(
+        result,
+        Err(ScheduleError::InvalidCalendar(ref id)) if id == "bad"
+    ));
+}
+
+/// A project whose only calendar has no working days, and the same
+/// project on a Monday-Friday calendar to get a schedule from
+fn no_working_days_project() -> (Project, Schedule) {
+    let mut project = Project::new("No Working Days");
+    project.start = today();
+    let mut no_days_cal = Calendar::default();
+    no_days_cal.id = "no_days".to_string();
+    no_days_cal.working_days = vec![];
+    project.calendar = "no_days".to_string();
+    project.calendars.push(no_days_cal);
+    project.resources.push(Resource::new("dev"));
+    project
+        .tasks
+        .push(Task::new("task1").duration(Duration::days(5)).assign("dev"));
+    project
+        .tasks
+        .push(Task::new("task2").duration(Duration::days(5)).assign("dev"));
+
+    let mut workable = project.clone();
+    for calendar in &mut workable.calendars {
+        calendar.working_days = vec![1, 2, 3, 4, 5];
+    }
+    let schedule = CpmSolver::new().schedule(&workable).unwrap();
+    (project, schedule)
+}
+
+/// Test: Feasibility checks report a calendar with no working days
+#[test]
+fn no_working_days_calendar_is_infeasible() {
+    let (project, _) = no_working_days_project();
+
+    let result = CpmSolver::new().is_feasible(&project);
+
+    assert!(!result.feasible);
+    assert!(result.conflicts[0].description.contains("no_days"));
+}
+
+/// Test: Explaining a task on a calendar with no working days returns
+#[test]
+fn no_working_days_calendar_explains_without_dates() {
+    let (project, _) = no_working_days_project();
+
+    let explanation = CpmSolver::new().explain(&project, &"task1".to_string());
+
+    assert_eq!(explanation.task_id, "task1");
+    assert!(explanation.calendar_impact.is_none());
+}
+
+/// Test: Analysis runs against a calendar with no working days
+#[test]
+fn no_working_days_calendar_is_analyzed() {
+    let (project, schedule) = no_working_days_project();
+
+    let mut emitter = CollectingEmitter::new();
+    analyze_project(
+        &project,
+        Some(&schedule),
+        &AnalysisConfig::default(),
+        &mut emitter,
+    );
+
+    assert!(emitter
+        .diagnostics
+        .iter()
+        .any(|d| d.code == DiagnosticCode::C002NoWorkingDays));
+}
+
+/// Test: Leveling on a calendar with no working days leaves the schedule
+#[test]
+fn no_working_days_calendar_is_not_leveled() {
+    let (project, schedule) = no_working_days_project();
+    let calendar = project.calendars.last().unwrap().clone();
+
+    let result = level_resources(&project, &schedule, &calendar);
+
+    assert!(result.shifted_tasks.is_empty());
+    assert_eq!(result.leveled_schedule.project_end, schedule.project_end);
+    assert!(result
+        .diagnostics
+        .iter()
+        .any(|d| d.code == DiagnosticCode::C002NoWorkingDays));
+}
+
 /// Test: C010 - Task scheduled on non-working day emits warning
 #[test]
 fn c010_non_working_day() {
@@ -121,6 +253,63 @@ fn c010_non_working_day() {
     );
 }
 
+/// Project starting on `start` on a Mon-Fri calendar with one exception
+fn c010_project(start: chrono::NaiveDate, exception: CalendarException) -> Vec<DiagnosticCode> {
+    let mut project = Project::new("C010 Exception Test");
+    project.start = start;
+    let mut cal = Calendar::default();
+    cal.id = "standard".to_string();
+    cal.exceptions = vec![exception];
+    project.calendars.push(cal);
+    project.calendar = "standard".to_string();
+    project
+        .tasks
+        .push(Task::new("task1").duration(Duration::days(1)));
+
+    let schedule = CpmSolver::new().schedule(&project).expect("Should succeed");
+    let mut emitter = CollectingEmitter::new();
+    analyze_project(
+        &project,
+        Some(&schedule),
+        &AnalysisConfig::default(),
+        &mut emitter,
+    );
+    emitter.diagnostics.iter().map(|d| d.code.clone()).collect()
+}
+
+/// Test: No C010 for a start on a Saturday an exception makes working
+#[test]
+fn c010_not_emitted_on_exception_working_saturday() {
+    let saturday = chrono::NaiveDate::from_ymd_opt(2025, 1, 4).unwrap();
+    let codes = c010_project(
+        saturday,
+        CalendarException {
+            date: saturday,
+            working_hours: Some(vec![TimeRange {
+                start: 9 * 60,
+                end: 13 * 60,
+            }]),
+        },
+    );
+
+    assert!(!codes.contains(&DiagnosticCode::C010NonWorkingDay));
+}
+
+/// Test: C010 for a start on a weekday an exception blanks out
+#[test]
+fn c010_emitted_on_exception_blanked_weekday() {
+    let monday = chrono::NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+    let codes = c010_project(
+        monday,
+        CalendarException {
+            date: monday,
+            working_hours: None,
+        },
+    );
+
+    assert!(codes.contains(&DiagnosticCode::C010NonWorkingDay));
+}
+
 /// Test: C011 - Calendar mismatch between project and resource emits warning
 #[test]
 fn c011_calendar_mismatch() {
@@ -318,6 +507,59 @@ fn c023_redundant_holiday() {
     );
 }
 
+/// Test: C012 and C024 - Contradictory and overlapping holidays, checked
+/// without a schedule
+#[test]
+fn c012_c024_inconsistent_holidays() {
+    let mut project = Project::new("C012 Test");
+    let date = |day| chrono::NaiveDate::from_ymd_opt(2025, 12, day).expect("valid date");
+
+    let mut cal = Calendar::default();
+    cal.id = "inconsistent".to_string();
+    cal.holidays = vec![
+        Holiday {
+            name: "Christmas".to_string(),
+            start: date(25),
+            end: date(26),
+        },
+        Holiday {
+            name: "Year End".to_string(),
+            start: date(26),
+            end: date(31),
+        },
+    ];
+    cal.exceptions = vec![CalendarException {
+        date: date(26),
+        working_hours: Some(vec![TimeRange {
+            start: 9 * 60,
+            end: 12 * 60,
+        }]),
+    }];
+    project.calendars.push(cal);
+
+    let mut emitter = CollectingEmitter::new();
+    analyze_project(&project, None, &AnalysisConfig::default(), &mut emitter);
+
+    let contradictions: Vec<_> = emitter
+        .diagnostics
+        .iter()
+        .filter(|d| d.code == DiagnosticCode::C012HolidayExceptionConflict)
+        .collect();
+    assert_eq!(contradictions.len(), 2, "both holidays cover Dec 26");
+    assert!(contradictions[0].message.contains("Christmas"));
+    assert!(contradictions[1].message.contains("Year End"));
+
+    assert!(
+        emitter
+            .diagnostics
+            .iter()
+            .any(|d| d.code == DiagnosticCode::C024OverlappingHolidays
+                && d.message
+                    .contains("'Year End' overlaps holiday 'Christmas'")),
+        "Should emit C024OverlappingHolidays naming both holidays"
+    );
+}
+
 /// Test: Valid calendar emits no calendar diagnostics
 #[test]
 fn valid_calendar_no_diagnostics() {
@@ -563,3 +805,328 @@ fn filter_diagnostics_empty_for_unrelated_task() {
         first_task_codes
     );
 }
+
+/// Test: C011 - Task calendar override disagreeing with resource calendar
+#[test]
+fn c011_task_calendar_override_mismatch() {
+    let mut project = Project::new("C011 Override Test");
+    project.start = today();
+
+    let mut night = Calendar::default();
+    night.id = "night".to_string();
+    project.calendars.push(night);
+
+    let mut day = Calendar::default();
+    day.id = "day".to_string();
+    project.calendars.push(day);
+
+    let mut resource = Resource::new("dev");
+    resource.calendar = Some("day".to_string());
+    project.resources.push(resource);
+
+    project.tasks.push(
+        Task::new("shift_work")
+            .duration(Duration::days(2))
+            .calendar("night")
+            .assign("dev"),
+    );
+
+    let mut emitter = CollectingEmitter::new();
+    analyze_project(&project, None, &AnalysisConfig::default(), &mut emitter);
+
+    let c011: Vec<_> = emitter
+        .diagnostics
+        .iter()
+        .filter(|d| d.code == DiagnosticCode::C011CalendarMismatch)
+        .collect();
+    assert_eq!(c011.len(), 1);
+    assert!(c011[0].message.contains("uses calendar 'night'"));
+    assert!(c011[0]
+        .message
+        .contains("resource 'dev' uses calendar 'day'"));
+}
+
+/// Test: No C011 when the task override matches the resource calendar
+#[test]
+fn c011_not_emitted_when_task_and_resource_calendars_agree() {
+    let mut project = Project::new("C011 Agree Test");
+    project.start = today();
+
+    let mut night = Calendar::default();
+    night.id = "night".to_string();
+    project.calendars.push(night);
+
+    let mut resource = Resource::new("dev");
+    resource.calendar = Some("night".to_string());
+    project.resources.push(resource);
+
+    project.tasks.push(
+        Task::new("shift_work")
+            .duration(Duration::days(2))
+            .calendar("night")
+            .assign("dev"),
+    );
+
+    let mut emitter = CollectingEmitter::new();
+    analyze_project(&project, None, &AnalysisConfig::default(), &mut emitter);
+
+    assert!(
+        !emitter
+            .diagnostics
+            .iter()
+            .any(|d| d.code == DiagnosticCode::C011CalendarMismatch),
+        "task and resource share a calendar, C011 should not fire"
+    );
+}
+
+// =============================================================================
+// Per-task calendar overrides
+// =============================================================================
+
+/// Night shift runs only on Tuesdays and Thursdays
+fn night_shift_calendar() -> Calendar {
+    Calendar {
+        id: "night".to_string(),
+        name: "Night Shift".to_string(),
+        working_days: vec![2, 4],
+        ..Calendar::default()
+    }
+}
+
+/// Weekend crew works Saturday and Sunday only
+fn weekend_calendar() -> Calendar {
+    Calendar {
+        id: "weekend".to_string(),
+        name: "Weekend Crew".to_string(),
+        working_days: vec![0, 6],
+        ..Calendar::default()
+    }
+}
+
+/// Four-day week, no Fridays
+fn four_day_calendar() -> Calendar {
+    Calendar {
+        id: "four_day".to_string(),
+        name: "Four-Day Week".to_string(),
+        working_days: vec![1, 2, 3, 4],
+        ..Calendar::default()
+    }
+}
+
+fn task_calendars_project() -> Project {
+    let mut project = Project::new("Task Calendars");
+    project.start = date(2025, 1, 6); // Monday
+    project.calendars.push(night_shift_calendar());
+    project.calendars.push(weekend_calendar());
+    project.calendars.push(four_day_calendar());
+    project
+}
+
+#[test]
+fn task_calendar_override_uses_own_working_days() {
+    let mut project = task_calendars_project();
+    project.tasks.push(
+        Task::new("maintenance")
+            .duration(Duration::days(3))
+            .calendar("night"),
+    );
+
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+    let task = &schedule.tasks["maintenance"];
+
+    // Tue 7, Thu 9, Tue 14
+    assert_eq!(task.start, date(2025, 1, 7));
+    assert_eq!(task.finish, date(2025, 1, 14));
+    assert_eq!(task.baseline_finish, task.finish);
+    assert_eq!(task.finish_variance_days, 0);
+}
+
+#[test]
+fn task_calendar_takes_precedence_over_resource_calendar() {
+    let mut project = task_calendars_project();
+    let mut crew = Resource::new("crew");
+    crew.calendar = Some("weekend".to_string());
+    project.resources.push(crew);
+    project.tasks.push(
+        Task::new("maintenance")
+            .duration(Duration::days(2))
+            .calendar("night")
+            .assign("crew"),
+    );
+
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+    let task = &schedule.tasks["maintenance"];
+
+    assert_eq!(task.start, date(2025, 1, 7));
+    assert_eq!(task.finish, date(2025, 1, 9));
+}
+
+#[test]
+fn resource_calendar_used_when_task_has_no_override() {
+    let mut project = task_calendars_project();
+    let mut alice = Resource::new("alice");
+    alice.calendar = Some("four_day".to_string());
+    project.resources.push(alice);
+    project.tasks.push(
+        Task::new("report")
+            .duration(Duration::days(5))
+            .assign("alice"),
+    );
+    project.tasks.push(
+        Task::new("review")
+            .duration(Duration::days(1))
+            .depends_on("report"),
+    );
+
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+    let report = &schedule.tasks["report"];
+
+    // Mon 6 - Thu 9, skipping Friday 10, then Mon 13
+    assert_eq!(report.start, date(2025, 1, 6));
+    assert_eq!(report.finish, date(2025, 1, 13));
+    assert_eq!(schedule.tasks["review"].start, date(2025, 1, 14));
+    assert!(schedule.verify(&project).is_empty());
+}
+
+#[test]
+fn resource_calendar_skips_project_holidays() {
+    let mut project = task_calendars_project();
+    let shutdown = date(2025, 1, 8);
+    project.calendars.push(Calendar {
+        id: "office".to_string(),
+        holidays: vec![Holiday {
+            name: "Shutdown".to_string(),
+            start: shutdown,
+            end: shutdown,
+        }],
+        ..Calendar::default()
+    });
+    project.calendar = "office".to_string();
+    let mut alice = Resource::new("alice");
+    alice.calendar = Some("four_day".to_string());
+    project.resources.push(alice);
+    project.tasks.push(
+        Task::new("report")
+            .duration(Duration::days(5))
+            .assign("alice"),
+    );
+
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+
+    // Mon 6, Tue 7, Thu 9, Mon 13, Tue 14: neither Wednesday's shutdown
+    // nor Friday is worked
+    assert_eq!(schedule.tasks["report"].finish, date(2025, 1, 14));
+}
+
+#[test]
+fn resource_calendar_without_common_days_is_rejected() {
+    let mut project = task_calendars_project();
+    let mut crew = Resource::new("crew");
+    crew.calendar = Some("weekend".to_string());
+    project.resources.push(crew);
+    project.tasks.push(
+        Task::new("cleanup")
+            .duration(Duration::days(2))
+            .assign("crew"),
+    );
+
+    // The weekend crew shares no working day with the Mon-Fri project
+    let result = CpmSolver::new().schedule(&project);
+    assert!(matches!(result, Err(ScheduleError::InvalidCalendar(id)) if id == "weekend"));
+}
+
+#[test]
+fn successor_aligns_on_its_calendar_after_cross_calendar_dependency() {
+    let mut project = task_calendars_project();
+    project.tasks.push(
+        Task::new("weekend_work")
+            .duration(Duration::days(1))
+            .calendar("weekend"),
+    );
+    project.tasks.push(
+        Task::new("review")
+            .duration(Duration::days(1))
+            .depends_on("weekend_work"),
+    );
+    project.tasks.push(
+        Task::new("night_followup")
+            .duration(Duration::days(1))
+            .calendar("night")
+            .depends_on("review"),
+    );
+
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+
+    // Weekend work lands on Saturday
+    assert_eq!(schedule.tasks["weekend_work"].finish, date(2025, 1, 11));
+    // Project-calendar successor starts on the next working day (Monday)
+    assert_eq!(schedule.tasks["review"].start, date(2025, 1, 13));
+    // Night shift successor waits for its calendar's next working day (Tuesday)
+    assert_eq!(schedule.tasks["night_followup"].start, date(2025, 1, 14));
+    assert_eq!(schedule.project_end, date(2025, 1, 14));
+}
+
+#[test]
+fn unknown_task_calendar_falls_back_to_project_calendar() {
+    let mut project = task_calendars_project();
+    project.tasks.push(
+        Task::new("work")
+            .duration(Duration::days(3))
+            .calendar("missing"),
+    );
+
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+    let task = &schedule.tasks["work"];
+
+    assert_eq!(task.start, date(2025, 1, 6));
+    assert_eq!(task.finish, date(2025, 1, 8));
+}
+
+#[test]
+fn late_dates_follow_task_calendar() {
+    let mut project = task_calendars_project();
+    project.tasks = vec![
+        Task::new("build").duration(Duration::days(10)),
+        Task::new("review")
+            .duration(Duration::days(4))
+            .calendar("four_day"),
+    ];
+
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+    let review = &schedule.tasks["review"];
+
+    // Must finish by Thu 16 (no Fridays), so it starts Mon 13 at the latest
+    assert_eq!(review.late_finish, date(2025, 1, 16));
+    assert_eq!(review.late_start, date(2025, 1, 13));
+    assert_eq!(review.slack, Duration::days(5));
+    assert!(schedule.verify(&project).is_empty());
+}
+
+#[test]
+fn task_on_own_calendar_can_be_critical() {
+    let mut project = task_calendars_project();
+    project.tasks = vec![
+        Task::new("prep")
+            .duration(Duration::days(2))
+            .calendar("night"),
+        Task::new("ship")
+            .duration(Duration::days(1))
+            .depends_on("prep"),
+        Task::new("docs").duration(Duration::days(3)),
+    ];
+
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+
+    // Tue 7 and Thu 9 on the night shift, then ship on Fri 10
+    let prep = &schedule.tasks["prep"];
+    assert_eq!(
+        (prep.start, prep.finish),
+        (date(2025, 1, 7), date(2025, 1, 9))
+    );
+    assert_eq!(
+        (prep.late_start, prep.late_finish),
+        (prep.start, prep.finish)
+    );
+    assert!(prep.is_critical);
+    assert_eq!(schedule.tasks["docs"].slack, Duration::days(2));
+}
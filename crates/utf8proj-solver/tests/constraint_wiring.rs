@@ -5,8 +5,11 @@
 //! Phase 3: Global feasibility detection
 
 use chrono::NaiveDate;
-use utf8proj_core::{Duration, Project, Scheduler, Task, TaskConstraint};
-use utf8proj_solver::CpmSolver;
+use utf8proj_core::{
+    CollectingEmitter, ConstraintEffectType, DiagnosticCode, Duration, Project, Schedule,
+    ScheduleError, Scheduler, StartOrFinish, Task, TaskConstraint,
+};
+use utf8proj_solver::{analyze_project, AnalysisConfig, CpmSolver};
 
 fn date(year: i32, month: u32, day: u32) -> NaiveDate {
     NaiveDate::from_ymd_opt(year, month, day).unwrap()
@@ -47,10 +50,10 @@ fn start_no_earlier_than_respects_dependencies() {
 
     project
         .tasks
-        .push(Task::new("first").effort(Duration::days(10))); // Finishes 2025-01-17
+        .push(Task::new("first").effort(Duration::hours(70))); // Finishes 2025-01-17
 
     let mut second = Task::new("second")
-        .effort(Duration::days(5))
+        .effort(Duration::hours(35))
         .depends_on("first");
     second
         .constraints
@@ -76,7 +79,7 @@ fn finish_no_earlier_than_pushes_ef() {
     let mut project = Project::new("FNET Test");
     project.start = date(2025, 1, 6);
 
-    let mut task = Task::new("held").effort(Duration::days(3));
+    let mut task = Task::new("held").effort(Duration::hours(21));
     task.constraints
         .push(TaskConstraint::FinishNoEarlierThan(date(2025, 1, 17))); // Must finish on/after 1/17
     project.tasks.push(task);
@@ -105,7 +108,7 @@ fn must_finish_on_sets_dates() {
     let mut project = Project::new("MFO Test");
     project.start = date(2025, 1, 6);
 
-    let mut task = Task::new("pinned").effort(Duration::days(5));
+    let mut task = Task::new("pinned").effort(Duration::hours(35));
     task.constraints
         .push(TaskConstraint::MustFinishOn(date(2025, 1, 24))); // Friday
     project.tasks.push(task);
@@ -344,7 +347,7 @@ fn feasible_window_fits() {
     let mut project = Project::new("Tight Fit");
     project.start = date(2025, 1, 6);
 
-    let mut task = Task::new("bounded").effort(Duration::days(5));
+    let mut task = Task::new("bounded").effort(Duration::hours(35));
     task.constraints
         .push(TaskConstraint::StartNoEarlierThan(date(2025, 1, 13)));
     task.constraints
@@ -459,3 +462,318 @@ fn nested_milestone_snet_on_weekend_stays_on_weekend() {
         "Nested milestone SNET on Sunday should stay on Sunday (events can occur any day)"
     );
 }
+
+// =============================================================================
+// Project-wide constraint report
+// =============================================================================
+
+/// design (Jan 6-10) -> build; review is pinned on its own
+fn constraint_report_project() -> Project {
+    let mut project = Project::new("Constraint Report");
+    project.start = date(2025, 1, 6);
+    project.tasks = vec![
+        Task::new("design")
+            .duration(Duration::days(5))
+            .constraint(TaskConstraint::FinishNoLaterThan(date(2025, 1, 10))),
+        Task::new("build")
+            .duration(Duration::days(3))
+            .depends_on("design")
+            .constraint(TaskConstraint::StartNoEarlierThan(date(2025, 1, 7)))
+            .constraint(TaskConstraint::FinishNoLaterThan(date(2025, 1, 31))),
+        Task::new("review")
+            .duration(Duration::days(2))
+            .constraint(TaskConstraint::MustStartOn(date(2025, 1, 8))),
+        Task::new("notes").duration(Duration::days(1)),
+    ];
+    project
+}
+
+#[test]
+fn every_constraint_gets_an_entry() {
+    let project = constraint_report_project();
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+
+    let report = schedule.constraint_report(&project);
+    let summary: Vec<(&str, ConstraintEffectType)> = report
+        .iter()
+        .map(|(id, effect)| (id.as_str(), effect.effect.clone()))
+        .collect();
+
+    assert_eq!(
+        summary,
+        vec![
+            // SNET before design finishes; FNLT after the project ends
+            ("build", ConstraintEffectType::Redundant),
+            ("build", ConstraintEffectType::Redundant),
+            ("design", ConstraintEffectType::CappedLate),
+            ("review", ConstraintEffectType::Pinned),
+        ]
+    );
+}
+
+#[test]
+fn report_matches_explain() {
+    let project = constraint_report_project();
+    let solver = CpmSolver::new();
+    let schedule = solver.schedule(&project).unwrap();
+    let report = schedule.constraint_report(&project);
+
+    let explained = solver.explain(&project, &"review".to_string());
+    let (_, reported) = report.iter().find(|(id, _)| id == "review").unwrap();
+    assert_eq!(
+        reported.description,
+        explained.constraint_effects[0].description
+    );
+}
+
+#[test]
+fn unconstrained_project_reports_nothing() {
+    let mut project = Project::new("Free");
+    project.start = date(2025, 1, 6);
+    project.tasks = vec![Task::new("a").duration(Duration::days(1))];
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+
+    assert!(schedule.constraint_report(&project).is_empty());
+}
+
+// =============================================================================
+// Constraints relative to another task's dates
+// =============================================================================
+
+fn relative(anchor: &str, offset: Duration, from: StartOrFinish) -> TaskConstraint {
+    TaskConstraint::RelativeTo {
+        anchor: anchor.into(),
+        offset,
+        from,
+    }
+}
+
+/// build (Jan 6-10) -> release (Jan 13); review anchored to release
+fn release_project(review: TaskConstraint) -> Project {
+    let mut project = Project::new("Relative");
+    project.start = date(2025, 1, 6);
+    project.tasks = vec![
+        Task::new("build").duration(Duration::days(5)),
+        Task::new("release").milestone().depends_on("build"),
+        Task::new("review")
+            .duration(Duration::days(1))
+            .constraint(review),
+    ];
+    project
+}
+
+#[test]
+fn review_two_weeks_after_release() {
+    let project = release_project(relative(
+        "release",
+        Duration::weeks(2),
+        StartOrFinish::Finish,
+    ));
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+
+    let release = &schedule.tasks["release"];
+    let review = &schedule.tasks["review"];
+    assert_eq!(release.finish, date(2025, 1, 13));
+    assert_eq!(review.start, date(2025, 1, 27));
+    assert_eq!(review.finish, date(2025, 1, 27));
+}
+
+#[test]
+fn offset_from_anchor_start() {
+    let project = release_project(relative("build", Duration::days(2), StartOrFinish::Start));
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+
+    assert_eq!(schedule.tasks["review"].start, date(2025, 1, 8));
+}
+
+#[test]
+fn anchor_is_not_a_dependency() {
+    let project = release_project(relative(
+        "release",
+        Duration::weeks(2),
+        StartOrFinish::Finish,
+    ));
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+
+    assert_eq!(schedule.tasks["review"].driving_predecessor, None);
+    // The review sets the project end, but build and release keep their slack
+    assert!(!schedule.tasks["build"].is_critical);
+    assert!(!schedule.tasks["release"].is_critical);
+}
+
+#[test]
+fn chained_anchors_settle() {
+    let mut project = release_project(relative(
+        "release",
+        Duration::weeks(2),
+        StartOrFinish::Finish,
+    ));
+    project.tasks.push(
+        Task::new("retro")
+            .duration(Duration::days(1))
+            .constraint(relative("review", Duration::days(1), StartOrFinish::Finish)),
+    );
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+
+    assert_eq!(schedule.tasks["retro"].start, date(2025, 1, 28));
+}
+
+#[test]
+fn explain_describes_relative_anchor() {
+    let project = release_project(relative(
+        "release",
+        Duration::weeks(2),
+        StartOrFinish::Finish,
+    ));
+    let explanation = CpmSolver::new().explain(&project, &"review".to_string());
+
+    let effect = &explanation.constraint_effects[0];
+    assert_eq!(effect.effect, ConstraintEffectType::Pinned);
+    assert_eq!(
+        effect.description,
+        "Task pinned to start on 2025-01-27, 10d after 'release' finishes"
+    );
+}
+
+#[test]
+fn unknown_anchor_is_an_error() {
+    let project = release_project(relative("launch", Duration::zero(), StartOrFinish::Start));
+
+    assert!(matches!(
+        CpmSolver::new().schedule(&project),
+        Err(ScheduleError::TaskNotFound(id)) if id == "launch"
+    ));
+}
+
+// =============================================================================
+// Constraint-pinned tasks
+// =============================================================================
+
+/// design (Jan 6-10) -> build, plus a standalone review with `constraint`
+fn schedule_with(constraint: TaskConstraint) -> Schedule {
+    let mut project = Project::new("Pinned Tasks");
+    project.start = date(2025, 1, 6);
+    project.tasks = vec![
+        Task::new("design").duration(Duration::days(5)),
+        Task::new("build")
+            .duration(Duration::days(3))
+            .depends_on("design"),
+        Task::new("review")
+            .duration(Duration::days(2))
+            .constraint(constraint),
+    ];
+    CpmSolver::new().schedule(&project).unwrap()
+}
+
+#[test]
+fn must_start_on_pins_only_the_constrained_task() {
+    let schedule = schedule_with(TaskConstraint::MustStartOn(date(2025, 1, 8)));
+
+    assert_eq!(schedule.tasks["review"].start, date(2025, 1, 8));
+    assert!(schedule.tasks["review"].is_pinned);
+    assert!(!schedule.tasks["build"].is_pinned);
+    assert!(!schedule.tasks["design"].is_pinned);
+}
+
+#[test]
+fn must_finish_on_pins_the_task() {
+    let schedule = schedule_with(TaskConstraint::MustFinishOn(date(2025, 1, 10)));
+
+    assert_eq!(schedule.tasks["review"].finish, date(2025, 1, 10));
+    assert!(schedule.tasks["review"].is_pinned);
+}
+
+#[test]
+fn soft_constraint_does_not_pin() {
+    let schedule = schedule_with(TaskConstraint::StartNoEarlierThan(date(2025, 1, 8)));
+
+    assert_eq!(schedule.tasks["review"].start, date(2025, 1, 8));
+    assert!(!schedule.tasks["review"].is_pinned);
+}
+
+// =============================================================================
+// Schedule fragility (I006)
+// =============================================================================
+
+/// a (Jan 6-10) -> c (Jan 13-14) is critical; b (Jan 6-8) has slack
+fn schedule_fragility_project() -> Project {
+    let mut project = Project::new("Fragility");
+    project.start = date(2025, 1, 6);
+    project.end = Some(date(2025, 1, 17));
+    project.tasks = vec![
+        Task::new("a").duration(Duration::days(5)),
+        Task::new("b").duration(Duration::days(3)),
+        Task::new("c").duration(Duration::days(2)).depends_on("a"),
+    ];
+    project
+}
+
+fn with_deadline_on_b(mut project: Project) -> Project {
+    project.tasks[1] = Task::new("b")
+        .duration(Duration::days(3))
+        .constraint(TaskConstraint::FinishNoLaterThan(date(2025, 1, 8)));
+    project
+}
+
+#[test]
+fn network_critical_tasks_are_not_fragile() {
+    let project = schedule_fragility_project();
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+
+    let fragility = schedule.fragility(&project);
+
+    assert_eq!(fragility.score(), 0);
+    assert_eq!(fragility.deadline_buffer_days, Some(3));
+}
+
+#[test]
+fn constraint_induced_critical_task_raises_score() {
+    let project = with_deadline_on_b(schedule_fragility_project());
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+    assert_eq!(schedule.tasks["b"].slack, Duration::zero());
+
+    let fragility = schedule.fragility(&project);
+
+    assert_eq!(fragility.score(), 1);
+    assert_eq!(fragility.constraint_critical, vec!["b".to_string()]);
+    assert_eq!(fragility.deadline_buffer_days, Some(3));
+}
+
+#[test]
+fn no_end_date_means_no_buffer() {
+    let mut project = with_deadline_on_b(schedule_fragility_project());
+    project.end = None;
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+
+    assert_eq!(schedule.fragility(&project).deadline_buffer_days, None);
+}
+
+#[test]
+fn i006_emitted_only_when_fragile() {
+    let solver = CpmSolver::new();
+    let i006 = |project: &Project| {
+        let schedule = solver.schedule(project).unwrap();
+        let mut emitter = CollectingEmitter::new();
+        analyze_project(
+            project,
+            Some(&schedule),
+            &AnalysisConfig::default(),
+            &mut emitter,
+        );
+        emitter
+            .diagnostics
+            .into_iter()
+            .filter(|d| d.code == DiagnosticCode::I006ScheduleFragility)
+            .collect::<Vec<_>>()
+    };
+
+    assert!(i006(&schedule_fragility_project()).is_empty());
+
+    let diagnostics = i006(&with_deadline_on_b(schedule_fragility_project()));
+    assert_eq!(diagnostics.len(), 1);
+    assert!(diagnostics[0].message.contains("schedule fragility 1"));
+    assert!(diagnostics[0]
+        .notes
+        .iter()
+        .any(|n| n.contains("3 days of buffer")));
+}
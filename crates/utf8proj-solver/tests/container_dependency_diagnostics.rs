@@ -43,6 +43,7 @@ fn dep(predecessor: &str) -> Dependency {
         predecessor: predecessor.to_string(),
         dep_type: DependencyType::FinishToStart,
         lag: None,
+        lag_calendar: false,
     }
 }
 
@@ -0,0 +1,794 @@
+//! Integration tests for cost analysis
+//!
+//! Covers cost roll-ups, per-assignment explanations, cost policies,
+//! currencies, material resources and profile refinement.
+
+use chrono::NaiveDate;
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+use utf8proj_core::{
+    refinement::RefinementError, Calendar, CollectingEmitter, CostPolicy, CostRange, Diagnostic,
+    DiagnosticCode, Duration, Money, Project, RateRange, Resource, ResourceProfile, Schedule,
+    Scheduler, Task, Trait,
+};
+use utf8proj_solver::{analyze_project, detect_overallocations, AnalysisConfig, CpmSolver};
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+// =============================================================================
+// Per-resource cost rollup
+// =============================================================================
+
+fn cost_rollup_project() -> Project {
+    let mut project = Project::new("Cost Rollup");
+    project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+    project
+        .resources
+        .push(Resource::new("alice").rate(Money::new(Decimal::from(500), "USD")));
+    project.resources.push(Resource::new("bob"));
+    project.profiles.push(
+        ResourceProfile::new("contractor")
+            .rate_range(RateRange::new(Decimal::from(400), Decimal::from(800))),
+    );
+    project
+}
+
+#[test]
+fn concrete_resource_costs_sum_to_fixed_total() {
+    let mut project = cost_rollup_project();
+    project.tasks = vec![
+        Task::new("design")
+            .duration(Duration::days(2))
+            .assign("alice"),
+        Task::new("build")
+            .duration(Duration::days(3))
+            .assign("alice")
+            .depends_on("design"),
+    ];
+
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+    let rollup = schedule.cost_by_resource();
+
+    let alice = &rollup["alice"];
+    assert_eq!(alice.task_count, 2);
+    assert!(alice.cost.is_fixed());
+    assert_eq!(alice.cost.expected, Decimal::from(2_500));
+    assert!(!alice.mixed_currencies);
+}
+
+#[test]
+fn profile_costs_keep_their_range() {
+    let mut project = cost_rollup_project();
+    project.tasks = vec![
+        Task::new("api")
+            .duration(Duration::days(5))
+            .assign("contractor"),
+        Task::new("ui")
+            .duration(Duration::days(5))
+            .assign("contractor"),
+        Task::new("review")
+            .duration(Duration::days(1))
+            .assign("alice"),
+    ];
+
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+    let rollup = schedule.cost_by_resource();
+
+    let contractor = &rollup["contractor"];
+    assert_eq!(contractor.task_count, 2);
+    assert_eq!(contractor.cost.min, Decimal::from(4_000));
+    assert_eq!(contractor.cost.max, Decimal::from(8_000));
+    assert_eq!(rollup["alice"].cost.expected, Decimal::from(500));
+}
+
+#[test]
+fn unrated_resources_are_omitted() {
+    let mut project = cost_rollup_project();
+    project.tasks = vec![Task::new("docs").duration(Duration::days(2)).assign("bob")];
+
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+
+    assert!(schedule.cost_by_resource().is_empty());
+}
+
+// =============================================================================
+// Container cost roll-up
+// =============================================================================
+
+/// phase holds backend (profile, 400-600/day) and review (alice,
+/// 100/day); ops sits outside it
+fn container_costs_project() -> Project {
+    let mut project = Project::new("Phase Costs");
+    project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+    project.profiles = vec![ResourceProfile::new("developer")
+        .rate_range(RateRange::new(Decimal::from(400), Decimal::from(600)))];
+    project.resources = vec![Resource::new("alice").rate(Money::new(Decimal::from(100), "USD"))];
+    project.tasks = vec![
+        Task::new("phase")
+            .child(
+                Task::new("backend")
+                    .duration(Duration::days(5))
+                    .assign("developer"),
+            )
+            .child(
+                Task::new("review")
+                    .duration(Duration::days(2))
+                    .assign("alice")
+                    .depends_on("backend"),
+            ),
+        Task::new("ops").duration(Duration::days(3)).assign("alice"),
+    ];
+    project
+}
+
+fn range(schedule: &utf8proj_core::Schedule, id: &str) -> CostRange {
+    schedule.tasks[id].cost_range.clone().unwrap()
+}
+
+#[test]
+fn container_range_sums_its_children() {
+    let schedule = CpmSolver::new()
+        .schedule(&container_costs_project())
+        .unwrap();
+
+    let backend = range(&schedule, "phase.backend");
+    let review = range(&schedule, "phase.review");
+    let phase = range(&schedule, "phase");
+
+    assert_eq!(phase.min, backend.min + review.min);
+    assert_eq!(phase.expected, backend.expected + review.expected);
+    assert_eq!(phase.max, backend.max + review.max);
+    assert_eq!(phase.min, Decimal::from(2200));
+    assert_eq!(phase.max, Decimal::from(3200));
+}
+
+#[test]
+fn nested_containers_roll_up() {
+    let mut project = container_costs_project();
+    let phase = project.tasks.remove(0);
+    project.tasks.insert(0, Task::new("release").child(phase));
+
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+
+    assert_eq!(
+        range(&schedule, "release"),
+        range(&schedule, "release.phase")
+    );
+    assert_eq!(range(&schedule, "release").expected, Decimal::from(2700));
+}
+
+#[test]
+fn project_total_is_unchanged() {
+    let schedule = CpmSolver::new()
+        .schedule(&container_costs_project())
+        .unwrap();
+
+    let total = schedule.total_cost_range.clone().unwrap();
+    let ops = range(&schedule, "ops");
+    let phase = range(&schedule, "phase");
+
+    assert_eq!(total.expected, phase.expected + ops.expected);
+    assert_eq!(total.expected, Decimal::from(3000));
+}
+
+// =============================================================================
+// Per-assignment cost explanations
+// =============================================================================
+
+/// api staffed by a senior contractor developer and alice
+fn schedule() -> Schedule {
+    let mut project = Project::new("Cost Explanation");
+    project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+    project.traits = vec![
+        Trait::new("senior").rate_multiplier(1.5),
+        Trait::new("contractor").rate_multiplier(1.2),
+    ];
+    project.profiles = vec![
+        ResourceProfile::new("developer")
+            .rate_range(RateRange::new(Decimal::from(100), Decimal::from(200))),
+        ResourceProfile::new("senior_contractor")
+            .specializes("developer")
+            .with_traits(["senior", "contractor"]),
+    ];
+    project.resources = vec![Resource::new("alice").rate(Money::new(Decimal::from(300), "USD"))];
+    project.tasks = vec![Task::new("api")
+        .duration(Duration::days(5))
+        .assign("senior_contractor")
+        .assign("alice")];
+    CpmSolver::new().schedule(&project).unwrap()
+}
+
+#[test]
+fn breakdown_lists_both_traits_in_order() {
+    let schedule = schedule();
+    let explanation = schedule.tasks["api"].assignments[0]
+        .cost_explanation
+        .clone()
+        .expect("profile assignment is explained");
+
+    assert_eq!(
+        explanation.trait_multipliers,
+        vec![("senior".to_string(), 1.5), ("contractor".to_string(), 1.2)]
+    );
+    // 100 × 1.5 × 1.2 = 180, 200 × 1.5 × 1.2 = 360
+    assert_eq!(explanation.rate.min, Decimal::from(180));
+    assert_eq!(explanation.rate.max, Decimal::from(360));
+}
+
+#[test]
+fn inherited_rate_is_the_base() {
+    let schedule = schedule();
+    let explanation = schedule.tasks["api"].assignments[0]
+        .cost_explanation
+        .as_ref()
+        .unwrap();
+
+    assert_eq!(
+        explanation.base_rate,
+        RateRange::new(Decimal::from(100), Decimal::from(200))
+    );
+}
+
+#[test]
+fn concrete_assignments_have_no_explanation() {
+    let schedule = schedule();
+    let alice = &schedule.tasks["api"].assignments[1];
+
+    assert_eq!(alice.resource_id, "alice");
+    assert!(alice.cost_explanation.is_none());
+}
+
+// =============================================================================
+// Reading a schedule's cost under each policy
+// =============================================================================
+
+/// alice (fixed $500/day) and a developer profile at $400-$800/day, 5 days each
+fn total_cost(policy: CostPolicy) -> CostRange {
+    let mut project = Project::new("Cost Readings");
+    project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+    project.cost_policy = policy;
+    project.profiles = vec![ResourceProfile::new("developer")
+        .rate_range(RateRange::new(Decimal::from(400), Decimal::from(800)))];
+    project.resources = vec![Resource::new("alice").rate(Money::new(Decimal::from(500), "USD"))];
+    project.tasks = vec![
+        Task::new("design")
+            .duration(Duration::days(5))
+            .assign("alice"),
+        Task::new("build")
+            .duration(Duration::days(5))
+            .assign("developer"),
+    ];
+    CpmSolver::new()
+        .schedule(&project)
+        .unwrap()
+        .total_cost_range
+        .unwrap()
+}
+
+#[test]
+fn total_records_the_project_policy() {
+    assert_eq!(
+        total_cost(CostPolicy::Midpoint).policy,
+        CostPolicy::Midpoint
+    );
+    assert_eq!(
+        total_cost(CostPolicy::Pessimistic).policy,
+        CostPolicy::Pessimistic
+    );
+}
+
+#[test]
+fn three_readings_from_one_range() {
+    let range = total_cost(CostPolicy::Midpoint);
+
+    // $2500 fixed + $2000-$4000 for the developer
+    assert_eq!(
+        range.expected_under(CostPolicy::Optimistic),
+        Decimal::from(4500)
+    );
+    assert_eq!(
+        range.expected_under(CostPolicy::Midpoint),
+        Decimal::from(5500)
+    );
+    assert_eq!(
+        range.expected_under(CostPolicy::Pessimistic),
+        Decimal::from(6500)
+    );
+}
+
+#[test]
+fn producing_policy_reads_back_the_schedule() {
+    let policy = CostPolicy::Triangular { seed: 42 };
+    let range = total_cost(policy);
+
+    assert_eq!(range.expected_under(policy), range.expected);
+    assert_eq!(
+        range.expected_under(CostPolicy::Pessimistic),
+        total_cost(CostPolicy::Pessimistic).expected
+    );
+}
+
+// =============================================================================
+// Mixed-currency cost aggregation (E005)
+// =============================================================================
+
+/// build (alice) and review (bob, in `bob_currency`), both in a phase
+fn mixed_currency_project(bob_currency: &str) -> Project {
+    let mut project = Project::new("Currencies");
+    project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+    project.resources = vec![
+        Resource::new("alice").rate(Money::new(Decimal::from(500), "USD")),
+        Resource::new("bob").rate(Money::new(Decimal::from(400), bob_currency)),
+    ];
+    project.tasks = vec![Task::new("phase")
+        .child(
+            Task::new("build")
+                .duration(Duration::days(2))
+                .assign("alice"),
+        )
+        .child(
+            Task::new("review")
+                .duration(Duration::days(1))
+                .assign("alice")
+                .assign("bob"),
+        )];
+    project
+}
+
+fn analyze(project: &Project, schedule: &Schedule) -> CollectingEmitter {
+    let mut emitter = CollectingEmitter::new();
+    analyze_project(
+        project,
+        Some(schedule),
+        &AnalysisConfig::default(),
+        &mut emitter,
+    );
+    emitter
+}
+
+#[test]
+fn mixed_currencies_emit_e005() {
+    let project = mixed_currency_project("EUR");
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+
+    // Assignment costs keep their own currency; sums across them are dropped
+    let review = &schedule.tasks["phase.review"];
+    assert_eq!(review.assignments.len(), 2);
+    assert!(review.assignments.iter().all(|a| a.cost_range.is_some()));
+    assert!(review.cost_range.is_none());
+    assert!(schedule.tasks["phase"].cost_range.is_none());
+    assert!(schedule.total_cost_range.is_none());
+    assert!(schedule.tasks["phase.build"].cost_range.is_some());
+
+    let emitter = analyze(&project, &schedule);
+    let mixed: Vec<_> = emitter
+        .diagnostics
+        .iter()
+        .filter(|d| d.code == DiagnosticCode::E005MixedCurrency)
+        .collect();
+    assert_eq!(mixed.len(), 1);
+    assert!(mixed[0].message.contains("EUR, USD"));
+    assert_eq!(
+        mixed[0].notes,
+        vec![
+            "EUR: phase.review".to_string(),
+            "USD: phase.build, phase.review".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn single_currency_keeps_totals() {
+    let project = mixed_currency_project("USD");
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+
+    // 2 days × $500 + 1 day × ($500 + $400)
+    let total = schedule.total_cost_range.as_ref().unwrap();
+    assert_eq!(total.expected, Decimal::from(1900));
+    assert_eq!(total.currency, "USD");
+
+    let emitter = analyze(&project, &schedule);
+    assert!(!emitter
+        .diagnostics
+        .iter()
+        .any(|d| d.code == DiagnosticCode::E005MixedCurrency));
+}
+
+// =============================================================================
+// Material resources
+// =============================================================================
+
+fn usd(amount: i64) -> Money {
+    Money::new(Decimal::from(amount), "USD")
+}
+
+/// alice at $500/day and a $5,000 license, from Mon Jan 6
+fn material_resources_project() -> Project {
+    let mut project = Project::new("Materials");
+    project.start = date(2025, 1, 6);
+    project.resources = vec![
+        Resource::new("alice").rate(usd(500)),
+        Resource::new("license").material(usd(5000)),
+    ];
+    project
+}
+
+#[test]
+fn material_adds_a_fixed_cost_without_changing_the_length() {
+    let mut project = material_resources_project();
+    project.tasks = vec![Task::new("setup")
+        .duration(Duration::days(3))
+        .assign("alice")
+        .assign("license")];
+
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+    let setup = &schedule.tasks["setup"];
+
+    assert_eq!(setup.finish, date(2025, 1, 8));
+    let license = &setup.assignments[1];
+    assert!(!license.is_abstract);
+    let range = license.cost_range.as_ref().unwrap();
+    assert_eq!(range.expected, Decimal::from(5000));
+    assert_eq!(range.spread_percent(), 0.0);
+    // 3 days × $500 + $5,000
+    let total = setup.cost_range.as_ref().unwrap();
+    assert_eq!(total.min, Decimal::from(6500));
+    assert_eq!(total.max, Decimal::from(6500));
+}
+
+#[test]
+fn units_count_material_quantity() {
+    let mut project = material_resources_project();
+    project.tasks = vec![Task::new("rollout")
+        .duration(Duration::days(10))
+        .assign_with_units("license", 3.0)];
+
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+    let rollout = &schedule.tasks["rollout"];
+
+    assert_eq!(rollout.duration, Duration::days(10));
+    assert_eq!(
+        rollout.cost_range.as_ref().unwrap().expected,
+        Decimal::from(15000)
+    );
+}
+
+#[test]
+fn material_does_not_shorten_effort_driven_work() {
+    let mut project = material_resources_project();
+    project.tasks = vec![
+        Task::new("alone")
+            .effort(Duration::hours(35))
+            .assign("alice"),
+        Task::new("licensed")
+            .effort(Duration::hours(35))
+            .assign("alice")
+            .assign("license"),
+    ];
+
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+
+    assert_eq!(
+        schedule.tasks["licensed"].duration,
+        schedule.tasks["alone"].duration
+    );
+    assert_eq!(schedule.tasks["licensed"].duration, Duration::days(5));
+}
+
+#[test]
+fn shared_material_is_not_overallocated() {
+    let mut project = material_resources_project();
+    project.tasks = vec![
+        Task::new("a").duration(Duration::days(2)).assign("license"),
+        Task::new("b").duration(Duration::days(2)).assign("license"),
+    ];
+
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+
+    assert!(detect_overallocations(&project, &schedule).is_empty());
+}
+
+// =============================================================================
+// Refining profile assignments into concrete resources
+// =============================================================================
+
+/// api (5d) staffed by an abstract developer; alice is a rustacean, bob a designer
+fn assignment_refinement_project() -> Project {
+    let mut project = Project::new("Refinement");
+    project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+    project.profiles = vec![
+        ResourceProfile::new("developer")
+            .skill("rust")
+            .rate_range(RateRange::new(Decimal::from(400), Decimal::from(800))),
+        ResourceProfile::new("rustacean")
+            .specializes("developer")
+            .skill("async"),
+        ResourceProfile::new("designer").skill("figma"),
+    ];
+    project.resources = vec![
+        Resource::new("alice")
+            .specializes("rustacean")
+            .rate(Money::new(Decimal::from(700), "USD")),
+        Resource::new("bob")
+            .specializes("designer")
+            .rate(Money::new(Decimal::from(500), "USD")),
+    ];
+    project.tasks = vec![Task::new("api")
+        .duration(Duration::days(5))
+        .assign("developer")];
+    project
+}
+
+#[test]
+fn refining_a_profile_collapses_the_cost_range() {
+    let mut project = assignment_refinement_project();
+    let before = CpmSolver::new().schedule(&project).unwrap();
+    let range = before.tasks["api"].cost_range.clone().unwrap();
+    assert_eq!(range.min, Decimal::from(2000));
+    assert_eq!(range.max, Decimal::from(4000));
+
+    project
+        .refine_assignment("api", "developer", "alice")
+        .unwrap();
+
+    let after = CpmSolver::new().schedule(&project).unwrap();
+    let api = &after.tasks["api"];
+    let cost = api.cost_range.clone().unwrap();
+    assert_eq!(cost.min, Decimal::from(3500));
+    assert_eq!(cost.max, Decimal::from(3500));
+    assert!(!api.has_abstract_assignments);
+}
+
+#[test]
+fn refinement_reports_the_rate_change() {
+    let mut project = assignment_refinement_project();
+
+    let refinement = project
+        .refine_assignment("api", "developer", "alice")
+        .unwrap();
+
+    assert_eq!(refinement.units, 1.0);
+    assert_eq!(
+        refinement.profile_rate,
+        Some(RateRange::new(Decimal::from(400), Decimal::from(800)))
+    );
+    assert_eq!(
+        refinement.resource_rate.as_ref().unwrap().amount,
+        Decimal::from(700)
+    );
+    assert_eq!(refinement.expected_rate_change(), Some(Decimal::from(100)));
+    assert_eq!(project.tasks[0].assigned[0].resource_id, "alice");
+}
+
+#[test]
+fn resource_without_required_skills_is_rejected() {
+    let mut project = assignment_refinement_project();
+
+    let err = project
+        .refine_assignment("api", "developer", "bob")
+        .unwrap_err();
+
+    assert_eq!(
+        err,
+        RefinementError::MissingSkills {
+            resource_id: "bob".into(),
+            profile_id: "developer".into(),
+            missing: vec!["rust".into()],
+        }
+    );
+    assert_eq!(project.tasks[0].assigned[0].resource_id, "developer");
+}
+
+#[test]
+fn refining_an_unassigned_profile_is_an_error() {
+    let mut project = assignment_refinement_project();
+    project.tasks[0].assigned[0].resource_id = "alice".into();
+
+    let err = project
+        .refine_assignment("api", "developer", "alice")
+        .unwrap_err();
+
+    assert!(matches!(err, RefinementError::NotAssigned { .. }));
+}
+
+// =============================================================================
+// I002 refinement progress summary
+// =============================================================================
+
+/// Three concrete assignments and two to a developer profile at $400-$800/day
+fn refinement_progress_project() -> Project {
+    let mut project = Project::new("Staffing");
+    project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+    project.profiles = vec![ResourceProfile::new("developer")
+        .rate_range(RateRange::new(Decimal::from(400), Decimal::from(800)))];
+    project.resources = vec![
+        Resource::new("alice").rate(Money::new(Decimal::from(600), "USD")),
+        Resource::new("bob").rate(Money::new(Decimal::from(600), "USD")),
+    ];
+    project.tasks = vec![
+        Task::new("design")
+            .duration(Duration::days(5))
+            .assign("alice")
+            .assign("bob"),
+        Task::new("build")
+            .duration(Duration::days(5))
+            .assign("alice")
+            .assign("developer")
+            .depends_on("design"),
+        Task::new("test")
+            .duration(Duration::days(5))
+            .assign("developer")
+            .depends_on("build"),
+    ];
+    project
+}
+
+fn refinement_progress(project: &Project, with_schedule: bool) -> Option<Diagnostic> {
+    let schedule = with_schedule.then(|| CpmSolver::new().schedule(project).unwrap());
+    let mut emitter = CollectingEmitter::new();
+    analyze_project(
+        project,
+        schedule.as_ref(),
+        &AnalysisConfig::default(),
+        &mut emitter,
+    );
+    emitter
+        .diagnostics
+        .into_iter()
+        .find(|d| d.code == DiagnosticCode::I002RefinementProgress)
+}
+
+#[test]
+fn mixed_staffing_reports_share_and_spread() {
+    let diagnostic =
+        refinement_progress(&refinement_progress_project(), true).expect("I002 emitted");
+
+    assert_eq!(
+        diagnostic.message,
+        "refinement status: 60% of assignments concrete"
+    );
+    assert!(diagnostic
+        .notes
+        .contains(&"concrete assignments: 3 (60%)".to_string()));
+    assert!(diagnostic
+        .notes
+        .contains(&"abstract assignments: 2 (40%)".to_string()));
+    // Two 5-day profile assignments spread $4000-$8000
+    assert!(diagnostic
+        .notes
+        .contains(&"remaining uncertainty: ±$2,000.00".to_string()));
+}
+
+#[test]
+fn fully_concrete_staffing_is_silent() {
+    let mut project = refinement_progress_project();
+    for task in &mut project.tasks {
+        for assignment in &mut task.assigned {
+            if assignment.resource_id == "developer" {
+                assignment.resource_id = "bob".into();
+            }
+        }
+    }
+
+    assert!(refinement_progress(&project, true).is_none());
+}
+
+#[test]
+fn cost_notes_need_a_schedule() {
+    let diagnostic =
+        refinement_progress(&refinement_progress_project(), false).expect("I002 emitted");
+
+    assert!(diagnostic
+        .notes
+        .iter()
+        .all(|note| !note.starts_with("remaining uncertainty")));
+}
+
+// =============================================================================
+// Person-time effort helpers
+// =============================================================================
+
+#[test]
+fn explicit_effort_converts_to_person_time() {
+    let task = Task::new("build")
+        .effort(Duration::hours(70))
+        .assign("alice");
+
+    // 7-hour days
+    let calendar = Calendar::default();
+    assert_eq!(task.effort_person_days(&calendar), Some(10.0));
+    assert_eq!(task.effort_person_hours(&calendar), Some(70.0));
+}
+
+#[test]
+fn duration_times_units_is_person_time() {
+    let task = Task::new("build")
+        .duration(Duration::days(10))
+        .assign("alice")
+        .assign_with_units("bob", 0.5);
+
+    let calendar = Calendar::default();
+    assert_eq!(task.effort_person_days(&calendar), Some(15.0));
+    assert_eq!(task.effort_person_hours(&calendar), Some(105.0));
+}
+
+#[test]
+fn containers_and_unassigned_tasks_have_no_effort() {
+    let unassigned = Task::new("wait").duration(Duration::days(10));
+    let container = Task::new("phase").child(
+        Task::new("build")
+            .duration(Duration::days(2))
+            .assign("alice"),
+    );
+
+    let calendar = Calendar::default();
+    assert_eq!(unassigned.effort_person_days(&calendar), None);
+    assert_eq!(container.effort_person_days(&calendar), None);
+    assert_eq!(container.effort_person_hours(&calendar), None);
+}
+
+#[test]
+fn schedule_total_effort_sums_leaves() {
+    let mut project = Project::new("Effort");
+    project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+    project.resources = vec![Resource::new("alice"), Resource::new("bob")];
+    project.tasks = vec![
+        Task::new("phase")
+            .child(
+                Task::new("design")
+                    .duration(Duration::days(3))
+                    .assign("alice")
+                    .assign("bob"),
+            )
+            .child(
+                Task::new("build")
+                    .effort(Duration::hours(28))
+                    .assign("alice")
+                    .depends_on("design"),
+            ),
+        Task::new("review")
+            .duration(Duration::days(2))
+            .assign_with_units("bob", 0.5),
+    ];
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+
+    // 3d x 2 people + 4 person-days + 2d x 0.5
+    assert_eq!(schedule.total_effort_person_days(), 11.0);
+}
+
+#[test]
+fn effort_driven_assignments_cost_their_effort() {
+    let mut project = Project::new("Effort cost");
+    project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+    project.resources = vec![
+        Resource::new("alice").rate(Money::new(Decimal::from(100), "USD")),
+        Resource::new("bob").rate(Money::new(Decimal::from(100), "USD")),
+    ];
+    project.tasks = vec![Task::new("build")
+        .effort(Duration::hours(40))
+        .assign("alice")
+        .assign_with_units("bob", 0.5)];
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+    let build = &schedule.tasks["build"];
+
+    // 40h is 5.7 seven-hour days; at 1.5 units that is 4 whole days
+    assert_eq!(build.duration, Duration::days(4));
+    let effort: Vec<f64> = build
+        .assignments
+        .iter()
+        .map(|a| a.effort_days.unwrap())
+        .collect();
+    assert!((effort[0] - 40.0 / 7.0 * 2.0 / 3.0).abs() < 1e-9);
+    assert!((effort[1] - 40.0 / 7.0 / 3.0).abs() < 1e-9);
+    // $100 per person-day of effort, not per day of the stretched duration
+    let cost = build
+        .cost_range
+        .as_ref()
+        .unwrap()
+        .expected
+        .to_f64()
+        .unwrap();
+    assert!((cost - 4000.0 / 7.0).abs() < 0.01);
+    assert!((schedule.total_effort_person_days() - 40.0 / 7.0).abs() < 1e-9);
+}
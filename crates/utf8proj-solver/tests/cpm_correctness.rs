@@ -11,9 +11,20 @@
 //! 5. Cross-container dependencies are honored
 //! 6. Container dates derive from children
 //! 7. Non-FS dependency types compute correct ES/LS/EF/LF
-
-use utf8proj_core::{Dependency, DependencyType, Duration, Project, Scheduler, Task};
-use utf8proj_solver::CpmSolver;
+//!
+//! Also covers lag attribution, slack-derived metrics, critical-path
+//! explanations and the schedule self-check.
+
+use chrono::NaiveDate;
+use utf8proj_core::{
+    verify::Invariant, CollectingEmitter, CriticalReason, Dependency, DependencyType,
+    DiagnosticCode, Duration, Project, Schedule, Scheduler, Task, TaskConstraint, TemporalRegime,
+};
+use utf8proj_solver::{analyze_project, AnalysisConfig, CpmSolver};
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
 
 // ============================================================================
 // INVARIANT 1: Slack is never negative
@@ -385,6 +396,7 @@ fn dep(predecessor: &str, dep_type: DependencyType) -> Dependency {
         predecessor: predecessor.to_string(),
         dep_type,
         lag: None,
+        lag_calendar: false,
     }
 }
 
@@ -394,6 +406,7 @@ fn dep_lag(predecessor: &str, dep_type: DependencyType, lag_days: i64) -> Depend
         predecessor: predecessor.to_string(),
         dep_type,
         lag: Some(Duration::days(lag_days)),
+        lag_calendar: false,
     }
 }
 
@@ -594,6 +607,71 @@ fn ff_with_lag_forward_pass() {
     );
 }
 
+/// Helper to create a dependency with a sub-day lag in hours
+fn dep_lag_hours(predecessor: &str, dep_type: DependencyType, hours: i64) -> Dependency {
+    Dependency {
+        predecessor: predecessor.to_string(),
+        dep_type,
+        lag: Some(Duration::hours(hours)),
+        lag_calendar: false,
+    }
+}
+
+fn ss_start_day_with_lag_hours(hours: i64) -> i64 {
+    let mut project = Project::new("Sub-day Lag Test");
+    project.tasks = vec![
+        Task::new("a").duration(Duration::days(5)),
+        Task::new("b")
+            .duration(Duration::days(3))
+            .with_dependency(dep_lag_hours("a", DependencyType::StartToStart, hours)),
+    ];
+
+    let schedule = CpmSolver::new()
+        .schedule(&project)
+        .expect("Should schedule");
+    (schedule.tasks["b"].start - project.start).num_days()
+}
+
+#[test]
+fn sub_day_lag_rounds_to_nearest_working_day() {
+    // Scheduling is day-granular: lag is rounded, not truncated.
+    // A 4-hour lag (half an 8-hour working day) used to vanish.
+    assert_eq!(
+        ss_start_day_with_lag_hours(4),
+        1,
+        "SS+4h rounds up to 1 day"
+    );
+    assert_eq!(ss_start_day_with_lag_hours(3), 0, "SS+3h rounds down to 0");
+    // 12 hours is 1.5 working days, which used to truncate to 1
+    assert_eq!(
+        ss_start_day_with_lag_hours(12),
+        2,
+        "SS+12h rounds to 2 days"
+    );
+}
+
+fn fs_start_with_lag_hours(hours: i64) -> chrono::NaiveDate {
+    let mut project = Project::new("Sub-day Lead Test");
+    project.tasks = vec![
+        Task::new("a").duration(Duration::days(5)),
+        Task::new("b")
+            .duration(Duration::days(3))
+            .with_dependency(dep_lag_hours("a", DependencyType::FinishToStart, hours)),
+    ];
+
+    let schedule = CpmSolver::new()
+        .schedule(&project)
+        .expect("Should schedule");
+    schedule.tasks["b"].start
+}
+
+#[test]
+fn sub_day_lead_rounds_away_from_zero() {
+    // A half-day lead rounds to a full one-day lead instead of vanishing
+    assert_eq!(fs_start_with_lag_hours(-4), fs_start_with_lag_hours(-8));
+    assert!(fs_start_with_lag_hours(-4) < fs_start_with_lag_hours(0));
+}
+
 #[test]
 fn mixed_dependency_types_correct_critical_path() {
     // Complex graph with mixed dependency types
@@ -656,3 +734,802 @@ fn mixed_dependency_types_correct_critical_path() {
         "Mixed: C should have slack (FF constraint weaker than FS for shorter C)"
     );
 }
+
+// =============================================================================
+// Lag impact attribution
+// =============================================================================
+
+fn lagged(predecessor: &str, days: i64) -> Dependency {
+    Dependency {
+        predecessor: predecessor.into(),
+        dep_type: DependencyType::FinishToStart,
+        lag: Some(Duration::days(days)),
+        lag_calendar: false,
+    }
+}
+
+/// pour (3d) -5d-> cure (2d) -> review, which also waits a day on pour
+fn lag_impact_project() -> Project {
+    let mut project = Project::new("Lags");
+    project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+    project.tasks = vec![
+        Task::new("pour").duration(Duration::days(3)),
+        Task::new("cure")
+            .duration(Duration::days(2))
+            .with_dependency(lagged("pour", 5)),
+        Task::new("review")
+            .duration(Duration::days(1))
+            .with_dependency(lagged("pour", 1))
+            .depends_on("cure"),
+    ];
+    project
+}
+
+#[test]
+fn binding_lag_is_applied_in_full() {
+    let project = lag_impact_project();
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+
+    let impacts = schedule.lag_impact(&project);
+    let cure = impacts.iter().find(|i| i.successor == "cure").unwrap();
+
+    assert_eq!(cure.predecessor, "pour");
+    assert_eq!(cure.lag_days, 5);
+    assert_eq!(cure.applied_days, 5);
+    // pour ends Wed Jan 8; five working days later cure starts Thu Jan 16
+    assert_eq!(
+        schedule.tasks["cure"].start,
+        NaiveDate::from_ymd_opt(2025, 1, 16).unwrap()
+    );
+}
+
+#[test]
+fn overtaken_lag_is_not_applied() {
+    let project = lag_impact_project();
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+
+    let impacts = schedule.lag_impact(&project);
+    let review = impacts.iter().find(|i| i.successor == "review").unwrap();
+
+    assert_eq!(review.lag_days, 1);
+    assert_eq!(review.applied_days, 0);
+}
+
+#[test]
+fn zero_lags_are_omitted() {
+    let project = lag_impact_project();
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+
+    let impacts = schedule.lag_impact(&project);
+
+    // review -> cure has no lag
+    assert_eq!(impacts.len(), 2);
+    let total: i64 = impacts.iter().map(|i| i.applied_days).sum();
+    assert_eq!(total, 5);
+}
+
+#[test]
+fn lag_after_milestone_counts_from_its_day() {
+    let mut project = Project::new("Milestone lag");
+    project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+    project.tasks = vec![
+        Task::new("gate").milestone(),
+        Task::new("prep").duration(Duration::days(3)),
+        Task::new("work")
+            .duration(Duration::days(1))
+            .with_dependency(lagged("gate", 2))
+            .depends_on("prep"),
+    ];
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+
+    let impacts = schedule.lag_impact(&project);
+
+    // gate is reached Mon Jan 6, so its lag allows Wed Jan 8; prep holds
+    // work to Thu Jan 9 instead
+    assert_eq!(
+        schedule.tasks["work"].start,
+        NaiveDate::from_ymd_opt(2025, 1, 9).unwrap()
+    );
+    assert_eq!(impacts[0].predecessor, "gate");
+    assert_eq!(impacts[0].applied_days, 0);
+}
+
+// =============================================================================
+// Lags measured in elapsed calendar days
+// =============================================================================
+
+/// cure (`cure_days` from Mon Jan 6) -> coat with the given lag
+fn schedule_with(
+    dep_type: DependencyType,
+    cure_days: i64,
+    lag_days: i64,
+    lag_calendar: bool,
+) -> Schedule {
+    let mut project = Project::new("Elapsed Lag");
+    project.start = date(2025, 1, 6);
+    project.tasks = vec![
+        Task::new("prep").duration(Duration::days(3)),
+        Task::new("cure")
+            .duration(Duration::days(cure_days))
+            .depends_on("prep"),
+        Task::new("coat")
+            .duration(Duration::days(2))
+            .with_dependency(Dependency {
+                predecessor: "cure".into(),
+                dep_type,
+                lag: Some(Duration::days(lag_days)),
+                lag_calendar,
+            }),
+    ];
+    CpmSolver::new().schedule(&project).unwrap()
+}
+
+#[test]
+fn elapsed_lag_counts_the_weekend() {
+    // cure runs Thu Jan 9 - Fri Jan 10
+    let working = schedule_with(DependencyType::FinishToStart, 2, 2, false);
+    let elapsed = schedule_with(DependencyType::FinishToStart, 2, 2, true);
+
+    // Working lag: Mon and Tue wait, coat starts Wed
+    assert_eq!(working.tasks["coat"].start, date(2025, 1, 15));
+    // Elapsed lag: Sat and Sun wait, coat starts Mon
+    assert_eq!(elapsed.tasks["coat"].start, date(2025, 1, 13));
+    assert!(elapsed.tasks["coat"].start < working.tasks["coat"].start);
+}
+
+#[test]
+fn elapsed_lag_ending_on_weekend_snaps_to_next_working_day() {
+    // cure starts Thu Jan 9; three elapsed days end on Sun Jan 12
+    let schedule = schedule_with(DependencyType::StartToStart, 2, 3, true);
+
+    assert_eq!(schedule.tasks["coat"].start, date(2025, 1, 13));
+}
+
+#[test]
+fn elapsed_and_working_lag_agree_within_a_week() {
+    // cure runs Thu Jan 9 only; one day of lag is Fri either way
+    let working = schedule_with(DependencyType::FinishToStart, 1, 1, false);
+    let elapsed = schedule_with(DependencyType::FinishToStart, 1, 1, true);
+
+    assert_eq!(elapsed.tasks["coat"].start, date(2025, 1, 13));
+    assert_eq!(elapsed.tasks["coat"].start, working.tasks["coat"].start);
+}
+
+#[test]
+fn chain_through_elapsed_lag_stays_critical() {
+    let schedule = schedule_with(DependencyType::FinishToStart, 2, 2, true);
+
+    for id in ["prep", "cure", "coat"] {
+        assert!(schedule.tasks[id].is_critical, "{} should be critical", id);
+        assert_eq!(schedule.tasks[id].slack, Duration::zero());
+    }
+}
+
+// =============================================================================
+// Degenerate dependency detection
+// =============================================================================
+
+fn lead(predecessor: &str, dep_type: DependencyType, days: i64) -> Dependency {
+    Dependency {
+        predecessor: predecessor.into(),
+        dep_type,
+        lag: Some(Duration::days(-days)),
+        lag_calendar: false,
+    }
+}
+
+fn degenerate(project: &Project) -> Vec<String> {
+    let schedule = CpmSolver::new().schedule(project).unwrap();
+    let mut emitter = CollectingEmitter::new();
+    analyze_project(
+        project,
+        Some(&schedule),
+        &AnalysisConfig::new(),
+        &mut emitter,
+    );
+    emitter
+        .diagnostics
+        .into_iter()
+        .filter(|d| d.code == DiagnosticCode::W017DegenerateDependency)
+        .map(|d| d.message)
+        .collect()
+}
+
+fn project_with(tasks: Vec<Task>) -> Project {
+    let mut project = Project::new("Leads");
+    project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+    project.tasks = tasks;
+    project
+}
+
+#[test]
+fn finish_to_finish_lead_before_start_is_flagged() {
+    // prep finishes on day 2; pack must finish five days earlier
+    let project = project_with(vec![
+        Task::new("prep").duration(Duration::days(2)),
+        Task::new("pack")
+            .duration(Duration::days(3))
+            .with_dependency(lead("prep", DependencyType::FinishToFinish, 5)),
+    ]);
+
+    assert_eq!(
+        degenerate(&project),
+        vec!["FF dependency 'prep' -> 'pack' with lag -5d requires 'pack' to finish before it can start"]
+    );
+}
+
+#[test]
+fn start_to_finish_lead_before_project_start_is_flagged() {
+    let project = project_with(vec![
+        Task::new("shift").duration(Duration::days(3)),
+        Task::new("handover")
+            .duration(Duration::days(1))
+            .with_dependency(lead("shift", DependencyType::StartToFinish, 2)),
+    ]);
+
+    let flagged = degenerate(&project);
+
+    assert_eq!(flagged.len(), 1);
+    assert!(flagged[0].starts_with("SF dependency 'shift' -> 'handover' with lag -2d"));
+}
+
+#[test]
+fn ordinary_leads_are_not_flagged() {
+    let project = project_with(vec![
+        Task::new("build").duration(Duration::days(10)),
+        Task::new("docs")
+            .duration(Duration::days(3))
+            .with_dependency(lead("build", DependencyType::FinishToStart, 2)),
+        Task::new("qa")
+            .duration(Duration::days(15))
+            .with_dependency(lead("build", DependencyType::FinishToFinish, 0)),
+    ]);
+
+    assert!(degenerate(&project).is_empty());
+}
+
+// =============================================================================
+// Working-day gaps between dependent tasks
+// =============================================================================
+
+fn link(predecessor: &str, dep_type: DependencyType, lag_days: i64) -> Dependency {
+    Dependency {
+        predecessor: predecessor.into(),
+        dep_type,
+        lag: Some(Duration::days(lag_days)),
+        lag_calendar: false,
+    }
+}
+
+/// design (Mon-Fri) -> build (FS +2d), review (SS), test (FS)
+fn task_gaps_project() -> Project {
+    let mut project = Project::new("Gaps");
+    project.start = date(2025, 1, 6);
+    project.tasks = vec![
+        Task::new("design").duration(Duration::days(5)),
+        Task::new("build")
+            .duration(Duration::days(3))
+            .with_dependency(link("design", DependencyType::FinishToStart, 2)),
+        Task::new("review")
+            .duration(Duration::days(2))
+            .with_dependency(link("design", DependencyType::StartToStart, 0)),
+        Task::new("test")
+            .duration(Duration::days(1))
+            .depends_on("build"),
+    ];
+    project
+}
+
+#[test]
+fn lag_shows_as_working_day_gap_across_weekend() {
+    let project = task_gaps_project();
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+
+    // design finishes Fri Jan 10; build starts Wed Jan 15 (Mon, Tue idle)
+    assert_eq!(schedule.tasks["build"].start, date(2025, 1, 15));
+    assert_eq!(
+        schedule.gap_between(&project, "design", "build"),
+        Some(Duration::days(2))
+    );
+}
+
+#[test]
+fn plain_finish_to_start_has_no_gap() {
+    let project = task_gaps_project();
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+
+    assert_eq!(
+        schedule.gap_between(&project, "build", "test"),
+        Some(Duration::zero())
+    );
+}
+
+#[test]
+fn start_to_start_overlap_is_negative() {
+    let project = task_gaps_project();
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+
+    // review runs alongside all five days of design
+    assert_eq!(
+        schedule.gap_between(&project, "design", "review"),
+        Some(Duration::days(-5))
+    );
+}
+
+#[test]
+fn unrelated_tasks_have_no_gap() {
+    let project = task_gaps_project();
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+
+    assert_eq!(schedule.gap_between(&project, "design", "test"), None);
+    assert_eq!(schedule.gap_between(&project, "build", "design"), None);
+    assert_eq!(schedule.gap_between(&project, "design", "missing"), None);
+}
+
+#[test]
+fn nested_tasks_resolve_relative_dependencies() {
+    let mut project = Project::new("Nested Gaps");
+    project.start = date(2025, 1, 6);
+    project.tasks = vec![Task::new("phase")
+        .child(Task::new("a").duration(Duration::days(2)))
+        .child(
+            Task::new("b")
+                .duration(Duration::days(2))
+                .with_dependency(link("a", DependencyType::FinishToStart, 1)),
+        )];
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+
+    assert_eq!(
+        schedule.gap_between(&project, "phase.a", "phase.b"),
+        Some(Duration::days(1))
+    );
+}
+
+#[test]
+fn free_slack_is_limited_by_successor_links() {
+    let mut project = task_gaps_project();
+    // Hold release until Fri Jan 24 so the build -> test chain has slack
+    project.tasks.push(
+        Task::new("release")
+            .duration(Duration::days(1))
+            .depends_on("test")
+            .constraint(TaskConstraint::StartNoEarlierThan(date(2025, 1, 24))),
+    );
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+
+    // test finishes Mon Jan 20; release waits until Fri Jan 24
+    assert_eq!(
+        schedule.free_slack(&project, "test"),
+        Some(Duration::days(3))
+    );
+    // build finishes right before test starts
+    assert_eq!(
+        schedule.free_slack(&project, "build"),
+        Some(Duration::zero())
+    );
+    // review has no successors: free slack equals total slack
+    assert_eq!(
+        schedule.free_slack(&project, "review"),
+        Some(schedule.tasks["review"].slack)
+    );
+    assert_eq!(schedule.free_slack(&project, "missing"), None);
+}
+
+// =============================================================================
+// Project flexibility metric
+// =============================================================================
+
+fn schedule(tasks: Vec<Task>) -> Schedule {
+    let mut project = Project::new("Flexibility");
+    project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+    project.tasks = tasks;
+    CpmSolver::new().schedule(&project).unwrap()
+}
+
+#[test]
+fn linear_chain_has_no_flexibility() {
+    let schedule = schedule(vec![
+        Task::new("a").duration(Duration::days(2)),
+        Task::new("b").duration(Duration::days(2)).depends_on("a"),
+        Task::new("c").duration(Duration::days(2)).depends_on("b"),
+    ]);
+
+    assert_eq!(schedule.flexibility(), 0.0);
+}
+
+#[test]
+fn parallel_tasks_are_flexible() {
+    let chain = schedule(vec![
+        Task::new("a").duration(Duration::days(5)),
+        Task::new("b").duration(Duration::days(1)).depends_on("a"),
+    ]);
+    let parallel = schedule(vec![
+        Task::new("a").duration(Duration::days(5)),
+        Task::new("b").duration(Duration::days(1)),
+        Task::new("c").duration(Duration::days(1)),
+    ]);
+
+    // b and c each float 4 of the 5 days: 8 / (3 × 5)
+    assert!((parallel.flexibility() - 8.0 / 15.0).abs() < 1e-9);
+    assert!(parallel.flexibility() > chain.flexibility());
+}
+
+#[test]
+fn containers_do_not_count() {
+    let schedule = schedule(vec![Task::new("phase")
+        .child(Task::new("a").duration(Duration::days(4)))
+        .child(Task::new("b").duration(Duration::days(2)))]);
+
+    // Only the leaves count: b floats 2 of 4 days
+    assert!((schedule.flexibility() - 2.0 / 8.0).abs() < 1e-9);
+}
+
+// =============================================================================
+// Critical path within a date window
+// =============================================================================
+
+/// spec (Jan 6-10) -> design (Jan 13-17) -> build (Jan 20-24) -> test
+/// (Jan 27-31), with docs (Jan 13-14) hanging off spec
+fn critical_path_window_schedule() -> Schedule {
+    let mut project = Project::new("Phases");
+    project.start = date(2025, 1, 6);
+    project.tasks = vec![
+        Task::new("spec").duration(Duration::days(5)),
+        Task::new("design")
+            .duration(Duration::days(5))
+            .depends_on("spec"),
+        Task::new("build")
+            .duration(Duration::days(5))
+            .depends_on("design"),
+        Task::new("test")
+            .duration(Duration::days(5))
+            .depends_on("build"),
+        Task::new("docs")
+            .duration(Duration::days(2))
+            .depends_on("spec"),
+    ];
+    CpmSolver::new().schedule(&project).unwrap()
+}
+
+#[test]
+fn window_keeps_only_active_critical_tasks() {
+    let schedule = critical_path_window_schedule();
+
+    assert_eq!(
+        schedule.critical_path_in(date(2025, 1, 13), date(2025, 1, 24)),
+        vec!["design", "build"]
+    );
+}
+
+#[test]
+fn tasks_spanning_the_boundary_are_included() {
+    let schedule = critical_path_window_schedule();
+
+    // Jan 10 is spec's last day, Jan 20 is build's first
+    assert_eq!(
+        schedule.critical_path_in(date(2025, 1, 10), date(2025, 1, 20)),
+        vec!["spec", "design", "build"]
+    );
+}
+
+#[test]
+fn tasks_with_slack_are_left_out() {
+    let schedule = critical_path_window_schedule();
+
+    assert!(!schedule.tasks["docs"].is_critical);
+    assert_eq!(
+        schedule.critical_path_in(date(2025, 1, 13), date(2025, 1, 14)),
+        vec!["design"]
+    );
+}
+
+// =============================================================================
+// Critical-path reasons
+// =============================================================================
+
+/// a (Jan 6-10) -> c (Jan 13-14) is critical; prep -> b (Jan 6-8) has slack
+fn critical_reason_project() -> Project {
+    let mut project = Project::new("Critical Reasons");
+    project.start = date(2025, 1, 6);
+    project.tasks = vec![
+        Task::new("a").duration(Duration::days(5)),
+        Task::new("prep").duration(Duration::days(1)),
+        Task::new("b")
+            .duration(Duration::days(2))
+            .depends_on("prep"),
+        Task::new("c").duration(Duration::days(2)).depends_on("a"),
+    ];
+    project
+}
+
+fn constrain_b(mut project: Project, regime: Option<TemporalRegime>) -> Project {
+    let mut b = Task::new("b")
+        .duration(Duration::days(2))
+        .depends_on("prep")
+        .constraint(TaskConstraint::FinishNoLaterThan(date(2025, 1, 8)));
+    b.regime = regime;
+    project.tasks[2] = b;
+    project
+}
+
+#[test]
+fn longest_chain_is_network_driven() {
+    let schedule = CpmSolver::new()
+        .schedule(&critical_reason_project())
+        .unwrap();
+
+    assert_eq!(
+        schedule.tasks["a"].critical_reason,
+        Some(CriticalReason::NetworkDriven)
+    );
+    assert_eq!(
+        schedule.tasks["c"].critical_reason,
+        Some(CriticalReason::NetworkDriven)
+    );
+    assert_eq!(schedule.tasks["b"].critical_reason, None);
+    assert_eq!(schedule.tasks["prep"].critical_reason, None);
+}
+
+#[test]
+fn binding_ceiling_is_constraint_driven() {
+    let schedule = CpmSolver::new()
+        .schedule(&constrain_b(critical_reason_project(), None))
+        .unwrap();
+
+    let b = &schedule.tasks["b"];
+    assert!(b.is_critical);
+    assert_eq!(b.critical_reason, Some(CriticalReason::ConstraintDriven));
+    // The network chain is unaffected
+    assert_eq!(
+        schedule.tasks["a"].critical_reason,
+        Some(CriticalReason::NetworkDriven)
+    );
+}
+
+#[test]
+fn constraint_reason_flows_to_pinned_predecessors() {
+    let schedule = CpmSolver::new()
+        .schedule(&constrain_b(critical_reason_project(), None))
+        .unwrap();
+
+    assert_eq!(
+        schedule.tasks["prep"].critical_reason,
+        Some(CriticalReason::ConstraintDriven)
+    );
+}
+
+#[test]
+fn deadline_regime_ceiling_is_deadline_driven() {
+    let schedule = CpmSolver::new()
+        .schedule(&constrain_b(
+            critical_reason_project(),
+            Some(TemporalRegime::Deadline),
+        ))
+        .unwrap();
+
+    assert_eq!(
+        schedule.tasks["b"].critical_reason,
+        Some(CriticalReason::DeadlineDriven)
+    );
+}
+
+// =============================================================================
+// Driving predecessors and driving path traces
+// =============================================================================
+
+fn driving_path_project() -> Project {
+    let mut project = Project::new("Driving Path");
+    project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+    project.tasks = vec![
+        Task::new("spec").duration(Duration::days(3)),
+        Task::new("design")
+            .duration(Duration::days(5))
+            .depends_on("spec"),
+        Task::new("procure").duration(Duration::days(2)),
+        Task::new("build")
+            .duration(Duration::days(4))
+            .depends_on("design")
+            .depends_on("procure"),
+        Task::new("docs")
+            .duration(Duration::days(1))
+            .depends_on("procure"),
+    ];
+    project
+}
+
+#[test]
+fn driving_predecessor_is_latest_binding_input() {
+    let schedule = CpmSolver::new().schedule(&driving_path_project()).unwrap();
+
+    assert_eq!(
+        schedule.tasks["build"].driving_predecessor.as_deref(),
+        Some("design")
+    );
+    assert_eq!(schedule.tasks["spec"].driving_predecessor, None);
+}
+
+#[test]
+fn driving_path_of_critical_task_follows_critical_path() {
+    let schedule = CpmSolver::new().schedule(&driving_path_project()).unwrap();
+
+    assert_eq!(
+        schedule.driving_path_to("build"),
+        vec!["spec", "design", "build"]
+    );
+}
+
+#[test]
+fn driving_path_of_non_critical_task_is_its_own_chain() {
+    let schedule = CpmSolver::new().schedule(&driving_path_project()).unwrap();
+
+    assert!(!schedule.tasks["docs"].is_critical);
+    assert_eq!(schedule.driving_path_to("docs"), vec!["procure", "docs"]);
+}
+
+#[test]
+fn binding_constraint_ends_the_driving_path() {
+    let mut project = driving_path_project();
+    project.tasks[4] = Task::new("docs")
+        .duration(Duration::days(1))
+        .depends_on("procure")
+        .constraint(TaskConstraint::StartNoEarlierThan(
+            NaiveDate::from_ymd_opt(2025, 2, 3).unwrap(),
+        ));
+
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+
+    assert_eq!(schedule.tasks["docs"].driving_predecessor, None);
+    assert_eq!(schedule.driving_path_to("docs"), vec!["docs"]);
+}
+
+#[test]
+fn driving_path_uses_qualified_ids_in_containers() {
+    let mut project = Project::new("Nested");
+    project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+    project.tasks = vec![Task::new("phase")
+        .child(Task::new("design").duration(Duration::days(2)))
+        .child(
+            Task::new("build")
+                .duration(Duration::days(3))
+                .depends_on("design"),
+        )];
+
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+
+    assert_eq!(
+        schedule.driving_path_to("phase.build"),
+        vec!["phase.design", "phase.build"]
+    );
+}
+
+#[test]
+fn driving_path_of_unknown_task_is_empty() {
+    let schedule = CpmSolver::new().schedule(&driving_path_project()).unwrap();
+
+    assert!(schedule.driving_path_to("missing").is_empty());
+}
+
+#[test]
+fn finish_driver_is_end_of_longest_chain() {
+    let schedule = CpmSolver::new().schedule(&driving_path_project()).unwrap();
+
+    assert_eq!(schedule.finish_drivers(), vec!["build"]);
+    assert_eq!(
+        schedule.finish_driving_paths(),
+        vec![vec!["spec", "design", "build"]]
+    );
+}
+
+#[test]
+fn parallel_chains_tied_at_the_finish_are_all_drivers() {
+    let mut project = driving_path_project();
+    // procure (2d) -> qa (10d) ends the same day as spec -> design -> build
+    project.tasks.push(
+        Task::new("qa")
+            .duration(Duration::days(10))
+            .depends_on("procure"),
+    );
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+
+    assert_eq!(schedule.finish_drivers(), vec!["build", "qa"]);
+    assert_eq!(
+        schedule.finish_driving_paths(),
+        vec![vec!["spec", "design", "build"], vec!["procure", "qa"]]
+    );
+}
+
+#[test]
+fn finish_driver_skips_containers() {
+    let mut project = Project::new("Nested");
+    project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+    project.tasks = vec![Task::new("phase")
+        .child(Task::new("design").duration(Duration::days(2)))
+        .child(
+            Task::new("build")
+                .duration(Duration::days(3))
+                .depends_on("design"),
+        )];
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+
+    assert_eq!(schedule.finish_drivers(), vec!["phase.build"]);
+}
+
+// =============================================================================
+// Schedule invariant self-check
+// =============================================================================
+
+/// build (design -> code -> done) alongside docs, from Mon Jan 6
+fn schedule_verify_project() -> Project {
+    let mut project = Project::new("Verify");
+    project.start = date(2025, 1, 6);
+    project.tasks = vec![
+        Task::new("build")
+            .child(Task::new("design").duration(Duration::days(3)))
+            .child(
+                Task::new("code")
+                    .duration(Duration::days(5))
+                    .depends_on("design"),
+            )
+            .child(Task::new("done").milestone().depends_on("code")),
+        Task::new("docs").duration(Duration::days(2)),
+    ];
+    project
+}
+
+#[test]
+fn solver_schedule_is_consistent() {
+    let project = schedule_verify_project();
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+
+    assert_eq!(schedule.verify(&project), vec![]);
+}
+
+#[test]
+fn broken_dependency_is_reported() {
+    let project = schedule_verify_project();
+    let mut schedule = CpmSolver::new().schedule(&project).unwrap();
+    // code starts on design's last day
+    let code = schedule.tasks.get_mut("build.code").unwrap();
+    code.start = date(2025, 1, 8);
+
+    let violations = schedule.verify(&project);
+
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].task_id, "build.code");
+    assert_eq!(violations[0].invariant, Invariant::DependencySatisfied);
+    assert!(violations[0].detail.contains("build.design"));
+}
+
+#[test]
+fn slack_mismatch_is_reported() {
+    let project = schedule_verify_project();
+    let mut schedule = CpmSolver::new().schedule(&project).unwrap();
+    let design = schedule.tasks.get_mut("build.design").unwrap();
+    design.slack = Duration::days(2);
+
+    let invariants: Vec<_> = schedule
+        .verify(&project)
+        .into_iter()
+        .map(|v| (v.task_id, v.invariant))
+        .collect();
+
+    assert!(invariants.contains(&("build.design".into(), Invariant::SlackMatchesLateStart)));
+    assert!(invariants.contains(&("build.design".into(), Invariant::CriticalHasNoSlack)));
+}
+
+#[test]
+fn container_out_of_bounds_is_reported() {
+    let project = schedule_verify_project();
+    let mut schedule = CpmSolver::new().schedule(&project).unwrap();
+    let build = schedule.tasks.get_mut("build").unwrap();
+    build.finish = date(2025, 1, 10);
+
+    let violations = schedule.verify(&project);
+
+    // Both code (to Jan 15) and the milestone after it overrun
+    assert_eq!(violations.len(), 2);
+    assert!(violations
+        .iter()
+        .all(|v| v.task_id == "build" && v.invariant == Invariant::ContainerSpansChildren));
+    assert!(violations.iter().any(|v| v.detail.contains("'build.code'")));
+}
@@ -1,5 +1,10 @@
-use utf8proj_core::{CollectingEmitter, DiagnosticCode, Duration, Project, Task};
-use utf8proj_solver::{analyze_project, AnalysisConfig};
+use chrono::NaiveDate;
+use utf8proj_core::{
+    CollectingEmitter, DiagnosticCode, Duration, Project, ResourceProfile, Scheduler, Task,
+};
+use utf8proj_solver::{
+    analyze_project, analyze_with_schedule, lint_structure, AnalysisConfig, CpmSolver,
+};
 
 #[test]
 fn duplicate_ids_at_root_emits_e004() {
@@ -121,5 +126,92 @@ fn triple_duplicate_emits_e004_once() {
         .iter()
         .filter(|d| d.code == DiagnosticCode::E004DuplicateTaskId)
         .count();
-    assert_eq!(count, 1, "should emit E004 exactly once for triple duplicate");
+    assert_eq!(
+        count, 1,
+        "should emit E004 exactly once for triple duplicate"
+    );
+}
+
+// =============================================================================
+// Structural lint pass
+// =============================================================================
+
+/// build uses an unknown trait's profile; docs has no estimate
+fn lint_structure_project() -> Project {
+    let mut project = Project::new("Lint");
+    project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+    project.profiles = vec![ResourceProfile::new("developer").with_traits(["rockstar"])];
+    project.tasks = vec![
+        Task::new("build")
+            .duration(Duration::days(5))
+            .assign("developer"),
+        Task::new("docs"),
+    ];
+    project
+}
+
+fn codes(emitter: &CollectingEmitter) -> Vec<DiagnosticCode> {
+    let mut codes: Vec<_> = emitter.diagnostics.iter().map(|d| d.code).collect();
+    codes.sort_by_key(|c| c.as_str());
+    codes
+}
+
+#[test]
+fn lint_runs_without_a_schedule() {
+    let mut emitter = CollectingEmitter::new();
+    lint_structure(
+        &lint_structure_project(),
+        &AnalysisConfig::new(),
+        &mut emitter,
+    );
+
+    let codes = codes(&emitter);
+    assert!(codes.contains(&DiagnosticCode::W003UnknownTrait));
+    assert!(codes.contains(&DiagnosticCode::W011UnsizedTask));
+    assert!(codes.contains(&DiagnosticCode::W001AbstractAssignment));
+}
+
+#[test]
+fn lint_emits_no_schedule_based_diagnostics() {
+    let mut emitter = CollectingEmitter::new();
+    lint_structure(
+        &lint_structure_project(),
+        &AnalysisConfig::new(),
+        &mut emitter,
+    );
+
+    for code in codes(&emitter) {
+        assert!(
+            !matches!(
+                code,
+                DiagnosticCode::I001ProjectCostSummary
+                    | DiagnosticCode::I002RefinementProgress
+                    | DiagnosticCode::I004ProjectStatus
+                    | DiagnosticCode::W002WideCostRange
+                    | DiagnosticCode::C010NonWorkingDay
+            ),
+            "{} needs a schedule",
+            code
+        );
+    }
+}
+
+#[test]
+fn both_passes_match_full_analysis() {
+    let project = lint_structure_project();
+    let config = AnalysisConfig::new();
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+
+    let mut full = CollectingEmitter::new();
+    analyze_project(&project, Some(&schedule), &config, &mut full);
+
+    let mut split = CollectingEmitter::new();
+    lint_structure(&project, &config, &mut split);
+    let structural = split.diagnostics.len();
+    analyze_with_schedule(&project, Some(&schedule), &config, &mut split);
+
+    assert_eq!(codes(&split), codes(&full));
+    assert!(split.diagnostics[structural..]
+        .iter()
+        .any(|d| d.code == DiagnosticCode::I001ProjectCostSummary));
 }
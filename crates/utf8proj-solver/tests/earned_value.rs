@@ -1,11 +1,20 @@
 //! Integration tests for Earned Value metrics (I005)
 //!
-//! These tests verify PV, EV, and SPI computation.
+//! These tests verify PV, EV, and SPI computation, and the cash-flow
+//! projection.
 
 use chrono::{Local, NaiveDate};
-use utf8proj_core::{Duration, Project, Scheduler, Task};
+use rust_decimal::Decimal;
+use utf8proj_core::{
+    Duration, Money, PaymentTerms, Project, ReportInterval, Resource, Scheduler, Task,
+    TaskConstraint,
+};
 use utf8proj_solver::CpmSolver;
 
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
 /// Helper to get current date
 fn today() -> NaiveDate {
     Local::now().date_naive()
@@ -246,6 +255,56 @@ fn container_tasks_excluded() {
     assert_eq!(schedule.earned_value, 50);
 }
 
+/// Test: Physical completion drives EV, schedule completion drives progress
+#[test]
+fn physical_complete_used_for_ev() {
+    let mut project = Project::new("Physical Test");
+    project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+    project.status_date = Some(NaiveDate::from_ymd_opt(2025, 1, 13).unwrap());
+
+    // 90% through its schedule but only half the deliverable is done
+    project.tasks.push(
+        Task::new("build")
+            .duration(Duration::days(10))
+            .complete(90.0)
+            .physical_complete(50.0),
+    );
+    // No physical figure: EV falls back to complete
+    project.tasks.push(
+        Task::new("docs")
+            .duration(Duration::days(10))
+            .complete(30.0),
+    );
+
+    let schedule = CpmSolver::new().schedule(&project).expect("Should succeed");
+
+    assert_eq!(schedule.project_progress, 60);
+    assert_eq!(schedule.earned_value, 40);
+    assert_eq!(schedule.tasks["build"].percent_complete, 90);
+}
+
+/// Test: EV rounds its weighted average like PV
+#[test]
+fn earned_value_rounds_like_planned_value() {
+    let mut project = Project::new("Rounding Test");
+    project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+    project.status_date = Some(NaiveDate::from_ymd_opt(2025, 1, 6).unwrap());
+
+    project.tasks.push(
+        Task::new("short")
+            .duration(Duration::days(1))
+            .physical_complete(50.0),
+    );
+    project
+        .tasks
+        .push(Task::new("long").duration(Duration::days(2)));
+
+    let schedule = CpmSolver::new().schedule(&project).expect("Should succeed");
+
+    // (1*50 + 2*0) / 3 = 16.7%
+    assert_eq!(schedule.earned_value, 17);
+}
+
 /// Test: I005 diagnostic is emitted
 #[test]
 fn i005_diagnostic_emitted() {
@@ -283,3 +342,136 @@ fn i005_diagnostic_emitted() {
         .expect("Should have I005");
     assert!(i005.message.contains("SPI"));
 }
+
+fn i005_notes(project: &Project) -> Vec<String> {
+    use utf8proj_core::{CollectingEmitter, DiagnosticCode};
+    use utf8proj_solver::{analyze_project, AnalysisConfig};
+
+    let schedule = CpmSolver::new().schedule(project).expect("Should succeed");
+    let mut emitter = CollectingEmitter::new();
+    analyze_project(
+        project,
+        Some(&schedule),
+        &AnalysisConfig::default(),
+        &mut emitter,
+    );
+    emitter
+        .diagnostics
+        .into_iter()
+        .find(|d| d.code == DiagnosticCode::I005EarnedValueSummary)
+        .expect("Should have I005")
+        .notes
+}
+
+#[test]
+fn i005_reports_spi_forecast_when_progress_is_earned() {
+    let mut project = Project::new("Behind");
+    project.start = today() - chrono::Duration::days(8);
+    let mut task = Task::new("work").name("Work").duration(Duration::days(10));
+    task.complete = Some(20.0);
+    project.tasks.push(task);
+
+    let notes = i005_notes(&project);
+
+    assert!(notes.iter().any(|n| n.starts_with("SPI forecast finish: ")));
+}
+
+#[test]
+fn i005_omits_spi_forecast_without_earned_progress() {
+    let mut project = Project::new("Not Started");
+    project.start = today() - chrono::Duration::days(5);
+    project
+        .tasks
+        .push(Task::new("work").name("Work").duration(Duration::days(10)));
+
+    let notes = i005_notes(&project);
+
+    assert!(!notes.iter().any(|n| n.starts_with("SPI forecast finish")));
+}
+
+// =============================================================================
+// Cash-flow projection
+// =============================================================================
+
+/// Alice at 500/day: design Mon-Tue (1000), build Wed-Fri (1500)
+fn cash_flow_project() -> Project {
+    let mut project = Project::new("Cash Flow");
+    project.start = date(2025, 1, 6);
+    project
+        .resources
+        .push(Resource::new("alice").rate(Money::new(Decimal::from(500), "USD")));
+    project.tasks = vec![
+        Task::new("design")
+            .duration(Duration::days(2))
+            .assign("alice"),
+        Task::new("build")
+            .duration(Duration::days(3))
+            .assign("alice")
+            .depends_on("design"),
+    ];
+    project
+}
+
+fn amounts(flow: &[(NaiveDate, Money)]) -> Vec<(NaiveDate, Decimal)> {
+    flow.iter().map(|(d, m)| (*d, m.amount)).collect()
+}
+
+#[test]
+fn immediate_daily_flow_matches_incurred_cost() {
+    let schedule = CpmSolver::new().schedule(&cash_flow_project()).unwrap();
+    let flow = schedule.cash_flow(PaymentTerms::immediate(), ReportInterval::Day);
+
+    let expected: Vec<_> = (6..=10)
+        .map(|day| (date(2025, 1, day), Decimal::from(500)))
+        .collect();
+    assert_eq!(amounts(&flow), expected);
+    assert!(flow.iter().all(|(_, m)| m.currency == "USD"));
+}
+
+#[test]
+fn payment_lag_shifts_cost_into_later_periods() {
+    let schedule = CpmSolver::new().schedule(&cash_flow_project()).unwrap();
+    let flow = schedule.cash_flow(PaymentTerms::net(3), ReportInterval::Week);
+
+    // Paid Jan 9-13: Thu-Sun fall in the week of Jan 6, Monday in the next
+    assert_eq!(
+        amounts(&flow),
+        vec![
+            (date(2025, 1, 6), Decimal::from(2_000)),
+            (date(2025, 1, 13), Decimal::from(500)),
+        ]
+    );
+}
+
+#[test]
+fn monthly_flow_includes_empty_periods() {
+    let mut project = cash_flow_project();
+    project.tasks.push(
+        Task::new("launch")
+            .duration(Duration::days(1))
+            .assign("alice")
+            .constraint(TaskConstraint::StartNoEarlierThan(date(2025, 3, 3))),
+    );
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+    let flow = schedule.cash_flow(PaymentTerms::net(30), ReportInterval::Month);
+
+    assert_eq!(
+        amounts(&flow),
+        vec![
+            (date(2025, 2, 1), Decimal::from(2_500)),
+            (date(2025, 3, 1), Decimal::ZERO),
+            (date(2025, 4, 1), Decimal::from(500)),
+        ]
+    );
+}
+
+#[test]
+fn no_cost_baseline_yields_empty_flow() {
+    let mut project = cash_flow_project();
+    project.resources = vec![Resource::new("alice")];
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+
+    assert!(schedule
+        .cash_flow(PaymentTerms::net(30), ReportInterval::Month)
+        .is_empty());
+}
@@ -6,8 +6,12 @@
 //! Test progression matches SPEC_HIERARCHICAL_TASKS.md
 
 use chrono::NaiveDate;
-use utf8proj_core::{Duration, Project, Scheduler, Task, TaskConstraint};
-use utf8proj_solver::CpmSolver;
+use rust_decimal::Decimal;
+use utf8proj_core::{
+    CollectingEmitter, Dependency, DependencyType, Diagnostic, DiagnosticCode, Duration, Money,
+    Project, Resource, Schedule, Scheduler, Task, TaskConstraint,
+};
+use utf8proj_solver::{analyze_project, AnalysisConfig, CpmSolver};
 
 fn date(year: i32, month: u32, day: u32) -> NaiveDate {
     NaiveDate::from_ymd_opt(year, month, day).unwrap()
@@ -165,6 +169,7 @@ fn ss_dependency_aligns_starts() {
         predecessor: "act1".to_string(),
         dep_type: utf8proj_core::DependencyType::StartToStart,
         lag: None,
+        lag_calendar: false,
     });
 
     project.tasks = vec![act1, act2];
@@ -197,6 +202,7 @@ fn ss_dependency_with_positive_lag() {
         predecessor: "act1".to_string(),
         dep_type: utf8proj_core::DependencyType::StartToStart,
         lag: Some(Duration::days(5)),
+        lag_calendar: false,
     });
 
     project.tasks = vec![act1, act2];
@@ -231,6 +237,7 @@ fn ff_dependency_aligns_finishes() {
         predecessor: "act1".to_string(),
         dep_type: utf8proj_core::DependencyType::FinishToFinish,
         lag: None,
+        lag_calendar: false,
     });
 
     project.tasks = vec![act1, act2];
@@ -266,6 +273,7 @@ fn sf_dependency_finish_after_start() {
         predecessor: "act1".to_string(),
         dep_type: utf8proj_core::DependencyType::StartToFinish,
         lag: None,
+        lag_calendar: false,
     });
 
     project.tasks = vec![act1, act2];
@@ -299,17 +307,18 @@ fn negative_lag_allows_overlap() {
     project.start = date(2025, 2, 3); // Monday
 
     // act1: 20 days starting Feb 03, finishes Feb 28
-    let mut act1 = Task::new("act1").effort(Duration::days(20));
+    let mut act1 = Task::new("act1").effort(Duration::hours(140));
     act1.constraints
         .push(TaskConstraint::MustStartOn(date(2025, 2, 3)));
 
     // act2 depends on act1 with -5d lag (5 day lead)
     // B.start >= A.finish - 5d = Feb 28 - 5d = Feb 21
-    let mut act2 = Task::new("act2").effort(Duration::days(10));
+    let mut act2 = Task::new("act2").effort(Duration::hours(70));
     act2.depends.push(utf8proj_core::Dependency {
         predecessor: "act1".to_string(),
         dep_type: utf8proj_core::DependencyType::FinishToStart,
         lag: Some(Duration::days(-5)),
+        lag_calendar: false,
     });
 
     project.tasks = vec![act1, act2];
@@ -354,6 +363,7 @@ fn ss_negative_lag_acts_as_lead() {
         predecessor: "act1".to_string(),
         dep_type: utf8proj_core::DependencyType::StartToStart,
         lag: Some(Duration::days(-3)),
+        lag_calendar: false,
     });
 
     project.tasks = vec![act1, act2];
@@ -406,6 +416,7 @@ fn critical_path_with_ss_dependencies() {
         predecessor: "validation".to_string(),
         dep_type: utf8proj_core::DependencyType::StartToStart,
         lag: Some(Duration::days(5)), // SS+5d (1 week)
+        lag_calendar: false,
     });
 
     let mut migration = Task::new("migration").effort(Duration::days(75));
@@ -413,6 +424,7 @@ fn critical_path_with_ss_dependencies() {
         predecessor: "validation".to_string(),
         dep_type: utf8proj_core::DependencyType::StartToStart,
         lag: Some(Duration::days(10)), // SS+10d (2 weeks)
+        lag_calendar: false,
     });
 
     let cutover = Task::new("cutover")
@@ -511,6 +523,7 @@ fn critical_path_with_parallel_ss_chains() {
         predecessor: "start".to_string(),
         dep_type: utf8proj_core::DependencyType::StartToStart,
         lag: None,
+        lag_calendar: false,
     });
     let task_a2 = Task::new("taskA2")
         .effort(Duration::days(5))
@@ -522,6 +535,7 @@ fn critical_path_with_parallel_ss_chains() {
         predecessor: "start".to_string(),
         dep_type: utf8proj_core::DependencyType::StartToStart,
         lag: None,
+        lag_calendar: false,
     });
     let task_b2 = Task::new("taskB2")
         .effort(Duration::days(5))
@@ -594,6 +608,7 @@ fn critical_path_with_ff_dependencies() {
         predecessor: "task1".to_string(),
         dep_type: utf8proj_core::DependencyType::FinishToFinish,
         lag: None,
+        lag_calendar: false,
     });
 
     let task3 = Task::new("task3")
@@ -1258,3 +1273,419 @@ fn complex_dependency_graph_performance() {
         elapsed.as_secs_f64() * 1000.0
     );
 }
+
+// =============================================================================
+// Dependencies on container tasks
+// =============================================================================
+
+/// phase1.a (Jan 6-8) -> phase1.b (Jan 9-10) and phase1.c (Jan 9)
+fn schedule_with(dep_type: DependencyType, lag_days: i64, succ_days: i64) -> Schedule {
+    let mut project = Project::new("Container Dependencies");
+    project.start = date(2025, 1, 6);
+    project.tasks = vec![
+        Task::new("phase1")
+            .child(Task::new("a").duration(Duration::days(3)))
+            .child(Task::new("b").duration(Duration::days(2)).depends_on("a"))
+            .child(Task::new("c").duration(Duration::days(1)).depends_on("a")),
+        Task::new("next")
+            .duration(Duration::days(succ_days))
+            .with_dependency(Dependency {
+                predecessor: "phase1".into(),
+                dep_type,
+                lag: (lag_days != 0).then(|| Duration::days(lag_days)),
+                lag_calendar: false,
+            }),
+    ];
+    CpmSolver::new().schedule(&project).unwrap()
+}
+
+#[test]
+fn fs_on_container_waits_for_latest_finishing_leaf() {
+    let schedule = schedule_with(DependencyType::FinishToStart, 0, 2);
+    let next = &schedule.tasks["next"];
+
+    assert_eq!(next.start, date(2025, 1, 13));
+    assert_eq!(next.driving_predecessor.as_deref(), Some("phase1.b"));
+    assert_eq!(
+        schedule.driving_path_to("next"),
+        vec!["phase1.a", "phase1.b", "next"]
+    );
+}
+
+#[test]
+fn fs_lag_on_container_counts_from_latest_leaf() {
+    let schedule = schedule_with(DependencyType::FinishToStart, 2, 2);
+
+    assert_eq!(schedule.tasks["next"].start, date(2025, 1, 15));
+}
+
+#[test]
+fn fs_on_container_holds_every_leaf() {
+    let schedule = schedule_with(DependencyType::FinishToStart, 0, 5);
+
+    assert!(schedule.tasks["phase1.a"].is_critical);
+    assert!(schedule.tasks["phase1.b"].is_critical);
+    assert!(schedule.tasks["phase1"].is_critical);
+    // c must also finish before next starts (Jan 13), not by project end
+    assert_eq!(schedule.tasks["phase1.c"].late_finish, date(2025, 1, 10));
+    assert_eq!(schedule.tasks["phase1.c"].slack, Duration::days(1));
+}
+
+#[test]
+fn ss_on_container_follows_earliest_starting_leaf() {
+    let schedule = schedule_with(DependencyType::StartToStart, 1, 2);
+    let next = &schedule.tasks["next"];
+
+    assert_eq!(next.start, date(2025, 1, 7));
+    assert_eq!(next.driving_predecessor.as_deref(), Some("phase1.a"));
+
+    // A long SS successor holds a, but not the leaves after it
+    let schedule = schedule_with(DependencyType::StartToStart, 0, 10);
+    assert!(schedule.tasks["phase1.a"].is_critical);
+    assert!(!schedule.tasks["phase1.b"].is_critical);
+    assert!(!schedule.tasks["phase1.c"].is_critical);
+}
+
+#[test]
+fn ff_on_container_finishes_with_latest_leaf() {
+    let schedule = schedule_with(DependencyType::FinishToFinish, 0, 2);
+    let next = &schedule.tasks["next"];
+
+    assert_eq!(next.start, date(2025, 1, 9));
+    assert_eq!(next.finish, schedule.tasks["phase1.b"].finish);
+    assert_eq!(next.driving_predecessor.as_deref(), Some("phase1.b"));
+}
+
+#[test]
+fn sf_on_container_measures_from_earliest_leaf() {
+    let schedule = schedule_with(DependencyType::StartToFinish, 3, 2);
+    let next = &schedule.tasks["next"];
+
+    // Finish no earlier than 3 days after a starts
+    assert_eq!(next.start, date(2025, 1, 7));
+    assert_eq!(next.finish, date(2025, 1, 8));
+    assert_eq!(next.driving_predecessor.as_deref(), Some("phase1.a"));
+}
+
+#[test]
+fn dependency_on_container_reaches_nested_leaves() {
+    let mut project = Project::new("Nested Container");
+    project.start = date(2025, 1, 6);
+    project.tasks = vec![
+        Task::new("outer")
+            .child(Task::new("inner").child(Task::new("x").duration(Duration::days(5))))
+            .child(Task::new("y").duration(Duration::days(2))),
+        Task::new("next")
+            .duration(Duration::days(1))
+            .depends_on("outer"),
+    ];
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+
+    assert_eq!(schedule.tasks["next"].start, date(2025, 1, 13));
+    assert_eq!(
+        schedule.tasks["next"].driving_predecessor.as_deref(),
+        Some("outer.inner.x")
+    );
+    assert!(schedule.tasks["outer.inner.x"].is_critical);
+    assert!(!schedule.tasks["outer.y"].is_critical);
+}
+
+// =============================================================================
+// W013 container effort warning
+// =============================================================================
+
+/// phase { design (2d) -> build (3d) }, with `container` as the phase
+fn container_effort_project(container: Task) -> Project {
+    let mut project = Project::new("Container Effort");
+    project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+    project.tasks = vec![container
+        .child(Task::new("design").duration(Duration::days(2)))
+        .child(
+            Task::new("build")
+                .duration(Duration::days(3))
+                .depends_on("design"),
+        )];
+    project
+}
+
+fn container_effort(project: &Project) -> Vec<Diagnostic> {
+    let mut emitter = CollectingEmitter::new();
+    analyze_project(project, None, &AnalysisConfig::default(), &mut emitter);
+    emitter
+        .diagnostics
+        .into_iter()
+        .filter(|d| d.code == DiagnosticCode::W013ContainerEffort)
+        .collect()
+}
+
+#[test]
+fn container_effort_is_flagged_and_overridden() {
+    let project = container_effort_project(Task::new("phase").effort(Duration::days(20)));
+
+    let diagnostics = container_effort(&project);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(
+        diagnostics[0].message,
+        "container 'phase' sets effort directly"
+    );
+    assert!(diagnostics[0].notes.contains(
+        &"its dates are rolled up from its 2 children, so the effort is ignored".to_string()
+    ));
+
+    // The phase spans its children (5 days), not its own 20 days of effort
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+    assert_eq!(
+        schedule.tasks["phase"].finish,
+        NaiveDate::from_ymd_opt(2025, 1, 10).unwrap()
+    );
+}
+
+#[test]
+fn effort_and_duration_are_both_named() {
+    let project = container_effort_project(
+        Task::new("phase")
+            .effort(Duration::days(20))
+            .duration(Duration::days(10)),
+    );
+
+    let diagnostics = container_effort(&project);
+    assert_eq!(
+        diagnostics[0].message,
+        "container 'phase' sets effort and duration directly"
+    );
+}
+
+#[test]
+fn unestimated_containers_are_silent() {
+    assert!(container_effort(&container_effort_project(Task::new("phase"))).is_empty());
+}
+
+// =============================================================================
+// W012 ambiguous dependency warning
+// =============================================================================
+
+/// frontend and backend each have a design task; frontend.build
+/// refers to its sibling with `reference`
+fn ambiguous_dependency_project(reference: &str) -> Project {
+    let mut project = Project::new("Ambiguous");
+    project.tasks = vec![
+        Task::new("frontend")
+            .child(Task::new("design").duration(Duration::days(2)))
+            .child(Task::new("mockup").duration(Duration::days(1)))
+            .child(
+                Task::new("build")
+                    .duration(Duration::days(3))
+                    .depends_on(reference),
+            ),
+        Task::new("backend").child(Task::new("design").duration(Duration::days(2))),
+    ];
+    project
+}
+
+fn ambiguous_dependencies(project: &Project) -> Vec<Diagnostic> {
+    let mut emitter = CollectingEmitter::new();
+    analyze_project(project, None, &AnalysisConfig::default(), &mut emitter);
+    emitter
+        .diagnostics
+        .into_iter()
+        .filter(|d| d.code == DiagnosticCode::W012AmbiguousDependency)
+        .collect()
+}
+
+#[test]
+fn relative_name_in_two_containers_is_ambiguous() {
+    let diagnostics = ambiguous_dependencies(&ambiguous_dependency_project("design"));
+
+    assert_eq!(diagnostics.len(), 1);
+    let diagnostic = &diagnostics[0];
+    assert_eq!(
+        diagnostic.message,
+        "task 'frontend.build' depends on 'design', which matches 2 tasks"
+    );
+    assert!(diagnostic
+        .notes
+        .contains(&"candidates: backend.design, frontend.design".to_string()));
+    assert!(diagnostic
+        .notes
+        .contains(&"currently resolves to 'frontend.design'".to_string()));
+    assert_eq!(
+        diagnostic.hints,
+        vec!["use a qualified path, e.g. 'depends: frontend.design'".to_string()]
+    );
+}
+
+#[test]
+fn qualified_and_unique_names_are_not_ambiguous() {
+    assert!(ambiguous_dependencies(&ambiguous_dependency_project("frontend.design")).is_empty());
+    assert!(ambiguous_dependencies(&ambiguous_dependency_project("mockup")).is_empty());
+}
+
+// =============================================================================
+// Inactive (cancelled) tasks
+// =============================================================================
+
+/// design (3d) -> spike (5d, alice) -> build (2d), from Mon Jan 6
+fn inactive_tasks_project() -> Project {
+    let mut project = Project::new("Inactive");
+    project.start = date(2025, 1, 6);
+    project.resources = vec![Resource::new("alice").rate(Money::new(Decimal::from(100), "USD"))];
+    project.tasks = vec![
+        Task::new("design").duration(Duration::days(3)),
+        Task::new("spike")
+            .duration(Duration::days(5))
+            .assign("alice")
+            .depends_on("design"),
+        Task::new("build")
+            .duration(Duration::days(2))
+            .depends_on("spike"),
+    ];
+    project
+}
+
+#[test]
+fn inactive_task_does_not_affect_the_schedule() {
+    let mut project = inactive_tasks_project();
+    project.tasks[1] = project.tasks[1].clone().inactive();
+
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+
+    assert!(!schedule.tasks.contains_key("spike"));
+    assert!(!schedule.critical_path.contains(&"spike".to_string()));
+    assert!(schedule.total_cost.is_none());
+    // The task is still in the project for reference
+    assert!(!project.get_task("spike").unwrap().active);
+}
+
+#[test]
+fn successor_ignores_the_dead_dependency() {
+    let mut project = inactive_tasks_project();
+    project.tasks[1] = project.tasks[1].clone().inactive();
+
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+
+    // build no longer waits for spike, so it starts with the project
+    assert_eq!(schedule.tasks["build"].start, date(2025, 1, 6));
+    assert_eq!(schedule.project_end, date(2025, 1, 8));
+}
+
+#[test]
+fn inactive_container_drops_its_children() {
+    let mut project = inactive_tasks_project();
+    project.tasks.push(
+        Task::new("phase2")
+            .child(Task::new("migrate").duration(Duration::days(20)))
+            .inactive(),
+    );
+
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+
+    assert!(!schedule.tasks.contains_key("phase2"));
+    assert!(!schedule.tasks.contains_key("phase2.migrate"));
+    assert_eq!(schedule.project_end, date(2025, 1, 17));
+}
+
+#[test]
+fn dependency_on_inactive_task_is_flagged() {
+    let mut project = inactive_tasks_project();
+    project.tasks[1] = project.tasks[1].clone().inactive();
+
+    let mut emitter = CollectingEmitter::new();
+    analyze_project(&project, None, &AnalysisConfig::new(), &mut emitter);
+
+    let flagged: Vec<_> = emitter
+        .diagnostics
+        .iter()
+        .filter(|d| d.code == DiagnosticCode::W015InactiveDependency)
+        .collect();
+    assert_eq!(flagged.len(), 1);
+    assert_eq!(
+        flagged[0].message,
+        "task 'build' depends on inactive task 'spike'"
+    );
+}
+
+// =============================================================================
+// H005: Task Disconnected from the Project End
+// =============================================================================
+
+/// design -> build -> release is the main network
+fn disconnected_tasks_project(extra: Vec<Task>) -> Project {
+    let mut project = Project::new("Islands");
+    project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+    project.tasks = vec![
+        Task::new("design").duration(Duration::days(3)),
+        Task::new("build")
+            .duration(Duration::days(5))
+            .depends_on("design"),
+        Task::new("release").milestone().depends_on("build"),
+    ];
+    project.tasks.extend(extra);
+    project
+}
+
+fn h005(project: &Project) -> Vec<Diagnostic> {
+    let schedule = CpmSolver::new().schedule(project).unwrap();
+    let mut emitter = CollectingEmitter::new();
+    analyze_project(
+        project,
+        Some(&schedule),
+        &AnalysisConfig::default(),
+        &mut emitter,
+    );
+    emitter
+        .diagnostics
+        .into_iter()
+        .filter(|d| d.code == DiagnosticCode::H005TaskDisconnected)
+        .collect()
+}
+
+#[test]
+fn disconnected_subgraph_is_one_island() {
+    let project = disconnected_tasks_project(vec![
+        Task::new("spike").duration(Duration::days(1)),
+        Task::new("prototype")
+            .duration(Duration::days(2))
+            .depends_on("spike"),
+    ]);
+
+    let hints = h005(&project);
+
+    assert_eq!(hints.len(), 1);
+    assert_eq!(
+        hints[0].message,
+        "tasks 'prototype', 'spike' form an island disconnected from the project end"
+    );
+    assert!(hints[0].notes[0].contains("'release'"));
+}
+
+#[test]
+fn dangling_branch_is_reported() {
+    let project = disconnected_tasks_project(vec![Task::new("docs")
+        .duration(Duration::days(1))
+        .depends_on("design")]);
+
+    let hints = h005(&project);
+
+    assert_eq!(hints.len(), 1);
+    assert_eq!(
+        hints[0].message,
+        "task 'docs' is disconnected from the project end"
+    );
+}
+
+#[test]
+fn container_links_reach_every_leaf() {
+    let mut project = disconnected_tasks_project(vec![Task::new("prep")
+        .child(Task::new("a").duration(Duration::days(1)))
+        .child(Task::new("b").duration(Duration::days(2)))]);
+    project.tasks[0] = Task::new("design")
+        .duration(Duration::days(3))
+        .depends_on("prep");
+
+    assert!(h005(&project).is_empty());
+}
+
+#[test]
+fn connected_network_has_no_hint() {
+    assert!(h005(&disconnected_tasks_project(Vec::new())).is_empty());
+}
@@ -635,3 +635,45 @@ fn test_08b_container_explicit_within_threshold_no_p006() {
         "Should NOT emit P006 when explicit is within 10% of derived"
     );
 }
+
+/// P006 reports both the manual and derived figures (80% vs 30%)
+///
+/// REQUIRES:
+/// - analyze_project uses Task::progress_mismatch
+/// - AnalysisConfig::progress_mismatch_threshold
+#[test]
+fn test_08c_container_progress_mismatch_reports_both_figures() {
+    use utf8proj_core::{CollectingEmitter, DiagnosticCode};
+    use utf8proj_solver::{analyze_project, AnalysisConfig};
+
+    let mut project = Project::new("Container Mismatch Test");
+    project.start = date(2026, 1, 6);
+
+    let mut design = Task::new("design").duration(Duration::days(5));
+    design.complete = Some(60.0);
+    let build = Task::new("build").duration(Duration::days(5));
+
+    let mut container = Task::new("phase").child(design).child(build);
+    container.complete = Some(80.0);
+    project.tasks.push(container);
+
+    let mismatches = |config: &AnalysisConfig| {
+        let mut emitter = CollectingEmitter::new();
+        analyze_project(&project, None, config, &mut emitter);
+        emitter
+            .diagnostics
+            .into_iter()
+            .filter(|d| d.code == DiagnosticCode::P006ContainerProgressMismatch)
+            .collect::<Vec<_>>()
+    };
+
+    let found = mismatches(&AnalysisConfig::default());
+    assert_eq!(found.len(), 1);
+    assert!(found[0].message.contains("80%"), "{}", found[0].message);
+    assert!(found[0].message.contains("30%"), "{}", found[0].message);
+    assert!(found[0].hints.iter().any(|h| h.contains("manual")));
+
+    // A 50-point gap is tolerated with a 50-point threshold
+    let lenient = AnalysisConfig::default().with_progress_mismatch_threshold(50);
+    assert!(mismatches(&lenient).is_empty());
+}
@@ -1,8 +1,16 @@
 //! Integration tests for progress tracking functionality
 
 use chrono::NaiveDate;
-use utf8proj_core::{Duration, Project, Scheduler, Task, TaskStatus};
-use utf8proj_solver::CpmSolver;
+use rust_decimal::Decimal;
+use utf8proj_core::{
+    CollectingEmitter, DiagnosticCode, Duration, Money, ProgressWeighting, Project, Resource,
+    Schedule, Scheduler, Task, TaskStatus,
+};
+use utf8proj_solver::{analyze_project, AnalysisConfig, CpmSolver};
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
 
 /// Test that progress tracking fields are calculated correctly
 #[test]
@@ -296,3 +304,530 @@ fn container_complete_when_all_children_complete() {
     assert_eq!(done.percent_complete, 100);
     assert_eq!(done.status, TaskStatus::Complete);
 }
+
+// =============================================================================
+// P007: Progress Field Conflict
+// =============================================================================
+
+fn p007_messages(task: Task) -> Vec<String> {
+    let mut project = Project::new("Progress");
+    project.start = date(2025, 1, 6);
+    project.tasks = vec![task.duration(Duration::days(5))];
+
+    let mut emitter = CollectingEmitter::new();
+    analyze_project(&project, None, &AnalysisConfig::default(), &mut emitter);
+    emitter
+        .diagnostics
+        .into_iter()
+        .filter(|d| d.code == DiagnosticCode::P007ProgressFieldConflict)
+        .map(|d| d.message)
+        .collect()
+}
+
+#[test]
+fn complete_status_below_100_percent() {
+    let task = Task::new("work")
+        .with_status(TaskStatus::Complete)
+        .complete(50.0);
+
+    assert_eq!(
+        p007_messages(task),
+        vec!["task 'work' has 'status: Complete' conflicting with 'complete: 50%'"]
+    );
+}
+
+#[test]
+fn not_started_with_actual_start() {
+    let task = Task::new("work")
+        .with_status(TaskStatus::NotStarted)
+        .actual_start(date(2025, 1, 6));
+
+    assert_eq!(
+        p007_messages(task),
+        vec!["task 'work' has 'status: Not Started' conflicting with 'actual_start: 2025-01-06'"]
+    );
+}
+
+#[test]
+fn not_started_with_progress() {
+    let task = Task::new("work")
+        .with_status(TaskStatus::NotStarted)
+        .complete(20.0);
+
+    assert_eq!(
+        p007_messages(task),
+        vec!["task 'work' has 'status: Not Started' conflicting with 'complete: 20%'"]
+    );
+}
+
+#[test]
+fn not_started_with_actual_finish() {
+    let task = Task::new("work")
+        .with_status(TaskStatus::NotStarted)
+        .actual_finish(date(2025, 1, 10));
+
+    let messages = p007_messages(task);
+    assert!(messages.contains(
+        &"task 'work' has 'status: Not Started' conflicting with 'actual_finish: 2025-01-10'"
+            .to_string()
+    ));
+}
+
+#[test]
+fn in_progress_at_100_percent() {
+    let task = Task::new("work")
+        .with_status(TaskStatus::InProgress)
+        .complete(100.0);
+
+    assert_eq!(
+        p007_messages(task),
+        vec!["task 'work' has 'status: In Progress' conflicting with 'complete: 100%'"]
+    );
+}
+
+#[test]
+fn in_progress_with_actual_finish() {
+    let task = Task::new("work")
+        .with_status(TaskStatus::InProgress)
+        .complete(100.0)
+        .actual_start(date(2025, 1, 6))
+        .actual_finish(date(2025, 1, 10));
+
+    let messages = p007_messages(task);
+    assert!(messages.contains(
+        &"task 'work' has 'status: In Progress' conflicting with 'actual_finish: 2025-01-10'"
+            .to_string()
+    ));
+}
+
+#[test]
+fn actual_finish_below_100_percent() {
+    let task = Task::new("work")
+        .complete(60.0)
+        .actual_start(date(2025, 1, 6))
+        .actual_finish(date(2025, 1, 10));
+
+    assert_eq!(
+        p007_messages(task),
+        vec!["task 'work' has 'complete: 60%' conflicting with 'actual_finish: 2025-01-10'"]
+    );
+}
+
+#[test]
+fn actual_finish_before_actual_start() {
+    let task = Task::new("work")
+        .complete(100.0)
+        .actual_start(date(2025, 1, 10))
+        .actual_finish(date(2025, 1, 6));
+
+    assert_eq!(
+        p007_messages(task),
+        vec!["task 'work' has 'actual_start: 2025-01-10' conflicting with 'actual_finish: 2025-01-06'"]
+    );
+}
+
+#[test]
+fn consistent_progress_is_quiet() {
+    let done = Task::new("work")
+        .with_status(TaskStatus::Complete)
+        .complete(100.0)
+        .actual_start(date(2025, 1, 6))
+        .actual_finish(date(2025, 1, 10));
+    let started = Task::new("work")
+        .with_status(TaskStatus::InProgress)
+        .complete(40.0)
+        .actual_start(date(2025, 1, 6));
+    let blocked = Task::new("work")
+        .with_status(TaskStatus::Blocked)
+        .complete(40.0);
+
+    assert!(p007_messages(done).is_empty());
+    assert!(p007_messages(started).is_empty());
+    assert!(p007_messages(blocked).is_empty());
+}
+
+// =============================================================================
+// Project progress weighting
+// =============================================================================
+
+/// migrate: 10d by an intern at $100/day, done
+/// audit: 42 hours (six 7-hour days) of effort by three consultants at
+/// $1000/day, not started, so it takes two days
+fn progress_weighting_project(weighting: ProgressWeighting) -> Project {
+    let mut project = Project::new("Weighting");
+    project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+    project.progress_weighting = weighting;
+    project.resources = vec![
+        Resource::new("intern").rate(Money::new(Decimal::from(100), "USD")),
+        Resource::new("consultant").rate(Money::new(Decimal::from(1000), "USD")),
+    ];
+    project.tasks = vec![
+        Task::new("migrate")
+            .duration(Duration::days(10))
+            .assign("intern")
+            .complete(100.0),
+        Task::new("audit")
+            .effort(Duration::hours(42))
+            .assign_with_units("consultant", 3.0),
+    ];
+    project
+}
+
+fn progress(weighting: ProgressWeighting) -> u8 {
+    let project = progress_weighting_project(weighting);
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+    schedule.project_progress
+}
+
+#[test]
+fn duration_weighting_is_the_default() {
+    assert_eq!(ProgressWeighting::default(), ProgressWeighting::ByDuration);
+    // 10 of 12 days are done
+    assert_eq!(progress(ProgressWeighting::ByDuration), 83);
+}
+
+#[test]
+fn effort_weighting_uses_effort() {
+    // 10 of 16 person-days are done (migrate has no effort, so its
+    // duration stands in)
+    assert_eq!(progress(ProgressWeighting::ByEffort), 62);
+}
+
+#[test]
+fn cost_weighting_uses_expected_cost() {
+    // $1,000 of $7,000 is done: audit costs its six person-days of effort
+    assert_eq!(progress(ProgressWeighting::ByCost), 14);
+}
+
+// =============================================================================
+// Scheduling a project as built from its actuals
+// =============================================================================
+
+/// Planned: design Jan 6-8, build Jan 9-10, ship Jan 13-14
+fn as_built_project() -> Project {
+    let mut project = Project::new("As Built");
+    project.start = date(2025, 1, 6);
+    project.tasks = vec![
+        Task::new("design").duration(Duration::days(3)),
+        Task::new("build")
+            .duration(Duration::days(2))
+            .depends_on("design"),
+        Task::new("ship")
+            .duration(Duration::days(2))
+            .depends_on("build"),
+    ];
+    project
+}
+
+/// design slipped a day, build started before design finished, ship
+/// took three days
+fn actualized() -> Project {
+    let mut project = as_built_project();
+    let actuals = [
+        ("design", date(2025, 1, 6), date(2025, 1, 9)),
+        ("build", date(2025, 1, 8), date(2025, 1, 13)),
+        ("ship", date(2025, 1, 14), date(2025, 1, 16)),
+    ];
+    for (id, start, finish) in actuals {
+        let task = project.get_task_mut(id).unwrap();
+        task.complete = Some(100.0);
+        task.actual_start = Some(start);
+        task.actual_finish = Some(finish);
+    }
+    project
+}
+
+#[test]
+fn fully_actualized_project_sits_at_actual_dates() {
+    let schedule = CpmSolver::new().schedule_as_built(&actualized()).unwrap();
+
+    for (id, start, finish) in [
+        ("design", date(2025, 1, 6), date(2025, 1, 9)),
+        ("build", date(2025, 1, 8), date(2025, 1, 13)),
+        ("ship", date(2025, 1, 14), date(2025, 1, 16)),
+    ] {
+        let task = &schedule.tasks[id];
+        assert_eq!(task.start, start, "{} start", id);
+        assert_eq!(task.finish, finish, "{} finish", id);
+    }
+    assert_eq!(schedule.project_end, date(2025, 1, 16));
+}
+
+#[test]
+fn variance_compares_as_built_with_the_plan() {
+    let schedule = CpmSolver::new().schedule_as_built(&actualized()).unwrap();
+
+    let design = &schedule.tasks["design"];
+    assert_eq!(design.baseline_finish, date(2025, 1, 8));
+    assert_eq!(design.finish_variance_days, 1);
+
+    let ship = &schedule.tasks["ship"];
+    assert_eq!(ship.baseline_start, date(2025, 1, 13));
+    assert_eq!(ship.start_variance_days, 1);
+    assert_eq!(ship.finish_variance_days, 2);
+}
+
+#[test]
+fn started_task_runs_its_planned_duration() {
+    let mut project = actualized();
+    let ship = project.get_task_mut("ship").unwrap();
+    ship.complete = None;
+    ship.actual_finish = None;
+
+    let schedule = CpmSolver::new().schedule_as_built(&project).unwrap();
+
+    let ship = &schedule.tasks["ship"];
+    assert_eq!(ship.start, date(2025, 1, 14));
+    assert_eq!(ship.finish, date(2025, 1, 15));
+}
+
+#[test]
+fn tasks_without_actuals_follow_the_plan() {
+    let mut project = actualized();
+    let ship = project.get_task_mut("ship").unwrap();
+    ship.complete = None;
+    ship.actual_start = None;
+    ship.actual_finish = None;
+
+    let schedule = CpmSolver::new().schedule_as_built(&project).unwrap();
+
+    // Chained from build's actual finish on Mon Jan 13
+    let ship = &schedule.tasks["ship"];
+    assert_eq!(ship.start, date(2025, 1, 14));
+    assert_eq!(ship.finish, date(2025, 1, 15));
+}
+
+// =============================================================================
+// Replanning remaining work from a status date
+// =============================================================================
+
+/// Planned: design Jan 6-10, build Jan 13-24, test Jan 27-29, docs Jan 6-7
+fn replan_project() -> Project {
+    let mut project = Project::new("Replan");
+    project.start = date(2025, 1, 6);
+    project.tasks = vec![
+        Task::new("design")
+            .duration(Duration::days(5))
+            .complete(100.0)
+            .actual_start(date(2025, 1, 6))
+            .actual_finish(date(2025, 1, 10)),
+        Task::new("build")
+            .duration(Duration::days(10))
+            .depends_on("design")
+            .complete(20.0)
+            .actual_start(date(2025, 1, 13)),
+        Task::new("test")
+            .duration(Duration::days(3))
+            .depends_on("build"),
+        Task::new("docs").duration(Duration::days(2)),
+    ];
+    project
+}
+
+#[test]
+fn replan_keeps_completed_work_at_actual_dates() {
+    let schedule = CpmSolver::new()
+        .replan_from(&replan_project(), date(2025, 1, 27))
+        .unwrap();
+
+    let design = &schedule.tasks["design"];
+    assert_eq!(design.start, date(2025, 1, 6));
+    assert_eq!(design.finish, date(2025, 1, 10));
+    assert_eq!(design.finish_variance_days, 0);
+}
+
+#[test]
+fn replan_extends_in_progress_work_past_the_plan() {
+    let schedule = CpmSolver::new()
+        .replan_from(&replan_project(), date(2025, 1, 27))
+        .unwrap();
+
+    // 8 days remain from Jan 27, so build finishes Feb 5 instead of Jan 24
+    let build = &schedule.tasks["build"];
+    assert_eq!(build.forecast_start, date(2025, 1, 13));
+    assert_eq!(build.forecast_finish, date(2025, 2, 5));
+    assert_eq!(build.baseline_finish, date(2025, 1, 24));
+    assert_eq!(build.finish_variance_days, 12);
+
+    // The successor chains from the replanned finish
+    assert_eq!(schedule.tasks["test"].forecast_start, date(2025, 2, 6));
+    assert_eq!(schedule.project_forecast_finish, date(2025, 2, 10));
+}
+
+#[test]
+fn replan_moves_not_started_work_to_as_of() {
+    let project = replan_project();
+    let as_of = date(2025, 1, 27);
+
+    // A plain status-date run leaves unstarted docs in the past
+    let tracked = CpmSolver::with_status_date(as_of)
+        .schedule(&project)
+        .unwrap();
+    assert_eq!(tracked.tasks["docs"].forecast_start, date(2025, 1, 6));
+
+    let replanned = CpmSolver::new().replan_from(&project, as_of).unwrap();
+    let docs = &replanned.tasks["docs"];
+    assert_eq!(docs.forecast_start, as_of);
+    assert_eq!(docs.forecast_finish, date(2025, 1, 28));
+    assert_eq!(docs.baseline_start, date(2025, 1, 6));
+    assert_eq!(docs.start_variance_days, 21);
+}
+
+// =============================================================================
+// Rescheduling with frozen tasks
+// =============================================================================
+
+/// design (3d) -> build (5d, frozen) -> test (2d), from Mon Jan 6
+fn frozen_tasks_project() -> Project {
+    let mut project = Project::new("Frozen");
+    project.start = date(2025, 1, 6);
+    project.tasks = vec![
+        Task::new("design").duration(Duration::days(3)),
+        Task::new("build")
+            .duration(Duration::days(5))
+            .depends_on("design")
+            .frozen(),
+        Task::new("test")
+            .duration(Duration::days(2))
+            .depends_on("build"),
+    ];
+    project
+}
+
+#[test]
+fn frozen_task_stays_while_successors_adjust() {
+    let solver = CpmSolver::new();
+    let mut project = frozen_tasks_project();
+    let prior = solver.schedule(&project).unwrap();
+    assert_eq!(prior.tasks["build"].start, date(2025, 1, 9));
+
+    // design now takes a day less and test a day more
+    project.tasks[0].duration = Some(Duration::days(2));
+    project.tasks[2].duration = Some(Duration::days(3));
+    let mut emitter = CollectingEmitter::new();
+    let schedule = solver
+        .reschedule_frozen(&project, &prior, &AnalysisConfig::default(), &mut emitter)
+        .unwrap();
+
+    assert_eq!(schedule.tasks["build"].start, date(2025, 1, 9));
+    assert_eq!(schedule.tasks["build"].finish, date(2025, 1, 15));
+    assert_eq!(schedule.tasks["test"].finish, date(2025, 1, 20));
+    assert!(emitter.diagnostics.is_empty());
+
+    // Without freezing, build would move up a day
+    let unfrozen = solver.schedule(&project).unwrap();
+    assert_eq!(unfrozen.tasks["build"].start, date(2025, 1, 8));
+}
+
+#[test]
+fn pushed_frozen_task_is_released_with_warning() {
+    let solver = CpmSolver::new();
+    let mut project = frozen_tasks_project();
+    let prior = solver.schedule(&project).unwrap();
+
+    // design now runs a week, past build's frozen start
+    project.tasks[0].duration = Some(Duration::days(5));
+    let mut emitter = CollectingEmitter::new();
+    let config = AnalysisConfig::default().with_file("plan.proj");
+    let schedule = solver
+        .reschedule_frozen(&project, &prior, &config, &mut emitter)
+        .unwrap();
+
+    assert_eq!(schedule.tasks["build"].start, date(2025, 1, 13));
+    assert_eq!(emitter.diagnostics.len(), 1);
+    let warning = &emitter.diagnostics[0];
+    assert_eq!(warning.code, DiagnosticCode::W016FrozenTaskMoved);
+    assert_eq!(
+        warning.message,
+        "frozen task 'build' cannot keep its start on 2025-01-09"
+    );
+    assert_eq!(warning.file, Some("plan.proj".into()));
+}
+
+#[test]
+fn frozen_container_holds_its_children() {
+    let solver = CpmSolver::new();
+    let mut project = Project::new("Frozen Phase");
+    project.start = date(2025, 1, 6);
+    project.tasks = vec![
+        Task::new("design").duration(Duration::days(3)),
+        Task::new("phase")
+            .depends_on("design")
+            .child(Task::new("build").duration(Duration::days(5)))
+            .frozen(),
+    ];
+    let prior = solver.schedule(&project).unwrap();
+
+    project.tasks[0].duration = Some(Duration::days(1));
+    let schedule = solver
+        .reschedule_frozen(
+            &project,
+            &prior,
+            &AnalysisConfig::default(),
+            &mut CollectingEmitter::new(),
+        )
+        .unwrap();
+
+    assert_eq!(
+        schedule.tasks["phase.build"].start,
+        prior.tasks["phase.build"].start
+    );
+}
+
+// =============================================================================
+// Remaining critical path from a status date
+// =============================================================================
+
+/// design (done Jan 6-8) -> build -> test, with docs hanging off design
+fn replanned(status_date: NaiveDate, docs_days: i64) -> Schedule {
+    let mut project = Project::new("Remaining");
+    project.start = date(2025, 1, 6);
+    project.status_date = Some(status_date);
+    project.tasks = vec![
+        Task::new("design")
+            .duration(Duration::days(3))
+            .complete(100.0)
+            .actual_start(date(2025, 1, 6))
+            .actual_finish(date(2025, 1, 8)),
+        Task::new("build")
+            .duration(Duration::days(2))
+            .depends_on("design"),
+        Task::new("test")
+            .duration(Duration::days(2))
+            .depends_on("build"),
+        Task::new("docs")
+            .duration(Duration::days(docs_days))
+            .depends_on("design"),
+    ];
+    CpmSolver::new().schedule(&project).unwrap()
+}
+
+#[test]
+fn completed_critical_task_drops_off() {
+    let schedule = replanned(date(2025, 1, 9), 1);
+
+    assert!(schedule.critical_path.contains(&"design".to_string()));
+    assert_eq!(
+        schedule.remaining_critical_path(date(2025, 1, 9)),
+        vec!["build", "test"]
+    );
+}
+
+#[test]
+fn criticality_follows_remaining_work() {
+    let schedule = replanned(date(2025, 1, 9), 6);
+
+    assert_eq!(
+        schedule.remaining_critical_path(date(2025, 1, 9)),
+        vec!["docs"]
+    );
+}
+
+#[test]
+fn finished_work_has_no_remaining_path() {
+    let schedule = replanned(date(2025, 1, 9), 1);
+
+    assert!(schedule
+        .remaining_critical_path(date(2025, 2, 3))
+        .is_empty());
+}
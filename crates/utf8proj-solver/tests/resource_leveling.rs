@@ -2,9 +2,17 @@
 //!
 //! Tests the CpmSolver with resource leveling enabled.
 
-use chrono::NaiveDate;
-use utf8proj_core::{Duration, Project, Resource, Scheduler, Task};
-use utf8proj_solver::{detect_overallocations, level_resources, CpmSolver};
+use chrono::{NaiveDate, NaiveTime};
+use rust_decimal::Decimal;
+use utf8proj_core::{
+    Calendar, CollectingEmitter, Diagnostic, DiagnosticCode, Duration, Project, RateRange,
+    Resource, ResourceProfile, Schedule, Scheduler, Task,
+};
+use utf8proj_solver::{
+    analyze_project, calculate_utilization, detect_overallocations, level_resources,
+    lint_structure, minimum_staffing, preview_leveling, resource_conflicts, AnalysisConfig,
+    CpmSolver,
+};
 
 fn date(year: i32, month: u32, day: u32) -> NaiveDate {
     NaiveDate::from_ymd_opt(year, month, day).unwrap()
@@ -244,6 +252,25 @@ fn leveling_extends_project_duration() {
     );
 }
 
+#[test]
+fn leveled_schedule_reports_unconstrained_finish() {
+    let mut project = Project::new("Unconstrained Finish Test");
+    project.start = date(2025, 1, 6);
+    project.resources = vec![Resource::new("dev").capacity(1.0)];
+    project.tasks = vec![
+        Task::new("task1").effort(Duration::hours(35)).assign("dev"),
+        Task::new("task2").effort(Duration::hours(35)).assign("dev"),
+    ];
+
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+    let leveled = CpmSolver::with_leveling().schedule(&project).unwrap();
+
+    // Unleveled: both run Jan 6-10; leveled: one waits until Jan 17
+    assert_eq!(schedule.unconstrained_finish, None);
+    assert_eq!(leveled.unconstrained_finish, Some(date(2025, 1, 10)));
+    assert_eq!(leveled.project_end, date(2025, 1, 17));
+}
+
 #[test]
 fn leveling_respects_dependencies() {
     let mut project = Project::new("Dependencies Test");
@@ -793,6 +820,33 @@ fn leveling_result_schedule_alias() {
     );
 }
 
+/// Preview shifts match what scheduling with leveling applies
+#[test]
+fn preview_leveling_matches_with_leveling() {
+    let mut project = Project::new("Preview Test");
+    project.start = date(2025, 1, 6);
+    project.resources = vec![Resource::new("dev").capacity(1.0)];
+    project.tasks = vec![
+        Task::new("task1").effort(Duration::days(3)).assign("dev"),
+        Task::new("task2").effort(Duration::days(3)).assign("dev"),
+        Task::new("task3").effort(Duration::days(2)).assign("dev"),
+    ];
+
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+    let calendar = utf8proj_core::Calendar::default();
+    let preview = preview_leveling(&project, &schedule, &calendar);
+    let leveled = CpmSolver::with_leveling().schedule(&project).unwrap();
+
+    // The input schedule is untouched
+    assert_eq!(schedule.tasks["task2"].start, date(2025, 1, 6));
+    assert_eq!(preview.shifted_tasks.len(), 2);
+    for shift in &preview.shifted_tasks {
+        assert_eq!(shift.original_start, schedule.tasks[&shift.task_id].start);
+        assert_eq!(shift.new_start, leveled.tasks[&shift.task_id].start);
+    }
+    assert_eq!(preview.leveled_schedule.project_end, leveled.project_end);
+}
+
 /// Test L004 diagnostic - milestone delay detection
 /// NOTE: L004 checks if milestone.start > original_date after leveling.
 /// Currently, milestones are not re-scheduled after predecessor shifts,
@@ -1217,3 +1271,501 @@ fn optimal_leveling_simple_conflict() {
         "Tasks should not overlap after optimal leveling"
     );
 }
+
+// =============================================================================
+// Resource conflict overlay
+// =============================================================================
+
+/// alice works api (Jan 6-8) and ui (Jan 6-7) at once; bob works docs alone
+fn resource_conflicts_project() -> Project {
+    let mut project = Project::new("Conflicts");
+    project.start = date(2025, 1, 6);
+    project.resources = vec![Resource::new("alice"), Resource::new("bob")];
+    project.tasks = vec![
+        Task::new("api").duration(Duration::days(3)).assign("alice"),
+        Task::new("ui")
+            .duration(Duration::days(2))
+            .assign_with_units("alice", 0.5),
+        Task::new("docs")
+            .duration(Duration::days(1))
+            .assign("bob")
+            .depends_on("ui"),
+    ];
+    project
+}
+
+#[test]
+fn overlapping_assignments_form_a_conflict_window() {
+    let project = resource_conflicts_project();
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+
+    let conflicts = resource_conflicts(&project, &schedule);
+
+    assert_eq!(conflicts.len(), 1);
+    let conflict = &conflicts[0];
+    assert_eq!(conflict.resource_id, "alice");
+    assert_eq!(conflict.start, date(2025, 1, 6));
+    assert_eq!(conflict.finish, date(2025, 1, 7));
+    assert_eq!(conflict.peak_units, 1.5);
+    assert_eq!(conflict.excess_units, 0.5);
+    assert_eq!(conflict.task_ids, vec!["api", "ui"]);
+}
+
+#[test]
+fn conflict_rows_match_gantt_bars() {
+    let project = resource_conflicts_project();
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+    let bars = schedule.bars();
+
+    let conflict = &resource_conflicts(&project, &schedule)[0];
+
+    for (task_id, row) in conflict.task_ids.iter().zip(&conflict.task_rows) {
+        assert_eq!(&bars[*row].task_id, task_id);
+    }
+}
+
+#[test]
+fn no_conflicts_within_capacity() {
+    let mut project = resource_conflicts_project();
+    project.tasks[1] = Task::new("ui").duration(Duration::days(2)).assign("bob");
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+
+    assert!(resource_conflicts(&project, &schedule).is_empty());
+}
+
+// =============================================================================
+// Per-resource timelines
+// =============================================================================
+
+/// alice: design (Jan 6-8) -> build (Jan 9-13), plus review half-time in
+/// parallel with design
+fn resource_timeline_schedule() -> Schedule {
+    let mut project = Project::new("Alice's Week");
+    project.start = date(2025, 1, 6);
+    project.resources = vec![Resource::new("alice"), Resource::new("bob")];
+    project.tasks = vec![
+        Task::new("build")
+            .duration(Duration::days(3))
+            .assign("alice")
+            .depends_on("design"),
+        Task::new("design")
+            .duration(Duration::days(3))
+            .assign("alice"),
+        Task::new("review")
+            .duration(Duration::days(2))
+            .assign_with_units("alice", 0.5),
+    ];
+    CpmSolver::new().schedule(&project).unwrap()
+}
+
+#[test]
+fn timeline_lists_assignments_in_start_order() {
+    let timeline = resource_timeline_schedule().resource_timeline("alice");
+
+    assert_eq!(
+        timeline,
+        vec![
+            (
+                "design".to_string(),
+                date(2025, 1, 6),
+                date(2025, 1, 8),
+                1.0
+            ),
+            (
+                "review".to_string(),
+                date(2025, 1, 6),
+                date(2025, 1, 7),
+                0.5
+            ),
+            (
+                "build".to_string(),
+                date(2025, 1, 9),
+                date(2025, 1, 13),
+                1.0
+            ),
+        ]
+    );
+}
+
+#[test]
+fn overlapping_bookings_are_kept() {
+    let timeline = resource_timeline_schedule().resource_timeline("alice");
+
+    // design and review both run on Jan 6-7
+    assert!(timeline[0].1 <= timeline[1].2 && timeline[1].1 <= timeline[0].2);
+}
+
+#[test]
+fn unassigned_resource_has_empty_timeline() {
+    assert!(resource_timeline_schedule()
+        .resource_timeline("bob")
+        .is_empty());
+}
+
+// =============================================================================
+// Sub-day assignment windows
+// =============================================================================
+
+fn time(hour: u32) -> NaiveTime {
+    NaiveTime::from_hms_opt(hour, 0, 0).unwrap()
+}
+
+/// call and review, one day each, both by alice on Mon Jan 6
+fn sub_day_assignments_project() -> Project {
+    let mut project = Project::new("Sub-day");
+    project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+    project.resources = vec![Resource::new("alice")];
+    project.tasks = vec![
+        Task::new("call")
+            .duration(Duration::days(1))
+            .assign("alice"),
+        Task::new("review")
+            .duration(Duration::days(1))
+            .assign("alice"),
+    ];
+    project
+}
+
+/// Schedule the project, then narrow each assignment to its window
+fn schedule_with_windows(project: &Project, windows: [(u32, u32); 2]) -> Schedule {
+    let mut schedule = CpmSolver::new().schedule(project).unwrap();
+    for (id, (start, finish)) in ["call", "review"].into_iter().zip(windows) {
+        let assignment = &mut schedule.tasks.get_mut(id).unwrap().assignments[0];
+        assignment.start_time = Some(time(start));
+        assignment.finish_time = Some(time(finish));
+    }
+    schedule
+}
+
+#[test]
+fn two_short_windows_share_a_day() {
+    let project = sub_day_assignments_project();
+    let whole_day = CpmSolver::new().schedule(&project).unwrap();
+    assert_eq!(detect_overallocations(&project, &whole_day).len(), 1);
+
+    let schedule = schedule_with_windows(&project, [(9, 11), (13, 15)]);
+
+    assert!(detect_overallocations(&project, &schedule).is_empty());
+    // Four of seven working hours are booked
+    let utilization = calculate_utilization(&project, &schedule, &Calendar::default());
+    assert!((utilization.resources[0].peak_usage - 4.0 / 7.0).abs() < 1e-6);
+}
+
+#[test]
+fn two_long_windows_overflow_the_day() {
+    let project = sub_day_assignments_project();
+    let schedule = schedule_with_windows(&project, [(8, 13), (13, 18)]);
+
+    let conflicts = detect_overallocations(&project, &schedule);
+    assert_eq!(conflicts.len(), 1);
+    let (resource, period) = &conflicts[0];
+    assert_eq!(resource, "alice");
+    // Ten hours of work in a seven-hour day
+    assert!((period.peak_usage - 10.0 / 7.0).abs() < 1e-6);
+    assert_eq!(period.involved_tasks.len(), 2);
+}
+
+#[test]
+fn overlapping_windows_conflict() {
+    let project = sub_day_assignments_project();
+    let schedule = schedule_with_windows(&project, [(9, 11), (10, 12)]);
+
+    let conflicts = detect_overallocations(&project, &schedule);
+    assert_eq!(conflicts.len(), 1);
+    assert_eq!(conflicts[0].1.peak_usage, 2.0);
+}
+
+// =============================================================================
+// Minimum staffing
+// =============================================================================
+
+/// api and ui (5d each) run in parallel, then review (2d); all by developers
+fn minimum_staffing_project() -> Project {
+    let mut project = Project::new("Staffing");
+    project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+    project.profiles = vec![ResourceProfile::new("developer")];
+    project.tasks = vec![
+        Task::new("api")
+            .duration(Duration::days(5))
+            .assign("developer"),
+        Task::new("ui")
+            .duration(Duration::days(5))
+            .assign("developer"),
+        Task::new("review")
+            .duration(Duration::days(2))
+            .assign("developer")
+            .depends_on("api")
+            .depends_on("ui"),
+    ];
+    project
+}
+
+#[test]
+fn parallel_work_needs_two() {
+    let staffing = minimum_staffing(&minimum_staffing_project(), &CpmSolver::new());
+
+    // One developer would have to do api and ui back to back
+    assert_eq!(staffing.len(), 1);
+    assert_eq!(staffing["developer"], 2);
+}
+
+#[test]
+fn sequential_work_needs_one() {
+    let mut project = minimum_staffing_project();
+    project.tasks[1] = project.tasks[1].clone().depends_on("api");
+
+    let staffing = minimum_staffing(&project, &CpmSolver::new());
+
+    assert_eq!(staffing["developer"], 1);
+}
+
+#[test]
+fn concrete_resources_are_not_sized() {
+    let mut project = minimum_staffing_project();
+    project.resources = vec![Resource::new("alice")];
+    for task in &mut project.tasks {
+        task.assigned[0].resource_id = "alice".into();
+    }
+
+    assert!(minimum_staffing(&project, &CpmSolver::new()).is_empty());
+}
+
+// =============================================================================
+// Abstract profile staffing report
+// =============================================================================
+
+/// api (Jan 6-10) and ui (Jan 8-14) both need a backend dev; docs is
+/// written by a named person
+fn profile_demand_project() -> Project {
+    let mut project = Project::new("Staffing");
+    project.start = date(2025, 1, 6);
+    project.profiles = vec![ResourceProfile::new("backend")
+        .rate_range(RateRange::new(Decimal::from(400), Decimal::from(600)))];
+    project.resources = vec![Resource::new("alice")];
+    project.tasks = vec![
+        Task::new("api")
+            .duration(Duration::days(5))
+            .assign("backend"),
+        Task::new("spec").duration(Duration::days(2)),
+        Task::new("ui")
+            .duration(Duration::days(5))
+            .assign("backend")
+            .depends_on("spec"),
+        Task::new("docs")
+            .duration(Duration::days(3))
+            .assign("alice"),
+    ];
+    project
+}
+
+#[test]
+fn overlapping_assignments_peak_at_two() {
+    let schedule = CpmSolver::new()
+        .schedule(&profile_demand_project())
+        .unwrap();
+
+    let demand = schedule.profile_demand();
+    let backend = &demand["backend"];
+
+    assert_eq!(backend.peak_units, 2.0);
+    // ui joins api on Wed Jan 8
+    assert_eq!(backend.peak_date, date(2025, 1, 8));
+}
+
+#[test]
+fn first_need_and_total_days() {
+    let schedule = CpmSolver::new()
+        .schedule(&profile_demand_project())
+        .unwrap();
+
+    let backend = &schedule.profile_demand()["backend"];
+
+    assert_eq!(backend.first_needed, date(2025, 1, 6));
+    assert!((backend.total_days - 10.0).abs() < 1e-9);
+}
+
+#[test]
+fn concrete_resources_are_left_out() {
+    let schedule = CpmSolver::new()
+        .schedule(&profile_demand_project())
+        .unwrap();
+
+    let demand = schedule.profile_demand();
+
+    assert_eq!(demand.len(), 1);
+    assert!(!demand.contains_key("alice"));
+}
+
+// =============================================================================
+// Assignments exceeding a resource's capacity
+// =============================================================================
+
+fn flagged(project: &Project) -> Vec<Diagnostic> {
+    let mut emitter = CollectingEmitter::new();
+    lint_structure(project, &AnalysisConfig::new(), &mut emitter);
+    emitter
+        .diagnostics
+        .into_iter()
+        .filter(|d| d.code == DiagnosticCode::W018AssignmentExceedsCapacity)
+        .collect()
+}
+
+/// bob works half-time; concrete is a pool of 10 units
+fn assignment_capacity_project(tasks: Vec<Task>) -> Project {
+    let mut project = Project::new("Capacity");
+    project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+    project.resources = vec![
+        Resource::new("bob").capacity(0.5),
+        Resource::new("concrete").consumable(10.0),
+    ];
+    project.tasks = tasks;
+    project
+}
+
+#[test]
+fn full_time_assignment_of_half_time_resource_is_flagged() {
+    let project = assignment_capacity_project(vec![Task::new("build")
+        .duration(Duration::days(5))
+        .assign_with_units("bob", 1.0)]);
+
+    let warnings = flagged(&project);
+
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(
+        warnings[0].message,
+        "task 'build' assigns 'bob' at 1 units, above its capacity of 0.5"
+    );
+}
+
+#[test]
+fn assignment_within_capacity_is_not_flagged() {
+    let project = assignment_capacity_project(vec![Task::new("build")
+        .duration(Duration::days(5))
+        .assign_with_units("bob", 0.5)]);
+
+    assert!(flagged(&project).is_empty());
+}
+
+#[test]
+fn assignment_is_checked_against_availability() {
+    let mut project = assignment_capacity_project(vec![Task::new("build")
+        .duration(Duration::days(5))
+        .assign_with_units("carol", 1.0)]);
+    project
+        .resources
+        .push(Resource::new("carol").availability(0.5));
+
+    let warnings = flagged(&project);
+
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(
+        warnings[0].message,
+        "task 'build' assigns 'carol' at 1 units, above its effective capacity of 0.5"
+    );
+}
+
+#[test]
+fn consumable_is_checked_against_its_pool() {
+    let project = assignment_capacity_project(vec![Task::new("pour")
+        .duration(Duration::days(1))
+        .assign_with_units("concrete", 12.0)]);
+
+    let warnings = flagged(&project);
+
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].message.ends_with("above its pool of 10"));
+}
+
+// =============================================================================
+// W008: Capacity Shortfall
+// =============================================================================
+
+/// A 3-person team with a fixed February window (20 working days each)
+fn team_project(effort_days: i64) -> Project {
+    let mut project = Project::new("Capacity");
+    project.start = date(2025, 2, 3);
+    project.end = Some(date(2025, 2, 28));
+    for id in ["alice", "bob", "carol"] {
+        project.resources.push(Resource::new(id));
+    }
+    project.tasks.push(
+        Task::new("work")
+            .effort(Duration::days(effort_days))
+            .assign("alice"),
+    );
+    project
+}
+
+fn analyze(project: &Project, config: &AnalysisConfig) -> CollectingEmitter {
+    let mut emitter = CollectingEmitter::new();
+    analyze_project(project, None, config, &mut emitter);
+    emitter
+}
+
+fn w008_count(emitter: &CollectingEmitter) -> usize {
+    emitter
+        .diagnostics
+        .iter()
+        .filter(|d| d.code == DiagnosticCode::W008CapacityShortfall)
+        .count()
+}
+
+#[test]
+fn w008_fires_when_effort_far_exceeds_capacity() {
+    let emitter = analyze(&team_project(400), &AnalysisConfig::default());
+
+    let diag = emitter
+        .diagnostics
+        .iter()
+        .find(|d| d.code == DiagnosticCode::W008CapacityShortfall)
+        .expect("W008 should fire");
+    assert!(diag.message.contains("400.0 person-days"));
+    assert!(diag.message.contains("60.0 person-days"));
+    assert!(diag.notes[0].contains("2025-02-03 to 2025-02-28"));
+}
+
+#[test]
+fn w008_silent_within_threshold() {
+    // 80 days of demand vs 60 of capacity is below the default 1.5x ratio
+    let emitter = analyze(&team_project(80), &AnalysisConfig::default());
+    assert_eq!(w008_count(&emitter), 0);
+}
+
+#[test]
+fn w008_threshold_is_configurable() {
+    let config = AnalysisConfig::default().with_capacity_shortfall_ratio(1.2);
+    let emitter = analyze(&team_project(80), &config);
+    assert_eq!(w008_count(&emitter), 1);
+}
+
+#[test]
+fn w008_ignores_completed_work_and_containers() {
+    let mut project = team_project(0);
+    project.tasks = vec![Task::new("phase")
+        .effort(Duration::days(500))
+        .child(
+            Task::new("done")
+                .effort(Duration::days(300))
+                .complete(100.0),
+        )
+        .child(Task::new("todo").effort(Duration::days(50)))];
+
+    assert_eq!(project.total_effort(), Duration::days(350));
+    assert_eq!(project.remaining_effort(), Duration::days(50));
+    assert_eq!(
+        w008_count(&analyze(&project, &AnalysisConfig::default())),
+        0
+    );
+}
+
+#[test]
+fn w008_skipped_without_planning_window() {
+    let mut project = team_project(400);
+    project.end = None;
+
+    // No end date and no schedule: nothing to compare against
+    assert_eq!(
+        w008_count(&analyze(&project, &AnalysisConfig::default())),
+        0
+    );
+}
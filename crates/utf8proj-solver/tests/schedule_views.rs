@@ -0,0 +1,448 @@
+//! Integration tests for schedule views
+//!
+//! Covers the Gantt bar and dependency line models, schedule queries,
+//! schedule reports and risk scores.
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use utf8proj_core::{
+    gantt::{BarEnd, GanttBarKind},
+    Dependency, DependencyType, DiagnosticCode, Duration, Money, Project, RateRange, Resource,
+    ResourceProfile, RiskWeights, Schedule, ScheduleReport, Scheduler, Severity, Task, TaskStatus,
+};
+use utf8proj_solver::{schedule_with_diagnostics, AnalysisConfig, CpmSolver};
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+// =============================================================================
+// Renderer-independent Gantt bar model
+// =============================================================================
+
+/// phase { design (Jan 6-8) -> build (Jan 9-14) } -> launch; notes has slack
+fn gantt_bars_project() -> Project {
+    let mut project = Project::new("Bars");
+    project.start = date(2025, 1, 6);
+    project.tasks = vec![
+        Task::new("phase")
+            .child(
+                Task::new("design")
+                    .duration(Duration::days(3))
+                    .complete(100.0),
+            )
+            .child(
+                Task::new("build")
+                    .duration(Duration::days(4))
+                    .depends_on("design")
+                    .complete(25.0),
+            ),
+        Task::new("launch").milestone().depends_on("phase"),
+        Task::new("notes").duration(Duration::days(1)),
+    ];
+    project
+}
+
+#[test]
+fn bars_are_in_start_order_with_containers_first() {
+    let schedule = CpmSolver::new().schedule(&gantt_bars_project()).unwrap();
+    let bars = schedule.bars();
+
+    let rows: Vec<(&str, usize)> = bars.iter().map(|b| (b.task_id.as_str(), b.row)).collect();
+    assert_eq!(
+        rows,
+        vec![
+            ("notes", 0),
+            ("phase", 1),
+            ("phase.design", 2),
+            ("phase.build", 3),
+            ("launch", 4),
+        ]
+    );
+}
+
+#[test]
+fn bars_carry_depth_kind_and_progress() {
+    let schedule = CpmSolver::new().schedule(&gantt_bars_project()).unwrap();
+    let bars = schedule.bars();
+    let bar = |id: &str| bars.iter().find(|b| b.task_id == id).unwrap();
+
+    assert_eq!(bar("phase").kind, GanttBarKind::Summary);
+    assert_eq!(bar("phase").depth, 0);
+
+    let build = bar("phase.build");
+    assert_eq!(build.kind, GanttBarKind::Task);
+    assert_eq!(build.depth, 1);
+    assert_eq!(build.progress, 25);
+    assert_eq!(build.start, date(2025, 1, 9));
+    assert_eq!(build.finish, date(2025, 1, 14));
+    assert!(build.is_critical);
+
+    let launch = bar("launch");
+    assert_eq!(launch.kind, GanttBarKind::Milestone);
+    assert!(launch.is_milestone);
+
+    assert!(!bar("notes").is_critical);
+}
+
+// =============================================================================
+// Gantt dependency lines
+// =============================================================================
+
+fn dep(predecessor: &str, dep_type: DependencyType) -> Dependency {
+    Dependency {
+        predecessor: predecessor.into(),
+        dep_type,
+        lag: None,
+        lag_calendar: false,
+    }
+}
+
+/// design -> code (SS) -> test (FS); docs finishes with code (FF)
+fn dependency_lines_project() -> Project {
+    let mut project = Project::new("Lines");
+    project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+    project.tasks = vec![
+        Task::new("design").duration(Duration::days(3)),
+        Task::new("code")
+            .duration(Duration::days(5))
+            .with_dependency(dep("design", DependencyType::StartToStart)),
+        Task::new("test")
+            .duration(Duration::days(2))
+            .depends_on("code"),
+        Task::new("docs")
+            .duration(Duration::days(1))
+            .with_dependency(dep("code", DependencyType::FinishToFinish))
+            .depends_on("missing"),
+    ];
+    project
+}
+
+#[test]
+fn start_to_start_joins_the_starts() {
+    let project = dependency_lines_project();
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+
+    let lines = schedule.dependency_lines(&project);
+    let ss = lines
+        .iter()
+        .find(|l| l.to_task == "code")
+        .expect("code has a line from design");
+
+    assert_eq!(ss.from_task, "design");
+    assert_eq!(ss.dep_type, DependencyType::StartToStart);
+    assert_eq!(ss.from_end, BarEnd::Start);
+    assert_eq!(ss.to_end, BarEnd::Start);
+}
+
+#[test]
+fn endpoints_follow_the_dependency_type() {
+    assert_eq!(
+        BarEnd::for_dependency(DependencyType::FinishToStart),
+        (BarEnd::Finish, BarEnd::Start)
+    );
+    assert_eq!(
+        BarEnd::for_dependency(DependencyType::FinishToFinish),
+        (BarEnd::Finish, BarEnd::Finish)
+    );
+    assert_eq!(
+        BarEnd::for_dependency(DependencyType::StartToFinish),
+        (BarEnd::Start, BarEnd::Finish)
+    );
+}
+
+#[test]
+fn lines_carry_rows_and_criticality() {
+    let project = dependency_lines_project();
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+    let bars = schedule.bars();
+    let row = |id: &str| bars.iter().find(|b| b.task_id == id).unwrap().row;
+
+    let lines = schedule.dependency_lines(&project);
+    // "missing" does not resolve, so three dependencies draw three lines
+    assert_eq!(lines.len(), 3);
+    assert!(lines.windows(2).all(|w| w[0].to_row <= w[1].to_row));
+
+    let fs = lines.iter().find(|l| l.to_task == "test").unwrap();
+    assert_eq!((fs.from_row, fs.to_row), (row("code"), row("test")));
+    assert_eq!((fs.from_end, fs.to_end), (BarEnd::Finish, BarEnd::Start));
+    assert!(fs.is_critical);
+
+    let ff = lines.iter().find(|l| l.to_task == "docs").unwrap();
+    assert_eq!((ff.from_end, ff.to_end), (BarEnd::Finish, BarEnd::Finish));
+    assert!(!ff.is_critical);
+}
+
+// =============================================================================
+// Schedule query builder
+// =============================================================================
+
+/// design (Jan 6-10) -> build, test (Jan 13-15) are critical; docs has slack
+fn schedule_query_project() -> Project {
+    let mut project = Project::new("Query");
+    project.start = date(2025, 1, 6);
+    project.resources = vec![Resource::new("alice"), Resource::new("bob")];
+    project.tasks = vec![
+        Task::new("design")
+            .duration(Duration::days(5))
+            .assign("alice"),
+        Task::new("build")
+            .duration(Duration::days(3))
+            .assign("alice")
+            .depends_on("design"),
+        Task::new("test")
+            .duration(Duration::days(3))
+            .assign("bob")
+            .depends_on("design"),
+        Task::new("docs")
+            .duration(Duration::days(2))
+            .assign("alice")
+            .complete(100.0),
+    ];
+    project
+}
+
+fn ids(tasks: &[&utf8proj_core::ScheduledTask]) -> Vec<String> {
+    tasks.iter().map(|t| t.task_id.clone()).collect()
+}
+
+#[test]
+fn query_composes_critical_date_and_resource() {
+    let schedule = CpmSolver::new()
+        .schedule(&schedule_query_project())
+        .unwrap();
+
+    let hits = schedule
+        .query()
+        .critical()
+        .between(date(2025, 1, 13), date(2025, 1, 20))
+        .assigned_to("alice")
+        .collect();
+
+    assert_eq!(ids(&hits), vec!["build"]);
+}
+
+#[test]
+fn query_without_predicates_returns_all_in_start_order() {
+    let schedule = CpmSolver::new()
+        .schedule(&schedule_query_project())
+        .unwrap();
+
+    let hits = schedule.query().collect();
+
+    assert_eq!(ids(&hits), vec!["design", "docs", "build", "test"]);
+}
+
+#[test]
+fn query_date_overlap_is_inclusive() {
+    let schedule = CpmSolver::new()
+        .schedule(&schedule_query_project())
+        .unwrap();
+
+    // design finishes on Jan 10, so a range starting that day still matches
+    let hits = schedule
+        .query()
+        .between(date(2025, 1, 10), date(2025, 1, 10))
+        .collect();
+
+    assert_eq!(ids(&hits), vec!["design"]);
+}
+
+#[test]
+fn query_filters_by_status_and_custom_predicate() {
+    let schedule = CpmSolver::new()
+        .schedule(&schedule_query_project())
+        .unwrap();
+
+    assert_eq!(
+        ids(&schedule.query().status(TaskStatus::Complete).collect()),
+        vec!["docs"]
+    );
+    assert_eq!(
+        schedule
+            .query()
+            .assigned_to("alice")
+            .filter(|t| t.duration.as_days() >= 3.0)
+            .count(),
+        2
+    );
+}
+
+#[test]
+fn milestones_view_skips_zero_duration_tasks() {
+    let mut project = schedule_query_project();
+    project.tasks.extend([
+        Task::new("launch").milestone().depends_on("build"),
+        Task::new("kickoff").milestone(),
+        // Zero duration by accident, not a milestone
+        Task::new("placeholder").duration(Duration::days(0)),
+    ]);
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+
+    assert_eq!(
+        schedule.tasks["placeholder"].duration,
+        schedule.tasks["launch"].duration
+    );
+    assert_eq!(ids(&schedule.milestones()), vec!["kickoff", "launch"]);
+}
+
+#[test]
+fn query_selects_tagged_tasks_including_nested() {
+    let mut project = schedule_query_project();
+    project.tasks[1] = project.tasks[1].clone().tag("backend");
+    project.tasks.push(
+        Task::new("release").child(
+            Task::new("notes")
+                .duration(Duration::days(1))
+                .tag("docs")
+                .tag("backend"),
+        ),
+    );
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+
+    assert_eq!(
+        schedule.tasks["release.notes"].tags,
+        vec!["docs", "backend"]
+    );
+    assert_eq!(
+        ids(&schedule.query().tagged("backend").collect()),
+        vec!["release.notes", "build"]
+    );
+    assert_eq!(
+        ids(&schedule.query().tagged("backend").critical().collect()),
+        vec!["build"]
+    );
+    assert_eq!(schedule.query().tagged("frontend").count(), 0);
+}
+
+// =============================================================================
+// Schedule reports bundling diagnostics with the schedule
+// =============================================================================
+
+/// build is staffed by a developer profile rather than a person
+fn report() -> ScheduleReport {
+    let mut project = Project::new("Report");
+    project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+    project.profiles = vec![ResourceProfile::new("developer")
+        .rate_range(RateRange::new(Decimal::from(100), Decimal::from(200)))];
+    project.tasks = vec![Task::new("build")
+        .duration(Duration::days(5))
+        .assign("developer")];
+    schedule_with_diagnostics(&project, &CpmSolver::new(), &AnalysisConfig::new()).unwrap()
+}
+
+#[test]
+fn report_carries_abstract_assignment_warning() {
+    let report = report();
+
+    assert_eq!(report.schedule.tasks["build"].duration, Duration::days(5));
+    assert!(report
+        .diagnostics
+        .iter()
+        .any(|d| d.code == DiagnosticCode::W001AbstractAssignment));
+}
+
+#[test]
+fn report_round_trips_through_json() {
+    let report = report();
+
+    let json = serde_json::to_value(&report).unwrap();
+    let w001 = json["diagnostics"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|d| d["code"] == "W001")
+        .expect("W001 is serialized by its code");
+    assert_eq!(w001["severity"], "warning");
+
+    let restored: ScheduleReport = serde_json::from_value(json).unwrap();
+    assert_eq!(restored.schedule.project_end, report.schedule.project_end);
+    assert_eq!(restored.diagnostics.len(), report.diagnostics.len());
+    let restored_w001 = restored
+        .diagnostics
+        .iter()
+        .find(|d| d.code == DiagnosticCode::W001AbstractAssignment)
+        .unwrap();
+    assert_eq!(restored_w001.severity, Severity::Warning);
+    assert!(restored_w001.message.contains("build"));
+}
+
+#[test]
+fn unknown_codes_are_rejected() {
+    let mut json = serde_json::to_value(report()).unwrap();
+    json["diagnostics"][0]["code"] = "W999".into();
+
+    let error = serde_json::from_value::<ScheduleReport>(json).unwrap_err();
+
+    assert!(error.to_string().contains("unknown diagnostic code"));
+}
+
+// =============================================================================
+// Slack-based risk scoring
+// =============================================================================
+
+/// design (contractor) -> build (alice) is critical; docs and notes have slack
+fn risk_scores_schedule() -> Schedule {
+    let mut project = Project::new("Risk Scores");
+    project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+    project
+        .resources
+        .push(Resource::new("alice").rate(Money::new(Decimal::from(500), "USD")));
+    project.profiles.push(
+        ResourceProfile::new("contractor")
+            .rate_range(RateRange::new(Decimal::from(400), Decimal::from(800))),
+    );
+    project.tasks = vec![
+        Task::new("design")
+            .duration(Duration::days(3))
+            .assign("contractor"),
+        Task::new("build")
+            .duration(Duration::days(2))
+            .assign("alice")
+            .depends_on("design"),
+        Task::new("docs")
+            .duration(Duration::days(1))
+            .assign("contractor"),
+        Task::new("notes").duration(Duration::days(1)),
+    ];
+    CpmSolver::new().schedule(&project).unwrap()
+}
+
+fn ranked(scores: &std::collections::HashMap<String, f64>) -> Vec<&str> {
+    let mut ids: Vec<&str> = scores.keys().map(String::as_str).collect();
+    ids.sort_by(|a, b| scores[*b].total_cmp(&scores[*a]).then_with(|| a.cmp(b)));
+    ids
+}
+
+#[test]
+fn zero_slack_wide_range_tasks_score_highest() {
+    let schedule = risk_scores_schedule();
+    let scores = schedule.risk_scores();
+
+    assert_eq!(ranked(&scores), vec!["design", "build", "docs", "notes"]);
+    assert!((scores["design"] - 1.0).abs() < 1e-9);
+    assert!(scores["notes"].abs() < 1e-9);
+    assert!(scores.values().all(|score| (0.0..=1.0).contains(score)));
+}
+
+#[test]
+fn weights_change_the_ranking() {
+    let schedule = risk_scores_schedule();
+    let cost_only = RiskWeights {
+        slack: 0.0,
+        cost_spread: 1.0,
+        criticality: 0.0,
+    };
+    let scores = schedule.risk_scores_with(&cost_only);
+
+    assert!((scores["docs"] - scores["design"]).abs() < 1e-9);
+    assert!(scores["docs"] > scores["build"]);
+}
+
+#[test]
+fn risk_index_is_mean_task_score() {
+    let schedule = risk_scores_schedule();
+
+    // (1.0 + 0.75 + 0.25 + 0.0) / 4
+    assert!((schedule.risk_index() - 0.5).abs() < 1e-9);
+}
@@ -3,8 +3,15 @@
 //! These tests verify that baseline vs forecast variance is computed correctly.
 
 use chrono::NaiveDate;
-use utf8proj_core::{Duration, Money, Project, Scheduler, Task};
-use utf8proj_solver::CpmSolver;
+use utf8proj_core::{
+    baseline::{extract_leaf_tasks, Baseline},
+    DiagnosticCode, Duration, Money, Project, Scheduler, Severity, Task,
+};
+use utf8proj_solver::{analyze_project, AnalysisConfig, CpmSolver};
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
 
 /// Test that tasks with no progress have zero variance
 #[test]
@@ -151,11 +158,11 @@ fn resource_parallelism_correct_variance() {
             .rate(Money::new(100, "USD")),
     );
 
-    // 10d effort with 2 resources = 5d duration
+    // 70h effort (10 working days) with 2 resources = 5d duration
     // No progress = no variance
     let task = Task::new("work")
         .name("Work")
-        .effort(Duration::days(10))
+        .effort(Duration::hours(70))
         .assign("dev1")
         .assign("dev2");
     project.tasks.push(task);
@@ -353,3 +360,161 @@ fn project_variance_ahead_of_schedule() {
         schedule.project_variance_days
     );
 }
+
+// =============================================================================
+// Attaching a saved baseline to a project
+// =============================================================================
+
+/// phase.design (Jan 6-8) -> phase.build (Jan 9-10)
+fn baseline_roundtrip_project(build_days: i64) -> Project {
+    let mut project = Project::new("Roundtrip");
+    project.start = date(2025, 1, 6);
+    project.tasks = vec![Task::new("phase")
+        .child(Task::new("design").duration(Duration::days(3)))
+        .child(
+            Task::new("build")
+                .duration(Duration::days(build_days))
+                .depends_on("design"),
+        )];
+    project
+}
+
+fn saved_baseline() -> Baseline {
+    let project = baseline_roundtrip_project(2);
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+    let baseline = Baseline::capture("original", &schedule, &project);
+    let json = serde_json::to_string(&baseline).unwrap();
+    serde_json::from_str(&json).unwrap()
+}
+
+#[test]
+fn captured_baseline_survives_json_round_trip() {
+    let project = baseline_roundtrip_project(2);
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+    let baseline = Baseline::capture("original", &schedule, &project);
+
+    let json = serde_json::to_string(&baseline).unwrap();
+    let reloaded: Baseline = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(reloaded, baseline);
+    assert_eq!(reloaded.project_finish, date(2025, 1, 10));
+    assert_eq!(reloaded.tasks["phase.build"].start, date(2025, 1, 9));
+    assert!(!reloaded.tasks.contains_key("phase"));
+}
+
+#[test]
+fn attached_baseline_measures_variance() {
+    let mut project = baseline_roundtrip_project(5);
+    project.baseline = Some(saved_baseline());
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+
+    let build = &schedule.tasks["phase.build"];
+    assert_eq!(build.baseline_start, date(2025, 1, 9));
+    assert_eq!(build.baseline_finish, date(2025, 1, 10));
+    assert_eq!(build.finish_variance_days, 5);
+    assert_eq!(schedule.project_baseline_finish, date(2025, 1, 10));
+    assert_eq!(schedule.project_variance_days, 5);
+}
+
+#[test]
+fn containers_span_attached_baseline_dates() {
+    let mut project = baseline_roundtrip_project(5);
+    project.baseline = Some(saved_baseline());
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+
+    let phase = &schedule.tasks["phase"];
+    assert_eq!(phase.baseline_start, date(2025, 1, 6));
+    assert_eq!(phase.baseline_finish, date(2025, 1, 10));
+}
+
+#[test]
+fn missing_baseline_tasks_warn() {
+    let mut project = baseline_roundtrip_project(2);
+    project.tasks[0].children.retain(|t| t.id != "build");
+    project.baseline = Some(saved_baseline());
+    let schedule = CpmSolver::new().schedule(&project).unwrap();
+
+    let mut emitter = utf8proj_core::CollectingEmitter::new();
+    analyze_project(
+        &project,
+        Some(&schedule),
+        &AnalysisConfig::default(),
+        &mut emitter,
+    );
+
+    let removed: Vec<_> = emitter
+        .diagnostics
+        .iter()
+        .filter(|d| d.code == DiagnosticCode::B005TaskRemoved)
+        .collect();
+    assert_eq!(removed.len(), 1);
+    assert_eq!(removed[0].severity, Severity::Warning);
+    assert!(removed[0].message.contains("'phase.build'"));
+}
+
+// =============================================================================
+// Milestone slip tracking against baselines
+// =============================================================================
+
+fn milestone_slips_project(design_days: i64, qa_days: i64) -> Project {
+    let mut project = Project::new("Milestones");
+    project.start = date(2025, 1, 6);
+    project.tasks = vec![
+        Task::new("design").duration(Duration::days(design_days)),
+        Task::new("design_done").milestone().depends_on("design"),
+        Task::new("qa").duration(Duration::days(qa_days)),
+        Task::new("qa_done").milestone().depends_on("qa"),
+        Task::new("kickoff").milestone(),
+    ];
+    project
+}
+
+fn baseline_of(project: &Project) -> Baseline {
+    let schedule = CpmSolver::new().schedule(project).unwrap();
+    let mut baseline = Baseline::new("original");
+    for snapshot in extract_leaf_tasks(&schedule, project).into_values() {
+        baseline.add_task(snapshot);
+    }
+    baseline
+}
+
+#[test]
+fn milestone_slips_report_direction_and_sort_worst_first() {
+    let baseline = baseline_of(&milestone_slips_project(5, 10));
+
+    // Design slips a week; QA is pulled in two working days (four calendar
+    // days, since the original milestone fell after a weekend)
+    let current = milestone_slips_project(10, 8);
+    let schedule = CpmSolver::new().schedule(&current).unwrap();
+    let slips = schedule.milestone_slips(&baseline);
+
+    let ids: Vec<&str> = slips.iter().map(|s| s.task_id.as_str()).collect();
+    assert_eq!(ids, vec!["design_done", "qa_done", "kickoff"]);
+
+    let design = &slips[0];
+    assert_eq!(design.baseline_date, baseline.tasks["design_done"].finish);
+    assert_eq!(
+        design.forecast_date,
+        schedule.tasks["design_done"].forecast_finish
+    );
+    assert_eq!(design.slip_days, 7);
+
+    assert_eq!(slips[1].slip_days, -4);
+    assert_eq!(slips[2].slip_days, 0);
+}
+
+#[test]
+fn milestone_slips_skip_milestones_added_after_baseline() {
+    let baseline = baseline_of(&milestone_slips_project(5, 10));
+
+    let mut current = milestone_slips_project(5, 10);
+    current
+        .tasks
+        .push(Task::new("launch").milestone().depends_on("qa_done"));
+    let schedule = CpmSolver::new().schedule(&current).unwrap();
+
+    let slips = schedule.milestone_slips(&baseline);
+    assert_eq!(slips.len(), 3);
+    assert!(slips.iter().all(|s| s.task_id != "launch"));
+    assert!(slips.iter().all(|s| s.slip_days == 0));
+}
@@ -0,0 +1,461 @@
+//! Tests for what-if analysis
+//!
+//! Covers batched changes (`CpmSolver::what_if_batch`), task insertion,
+//! resource substitution, compress-to-deadline, plan comparison and
+//! start-date stress testing.
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use utf8proj_core::{
+    Constraint, Duration, Money, Project, RateRange, Resource, ResourceProfile, ResourceRef,
+    Scheduler, Task, TaskConstraint,
+};
+use utf8proj_solver::{
+    compare_plans, compress_to, stress_test_start_date, BreakKind, CompressionSuggestion, CpmSolver,
+};
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+/// a (5d) -> c (2d) is critical; b (3d, alice at 100/day) has slack
+fn batch_project() -> Project {
+    let mut project = Project::new("What If");
+    project.start = date(2025, 1, 6);
+    project
+        .resources
+        .push(Resource::new("alice").rate(Money::new(Decimal::from(100), "USD")));
+    project.tasks = vec![
+        Task::new("a").duration(Duration::days(5)),
+        Task::new("b").duration(Duration::days(3)).assign("alice"),
+        Task::new("c").duration(Duration::days(2)).depends_on("a"),
+    ];
+    project
+}
+
+fn stretch_b() -> Constraint {
+    Constraint::TaskDuration {
+        task_id: "b".into(),
+        duration: Duration::days(10),
+    }
+}
+
+#[test]
+fn batch_reports_combined_effect() {
+    let changes = [
+        stretch_b(),
+        Constraint::TaskDuration {
+            task_id: "c".into(),
+            duration: Duration::days(1),
+        },
+    ];
+
+    let report = CpmSolver::new().what_if_batch(&batch_project(), &changes);
+
+    assert!(report.still_feasible);
+    assert!(!report.infeasible_in_combination);
+    assert_eq!(report.newly_critical, vec!["b".to_string()]);
+    // 7 days (a + c) becomes 10 days (b)
+    assert_eq!(report.schedule_delta, Some(Duration::days(3)));
+    // b costs 300 before and 1000 after
+    let cost = report.cost_delta.unwrap();
+    assert_eq!(cost.amount, Decimal::from(700));
+    assert_eq!(cost.currency, "USD");
+}
+
+#[test]
+fn batch_flags_infeasible_combination() {
+    // Each change alone keeps the plan feasible; together b misses the deadline
+    let changes = [
+        stretch_b(),
+        Constraint::Deadline {
+            date: date(2025, 1, 14),
+        },
+    ];
+
+    let report = CpmSolver::new().what_if_batch(&batch_project(), &changes);
+
+    assert!(!report.still_feasible);
+    assert!(report.infeasible_in_combination);
+    assert_eq!(report.solutions_before, 1u32.into());
+    assert_eq!(report.solutions_after, 0u32.into());
+    assert_eq!(report.schedule_delta, None);
+}
+
+#[test]
+fn batch_with_unknown_task_is_infeasible_on_its_own() {
+    let changes = [
+        stretch_b(),
+        Constraint::TaskEffort {
+            task_id: "missing".into(),
+            effort: Duration::days(1),
+        },
+    ];
+
+    let report = CpmSolver::new().what_if_batch(&batch_project(), &changes);
+
+    assert!(!report.still_feasible);
+    assert!(!report.infeasible_in_combination);
+}
+
+// =============================================================================
+// What-if task insertion
+// =============================================================================
+
+/// phase { a (2d, alice) -> b (2d) } -> c (2d), a linear 6-day chain
+fn what_if_add_task_project() -> Project {
+    let mut project = Project::new("Insert Task");
+    project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+    project
+        .resources
+        .push(Resource::new("alice").rate(Money::new(Decimal::from(100), "USD")));
+    project.tasks = vec![
+        Task::new("phase")
+            .child(Task::new("a").duration(Duration::days(2)).assign("alice"))
+            .child(Task::new("b").duration(Duration::days(2)).depends_on("a")),
+        Task::new("c")
+            .duration(Duration::days(2))
+            .depends_on("phase.b"),
+    ];
+    project
+}
+
+fn qa() -> Task {
+    Task::new("qa").duration(Duration::days(4)).assign("alice")
+}
+
+#[test]
+fn inserting_into_a_chain_extends_the_project() {
+    let report = CpmSolver::new().what_if_add_task(
+        &what_if_add_task_project(),
+        qa(),
+        &["phase.b".to_string()],
+        None,
+    );
+
+    assert!(report.still_feasible);
+    // qa runs 4 days after b, where c ran 2
+    assert_eq!(report.schedule_delta, Some(Duration::days(2)));
+    assert_eq!(report.newly_critical, vec!["qa".to_string()]);
+    // 4 days of alice at 100/day
+    let cost = report.cost_delta.unwrap();
+    assert_eq!(cost.amount, Decimal::from(400));
+}
+
+#[test]
+fn task_can_be_placed_in_a_container() {
+    let report = CpmSolver::new().what_if_add_task(
+        &what_if_add_task_project(),
+        qa(),
+        &["phase.b".to_string()],
+        Some("phase"),
+    );
+
+    assert!(report.still_feasible);
+    assert!(report.newly_critical.contains(&"phase.qa".to_string()));
+    assert_eq!(report.schedule_delta, Some(Duration::days(2)));
+}
+
+#[test]
+fn unknown_parent_is_infeasible() {
+    let report =
+        CpmSolver::new().what_if_add_task(&what_if_add_task_project(), qa(), &[], Some("missing"));
+
+    assert!(!report.still_feasible);
+    assert_eq!(report.schedule_delta, None);
+}
+
+#[test]
+fn task_can_be_placed_in_a_nested_container() {
+    let mut project = what_if_add_task_project();
+    project.tasks[0]
+        .children
+        .push(Task::new("review").child(Task::new("notes").duration(Duration::days(1))));
+
+    let report = CpmSolver::new().what_if_add_task(
+        &project,
+        qa(),
+        &["phase.b".to_string()],
+        Some("phase.review"),
+    );
+
+    assert!(report.still_feasible);
+    assert!(report
+        .newly_critical
+        .contains(&"phase.review.qa".to_string()));
+}
+
+// =============================================================================
+// What-if resource substitution
+// =============================================================================
+
+/// build (5d, senior) runs alongside docs (8d)
+fn what_if_substitute_project() -> Project {
+    let mut project = Project::new("Substitute");
+    project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+    project.profiles = vec![
+        ResourceProfile::new("senior")
+            .rate_range(RateRange::new(Decimal::from(800), Decimal::from(1200))),
+        ResourceProfile::new("junior")
+            .rate_range(RateRange::new(Decimal::from(300), Decimal::from(500))),
+    ];
+    project.resources = vec![
+        Resource::new("alice"),
+        Resource::new("bob").availability(0.5),
+    ];
+    project.tasks = vec![
+        Task::new("build")
+            .duration(Duration::days(5))
+            .assign("senior"),
+        Task::new("docs").duration(Duration::days(8)),
+    ];
+    project
+}
+
+#[test]
+fn junior_substitute_lowers_the_cost() {
+    let project = what_if_substitute_project();
+
+    let report = CpmSolver::new().what_if_substitute(&project, "senior", "junior");
+
+    assert!(report.still_feasible);
+    // 5 days at $1,000 expected become 5 days at $400
+    let delta = report.cost_delta.expect("both plans are costed");
+    assert_eq!(delta.amount, Decimal::from(-3000));
+    assert_eq!(report.schedule_delta, Some(Duration::zero()));
+    assert!(!report.critical_path_changed);
+    assert!(report.newly_critical.is_empty());
+}
+
+#[test]
+fn slower_substitute_changes_the_critical_path() {
+    let mut project = what_if_substitute_project();
+    project.tasks[0] = Task::new("build")
+        .effort(Duration::hours(35))
+        .assign("alice");
+
+    // At half availability, 5 days of effort take 10 days
+    let report = CpmSolver::new().what_if_substitute(&project, "alice", "bob");
+
+    assert!(report.still_feasible);
+    assert!(report.critical_path_changed);
+    assert_eq!(report.newly_critical, vec!["build".to_string()]);
+    assert_eq!(report.schedule_delta, Some(Duration::days(2)));
+}
+
+#[test]
+fn unknown_substitute_is_infeasible() {
+    let report =
+        CpmSolver::new().what_if_substitute(&what_if_substitute_project(), "senior", "principal");
+    assert!(!report.still_feasible);
+    assert!(report.cost_delta.is_none());
+}
+
+#[test]
+fn original_project_is_unchanged() {
+    let project = what_if_substitute_project();
+    CpmSolver::new().what_if_substitute(&project, "senior", "junior");
+    assert_eq!(
+        project.get_task("build").unwrap().assigned[0].resource_id,
+        "senior"
+    );
+}
+
+// =============================================================================
+// Compress-to-deadline assistant
+// =============================================================================
+
+/// design (2d) -> build (70h = 10 days of effort, alice) -> ship (1d), from Mon Jan 6
+fn project() -> Project {
+    let mut project = Project::new("Compress");
+    project.start = date(2025, 1, 6);
+    project.resources = vec![Resource::new("alice"), Resource::new("bob")];
+    project.tasks = vec![
+        Task::new("design").duration(Duration::days(2)),
+        Task::new("build")
+            .effort(Duration::hours(70))
+            .assign("alice")
+            .depends_on("design"),
+        Task::new("ship")
+            .duration(Duration::days(1))
+            .depends_on("build"),
+    ];
+    project
+}
+
+#[test]
+fn second_resource_on_one_task_meets_deadline() {
+    let project = project();
+    let solver = CpmSolver::new();
+    // 13 working days end Wed Jan 22; the deadline allows 8
+    assert_eq!(
+        solver.schedule(&project).unwrap().project_end,
+        date(2025, 1, 22)
+    );
+    let deadline = date(2025, 1, 15);
+
+    let suggestions = compress_to(&project, &solver, deadline);
+
+    assert_eq!(
+        suggestions,
+        vec![CompressionSuggestion {
+            task_id: "build".into(),
+            added_units: 1.0,
+            days_saved: 5,
+        }]
+    );
+
+    // Following the suggestion meets the deadline
+    let mut staffed = project.clone();
+    staffed
+        .get_task_mut("build")
+        .unwrap()
+        .assigned
+        .push(ResourceRef {
+            resource_id: "bob".into(),
+            units: 1.0,
+        });
+    assert!(solver.schedule(&staffed).unwrap().project_end <= deadline);
+}
+
+#[test]
+fn plan_meeting_deadline_needs_nothing() {
+    let suggestions = compress_to(&project(), &CpmSolver::new(), date(2025, 1, 31));
+
+    assert!(suggestions.is_empty());
+}
+
+#[test]
+fn fixed_duration_tasks_are_not_compressed() {
+    let mut project = project();
+    let build = project.get_task_mut("build").unwrap();
+    build.effort = None;
+    build.duration = Some(Duration::days(10));
+
+    let suggestions = compress_to(&project, &CpmSolver::new(), date(2025, 1, 15));
+
+    assert!(suggestions.is_empty());
+}
+
+// =============================================================================
+// Comparing candidate plans
+// =============================================================================
+
+/// api and ui run in parallel; ui is staffed by `ui_owner`
+fn make_plan(name: &str, ui_owner: &str) -> Project {
+    let mut project = Project::new(name);
+    project.start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+    project.resources = vec![
+        Resource::new("alice").rate(Money::new(Decimal::from(500), "USD")),
+        Resource::new("bob").rate(Money::new(Decimal::from(400), "USD")),
+    ];
+    project.tasks = vec![
+        Task::new("api").duration(Duration::days(5)).assign("alice"),
+        Task::new("ui").duration(Duration::days(3)).assign(ui_owner),
+    ];
+    project
+}
+
+#[test]
+fn plans_with_different_staffing_are_tabulated() {
+    let solo = make_plan("Solo", "alice");
+    let pair = make_plan("Pair", "bob");
+
+    let summaries = compare_plans(&[&solo, &pair], &CpmSolver::new());
+
+    assert_eq!(summaries.len(), 2);
+    let (solo, pair) = (&summaries[0], &summaries[1]);
+
+    assert_eq!(solo.name, "Solo");
+    assert!(solo.feasible);
+    assert_eq!(solo.makespan, Some(Duration::days(5)));
+    assert_eq!(
+        solo.expected_cost.as_ref().unwrap().amount,
+        Decimal::from(4000)
+    );
+    assert_eq!(solo.critical_tasks, 1);
+    assert_eq!(solo.peak_overallocation, 1.0);
+
+    assert_eq!(pair.name, "Pair");
+    assert!(pair.feasible);
+    assert_eq!(pair.makespan, Some(Duration::days(5)));
+    assert_eq!(
+        pair.expected_cost.as_ref().unwrap().amount,
+        Decimal::from(3700)
+    );
+    assert_eq!(pair.peak_overallocation, 0.0);
+}
+
+#[test]
+fn infeasible_plans_keep_their_conflict_reason() {
+    let feasible = make_plan("Feasible", "bob");
+    let mut late = make_plan("Late", "bob");
+    late.end = NaiveDate::from_ymd_opt(2025, 1, 8);
+
+    let summaries = compare_plans(&[&feasible, &late], &CpmSolver::new());
+
+    assert!(summaries[0].feasible);
+    let late = &summaries[1];
+    assert_eq!(late.name, "Late");
+    assert!(!late.feasible);
+    assert!(late
+        .conflict
+        .as_ref()
+        .unwrap()
+        .contains("after its end date"));
+    assert_eq!(late.makespan, None);
+}
+
+// =============================================================================
+// Start-date stress testing
+// =============================================================================
+
+/// design (5d) -> build (5d, due Fri Jan 24), from Mon Jan 6
+fn start_date_stress_project() -> Project {
+    let mut project = Project::new("Stress");
+    project.start = date(2025, 1, 6);
+    project.tasks = vec![
+        Task::new("design").duration(Duration::days(5)),
+        Task::new("build")
+            .duration(Duration::days(5))
+            .depends_on("design")
+            .constraint(TaskConstraint::FinishNoLaterThan(date(2025, 1, 24))),
+    ];
+    project
+}
+
+#[test]
+fn delayed_start_breaks_the_deadline() {
+    let project = start_date_stress_project();
+
+    // Two weeks later, build would finish Fri Jan 31
+    let broken = stress_test_start_date(&project, date(2025, 1, 20), &CpmSolver::new());
+
+    assert_eq!(broken.len(), 1);
+    assert_eq!(broken[0].task_id, "build");
+    assert_eq!(broken[0].kind, BreakKind::Infeasible);
+    assert!(matches!(
+        broken[0].constraint,
+        TaskConstraint::FinishNoLaterThan(_)
+    ));
+    assert_eq!(broken[0].detail, "finishes 2025-01-31, after 2025-01-24");
+}
+
+#[test]
+fn smaller_delay_makes_the_deadline_binding() {
+    let project = start_date_stress_project();
+
+    // One week later, build finishes exactly on its deadline
+    let broken = stress_test_start_date(&project, date(2025, 1, 13), &CpmSolver::new());
+
+    assert_eq!(broken.len(), 1);
+    assert_eq!(broken[0].task_id, "build");
+    assert_eq!(broken[0].kind, BreakKind::NewlyBinding);
+}
+
+#[test]
+fn current_start_breaks_nothing() {
+    let project = start_date_stress_project();
+    let broken = stress_test_start_date(&project, project.start, &CpmSolver::new());
+    assert!(broken.is_empty());
+}
@@ -308,12 +308,7 @@ fn calculate_calendar_impact(
     use chrono::Datelike;
 
     // Get the project calendar
-    let calendar = project
-        .calendars
-        .iter()
-        .find(|c| c.id == project.calendar)
-        .cloned()
-        .unwrap_or_default();
+    let calendar = project.default_calendar();
 
     let calendar_days = (finish - start).num_days() + 1;
     let mut weekend_days = 0u32;
@@ -878,7 +873,7 @@ impl Playground {
 
         // Apply resource leveling if enabled (RFC-0003, RFC-0014)
         let schedule = if self.resource_leveling {
-            let calendar = project.calendars.first().cloned().unwrap_or_default();
+            let calendar = project.default_calendar();
 
             // Resolve optimal leveling: CLI settings override, then project-level config
             let use_optimal =